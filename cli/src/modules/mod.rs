@@ -29,6 +29,7 @@ pub mod ping;
 pub mod pssb;
 pub mod reload;
 pub mod rpc;
+pub mod script;
 pub mod select;
 pub mod send;
 pub mod server;
@@ -58,8 +59,8 @@ pub fn register_handlers(cli: &Arc<SpectreCli>) -> Result<()> {
         cli.handlers(),
         [
             account, address, close, connect, details, disconnect, estimate, exit, export, guide, help, history, rpc, list, miner,
-            message, monitor, mute, network, node, open, ping, pssb, reload, select, send, server, settings, sweep, track, transfer,
-            wallet,
+            message, monitor, mute, network, node, open, ping, pssb, reload, script, select, send, server, settings, sweep, track,
+            transfer, wallet,
             // halt,
             // theme,  start, stop
         ]
@@ -131,7 +131,7 @@ impl Rpc {
                 let result = rpc
                     .get_virtual_chain_from_block_call(
                         None,
-                        GetVirtualChainFromBlockRequest { start_hash, include_accepted_transaction_ids },
+                        GetVirtualChainFromBlockRequest::new(start_hash, include_accepted_transaction_ids),
                     )
                     .await?;
                 self.println(&ctx, result);
@@ -165,7 +165,7 @@ impl Rpc {
                     return Err(Error::custom("Please specify at least one address"));
                 }
                 let addresses = argv.iter().map(|s| Address::try_from(s.as_str())).collect::<std::result::Result<Vec<_>, _>>()?;
-                let result = rpc.get_utxos_by_addresses_call(None, GetUtxosByAddressesRequest { addresses }).await?;
+                let result = rpc.get_utxos_by_addresses_call(None, GetUtxosByAddressesRequest::new(addresses)).await?;
                 self.println(&ctx, result);
             }
             RpcApiOps::GetBalanceByAddress => {
@@ -284,6 +284,129 @@ impl Rpc {
 
                 self.println(&ctx, result);
             }
+            RpcApiOps::PrioritizeTransaction => {
+                if argv.len() < 2 {
+                    return Err(Error::custom("Please specify a txid and a fee delta in sompi, optionally followed by 'exclude'"));
+                }
+
+                let transaction_id = argv.remove(0);
+                let transaction_id = RpcTransactionId::from_hex(transaction_id.as_str())?;
+
+                let fee_delta = argv.remove(0).parse::<i64>()?;
+                let excluded = argv.first().is_some_and(|arg| arg == "exclude");
+
+                rpc.prioritize_transaction(transaction_id, fee_delta, excluded).await?;
+                tprintln!(ctx, "ok");
+            }
+            RpcApiOps::GetPrioritizedTransactions => {
+                let result = rpc.get_prioritized_transactions_call(None, GetPrioritizedTransactionsRequest {}).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::InvalidateBlock => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Missing block hash argument"));
+                }
+                let hash = argv.remove(0);
+                let hash = RpcHash::from_hex(hash.as_str())?;
+                rpc.invalidate_block(hash).await?;
+                tprintln!(ctx, "ok");
+            }
+            RpcApiOps::ReconsiderBlock => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Missing block hash argument"));
+                }
+                let hash = argv.remove(0);
+                let hash = RpcHash::from_hex(hash.as_str())?;
+                rpc.reconsider_block(hash).await?;
+                tprintln!(ctx, "ok");
+            }
+            RpcApiOps::GenerateBlocks => {
+                if argv.len() < 2 {
+                    return Err(Error::custom("Usage: rpc generate-blocks <count> <pay address> [<transaction id> ...]"));
+                }
+                let count = argv.remove(0).parse::<u32>()?;
+                let pay_address = Address::try_from(argv.remove(0).as_str())?;
+                let transaction_ids =
+                    argv.iter().map(|id| RpcTransactionId::from_hex(id.as_str())).collect::<std::result::Result<Vec<_>, _>>()?;
+                let block_hashes = rpc.generate_blocks(count, pay_address, transaction_ids).await?;
+                block_hashes.iter().for_each(|hash| tprintln!(ctx, "{hash}"));
+            }
+            RpcApiOps::CreateBackup => {
+                let path = rpc.create_backup().await?;
+                tprintln!(ctx, "Backup created at {path}");
+            }
+            RpcApiOps::GetDatabaseStats => {
+                let GetDatabaseStatsResponse { databases, cache_stats } = rpc.get_database_stats().await?;
+                for db in databases {
+                    tprintln!(
+                        ctx,
+                        "{}: ~{} keys, {} bytes in table files, {} bytes in memtables, {} bytes pending compaction, files per level {:?}",
+                        db.name,
+                        db.estimated_key_count,
+                        db.total_sst_files_size,
+                        db.memtables_size,
+                        db.pending_compaction_bytes,
+                        db.files_per_level
+                    );
+                    for store in db.stores {
+                        tprintln!(ctx, "    {}: ~{} keys, ~{} bytes", store.name, store.approximate_key_count, store.approximate_size);
+                    }
+                }
+                tprintln!(ctx, "cache:");
+                for stats in cache_stats {
+                    tprintln!(
+                        ctx,
+                        "    {}: {} hits, {} misses ({:.2}% hit rate)",
+                        stats.name,
+                        stats.hits,
+                        stats.misses,
+                        stats.hit_rate() * 100.0
+                    );
+                }
+            }
+            RpcApiOps::GetChainBlockByBlueScore => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Missing blue score argument"));
+                }
+                let blue_score = argv.remove(0).parse::<u64>()?;
+                let result = rpc.get_chain_block_by_blue_score(blue_score).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetChainBlockByDaaScore => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Missing DAA score argument"));
+                }
+                let daa_score = argv.remove(0).parse::<u64>()?;
+                let result = rpc.get_chain_block_by_daa_score(daa_score).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetChainBlocksByBlueScoreRange => {
+                if argv.len() != 2 {
+                    return Err(Error::custom("Please specify a low and a high blue score"));
+                }
+                let low_blue_score = argv.remove(0).parse::<u64>()?;
+                let high_blue_score = argv.remove(0).parse::<u64>()?;
+                let result = rpc.get_chain_blocks_by_blue_score_range(low_blue_score, high_blue_score).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetChainBlocksByDaaScoreRange => {
+                if argv.len() != 2 {
+                    return Err(Error::custom("Please specify a low and a high DAA score"));
+                }
+                let low_daa_score = argv.remove(0).parse::<u64>()?;
+                let high_daa_score = argv.remove(0).parse::<u64>()?;
+                let result = rpc.get_chain_blocks_by_daa_score_range(low_daa_score, high_daa_score).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::DecodeTransaction => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Usage: rpc decode-transaction <hex> [true|false for 'verify_scripts']"));
+                }
+                let transaction = RpcSerializedTransaction::Hex(argv.remove(0));
+                let verify_scripts = argv.first().and_then(|x| x.parse::<bool>().ok()).unwrap_or_default();
+                let result = rpc.decode_transaction(transaction, verify_scripts, vec![]).await?;
+                self.println(&ctx, result);
+            }
             _ => {
                 tprintln!(ctx, "rpc method exists but is not supported by the cli: '{op_str}'\r\n");
                 return Ok(());
@@ -0,0 +1,51 @@
+use crate::imports::*;
+use spectre_txscript::{
+    pay_to_address_script,
+    script_asm::{assemble, disassemble},
+};
+
+#[derive(Default, Handler)]
+#[help("Disassemble a script to its text format or assemble a script from its text format")]
+pub struct Script;
+
+impl Script {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<SpectreCli>()?;
+
+        if argv.len() < 2 {
+            return self.display_help(ctx, argv).await;
+        }
+
+        match argv[0].as_str() {
+            "disasm" => {
+                let script = match Address::try_from(argv[1].as_str()) {
+                    Ok(address) => pay_to_address_script(&address).script().to_vec(),
+                    Err(_) => Vec::<u8>::from_hex(argv[1].as_str())?,
+                };
+                tprintln!(ctx, "{}", disassemble(&script));
+            }
+            "asm" => {
+                let script = assemble(&argv[1..].join(" ")).map_err(|err| Error::custom(err.to_string()))?;
+                tprintln!(ctx, "{}", script.to_hex());
+            }
+            v => {
+                tprintln!(ctx, "Unknown command: '{v}'\r\n");
+                return self.display_help(ctx, argv).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<SpectreCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("disasm <script hex | address>", "Display the script in its text format, e.g. `0x<pubkey> OpCheckSig`"),
+                ("asm <script text>", "Assemble a script from its text format and display it as hex"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
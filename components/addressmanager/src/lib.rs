@@ -518,7 +518,7 @@ mod address_store_with_cache {
         use rv::{dist::Uniform, misc::ks_test as one_way_ks_test, traits::Cdf};
         use spectre_consensus_core::config::{params::SIMNET_PARAMS, Config};
         use spectre_core::task::tick::TickService;
-        use spectre_database::create_temp_db;
+        use spectre_database::create_memory_db;
         use spectre_database::prelude::ConnBuilder;
         use spectre_utils::networking::IpAddress;
         use std::net::{IpAddr, Ipv6Addr};
@@ -546,7 +546,7 @@ mod address_store_with_cache {
             // Assert that initial distribution is skewed, and hence not uniform from the outset.
             assert!(bucket_reduction_ratio >= 1.25);
 
-            let db = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+            let db = create_memory_db!(ConnBuilder::default().with_files_limit(10));
             let config = Config::new(SIMNET_PARAMS);
             let (am, _) = AddressManager::new(Arc::new(config), db.1, Arc::new(TickService::default()));
 
@@ -2,7 +2,8 @@ use itertools::Itertools;
 use parking_lot::RwLock;
 use spectre_consensus_core::api::{ConsensusApi, DynConsensus};
 use spectre_core::{core::Core, debug, service::Service};
-use std::{collections::VecDeque, ops::Deref, sync::Arc, thread::JoinHandle};
+use spectre_database::{prelude::StoreResult, stats::DbStats};
+use std::{collections::VecDeque, io, ops::Deref, path::Path, sync::Arc, thread::JoinHandle};
 
 mod batch;
 mod session;
@@ -24,6 +25,13 @@ pub trait ConsensusCtl: Sync + Send {
 
     /// Set as current active consensus
     fn make_active(&self);
+
+    /// Create a consistent checkpoint of the consensus database within `checkpoint_dir`, under the
+    /// directory name of the database
+    fn create_checkpoint(&self, checkpoint_dir: &Path) -> io::Result<()>;
+
+    /// Collect the statistics of the consensus database
+    fn database_stats(&self) -> StoreResult<DbStats>;
 }
 
 pub type DynConsensusCtl = Arc<dyn ConsensusCtl>;
@@ -44,6 +52,13 @@ pub trait ConsensusFactory: Sync + Send {
     /// Delete the staging consensus entry and its database (this is done even if the node is archival
     /// since staging reflects non-final data)
     fn delete_staging_entry(&self);
+
+    /// Create a consistent checkpoint of the management database and of the database of the `active` consensus
+    /// within `checkpoint_dir`, mirroring the layout of the database directories
+    fn create_checkpoint(&self, active: &dyn ConsensusCtl, checkpoint_dir: &Path) -> io::Result<()>;
+
+    /// Collect the statistics of the database of the `active` consensus and of the management database
+    fn database_stats(&self, active: &dyn ConsensusCtl) -> StoreResult<Vec<DbStats>>;
 }
 
 /// Test-only mock factory
@@ -69,6 +84,14 @@ impl ConsensusFactory for MockFactory {
     fn delete_staging_entry(&self) {
         unimplemented!()
     }
+
+    fn create_checkpoint(&self, active: &dyn ConsensusCtl, checkpoint_dir: &Path) -> io::Result<()> {
+        active.create_checkpoint(checkpoint_dir)
+    }
+
+    fn database_stats(&self, active: &dyn ConsensusCtl) -> StoreResult<Vec<DbStats>> {
+        Ok(vec![active.database_stats()?])
+    }
 }
 
 /// Defines a trait which handles consensus resets for external parts of the system. We avoid using
@@ -171,6 +194,21 @@ impl ConsensusManager {
     pub fn delete_staging_entry(&self) {
         self.factory.delete_staging_entry();
     }
+
+    /// Creates a consistent checkpoint of the consensus databases within `checkpoint_dir`. The active consensus
+    /// cannot be replaced by a staging consensus while the checkpoint is created.
+    ///
+    /// Staging consensus data is not final and is thus excluded from the checkpoint
+    pub fn create_checkpoint(&self, checkpoint_dir: &Path) -> io::Result<()> {
+        let g = self.inner.read();
+        self.factory.create_checkpoint(&*g.current.ctl, checkpoint_dir)
+    }
+
+    /// Collects the statistics of the active consensus database and of the management database
+    pub fn database_stats(&self) -> StoreResult<Vec<DbStats>> {
+        let ctl = self.inner.read().current.ctl.clone();
+        self.factory.database_stats(&*ctl)
+    }
 }
 
 impl Service for ConsensusManager {
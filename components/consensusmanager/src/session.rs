@@ -8,7 +8,7 @@ use spectre_consensus_core::{
     block::Block,
     blockstatus::BlockStatus,
     daa_score_timestamp::DaaScoreTimestamp,
-    errors::consensus::ConsensusResult,
+    errors::{consensus::ConsensusResult, tx::TxResult},
     header::Header,
     pruning::{PruningPointProof, PruningPointTrustedData, PruningPointsList},
     trusted::{ExternalGhostdagData, TrustedBlock},
@@ -230,6 +230,10 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(|c| c.get_stats()).await
     }
 
+    pub async fn async_verify_transaction_scripts(&self, transaction: MutableTransaction) -> Vec<TxResult<()>> {
+        self.clone().spawn_blocking(move |c| c.verify_transaction_scripts(&transaction)).await
+    }
+
     pub async fn async_get_virtual_merge_depth_root(&self) -> Option<Hash> {
         self.clone().spawn_blocking(|c| c.get_virtual_merge_depth_root()).await
     }
@@ -322,6 +326,21 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.get_populated_transaction(txid, accepting_block_daa_score)).await
     }
 
+    pub async fn async_get_chain_blocks_by_blue_score(&self, low: u64, high: u64, limit: usize) -> ConsensusResult<Vec<(u64, Hash)>> {
+        self.clone().spawn_blocking(move |c| c.get_chain_blocks_by_blue_score(low, high, limit)).await
+    }
+
+    pub async fn async_get_chain_blocks_by_daa_score(&self, low: u64, high: u64, limit: usize) -> ConsensusResult<Vec<(u64, Hash)>> {
+        self.clone().spawn_blocking(move |c| c.get_chain_blocks_by_daa_score(low, high, limit)).await
+    }
+
+    pub async fn async_get_populated_accepted_transactions(
+        &self,
+        accepting_block_hash: Hash,
+    ) -> Result<Vec<(Hash, SignableTransaction)>, UtxoInquirerError> {
+        self.clone().spawn_blocking(move |c| c.get_populated_accepted_transactions(accepting_block_hash)).await
+    }
+
     /// Returns the antipast of block `hash` from the POV of `context`, i.e. `antipast(hash) ∩ past(context)`.
     /// Since this might be an expensive operation for deep blocks, we allow the caller to specify a limit
     /// `max_traversal_allowed` on the maximum amount of blocks to traverse for obtaining the answer
@@ -457,6 +476,14 @@ impl ConsensusSessionOwned {
     pub async fn async_finality_point(&self) -> Hash {
         self.clone().spawn_blocking(move |c| c.finality_point()).await
     }
+
+    pub async fn async_invalidate_block(&self, hash: Hash) -> ConsensusResult<()> {
+        self.clone().spawn_blocking(move |c| c.invalidate_block(hash)).await
+    }
+
+    pub async fn async_reconsider_block(&self, hash: Hash) -> ConsensusResult<()> {
+        self.clone().spawn_blocking(move |c| c.reconsider_block(hash)).await
+    }
 }
 
 pub type ConsensusProxy = ConsensusSessionOwned;
@@ -28,7 +28,8 @@ export interface ITransactionOutput {
  */
 export interface ITransactionOutputVerboseData {
     scriptPublicKeyType : string;
-    scriptPublicKeyAddress : string;
+    scriptPublicKeyAddress? : string;
+    scriptPublicKeyAsm : string;
 }
 "#;
 
@@ -26,10 +26,12 @@ use crate::{
 use spectre_hashes::Hash;
 
 pub use self::stats::{BlockCount, ConsensusStats};
+pub use self::verification::DbInconsistency;
 
 pub mod args;
 pub mod counters;
 pub mod stats;
+pub mod verification;
 
 pub type BlockValidationFuture = BoxFuture<'static, BlockProcessResult<BlockStatus>>;
 
@@ -98,6 +100,12 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Executes the script of every input of a transaction whose UTXO entries are all populated, in the
+    /// context of the virtual DAA score, returning one result per input
+    fn verify_transaction_scripts(&self, transaction: &MutableTransaction) -> Vec<TxResult<()>> {
+        unimplemented!()
+    }
+
     /// Returns an aggregation of consensus stats. Designed to be a fast call.
     fn get_stats(&self) -> ConsensusStats {
         unimplemented!()
@@ -177,6 +185,32 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns the transactions accepted by the chain block `accepting_block_hash` in acceptance order, each along with
+    /// the hash of its containing block and fully populated with the UTXO entries spent by its inputs.
+    fn get_populated_accepted_transactions(
+        &self,
+        accepting_block_hash: Hash,
+    ) -> Result<Vec<(Hash, SignableTransaction)>, UtxoInquirerError> {
+        unimplemented!()
+    }
+
+    /// Returns the selected chain blocks with a blue score within `[low, high]`, at most `limit` of them in ascending order,
+    /// each preceded by its selected chain index.
+    ///
+    /// Note: chain block scores are strictly increasing but not contiguous, so the chain block covering a given score,
+    /// i.e. the first one reaching it, is obtained with `low` set to the score, `high = u64::MAX` and `limit = 1`.
+    fn get_chain_blocks_by_blue_score(&self, low: u64, high: u64, limit: usize) -> ConsensusResult<Vec<(u64, Hash)>> {
+        unimplemented!()
+    }
+
+    /// Returns the selected chain blocks with a DAA score within `[low, high]`, at most `limit` of them in ascending order,
+    /// each preceded by its selected chain index.
+    ///
+    /// See [`ConsensusApi::get_chain_blocks_by_blue_score`].
+    fn get_chain_blocks_by_daa_score(&self, low: u64, high: u64, limit: usize) -> ConsensusResult<Vec<(u64, Hash)>> {
+        unimplemented!()
+    }
+
     fn get_virtual_parents(&self) -> BlockHashSet {
         unimplemented!()
     }
@@ -369,6 +403,24 @@ pub trait ConsensusApi: Send + Sync {
     fn finality_point(&self) -> Hash {
         unimplemented!()
     }
+
+    /// Disqualifies the block from the virtual chain and re-resolves virtual. The block must be in the future of the virtual
+    /// finality point.
+    fn invalidate_block(&self, hash: Hash) -> ConsensusResult<()> {
+        unimplemented!()
+    }
+
+    /// Requalifies the block and its future for the virtual chain, reverting previous invalidations, and re-resolves virtual.
+    /// The block must be in the future of the virtual finality point.
+    fn reconsider_block(&self, hash: Hash) -> ConsensusResult<()> {
+        unimplemented!()
+    }
+
+    /// Walks the consensus stores and checks their mutual consistency over the future of the pruning point, returning the
+    /// inconsistencies found. If `repair` is set, inconsistencies which can be recovered from the remaining data are fixed.
+    fn verify_database(&self, repair: bool) -> Vec<DbInconsistency> {
+        unimplemented!()
+    }
 }
 
 pub type DynConsensus = Arc<dyn ConsensusApi>;
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+/// An inconsistency found while verifying the node databases
+#[derive(Clone, Debug)]
+pub struct DbInconsistency {
+    /// Name of the store holding the inconsistent data
+    pub store: &'static str,
+    pub description: String,
+    /// Whether the inconsistency was repaired
+    pub repaired: bool,
+}
+
+impl DbInconsistency {
+    pub fn new(store: &'static str, description: String) -> Self {
+        Self { store, description, repaired: false }
+    }
+}
+
+impl Display for DbInconsistency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.store, self.description)?;
+        if self.repaired {
+            write!(f, " (repaired)")?;
+        }
+        Ok(())
+    }
+}
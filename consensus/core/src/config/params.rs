@@ -9,6 +9,7 @@ use crate::{
     BlockLevel, KType,
 };
 use spectre_addresses::Prefix;
+use spectre_hashes::Hash;
 use spectre_math::Uint256;
 use std::{
     cmp::min,
@@ -134,6 +135,12 @@ pub struct Params {
     /// Activation rules for when to enable using the payload field in transactions
     pub payload_activation: ForkActivation,
     pub runtime_sig_op_counting: ForkActivation,
+
+    /// A chain block whose selected chain past is assumed to hold valid transaction scripts. Script verification is skipped
+    /// for the transactions accepted by this block and its chain ancestors, while all other consensus rules are still checked.
+    /// `None` implies full script verification. No network ships a default block, so assume-valid is only enabled
+    /// by explicitly providing a block (see the `--assumevalid` daemon argument)
+    pub assume_valid: Option<Hash>,
 }
 
 fn unix_now() -> u64 {
@@ -391,6 +398,9 @@ pub const MAINNET_PARAMS: Params = Params {
 
     payload_activation: ForkActivation::never(),
     runtime_sig_op_counting: ForkActivation::never(),
+
+    // Expected to be advanced on releases to a chain block which is well below the selected tip at release time
+    assume_valid: None,
 };
 
 pub const TESTNET_PARAMS: Params = Params {
@@ -454,6 +464,8 @@ pub const TESTNET_PARAMS: Params = Params {
 
     payload_activation: ForkActivation::never(),
     runtime_sig_op_counting: ForkActivation::never(),
+
+    assume_valid: None,
 };
 
 pub const TESTNET11_PARAMS: Params = Params {
@@ -515,6 +527,8 @@ pub const TESTNET11_PARAMS: Params = Params {
     max_block_level: 250,
 
     runtime_sig_op_counting: ForkActivation::never(),
+
+    assume_valid: None,
 };
 
 pub const SIMNET_PARAMS: Params = Params {
@@ -572,6 +586,8 @@ pub const SIMNET_PARAMS: Params = Params {
 
     payload_activation: ForkActivation::never(),
     runtime_sig_op_counting: ForkActivation::never(),
+
+    assume_valid: None,
 };
 
 pub const DEVNET_PARAMS: Params = Params {
@@ -630,4 +646,6 @@ pub const DEVNET_PARAMS: Params = Params {
 
     payload_activation: ForkActivation::never(),
     runtime_sig_op_counting: ForkActivation::never(),
+
+    assume_valid: None,
 };
@@ -66,6 +66,15 @@ impl NetworkType {
         }
     }
 
+    pub fn default_http_rpc_port(&self) -> u16 {
+        match self {
+            NetworkType::Mainnet => 21110,
+            NetworkType::Testnet => 21210,
+            NetworkType::Simnet => 21510,
+            NetworkType::Devnet => 21610,
+        }
+    }
+
     pub fn iter() -> impl Iterator<Item = Self> {
         static NETWORK_TYPES: [NetworkType; 4] =
             [NetworkType::Mainnet, NetworkType::Testnet, NetworkType::Devnet, NetworkType::Simnet];
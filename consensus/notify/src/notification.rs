@@ -7,7 +7,9 @@ use spectre_notify::{
     notification::Notification as NotificationTrait,
     subscription::{
         context::SubscriptionContext,
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        single::{
+            AcceptedTransactionsChangedSubscription, OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription,
+        },
         Subscription,
     },
 };
@@ -42,6 +44,9 @@ pub enum Notification {
 
     #[display(fmt = "NewBlockTemplate notification")]
     NewBlockTemplate(NewBlockTemplateNotification),
+
+    #[display(fmt = "AcceptedTransactionsChanged notification: {} removed blocks, {} added blocks", "_0.removed_chain_block_hashes.len()", "_0.added_chain_block_hashes.len()")]
+    AcceptedTransactionsChanged(AcceptedTransactionsChangedNotification),
 }
 }
 
@@ -87,6 +92,16 @@ impl NotificationTrait for Notification {
         Some(self.clone())
     }
 
+    fn apply_accepted_transactions_changed_subscription(
+        &self,
+        _subscription: &AcceptedTransactionsChangedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        // The accepted transactions, hence the addresses they involve, are only resolved
+        // farther along the notification backbone, where the subscription addresses get applied.
+        Some(self.clone())
+    }
+
     fn event_type(&self) -> EventType {
         self.into()
     }
@@ -181,3 +196,17 @@ pub struct PruningPointUtxoSetOverrideNotification {}
 
 #[derive(Debug, Clone)]
 pub struct NewBlockTemplateNotification {}
+
+/// Changes of the selected chain, the transactions accepted or unaccepted by the chain blocks
+/// being left to the consumer to resolve
+#[derive(Debug, Clone)]
+pub struct AcceptedTransactionsChangedNotification {
+    pub added_chain_block_hashes: Arc<Vec<Hash>>,
+    pub removed_chain_block_hashes: Arc<Vec<Hash>>,
+}
+
+impl AcceptedTransactionsChangedNotification {
+    pub fn new(added_chain_block_hashes: Arc<Vec<Hash>>, removed_chain_block_hashes: Arc<Vec<Hash>>) -> Self {
+        Self { added_chain_block_hashes, removed_chain_block_hashes }
+    }
+}
@@ -1,9 +1,13 @@
 use super::{factory::MultiConsensusManagementStore, Consensus};
 use parking_lot::RwLock;
 use spectre_consensusmanager::ConsensusCtl;
-use spectre_database::prelude::DB;
+use spectre_database::{
+    prelude::{create_checkpoint, StoreError, StoreResult, DB},
+    stats::{collect_db_stats, DbStats},
+};
 use std::{
-    path::PathBuf,
+    io,
+    path::{Path, PathBuf},
     sync::{Arc, Weak},
     thread::JoinHandle,
 };
@@ -40,6 +44,17 @@ impl ConsensusCtl for Ctl {
         // TODO: pass a value to make sure the correct consensus is committed
         self.management_store.write().commit_staging_consensus().unwrap();
     }
+
+    fn create_checkpoint(&self, checkpoint_dir: &Path) -> io::Result<()> {
+        let db = self.consensus_db_ref.upgrade().ok_or_else(|| io::Error::other("the consensus database is closed"))?;
+        create_checkpoint(&db, &checkpoint_dir.join(self.consensus_db_path.file_name().unwrap())).map_err(io::Error::other)
+    }
+
+    fn database_stats(&self) -> StoreResult<DbStats> {
+        let db =
+            self.consensus_db_ref.upgrade().ok_or_else(|| StoreError::DataInconsistency("the consensus database is closed".into()))?;
+        collect_db_stats(&db)
+    }
 }
 
 /// Impl for test purposes
@@ -55,4 +70,12 @@ impl ConsensusCtl for Consensus {
     fn make_active(&self) {
         unimplemented!()
     }
+
+    fn create_checkpoint(&self, checkpoint_dir: &Path) -> io::Result<()> {
+        create_checkpoint(&self.db, &checkpoint_dir.join(self.db.path().file_name().unwrap())).map_err(io::Error::other)
+    }
+
+    fn database_stats(&self) -> StoreResult<DbStats> {
+        collect_db_stats(&self.db)
+    }
 }
@@ -5,21 +5,28 @@ use crate::{model::stores::U64Key, pipeline::ProcessingCounters};
 use itertools::Itertools;
 use spectre_consensus_core::config::Config;
 use spectre_consensus_notify::root::ConsensusNotificationRoot;
-use spectre_consensusmanager::{ConsensusFactory, ConsensusInstance, DynConsensusCtl, SessionLock};
+use spectre_consensusmanager::{ConsensusCtl, ConsensusFactory, ConsensusInstance, DynConsensusCtl, SessionLock};
 use spectre_core::{debug, time::unix_now, warn};
 use spectre_database::{
     prelude::{
-        BatchDbWriter, CachePolicy, CachedDbAccess, CachedDbItem, DirectDbWriter, StoreError, StoreResult, StoreResultExtensions, DB,
+        create_checkpoint, BatchDbWriter, CachePolicy, CachedDbAccess, CachedDbItem, DirectDbWriter, StoreError, StoreResult,
+        StoreResultExtensions, DB,
     },
     registry::DatabaseStorePrefixes,
+    stats::{collect_db_stats, DbStats},
 };
 
 use parking_lot::RwLock;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use spectre_txscript::caches::TxScriptCacheCounters;
 use spectre_utils::mem_size::MemSizeEstimator;
-use std::{collections::HashMap, error::Error, fs, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ConsensusEntry {
@@ -93,7 +100,7 @@ impl MultiConsensusManagementStore {
 
     fn init(&mut self) {
         if self.metadata.read().unwrap_option().is_none() {
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             let metadata = MultiConsensusMetadata::default();
             self.metadata.write(BatchDbWriter::new(&mut batch), &metadata).unwrap();
             self.db.write(batch).unwrap();
@@ -137,7 +144,7 @@ impl MultiConsensusManagementStore {
         if self.entries.has(key.into())? {
             return Err(StoreError::KeyAlreadyExists(format!("{key}")));
         }
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.entries.write(BatchDbWriter::new(&mut batch), key.into(), entry)?;
         self.metadata.update(BatchDbWriter::new(&mut batch), |mut data| {
             data.current_consensus_key = Some(key);
@@ -155,7 +162,7 @@ impl MultiConsensusManagementStore {
         metadata.staging_consensus_key = Some(new_key);
         let new_entry = ConsensusEntry::from_key(new_key);
 
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.metadata.write(BatchDbWriter::new(&mut batch), &metadata)?;
         self.entries.write(BatchDbWriter::new(&mut batch), new_key.into(), new_entry.clone())?;
         self.db.write(batch)?;
@@ -214,7 +221,7 @@ impl MultiConsensusManagementStore {
         let mut metadata = self.metadata.read().unwrap();
         if metadata.is_archival_node != is_archival_node {
             metadata.is_archival_node = is_archival_node;
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             self.metadata.write(BatchDbWriter::new(&mut batch), &metadata).unwrap();
         }
     }
@@ -410,6 +417,10 @@ impl ConsensusFactory for Factory {
                 Ok(_) => {
                     write_guard.delete_entry(entry).unwrap();
                 }
+                // Database checkpoints exclude the staging consensus directory while still holding its entry
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    write_guard.delete_entry(entry).unwrap();
+                }
                 Err(e) => {
                     warn!("Error deleting staging consensus entry {}: {}", entry.key, e);
                 }
@@ -417,4 +428,20 @@ impl ConsensusFactory for Factory {
             write_guard.cancel_staging_consensus().unwrap();
         }
     }
+
+    fn create_checkpoint(&self, active: &dyn ConsensusCtl, checkpoint_dir: &Path) -> io::Result<()> {
+        // Holding the management store lock keeps the consensus entries unchanged until both checkpoints are created
+        let management_store = self.management_store.read();
+        let management_db = &management_store.db;
+        fs::create_dir_all(checkpoint_dir)?;
+        create_checkpoint(management_db, &checkpoint_dir.join(management_db.path().file_name().unwrap())).map_err(io::Error::other)?;
+
+        let consensus_checkpoint_dir = checkpoint_dir.join(self.db_root_dir.file_name().unwrap());
+        fs::create_dir_all(&consensus_checkpoint_dir)?;
+        active.create_checkpoint(&consensus_checkpoint_dir)
+    }
+
+    fn database_stats(&self, active: &dyn ConsensusCtl) -> StoreResult<Vec<DbStats>> {
+        Ok(vec![active.database_stats()?, collect_db_stats(&self.management_store.read().db)?])
+    }
 }
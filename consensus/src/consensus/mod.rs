@@ -4,6 +4,7 @@ pub mod factory;
 pub mod services;
 pub mod storage;
 pub mod test_consensus;
+mod verification;
 
 #[cfg(feature = "devnet-prealloc")]
 mod utxo_set_override;
@@ -46,7 +47,7 @@ use spectre_consensus_core::{
     api::{
         args::{TransactionValidationArgs, TransactionValidationBatchArgs},
         stats::BlockCount,
-        BlockValidationFutures, ConsensusApi, ConsensusStats,
+        BlockValidationFutures, ConsensusApi, ConsensusStats, DbInconsistency,
     },
     block::{Block, BlockTemplate, TemplateBuildMode, TemplateTransactionSelector, VirtualStateApproxId},
     blockhash::BlockHashExtensions,
@@ -390,6 +391,45 @@ impl Consensus {
         Ok(self.services.window_manager.estimate_network_hashes_per_second(window)?)
     }
 
+    /// Returns the selected chain blocks with `score` within `[low, high]`, at most `limit` of them in ascending order,
+    /// each preceded by its selected chain index. `score` is expected to be strictly increasing along the selected chain.
+    fn get_chain_blocks_by_score(
+        &self,
+        low: u64,
+        high: u64,
+        limit: usize,
+        score: impl Fn(&CompactHeaderData) -> u64,
+    ) -> ConsensusResult<Vec<(u64, Hash)>> {
+        // We need consistency between the selected chain and headers store reads
+        let _guard = self.pruning_lock.blocking_read();
+        let sc_read = self.storage.selected_chain_store.read();
+        let chain_block_score =
+            |index: u64| score(&self.headers_store.get_compact_header_data(sc_read.get_by_index(index).unwrap()).unwrap());
+
+        let source_index = sc_read.get_by_hash(self.get_source()).unwrap();
+        let tip_index = sc_read.get_tip().unwrap().0;
+        if low < chain_block_score(source_index) {
+            return Err(ConsensusError::General("the queried score is lower than the score of the pruning point"));
+        }
+
+        // Binary search for the first chain block with a score reaching `low`
+        let (mut low_index, mut high_index) = (source_index, tip_index + 1);
+        while low_index < high_index {
+            let mid = low_index + (high_index - low_index) / 2;
+            if chain_block_score(mid) < low {
+                low_index = mid + 1;
+            } else {
+                high_index = mid;
+            }
+        }
+
+        Ok((low_index..=tip_index)
+            .map(|index| (index, sc_read.get_by_index(index).unwrap()))
+            .take_while(|&(_, hash)| score(&self.headers_store.get_compact_header_data(hash).unwrap()) <= high)
+            .take(limit)
+            .collect())
+    }
+
     fn pruning_point_compact_headers(&self) -> Vec<(Hash, CompactHeaderData)> {
         // PRUNE SAFETY: index is monotonic and past pruning point headers are expected permanently
         let current_pp_info = self.pruning_point_store.read().get().unwrap();
@@ -447,9 +487,16 @@ impl ConsensusApi for Consensus {
         self.services.mass_calculator.calc_tx_compute_mass(transaction)
     }
 
-    fn calculate_transaction_storage_mass(&self, _transaction: &MutableTransaction) -> Option<u64> {
-        // self.services.mass_calculator.calc_tx_storage_mass(&transaction.as_verifiable())
-        unimplemented!("unsupported at the API level until KIP9 is finalized")
+    fn calculate_transaction_storage_mass(&self, transaction: &MutableTransaction) -> Option<u64> {
+        if !transaction.is_verifiable() {
+            return None;
+        }
+        self.services.mass_calculator.calc_tx_storage_mass(&transaction.as_verifiable())
+    }
+
+    fn verify_transaction_scripts(&self, transaction: &MutableTransaction) -> Vec<TxResult<()>> {
+        assert!(transaction.is_verifiable(), "script verification requires the UTXO entries of all inputs");
+        self.services.transaction_validator.check_scripts_per_input(&transaction.as_verifiable(), self.get_virtual_daa_score())
     }
 
     fn get_stats(&self) -> ConsensusStats {
@@ -694,6 +741,23 @@ impl ConsensusApi for Consensus {
         self.virtual_processor.get_populated_transaction(txid, accepting_block_daa_score, self.get_source())
     }
 
+    fn get_populated_accepted_transactions(
+        &self,
+        accepting_block_hash: Hash,
+    ) -> Result<Vec<(Hash, SignableTransaction)>, UtxoInquirerError> {
+        // We need consistency between the acceptance data, utxo diffs, block transactions and headers store reads
+        let _guard = self.pruning_lock.blocking_read();
+        self.virtual_processor.get_populated_accepted_transactions(accepting_block_hash)
+    }
+
+    fn get_chain_blocks_by_blue_score(&self, low: u64, high: u64, limit: usize) -> ConsensusResult<Vec<(u64, Hash)>> {
+        self.get_chain_blocks_by_score(low, high, limit, |header| header.blue_score)
+    }
+
+    fn get_chain_blocks_by_daa_score(&self, low: u64, high: u64, limit: usize) -> ConsensusResult<Vec<(u64, Hash)>> {
+        self.get_chain_blocks_by_score(low, high, limit, |header| header.daa_score)
+    }
+
     fn get_virtual_parents(&self) -> BlockHashSet {
         self.lkg_virtual_state.load().parents.iter().copied().collect()
     }
@@ -1051,4 +1115,16 @@ impl ConsensusApi for Consensus {
     fn finality_point(&self) -> Hash {
         self.virtual_processor.virtual_finality_point(&self.lkg_virtual_state.load().ghostdag_data, self.pruning_point())
     }
+
+    fn invalidate_block(&self, hash: Hash) -> ConsensusResult<()> {
+        self.virtual_processor.invalidate_block(hash)
+    }
+
+    fn reconsider_block(&self, hash: Hash) -> ConsensusResult<()> {
+        self.virtual_processor.reconsider_block(hash)
+    }
+
+    fn verify_database(&self, repair: bool) -> Vec<DbInconsistency> {
+        self.verify_database_impl(repair)
+    }
 }
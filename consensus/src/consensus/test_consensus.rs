@@ -7,7 +7,7 @@ use spectre_consensus_core::{
     subnets::SUBNETWORK_ID_COINBASE, tx::Transaction,
 };
 use spectre_consensus_notify::{notification::Notification, root::ConsensusNotificationRoot};
-use spectre_consensusmanager::{ConsensusFactory, ConsensusInstance, DynConsensusCtl};
+use spectre_consensusmanager::{ConsensusCtl, ConsensusFactory, ConsensusInstance, DynConsensusCtl};
 use spectre_core::{core::Core, service::Service};
 use spectre_database::utils::DbLifetime;
 use spectre_hashes::Hash;
@@ -32,10 +32,11 @@ use crate::{
     pipeline::{body_processor::BlockBodyProcessor, virtual_processor::VirtualStateProcessor, ProcessingCounters},
     test_helpers::header_from_precomputed_hash,
 };
-use spectre_database::create_temp_db;
-use spectre_database::prelude::ConnBuilder;
+use spectre_database::create_memory_db;
+use spectre_database::prelude::{ConnBuilder, StoreResult};
+use spectre_database::stats::DbStats;
 use std::future::Future;
-use std::{sync::Arc, thread::JoinHandle};
+use std::{io, path::Path, sync::Arc, thread::JoinHandle};
 
 pub struct TestConsensus {
     params: Params,
@@ -64,9 +65,9 @@ impl TestConsensus {
         Self { params: config.params.clone(), consensus, block_builder, db_lifetime: Default::default() }
     }
 
-    /// Creates a test consensus instance based on `config` with a temp DB and the provided `notification_sender`
+    /// Creates a test consensus instance based on `config` with an in-memory DB and the provided `notification_sender`
     pub fn with_notifier(config: &Config, notification_sender: Sender<Notification>, context: SubscriptionContext) -> Self {
-        let (db_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (db_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
         let notification_root = Arc::new(ConsensusNotificationRoot::with_context(notification_sender, context));
         let counters = Default::default();
         let tx_script_cache_counters = Default::default();
@@ -84,9 +85,9 @@ impl TestConsensus {
         Self { consensus, block_builder, params: config.params.clone(), db_lifetime }
     }
 
-    /// Creates a test consensus instance based on `config` with a temp DB and no notifier
+    /// Creates a test consensus instance based on `config` with an in-memory DB and no notifier
     pub fn new(config: &Config) -> Self {
-        let (db_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (db_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
         let (dummy_notification_sender, _) = async_channel::unbounded();
         let notification_root = Arc::new(ConsensusNotificationRoot::new(dummy_notification_sender));
         let counters = Default::default();
@@ -315,4 +316,12 @@ impl ConsensusFactory for TestConsensusFactory {
     fn delete_staging_entry(&self) {
         unimplemented!()
     }
+
+    fn create_checkpoint(&self, active: &dyn ConsensusCtl, checkpoint_dir: &Path) -> io::Result<()> {
+        active.create_checkpoint(checkpoint_dir)
+    }
+
+    fn database_stats(&self, active: &dyn ConsensusCtl) -> StoreResult<Vec<DbStats>> {
+        Ok(vec![active.database_stats()?])
+    }
 }
@@ -0,0 +1,185 @@
+//!
+//! Offline consistency verification of the consensus stores.
+//!
+//! The verification covers the future of the pruning point, which is the part of the DAG holding full data. Blocks in its
+//! anticone and past are only partially kept by pruning, hence they are skipped.
+//!
+
+use super::Consensus;
+use crate::model::{
+    services::reachability::ReachabilityService,
+    stores::{
+        children::ChildrenStore,
+        ghostdag::GhostdagStoreReader,
+        headers::HeaderStoreReader,
+        pruning::PruningStoreReader,
+        reachability::ReachabilityStoreReader,
+        relations::{RelationsStore, RelationsStoreReader},
+        selected_chain::SelectedChainStoreReader,
+        statuses::StatusesStoreReader,
+        utxo_multisets::UtxoMultisetsStoreReader,
+        virtual_state::VirtualStateStoreReader,
+    },
+};
+use spectre_consensus_core::{api::DbInconsistency, blockstatus::BlockStatus, muhash::MuHashExtensions};
+use spectre_core::info;
+use spectre_database::prelude::StoreResultExtensions;
+use spectre_hashes::Hash;
+use spectre_muhash::MuHash;
+
+impl Consensus {
+    pub(super) fn verify_database_impl(&self, repair: bool) -> Vec<DbInconsistency> {
+        let _guard = self.pruning_lock.blocking_read();
+        let mut inconsistencies = Vec::new();
+        let pruning_point = self.pruning_point_store.read().pruning_point().unwrap();
+
+        info!("Verifying the DAG stores in the future of the pruning point {}", pruning_point);
+        // Blocks are verified while streaming the statuses, so the statuses store stays read locked throughout
+        let statuses_read = self.statuses_store.read();
+        let mut verified = 0;
+        for result in statuses_read.iterator() {
+            let (hash, status) = match result {
+                Ok(entry) => entry,
+                Err(err) => {
+                    inconsistencies.push(DbInconsistency::new("statuses", format!("failed reading a block status: {err}")));
+                    continue;
+                }
+            };
+            if !status.is_valid() || !self.is_in_pruning_point_future(pruning_point, hash) {
+                continue;
+            }
+            self.verify_block(hash, status, repair, &mut inconsistencies);
+            verified += 1;
+            if verified % 100_000 == 0 {
+                info!("Verified {} blocks so far...", verified);
+            }
+        }
+        drop(statuses_read);
+        info!("Verified {} blocks", verified);
+
+        self.verify_selected_chain(pruning_point, &mut inconsistencies);
+        self.verify_utxo_sets(pruning_point, &mut inconsistencies);
+        inconsistencies
+    }
+
+    fn is_in_pruning_point_future(&self, pruning_point: Hash, hash: Hash) -> bool {
+        self.reachability_store.read().has(hash).unwrap() && self.services.reachability_service.is_dag_ancestor_of(pruning_point, hash)
+    }
+
+    fn verify_block(&self, hash: Hash, status: BlockStatus, repair: bool, inconsistencies: &mut Vec<DbInconsistency>) {
+        if !self.headers_store.has(hash).unwrap() {
+            inconsistencies.push(DbInconsistency::new("headers", format!("block {hash} with status {status:?} has no header")));
+        }
+        if !self.ghostdag_store.has(hash).unwrap() {
+            inconsistencies.push(DbInconsistency::new("ghostdag", format!("block {hash} has no GHOSTDAG data")));
+        }
+        if status.has_block_body() && !self.block_transactions_store.has(hash).unwrap() {
+            inconsistencies
+                .push(DbInconsistency::new("block transactions", format!("block {hash} with status {status:?} has no body")));
+        }
+
+        let Some(parents) = self.relations_stores.read()[0].get_parents(hash).unwrap_option() else {
+            inconsistencies.push(DbInconsistency::new("relations", format!("block {hash} has no parents entry")));
+            return;
+        };
+        for &parent in parents.iter() {
+            // Parents which were pruned have no relations left to verify
+            let Some(has_child) =
+                self.relations_stores.read()[0].get_children(parent).unwrap_option().map(|c| c.read().contains(&hash))
+            else {
+                continue;
+            };
+            if !has_child {
+                let mut inconsistency =
+                    DbInconsistency::new("relations", format!("block {hash} is missing from the children of its parent {parent}"));
+                if repair {
+                    let mut relations_write = self.relations_stores.write();
+                    let writer = relations_write[0].default_writer();
+                    relations_write[0].insert_child(writer, parent, hash).unwrap();
+                    inconsistency.repaired = true;
+                }
+                inconsistencies.push(inconsistency);
+            }
+            if self.reachability_store.read().has(parent).unwrap()
+                && !self.services.reachability_service.is_dag_ancestor_of(parent, hash)
+            {
+                inconsistencies
+                    .push(DbInconsistency::new("reachability", format!("parent {parent} is not in the past of block {hash}")));
+            }
+        }
+    }
+
+    /// Verifies that every block of the selected chain from the pruning point to the sink is UTXO valid and has a UTXO multiset
+    /// matching the UTXO commitment of its header
+    fn verify_selected_chain(&self, pruning_point: Hash, inconsistencies: &mut Vec<DbInconsistency>) {
+        info!("Verifying the UTXO multisets of the selected chain");
+        let selected_chain_read = self.selected_chain_store.read();
+        let Some(pruning_point_index) = selected_chain_read.get_by_hash(pruning_point).unwrap_option() else {
+            inconsistencies.push(DbInconsistency::new("selected chain", format!("the pruning point {pruning_point} is not indexed")));
+            return;
+        };
+        let (tip_index, _) = selected_chain_read.get_tip().unwrap();
+        for index in pruning_point_index..=tip_index {
+            let Some(hash) = selected_chain_read.get_by_index(index).unwrap_option() else {
+                inconsistencies.push(DbInconsistency::new("selected chain", format!("chain index {index} has no block")));
+                continue;
+            };
+            let status = self.statuses_store.read().get(hash).unwrap_option();
+            if status != Some(BlockStatus::StatusUTXOValid) {
+                inconsistencies.push(DbInconsistency::new("statuses", format!("chain block {hash} has status {status:?}")));
+            }
+            match (self.utxo_multisets_store.get(hash).unwrap_option(), self.headers_store.get_header(hash).unwrap_option()) {
+                (None, _) => {
+                    inconsistencies.push(DbInconsistency::new("utxo multisets", format!("chain block {hash} has no multiset")))
+                }
+                (Some(multiset), Some(header)) if multiset.clone().finalize() != header.utxo_commitment => {
+                    inconsistencies.push(DbInconsistency::new(
+                        "utxo multisets",
+                        format!("the multiset of chain block {hash} does not match its header UTXO commitment"),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Verifies the virtual UTXO set against the virtual multiset, and the pruning point UTXO set against the
+    /// pruning point UTXO commitment
+    fn verify_utxo_sets(&self, pruning_point: Hash, inconsistencies: &mut Vec<DbInconsistency>) {
+        info!("Verifying the virtual UTXO set");
+        let virtual_stores = self.virtual_stores.read();
+        let mut multiset = MuHash::new();
+        for result in virtual_stores.utxo_set.iterator() {
+            match result {
+                Ok((outpoint, entry)) => multiset.add_utxo(&outpoint, &entry),
+                Err(err) => inconsistencies.push(DbInconsistency::new("virtual utxo set", format!("failed reading a UTXO: {err}"))),
+            }
+        }
+        if multiset.finalize() != virtual_stores.state.get().unwrap().multiset.clone().finalize() {
+            inconsistencies
+                .push(DbInconsistency::new("virtual utxo set", "the virtual UTXO set does not match the virtual multiset".into()));
+        }
+        drop(virtual_stores);
+
+        let pruning_utxoset_read = self.pruning_utxoset_stores.read();
+        // The pruning UTXO set is advanced to a new pruning point after it is set, so it is only verified once it caught up
+        if pruning_utxoset_read.utxoset_position().unwrap_option() == Some(pruning_point) {
+            info!("Verifying the pruning point UTXO set");
+            let mut multiset = MuHash::new();
+            for result in pruning_utxoset_read.utxo_set.iterator() {
+                match result {
+                    Ok((outpoint, entry)) => multiset.add_utxo(&outpoint, &entry),
+                    Err(err) => {
+                        inconsistencies.push(DbInconsistency::new("pruning utxo set", format!("failed reading a UTXO: {err}")))
+                    }
+                }
+            }
+            if multiset.finalize() != self.headers_store.get_header(pruning_point).unwrap().utxo_commitment {
+                inconsistencies.push(DbInconsistency::new(
+                    "pruning utxo set",
+                    "the pruning point UTXO set does not match the pruning point UTXO commitment".into(),
+                ));
+            }
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use rocksdb::WriteBatch;
 use serde::Deserialize;
 use serde::Serialize;
 use spectre_consensus_core::acceptance_data::AcceptanceData;
@@ -8,7 +7,7 @@ use spectre_consensus_core::BlockHasher;
 use spectre_database::prelude::CachePolicy;
 use spectre_database::prelude::StoreError;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter, WriteBatch};
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
 use spectre_utils::mem_size::MemSizeEstimator;
@@ -1,11 +1,10 @@
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use spectre_consensus_core::tx::{TransactionInput, TransactionOutput};
 use spectre_consensus_core::{tx::Transaction, BlockHasher};
 use spectre_database::prelude::CachePolicy;
 use spectre_database::prelude::StoreError;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter, WriteBatch};
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
 use spectre_utils::mem_size::MemSizeEstimator;
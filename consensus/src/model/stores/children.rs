@@ -1,4 +1,3 @@
-use rocksdb::WriteBatch;
 use spectre_consensus_core::BlockHashSet;
 use spectre_consensus_core::BlockHasher;
 use spectre_consensus_core::BlockLevel;
@@ -9,6 +8,7 @@ use spectre_database::prelude::DbWriter;
 use spectre_database::prelude::ReadLock;
 use spectre_database::prelude::StoreError;
 use spectre_database::prelude::StoreResult;
+use spectre_database::prelude::WriteBatch;
 use spectre_database::prelude::DB;
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
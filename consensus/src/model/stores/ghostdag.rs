@@ -3,14 +3,13 @@ use spectre_consensus_core::trusted::ExternalGhostdagData;
 use spectre_consensus_core::{blockhash::BlockHashes, BlueWorkType};
 use spectre_consensus_core::{BlockHashMap, BlockHasher, BlockLevel, HashMapCustomHasher};
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DbKey};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DbKey, WriteBatch};
 use spectre_database::prelude::{CachePolicy, StoreError};
 use spectre_database::registry::{DatabaseStorePrefixes, SEPARATOR};
 use spectre_hashes::Hash;
 
 use itertools::EitherOrBoth::{Both, Left, Right};
 use itertools::Itertools;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use spectre_utils::mem_size::MemSizeEstimator;
 use std::iter::once;
@@ -372,7 +371,7 @@ impl GhostdagStore for DbGhostdagStore {
         if self.compact_access.has(hash)? {
             return Err(StoreError::DataInconsistency(format!("store has compact data for {} but is missing full data", hash)));
         }
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.access.write(BatchDbWriter::new(&mut batch), hash, data.clone())?;
         self.compact_access.write(BatchDbWriter::new(&mut batch), hash, data.to_compact())?;
         self.db.write(batch)?;
@@ -380,7 +379,7 @@ impl GhostdagStore for DbGhostdagStore {
     }
 
     fn delete(&self, hash: Hash) -> Result<(), StoreError> {
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.compact_access.delete(BatchDbWriter::new(&mut batch), hash)?;
         self.access.delete(BatchDbWriter::new(&mut batch), hash)?;
         self.db.write(batch)?;
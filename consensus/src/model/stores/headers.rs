@@ -1,9 +1,8 @@
 use std::sync::Arc;
 
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use spectre_consensus_core::{header::Header, BlockHasher, BlockLevel};
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, WriteBatch};
 use spectre_database::prelude::{CachePolicy, DB};
 use spectre_database::prelude::{StoreError, StoreResult};
 use spectre_database::registry::DatabaseStorePrefixes;
@@ -157,7 +156,7 @@ impl HeaderStore for DbHeadersStore {
         if self.compact_headers_access.has(hash)? {
             return Err(StoreError::DataInconsistency(format!("store has compact data for {} but is missing full data", hash)));
         }
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.compact_headers_access.write(BatchDbWriter::new(&mut batch), hash, header.as_ref().into())?;
         self.headers_access.write(BatchDbWriter::new(&mut batch), hash, HeaderWithBlockLevel { header, block_level })?;
         self.db.write(batch)?;
@@ -165,7 +164,7 @@ impl HeaderStore for DbHeadersStore {
     }
 
     fn delete(&self, hash: Hash) -> Result<(), StoreError> {
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.compact_headers_access.delete(BatchDbWriter::new(&mut batch), hash)?;
         self.headers_access.delete(BatchDbWriter::new(&mut batch), hash)?;
         self.db.write(batch)?;
@@ -1,8 +1,7 @@
 use crate::processes::ghostdag::ordering::SortableBlock;
-use rocksdb::WriteBatch;
 use spectre_database::prelude::StoreResult;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter, WriteBatch};
 use spectre_database::registry::DatabaseStorePrefixes;
 use std::sync::Arc;
 
@@ -1,7 +1,6 @@
 use std::sync::Arc;
 
-use rocksdb::WriteBatch;
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter, WriteBatch};
 use spectre_database::prelude::{CachePolicy, DB};
 use spectre_database::prelude::{StoreError, StoreResult};
 use spectre_database::registry::DatabaseStorePrefixes;
@@ -1,10 +1,9 @@
 use std::sync::Arc;
 
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use spectre_database::prelude::StoreResult;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter, WriteBatch};
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
 
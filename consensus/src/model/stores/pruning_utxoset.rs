@@ -1,10 +1,9 @@
 use std::sync::Arc;
 
-use rocksdb::WriteBatch;
 use spectre_database::prelude::CachePolicy;
 use spectre_database::prelude::StoreResult;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbItem};
+use spectre_database::prelude::{BatchDbWriter, CachedDbItem, WriteBatch};
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
 
@@ -5,7 +5,8 @@ use spectre_consensus_core::{
 };
 use spectre_database::{
     prelude::{
-        BatchDbWriter, Cache, CachePolicy, CachedDbAccess, CachedDbItem, DbKey, DbSetAccess, DbWriter, DirectDbWriter, StoreError, DB,
+        BatchDbWriter, Cache, CachePolicy, CachedDbAccess, CachedDbItem, DbKey, DbSetAccess, DbWriter, DirectDbWriter, StoreError,
+        WriteBatch, DB,
     },
     registry::{DatabaseStorePrefixes, SEPARATOR},
 };
@@ -13,7 +14,6 @@ use spectre_hashes::Hash;
 
 use itertools::Itertools;
 use parking_lot::{RwLockUpgradableReadGuard, RwLockWriteGuard};
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use spectre_utils::mem_size::MemSizeEstimator;
 use std::{
@@ -215,7 +215,7 @@ impl ReachabilityStore for DbReachabilityStore {
         assert!(!self.access.has(origin)?);
 
         let data = ReachabilityData::new(blockhash::NONE, capacity, 0);
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.access.write(BatchDbWriter::new(&mut batch), origin, data)?;
         self.reindex_root.write(BatchDbWriter::new(&mut batch), &origin)?;
         self.db.write(batch)?;
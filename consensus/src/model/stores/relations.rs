@@ -1,8 +1,7 @@
 use itertools::Itertools;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::BlockHashSet;
 use spectre_consensus_core::{blockhash::BlockHashes, BlockHashMap, BlockHasher, BlockLevel};
-use spectre_database::prelude::{BatchDbWriter, CachePolicy, DbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachePolicy, DbWriter, WriteBatch};
 use spectre_database::prelude::{CachedDbAccess, DbKey, DirectDbWriter};
 use spectre_database::prelude::{DirectWriter, MemoryWriter};
 use spectre_database::prelude::{ReadLock, StoreError};
@@ -409,7 +408,7 @@ impl RelationsStore for MemoryRelationsStore {
 mod tests {
     use super::*;
     use crate::processes::relations::RelationsStoreExtensions;
-    use spectre_database::create_temp_db;
+    use spectre_database::create_memory_db;
     use spectre_utils::mem_size::MemMode;
 
     #[test]
@@ -419,7 +418,7 @@ mod tests {
 
     #[test]
     fn test_db_relations_store() {
-        let (lt, db) = create_temp_db!(spectre_database::prelude::ConnBuilder::default().with_files_limit(10));
+        let (lt, db) = create_memory_db!(spectre_database::prelude::ConnBuilder::default().with_files_limit(10));
         test_relations_store(DbRelationsStore::new(
             db,
             0,
@@ -1,12 +1,11 @@
 use parking_lot::RwLockWriteGuard;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::blockstatus::BlockStatus;
 use spectre_consensus_core::ChainPath;
 use spectre_database::registry::DatabaseStorePrefixes;
 
 use std::sync::Arc;
 
-use spectre_database::prelude::{BatchDbWriter, CachePolicy, CachedDbAccess, DbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachePolicy, CachedDbAccess, DbWriter, WriteBatch};
 use spectre_database::prelude::{CachedDbItem, DB};
 use spectre_database::prelude::{StoreError, StoreResult};
 use spectre_hashes::Hash;
@@ -1,13 +1,12 @@
 use parking_lot::{RwLock, RwLockWriteGuard};
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{blockstatus::BlockStatus, BlockHasher};
 use spectre_database::registry::DatabaseStorePrefixes;
-use std::sync::Arc;
+use std::{error::Error, sync::Arc};
 
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter, WriteBatch};
 use spectre_database::prelude::{CachePolicy, DB};
 use spectre_database::prelude::{StoreError, StoreResult};
-use spectre_hashes::Hash;
+use spectre_hashes::{Hash, HASH_SIZE};
 
 /// Reader API for `StatusesStore`.
 pub trait StatusesStoreReader {
@@ -46,6 +45,16 @@ impl DbStatusesStore {
     pub fn delete_batch(&self, batch: &mut WriteBatch, hash: Hash) -> Result<(), StoreError> {
         self.access.delete(BatchDbWriter::new(batch), hash)
     }
+
+    pub fn iterator(&self) -> impl Iterator<Item = Result<(Hash, BlockStatus), Box<dyn Error>>> + '_ {
+        self.access.iterator().map(|iter_result| match iter_result {
+            Ok((key_bytes, status)) => match <[u8; HASH_SIZE]>::try_from(key_bytes.as_ref()) {
+                Ok(hash_bytes) => Ok((Hash::from_bytes(hash_bytes), status)),
+                Err(e) => Err(e.into()),
+            },
+            Err(e) => Err(e),
+        })
+    }
 }
 
 pub trait StatusesStoreBatchExtensions {
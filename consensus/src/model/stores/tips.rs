@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use rocksdb::WriteBatch;
 use spectre_consensus_core::BlockHashSet;
 use spectre_consensus_core::BlockHasher;
 use spectre_database::prelude::CachedDbSetItem;
@@ -9,7 +8,7 @@ use spectre_database::prelude::ReadLock;
 use spectre_database::prelude::StoreResult;
 use spectre_database::prelude::StoreResultExtensions;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, DirectDbWriter, WriteBatch};
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
 
@@ -99,11 +98,11 @@ impl TipsStore for DbTipsStore {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use spectre_database::{create_temp_db, prelude::ConnBuilder};
+    use spectre_database::{create_memory_db, prelude::ConnBuilder};
 
     #[test]
     fn test_update_tips() {
-        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
         let mut store = DbTipsStore::new(db.clone());
         store.add_tip(1.into(), &[]).unwrap();
         store.add_tip(3.into(), &[]).unwrap();
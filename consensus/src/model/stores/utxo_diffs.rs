@@ -1,11 +1,10 @@
 use std::sync::Arc;
 
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{utxo::utxo_diff::UtxoDiff, BlockHasher};
 use spectre_database::prelude::CachePolicy;
 use spectre_database::prelude::StoreError;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter, WriteBatch};
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
 
@@ -1,4 +1,3 @@
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{
     tx::{TransactionIndexType, TransactionOutpoint, UtxoEntry},
     utxo::{
@@ -8,7 +7,7 @@ use spectre_consensus_core::{
 };
 use spectre_database::prelude::StoreResultExtensions;
 use spectre_database::prelude::DB;
-use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
+use spectre_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter, WriteBatch};
 use spectre_database::prelude::{CachePolicy, StoreError};
 use spectre_hashes::Hash;
 use std::{error::Error, fmt::Display, sync::Arc};
@@ -2,14 +2,13 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use arc_swap::ArcSwap;
-use rocksdb::WriteBatch;
 use serde::{Deserialize, Serialize};
 use spectre_consensus_core::api::stats::VirtualStateStats;
 use spectre_consensus_core::{
     block::VirtualStateApproxId, coinbase::BlockRewardData, config::genesis::GenesisBlock, tx::TransactionId,
     utxo::utxo_diff::UtxoDiff, BlockHashMap, BlockHashSet, HashMapCustomHasher,
 };
-use spectre_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter, StoreResultExtensions};
+use spectre_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter, StoreResultExtensions, WriteBatch};
 use spectre_database::prelude::{CachePolicy, StoreResult};
 use spectre_database::prelude::{StoreError, DB};
 use spectre_database::registry::DatabaseStorePrefixes;
@@ -25,7 +25,6 @@ use crate::{
 use crossbeam_channel::{Receiver, Sender};
 use parking_lot::RwLock;
 use rayon::ThreadPool;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{
     block::Block,
     blockstatus::BlockStatus::{self, StatusHeaderOnly, StatusInvalid},
@@ -230,7 +229,7 @@ impl BlockBodyProcessor {
     }
 
     fn commit_body(self: &Arc<BlockBodyProcessor>, hash: Hash, parents: &[Hash], transactions: Arc<Vec<Transaction>>) {
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
 
         // This is an append only store so it requires no lock.
         self.block_transactions_store.insert_batch(&mut batch, hash, transactions).unwrap();
@@ -249,7 +248,7 @@ impl BlockBodyProcessor {
 
     pub fn process_genesis(self: &Arc<BlockBodyProcessor>) {
         // Init tips store
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         let mut body_tips_write_guard = self.body_tips_store.write();
         body_tips_write_guard.init_batch(&mut batch, &[]).unwrap();
         self.db.write(batch).unwrap();
@@ -31,7 +31,6 @@ use crossbeam_channel::{Receiver, Sender};
 use itertools::Itertools;
 use parking_lot::RwLock;
 use rayon::ThreadPool;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{
     blockhash::{BlockHashes, ORIGIN},
     blockstatus::BlockStatus::{self, StatusHeaderOnly, StatusInvalid},
@@ -360,7 +359,7 @@ impl HeaderProcessor {
         let pp = ctx.pruning_point();
 
         // Create a DB batch writer
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
 
         //
         // Append-only stores: these require no lock and hence done first in order to reduce locking time
@@ -440,7 +439,7 @@ impl HeaderProcessor {
         let ghostdag_data = ctx.ghostdag_data.as_ref().unwrap();
 
         // Create a DB batch writer
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
 
         // This data might have been already written when applying the pruning proof.
         self.ghostdag_store.insert_batch(&mut batch, ctx.hash, ghostdag_data).unwrap_or_exists();
@@ -463,7 +462,7 @@ impl HeaderProcessor {
 
     pub fn process_genesis(&self) {
         // Init headers selected tip and selected chain stores
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         let mut hst_write = self.headers_selected_tip_store.write();
         hst_write.set_batch(&mut batch, SortableBlock::new(self.genesis.hash, 0.into())).unwrap();
         self.db.write(batch).unwrap();
@@ -496,7 +495,7 @@ impl HeaderProcessor {
             return;
         }
 
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         let mut relations_write = self.relations_stores.write();
         (0..=self.max_block_level)
             .for_each(|level| relations_write[level as usize].insert_batch(&mut batch, ORIGIN, BlockHashes::new(vec![])).unwrap());
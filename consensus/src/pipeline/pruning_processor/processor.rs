@@ -25,7 +25,6 @@ use crate::{
 use crossbeam_channel::Receiver as CrossbeamReceiver;
 use itertools::Itertools;
 use parking_lot::RwLockUpgradableReadGuard;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{
     blockhash::ORIGIN,
     blockstatus::BlockStatus::StatusHeaderOnly,
@@ -176,7 +175,7 @@ impl PruningProcessor {
 
         if !new_pruning_points.is_empty() {
             // Update past pruning points and pruning point stores
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             let mut pruning_point_write = RwLockUpgradableReadGuard::upgrade(pruning_point_read);
             for (i, past_pp) in new_pruning_points.iter().copied().enumerate() {
                 self.past_pruning_points_store.insert_batch(&mut batch, current_pruning_info.index + i as u64 + 1, past_pp).unwrap();
@@ -212,7 +211,7 @@ impl PruningProcessor {
                 return false;
             }
             let utxo_diff = self.utxo_diffs_store.get(chain_block).expect("chain blocks have utxo state");
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             pruning_utxoset_write.utxo_set.write_diff_batch(&mut batch, utxo_diff.as_ref()).unwrap();
             pruning_utxoset_write.set_utxoset_position(&mut batch, chain_block).unwrap();
             self.db.write(batch).unwrap();
@@ -279,7 +278,7 @@ impl PruningProcessor {
 
         {
             let mut counter = 0;
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             // At this point keep_relations only holds level-0 relations which is the correct filtering criteria for primary GHOSTDAG
             for kept in keep_relations.keys().copied() {
                 let Some(ghostdag) = self.ghostdag_store.get_data(kept).unwrap_option() else {
@@ -342,7 +341,7 @@ impl PruningProcessor {
 
         {
             // Start with a batch for pruning body tips and selected chain stores
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
 
             // Prune tips which can no longer be merged by virtual.
             // By the prunality proof, any tip which isn't in future(pruning_point) will never be merged
@@ -414,7 +413,7 @@ impl PruningProcessor {
             self.block_window_cache_for_past_median_time.remove(&current);
 
             if !keep_blocks.contains(&current) {
-                let mut batch = WriteBatch::default();
+                let mut batch = self.db.new_batch();
                 let mut level_relations_write = self.relations_stores.write();
                 let mut reachability_relations_write = self.reachability_relations_store.write();
                 let mut staging_relations = StagingRelationsStore::new(&mut reachability_relations_write);
@@ -516,7 +515,7 @@ impl PruningProcessor {
         {
             // Set the history root to the new pruning point only after we successfully pruned its past
             let mut pruning_point_write = self.pruning_point_store.write();
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             pruning_point_write.set_history_root(&mut batch, new_pruning_point).unwrap();
             self.db.write(batch).unwrap();
             drop(pruning_point_write);
@@ -52,9 +52,10 @@ use spectre_consensus_core::{
     acceptance_data::AcceptanceData,
     api::args::{TransactionValidationArgs, TransactionValidationBatchArgs},
     block::{BlockTemplate, MutableBlock, TemplateBuildMode, TemplateTransactionSelector},
-    blockstatus::BlockStatus::{StatusDisqualifiedFromChain, StatusUTXOValid},
+    blockstatus::BlockStatus::{StatusDisqualifiedFromChain, StatusUTXOPendingVerification, StatusUTXOValid},
     coinbase::MinerData,
     config::{genesis::GenesisBlock, params::ForkActivation},
+    errors::consensus::{ConsensusError, ConsensusResult},
     header::Header,
     merkle::calc_hash_merkle_root,
     pruning::PruningPointsList,
@@ -67,8 +68,8 @@ use spectre_consensus_core::{
 };
 use spectre_consensus_notify::{
     notification::{
-        NewBlockTemplateNotification, Notification, SinkBlueScoreChangedNotification, UtxosChangedNotification,
-        VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
+        AcceptedTransactionsChangedNotification, NewBlockTemplateNotification, Notification, SinkBlueScoreChangedNotification,
+        UtxosChangedNotification, VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
     },
     root::ConsensusNotificationRoot,
 };
@@ -88,7 +89,6 @@ use rayon::{
     prelude::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator},
     ThreadPool,
 };
-use rocksdb::WriteBatch;
 use spectre_consensus_core::tx::ValidatedTransaction;
 use spectre_utils::binary_heap::BinaryHeapExtensions;
 use std::{
@@ -167,6 +167,9 @@ pub struct VirtualStateProcessor {
     // Storage mass hardfork DAA score
     pub(crate) storage_mass_activation: ForkActivation,
     pub(crate) kip10_activation: ForkActivation,
+
+    // Chain block whose selected chain past is exempt from script verification
+    pub(super) assume_valid: Option<Hash>,
 }
 
 impl VirtualStateProcessor {
@@ -232,6 +235,7 @@ impl VirtualStateProcessor {
             counters,
             storage_mass_activation: params.storage_mass_activation,
             kip10_activation: params.kip10_activation,
+            assume_valid: params.assume_valid,
         }
     }
 
@@ -346,18 +350,28 @@ impl VirtualStateProcessor {
         self.notification_root
             .notify(Notification::VirtualDaaScoreChanged(VirtualDaaScoreChangedNotification::new(new_virtual_state.daa_score)))
             .expect("expecting an open unbounded channel");
+        let added_chain_block_hashes: Arc<Vec<Hash>> = chain_path.added.into();
+        let removed_chain_block_hashes: Arc<Vec<Hash>> = chain_path.removed.into();
         if self.notification_root.has_subscription(EventType::VirtualChainChanged) {
             // check for subscriptions before the heavy lifting
             let added_chain_blocks_acceptance_data =
-                chain_path.added.iter().copied().map(|added| self.acceptance_data_store.get(added).unwrap()).collect_vec();
+                added_chain_block_hashes.iter().copied().map(|added| self.acceptance_data_store.get(added).unwrap()).collect_vec();
             self.notification_root
                 .notify(Notification::VirtualChainChanged(VirtualChainChangedNotification::new(
-                    chain_path.added.into(),
-                    chain_path.removed.into(),
+                    added_chain_block_hashes.clone(),
+                    removed_chain_block_hashes.clone(),
                     Arc::new(added_chain_blocks_acceptance_data),
                 )))
                 .expect("expecting an open unbounded channel");
         }
+        if self.notification_root.has_subscription(EventType::AcceptedTransactionsChanged) {
+            self.notification_root
+                .notify(Notification::AcceptedTransactionsChanged(AcceptedTransactionsChangedNotification::new(
+                    added_chain_block_hashes,
+                    removed_chain_block_hashes,
+                )))
+                .expect("expecting an open unbounded channel");
+        }
     }
 
     pub(crate) fn virtual_finality_point(&self, virtual_ghostdag_data: &GhostdagData, pruning_point: Hash) -> Hash {
@@ -377,8 +391,9 @@ impl VirtualStateProcessor {
     /// `to` itself (with the exception of returning `from` if `to` is already known to be UTXO disqualified).
     /// When returning it is guaranteed that `diff` holds the diff of the returned block from virtual
     fn calculate_utxo_state_relatively(&self, stores: &VirtualStores, diff: &mut UtxoDiff, from: Hash, to: Hash) -> Hash {
-        // Avoid reorging if disqualified status is already known
-        if self.statuses_store.read().get(to).unwrap() == StatusDisqualifiedFromChain {
+        // Avoid reorging if disqualified status is already known. Note that `from` itself might be disqualified if it was
+        // explicitly invalidated while being the sink, in which case its state must be reverted regardless
+        if to != from && self.statuses_store.read().get(to).unwrap() == StatusDisqualifiedFromChain {
             return from;
         }
 
@@ -386,7 +401,9 @@ impl VirtualStateProcessor {
 
         // Walk down to the reorg split point
         for current in self.reachability_service.default_backward_chain_iterator(from) {
-            if self.reachability_service.is_chain_ancestor_of(current, to) {
+            if self.reachability_service.is_chain_ancestor_of(current, to)
+                && self.statuses_store.read().get(current).unwrap() != StatusDisqualifiedFromChain
+            {
                 split_point = Some(current);
                 break;
             }
@@ -417,17 +434,18 @@ impl VirtualStateProcessor {
                 continue;
             }
 
+            if self.statuses_store.read().get(current).unwrap() == StatusDisqualifiedFromChain {
+                // Current block is already known to be disqualified (possibly by an explicit invalidation, in which
+                // case its UTXO diff is still held but must not be applied)
+                continue;
+            }
+
             match self.utxo_diffs_store.get(current) {
                 Ok(mergeset_diff) => {
                     diff.with_diff_in_place(mergeset_diff.deref()).unwrap();
                     diff_point = current;
                 }
                 Err(StoreError::KeyNotFound(_)) => {
-                    if self.statuses_store.read().get(current).unwrap() == StatusDisqualifiedFromChain {
-                        // Current block is already known to be disqualified
-                        continue;
-                    }
-
                     let header = self.headers_store.get_header(current).unwrap();
                     let mergeset_data = self.ghostdag_store.get_data(current).unwrap();
                     let pov_daa_score = header.daa_score;
@@ -437,8 +455,9 @@ impl VirtualStateProcessor {
 
                     let mut ctx = UtxoProcessingContext::new(mergeset_data.into(), selected_parent_multiset_hash);
 
-                    self.calculate_utxo_state(&mut ctx, &selected_parent_utxo_view, pov_daa_score);
-                    let res = self.verify_expected_utxo_state(&mut ctx, &selected_parent_utxo_view, &header);
+                    let flags = self.chain_block_validation_flags(current);
+                    self.calculate_utxo_state(&mut ctx, &selected_parent_utxo_view, pov_daa_score, flags);
+                    let res = self.verify_expected_utxo_state(&mut ctx, &selected_parent_utxo_view, &header, flags);
 
                     if let Err(rule_error) = res {
                         info!("Block {} is disqualified from virtual chain: {}", current, rule_error);
@@ -470,7 +489,7 @@ impl VirtualStateProcessor {
     }
 
     fn commit_utxo_state(&self, current: Hash, mergeset_diff: UtxoDiff, multiset: MuHash, acceptance_data: AcceptanceData) {
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.utxo_diffs_store.insert_batch(&mut batch, current, Arc::new(mergeset_diff)).unwrap();
         self.utxo_multisets_store.insert_batch(&mut batch, current, multiset).unwrap();
         self.acceptance_data_store.insert_batch(&mut batch, current, Arc::new(acceptance_data)).unwrap();
@@ -517,7 +536,7 @@ impl VirtualStateProcessor {
         let virtual_past_median_time = self.window_manager.calc_past_median_time(&virtual_ghostdag_data)?.0;
 
         // Calc virtual UTXO state relative to selected parent
-        self.calculate_utxo_state(&mut ctx, &selected_parent_utxo_view, virtual_daa_window.daa_score);
+        self.calculate_utxo_state(&mut ctx, &selected_parent_utxo_view, virtual_daa_window.daa_score, TxValidationFlags::Full);
 
         // Update the accumulated diff
         accumulated_diff.with_diff_in_place(&ctx.mergeset_diff).unwrap();
@@ -544,7 +563,7 @@ impl VirtualStateProcessor {
         accumulated_diff: &UtxoDiff,
         chain_path: &ChainPath,
     ) {
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         let mut virtual_write = RwLockUpgradableReadGuard::upgrade(virtual_read);
         let mut selected_chain_write = self.selected_chain_store.write();
 
@@ -1077,7 +1096,7 @@ impl VirtualStateProcessor {
         if pruning_point_read.pruning_point().unwrap_option().is_none() {
             let mut pruning_point_write = RwLockUpgradableReadGuard::upgrade(pruning_point_read);
             let mut pruning_utxoset_write = self.pruning_utxoset_stores.write();
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             self.past_pruning_points_store.insert_batch(&mut batch, 0, self.genesis.hash).unwrap_or_exists();
             pruning_point_write.set_batch(&mut batch, self.genesis.hash, self.genesis.hash, 0).unwrap();
             pruning_point_write.set_history_root(&mut batch, self.genesis.hash).unwrap();
@@ -1095,7 +1114,7 @@ impl VirtualStateProcessor {
         self.commit_utxo_state(self.genesis.hash, UtxoDiff::default(), MuHash::new(), AcceptanceData::default());
 
         // Init the virtual selected chain store
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         let mut selected_chain_write = self.selected_chain_store.write();
         selected_chain_write.init_with_pruning_point(&mut batch, self.genesis.hash).unwrap();
         self.db.write(batch).unwrap();
@@ -1128,7 +1147,7 @@ impl VirtualStateProcessor {
 
         {
             // Set the pruning point utxoset position to the new point we just verified
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             let mut pruning_utxoset_write = self.pruning_utxoset_stores.write();
             pruning_utxoset_write.set_utxoset_position(&mut batch, new_pruning_point).unwrap();
             self.db.write(batch).unwrap();
@@ -1164,7 +1183,7 @@ impl VirtualStateProcessor {
         {
             // Submit partial UTXO state for the pruning point.
             // Note we only have and need the multiset; acceptance data and utxo-diff are irrelevant.
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             self.utxo_multisets_store.set_batch(&mut batch, new_pruning_point, imported_utxo_multiset.clone()).unwrap();
 
             let statuses_write = self.statuses_store.set_batch(&mut batch, new_pruning_point, StatusUTXOValid).unwrap();
@@ -1216,6 +1235,85 @@ impl VirtualStateProcessor {
         }
     }
 
+    /// Verifies that `hash` is a block with a body in the future of the virtual finality point, the only blocks whose
+    /// virtual chain eligibility can be changed by [`Self::invalidate_block`] and [`Self::reconsider_block`]
+    fn verify_chain_eligibility_changeable(&self, hash: Hash) -> ConsensusResult<()> {
+        match self.statuses_store.read().get(hash).unwrap_option() {
+            None => return Err(ConsensusError::BlockNotFound(hash)),
+            Some(status) if status.is_invalid() => return Err(ConsensusError::InvalidBlock(hash)),
+            Some(status) if !status.has_block_body() => return Err(ConsensusError::BlockNotFound(hash)),
+            Some(_) => {}
+        }
+        let pruning_point = self.pruning_point_store.read().pruning_point().unwrap();
+        let finality_point = self.virtual_finality_point(&self.lkg_virtual_state.load().ghostdag_data, pruning_point);
+        if hash == finality_point || !self.reachability_service.is_dag_ancestor_of(finality_point, hash) {
+            return Err(ConsensusError::BlockNotInFinalityPointFuture(hash));
+        }
+        Ok(())
+    }
+
+    /// Disqualifies `hash` from the virtual chain and re-resolves virtual, so that the sink moves to the best chain
+    /// not containing the block. Chain blocks in its future are disqualified as virtual resolution reaches them.
+    ///
+    /// Like any disqualified block, the block can still be merged by the virtual chain.
+    pub fn invalidate_block(self: &Arc<Self>, hash: Hash) -> ConsensusResult<()> {
+        self.verify_chain_eligibility_changeable(hash)?;
+
+        // Holding the virtual lock prevents the sink from moving until the current chain is disqualified
+        let virtual_read = self.virtual_stores.upgradable_read();
+        let sink = virtual_read.state.get().unwrap().ghostdag_data.selected_parent;
+        let mut statuses_write = self.statuses_store.write();
+        statuses_write.set(hash, StatusDisqualifiedFromChain).unwrap();
+        if self.reachability_service.is_chain_ancestor_of(hash, sink) {
+            // Disqualify the current chain blocks above the invalidated block as well, so that virtual resolution
+            // reverts their UTXO state rather than keeping the sink
+            for current in self.reachability_service.default_backward_chain_iterator(sink).take_while(|&current| current != hash) {
+                statuses_write.set(current, StatusDisqualifiedFromChain).unwrap();
+            }
+        }
+        drop(statuses_write);
+        drop(virtual_read);
+        info!("Block {} was invalidated and is disqualified from the virtual chain", hash);
+
+        self.resolve_virtual();
+        Ok(())
+    }
+
+    /// Reverts the disqualification from the virtual chain of `hash` and of all the blocks in its future, and re-resolves
+    /// virtual. Blocks which are actually UTXO-invalid are disqualified again once virtual resolution re-verifies them.
+    pub fn reconsider_block(self: &Arc<Self>, hash: Hash) -> ConsensusResult<()> {
+        self.verify_chain_eligibility_changeable(hash)?;
+
+        // PRUNE SAFETY: the future of the virtual finality point is never pruned
+        let mut reconsidered_count = 0;
+        let mut queue = VecDeque::from([hash]);
+        let mut visited = BlockHashSet::from_iter([hash]);
+        while let Some(current) = queue.pop_front() {
+            let statuses_read = self.statuses_store.upgradable_read();
+            if statuses_read.get(current).unwrap() == StatusDisqualifiedFromChain {
+                // Blocks holding a UTXO diff were verified prior to their disqualification
+                let status = if self.utxo_diffs_store.get(current).unwrap_option().is_some() {
+                    StatusUTXOValid
+                } else {
+                    StatusUTXOPendingVerification
+                };
+                RwLockUpgradableReadGuard::upgrade(statuses_read).set(current, status).unwrap();
+                reconsidered_count += 1;
+            } else {
+                drop(statuses_read);
+            }
+            for child in self.relations_service.get_children(current).unwrap().read().iter().copied() {
+                if visited.insert(child) {
+                    queue.push_back(child);
+                }
+            }
+        }
+        info!("Block {} was reconsidered, requalifying {} blocks for the virtual chain", hash, reconsidered_count);
+
+        self.resolve_virtual();
+        Ok(())
+    }
+
     /// Executes `op` within the thread pool associated with this processor.
     pub fn install<OP, R>(&self, op: OP) -> R
     where
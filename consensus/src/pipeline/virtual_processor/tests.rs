@@ -1,4 +1,7 @@
-use crate::{consensus::test_consensus::TestConsensus, model::services::reachability::ReachabilityService};
+use crate::{
+    consensus::test_consensus::TestConsensus, model::services::reachability::ReachabilityService,
+    processes::transaction_validator::tx_validation_in_utxo_context::TxValidationFlags,
+};
 use spectre_consensus_core::{
     api::ConsensusApi,
     block::{Block, BlockTemplate, MutableBlock, TemplateBuildMode, TemplateTransactionSelector},
@@ -6,6 +9,7 @@ use spectre_consensus_core::{
     blockstatus::BlockStatus,
     coinbase::MinerData,
     config::{params::MAINNET_PARAMS, ConfigBuilder},
+    errors::consensus::ConsensusError,
     tx::{ScriptPublicKey, ScriptVec, Transaction},
     BlockHashSet,
 };
@@ -296,6 +300,44 @@ async fn double_search_disqualified_test() {
     ctx.assert_tips_num(1);
 }
 
+#[tokio::test]
+async fn invalidate_and_reconsider_block_test() {
+    spectre_core::log::try_init_logger("info");
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+    let mut ctx = TestContext::new(TestConsensus::new(&config));
+
+    // Mine a valid chain
+    let mut chain = vec![];
+    for _ in 0..10 {
+        ctx.build_block_template_row(0..1).validate_and_insert_row().await.assert_valid_utxo_tip();
+        chain.push(ctx.consensus.get_sink());
+    }
+
+    // Invalidating a chain block moves the sink to its selected parent, disqualifying its chain future
+    ctx.consensus.invalidate_block(chain[7]).unwrap();
+    assert_eq!(ctx.consensus.get_sink(), chain[6]);
+    for &hash in chain[7..].iter() {
+        assert_eq!(ctx.consensus.get_block_status(hash), Some(BlockStatus::StatusDisqualifiedFromChain));
+    }
+
+    // New blocks are mined over the remaining chain
+    ctx.build_block_template_row(0..1).validate_and_insert_row().await.assert_valid_utxo_tip();
+    let fork_tip = ctx.consensus.get_sink();
+    assert!(ctx.consensus.reachability_service().is_chain_ancestor_of(chain[6], fork_tip));
+    assert!(!ctx.consensus.reachability_service().is_chain_ancestor_of(chain[7], fork_tip));
+
+    // Reconsidering the block requalifies its future, bringing back the heavier chain
+    ctx.consensus.reconsider_block(chain[7]).unwrap();
+    assert_eq!(ctx.consensus.get_sink(), chain[9]);
+    for &hash in chain[7..].iter() {
+        assert_eq!(ctx.consensus.get_block_status(hash), Some(BlockStatus::StatusUTXOValid));
+    }
+
+    // Blocks below the finality point and unknown blocks are rejected
+    assert!(matches!(ctx.consensus.invalidate_block(config.genesis.hash), Err(ConsensusError::BlockNotInFinalityPointFuture(_))));
+    assert!(matches!(ctx.consensus.reconsider_block(Hash::from(7)), Err(ConsensusError::BlockNotFound(_))));
+}
+
 fn new_miner_data() -> MinerData {
     let secp = secp256k1::Secp256k1::new();
     let mut rng = rand::thread_rng();
@@ -303,3 +345,76 @@ fn new_miner_data() -> MinerData {
     let script = ScriptVec::from_slice(&pk.serialize());
     MinerData::new(ScriptPublicKey::new(0, script), vec![])
 }
+
+#[tokio::test]
+async fn assume_valid_chain_past_test() {
+    let assume_valid: Hash = 3.into();
+    let config =
+        ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().edit_consensus_params(|p| p.assume_valid = Some(assume_valid)).build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+    let is_assumed_valid =
+        |hash: u64| consensus.virtual_processor().chain_block_validation_flags(hash.into()) == TxValidationFlags::SkipScriptChecks;
+
+    consensus.add_empty_utxo_valid_block_with_parents(1.into(), vec![config.genesis.hash]).await.unwrap();
+    consensus.add_empty_utxo_valid_block_with_parents(2.into(), vec![1.into()]).await.unwrap();
+    // Scripts are fully verified as long as the assume-valid block is unknown
+    assert!(!is_assumed_valid(1));
+
+    consensus.add_empty_utxo_valid_block_with_parents(3.into(), vec![2.into()]).await.unwrap();
+    consensus.add_empty_utxo_valid_block_with_parents(4.into(), vec![3.into()]).await.unwrap();
+    // Block 5 is in the past of 4 but not in the selected chain past of the assume-valid block
+    consensus.add_empty_utxo_valid_block_with_parents(5.into(), vec![1.into()]).await.unwrap();
+
+    assert!(is_assumed_valid(1));
+    assert!(is_assumed_valid(2));
+    assert!(is_assumed_valid(3));
+    assert!(!is_assumed_valid(4));
+    assert!(!is_assumed_valid(5));
+
+    consensus.shutdown(wait_handles);
+}
+
+#[tokio::test]
+async fn chain_blocks_by_score_test() {
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+    let mut ctx = TestContext::new(TestConsensus::new(&config));
+
+    // Rows of 2 blocks make the chain block scores non-contiguous
+    for _ in 0..10 {
+        ctx.build_block_template_row(0..2).validate_and_insert_row().await.assert_valid_utxo_tip();
+    }
+
+    let chain = std::iter::once(config.genesis.hash)
+        .chain(ctx.consensus.get_virtual_chain_from_block(config.genesis.hash, None).unwrap().added)
+        .collect::<Vec<_>>();
+    let blue_scores = chain.iter().map(|&hash| ctx.consensus.get_header(hash).unwrap().blue_score).collect::<Vec<_>>();
+    let daa_scores = chain.iter().map(|&hash| ctx.consensus.get_header(hash).unwrap().daa_score).collect::<Vec<_>>();
+    let hashes = |blocks: Vec<(u64, Hash)>| blocks.into_iter().map(|(_, hash)| hash).collect::<Vec<_>>();
+
+    // The full range is the whole chain, with consecutive chain indexes
+    let blocks = ctx.consensus.get_chain_blocks_by_blue_score(0, u64::MAX, usize::MAX).unwrap();
+    assert!(blocks.windows(2).all(|w| w[1].0 == w[0].0 + 1));
+    assert_eq!(hashes(blocks), chain);
+
+    // Every score is covered by the first chain block reaching it
+    for score in 0..=*blue_scores.last().unwrap() {
+        let expected = blue_scores.iter().position(|&blue_score| blue_score >= score).unwrap();
+        assert_eq!(hashes(ctx.consensus.get_chain_blocks_by_blue_score(score, u64::MAX, 1).unwrap()), vec![chain[expected]]);
+    }
+    for score in daa_scores[0]..=*daa_scores.last().unwrap() {
+        let expected = daa_scores.iter().position(|&daa_score| daa_score >= score).unwrap();
+        assert_eq!(hashes(ctx.consensus.get_chain_blocks_by_daa_score(score, u64::MAX, 1).unwrap()), vec![chain[expected]]);
+    }
+
+    // Ranges are inclusive and bounded by the limit
+    assert_eq!(
+        hashes(ctx.consensus.get_chain_blocks_by_blue_score(blue_scores[2], blue_scores[5], usize::MAX).unwrap()),
+        chain[2..=5]
+    );
+    assert_eq!(hashes(ctx.consensus.get_chain_blocks_by_daa_score(daa_scores[2], daa_scores[5], 2).unwrap()), chain[2..4]);
+
+    // Scores beyond the sink match no chain block
+    assert!(ctx.consensus.get_chain_blocks_by_blue_score(blue_scores.last().unwrap() + 1, u64::MAX, 1).unwrap().is_empty());
+    assert!(ctx.consensus.get_chain_blocks_by_daa_score(daa_scores.last().unwrap() + 1, u64::MAX, 1).unwrap().is_empty());
+}
@@ -2,8 +2,8 @@ use std::{cmp, sync::Arc};
 
 use spectre_consensus_core::{
     acceptance_data::AcceptanceData,
-    tx::{SignableTransaction, Transaction, UtxoEntry},
-    utxo::{utxo_diff::ImmutableUtxoDiff, utxo_inquirer::UtxoInquirerError},
+    tx::{SignableTransaction, Transaction, TransactionOutpoint, UtxoEntry},
+    utxo::{utxo_collection::UtxoCollection, utxo_diff::ImmutableUtxoDiff, utxo_inquirer::UtxoInquirerError},
 };
 use spectre_core::{trace, warn};
 use spectre_hashes::Hash;
@@ -76,6 +76,76 @@ impl VirtualStateProcessor {
         Ok(populated_tx)
     }
 
+    /// Returns the transactions accepted by the chain block `accepting_block_hash` in acceptance order, each along with
+    /// the hash of its containing block and fully populated with the UTXO entries spent by its inputs.
+    ///
+    /// *Assumed to be called under the pruning read lock.*
+    pub fn get_populated_accepted_transactions(
+        &self,
+        accepting_block_hash: Hash,
+    ) -> Result<Vec<(Hash, SignableTransaction)>, UtxoInquirerError> {
+        let accepting_block_daa_score = self
+            .headers_store
+            .get_compact_header_data(accepting_block_hash)
+            .map(|compact_header| compact_header.daa_score)
+            .map_err(|_| UtxoInquirerError::MissingCompactHeaderForBlockHash(accepting_block_hash))?;
+        let acceptance_data = self
+            .acceptance_data_store
+            .get(accepting_block_hash)
+            .map_err(|_| UtxoInquirerError::MissingAcceptanceDataForChainBlock(accepting_block_hash))?;
+        let utxo_diff = self
+            .utxo_diffs_store
+            .get(accepting_block_hash)
+            .map_err(|_| UtxoInquirerError::MissingUtxoDiffForChainBlock(accepting_block_hash))?;
+        let removed_diffs = utxo_diff.removed();
+
+        // Outputs of the transactions accepted so far. A UTXO created and spent within the same mergeset is missing
+        // from removed_diffs (see `get_populated_transaction`), but its creating transaction is always accepted first.
+        let mut created_entries = UtxoCollection::new();
+        let mut populated_txs = Vec::with_capacity(acceptance_data.iter().map(|mbad| mbad.accepted_transactions.len()).sum());
+        for mbad in acceptance_data.iter() {
+            let block_txs = self
+                .block_transactions_store
+                .get(mbad.block_hash)
+                .map_err(|_| UtxoInquirerError::MissingBlockFromBlockTxStore(mbad.block_hash))?;
+            for accepted_tx in mbad.accepted_transactions.iter() {
+                let index = accepted_tx.index_within_block as usize;
+                let tx = block_txs.get(index).ok_or(UtxoInquirerError::MissingTransactionIndexOfBlock(index, mbad.block_hash))?;
+                if tx.id() != accepted_tx.transaction_id {
+                    warn!(
+                        "Expected {} to match {} when checking block_transaction_store using array index of transaction",
+                        tx.id(),
+                        accepted_tx.transaction_id
+                    );
+                    return Err(UtxoInquirerError::UnexpectedTransactionMismatch(tx.id(), accepted_tx.transaction_id));
+                }
+
+                let entries = tx
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        removed_diffs
+                            .get(&input.previous_outpoint)
+                            .or_else(|| created_entries.get(&input.previous_outpoint))
+                            .cloned()
+                            .ok_or(UtxoInquirerError::MissingOtherTransactionAcceptanceData(input.previous_outpoint.transaction_id))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let is_coinbase = tx.is_coinbase();
+                created_entries.extend(tx.outputs.iter().enumerate().map(|(index, output)| {
+                    (
+                        TransactionOutpoint::new(accepted_tx.transaction_id, index as u32),
+                        UtxoEntry::new(output.value, output.script_public_key.clone(), accepting_block_daa_score, is_coinbase),
+                    )
+                }));
+                populated_txs.push((mbad.block_hash, SignableTransaction::with_entries(tx.clone(), entries)));
+            }
+        }
+
+        Ok(populated_txs)
+    }
+
     /// Find the accepting chain block hash at the given DAA score by binary searching
     /// through selected chain store using indexes.
     /// This method assumes that local caller have acquired the pruning read lock to guarantee
@@ -4,7 +4,10 @@ use crate::{
         BlockProcessResult,
         RuleError::{BadAcceptedIDMerkleRoot, BadCoinbaseTransaction, BadUTXOCommitment, InvalidTransactionsInUtxoContext},
     },
-    model::stores::{block_transactions::BlockTransactionsStoreReader, daa::DaaStoreReader, ghostdag::GhostdagData},
+    model::{
+        services::reachability::ReachabilityService,
+        stores::{block_transactions::BlockTransactionsStoreReader, daa::DaaStoreReader, ghostdag::GhostdagData},
+    },
     processes::transaction_validator::{
         errors::{TxResult, TxRuleError},
         tx_validation_in_utxo_context::TxValidationFlags,
@@ -63,12 +66,28 @@ impl<'a> UtxoProcessingContext<'a> {
 }
 
 impl VirtualStateProcessor {
-    /// Calculates UTXO state and transaction acceptance data relative to the selected parent state
+    /// Returns the validation flags for the transactions of chain block `hash`, skipping script checks if the block
+    /// is in the selected chain past of the assume-valid block
+    pub(super) fn chain_block_validation_flags(&self, hash: Hash) -> TxValidationFlags {
+        match self.assume_valid {
+            Some(assume_valid)
+                if self.reachability_service.has_reachability_data(assume_valid)
+                    && self.reachability_service.is_chain_ancestor_of(hash, assume_valid) =>
+            {
+                TxValidationFlags::SkipScriptChecks
+            }
+            _ => TxValidationFlags::Full,
+        }
+    }
+
+    /// Calculates UTXO state and transaction acceptance data relative to the selected parent state.
+    /// `flags` apply to the transactions of the mergeset blocks other than the selected parent
     pub(super) fn calculate_utxo_state<V: UtxoView + Sync>(
         &self,
         ctx: &mut UtxoProcessingContext,
         selected_parent_utxo_view: &V,
         pov_daa_score: u64,
+        flags: TxValidationFlags,
     ) {
         let selected_parent_transactions = self.block_transactions_store.get(ctx.selected_parent()).unwrap();
         let validated_coinbase = ValidatedTransaction::new_coinbase(&selected_parent_transactions[0]);
@@ -94,7 +113,7 @@ impl VirtualStateProcessor {
 
             // No need to fully validate selected parent transactions since selected parent txs were already validated
             // as part of selected parent UTXO state verification with the exact same UTXO context.
-            let validation_flags = if is_selected_parent { TxValidationFlags::SkipScriptChecks } else { TxValidationFlags::Full };
+            let validation_flags = if is_selected_parent { TxValidationFlags::SkipScriptChecks } else { flags };
             let (validated_transactions, inner_multiset) =
                 self.validate_transactions_with_muhash_in_parallel(&txs, &composed_view, pov_daa_score, validation_flags);
 
@@ -152,6 +171,7 @@ impl VirtualStateProcessor {
         ctx: &mut UtxoProcessingContext,
         selected_parent_utxo_view: &V,
         header: &Header,
+        flags: TxValidationFlags,
     ) -> BlockProcessResult<()> {
         // Verify header UTXO commitment
         let expected_commitment = ctx.multiset_hash.finalize();
@@ -179,8 +199,7 @@ impl VirtualStateProcessor {
 
         // Verify all transactions are valid in context
         let current_utxo_view = selected_parent_utxo_view.compose(&ctx.mergeset_diff);
-        let validated_transactions =
-            self.validate_transactions_in_parallel(&txs, &current_utxo_view, header.daa_score, TxValidationFlags::Full);
+        let validated_transactions = self.validate_transactions_in_parallel(&txs, &current_utxo_view, header.daa_score, flags);
         if validated_transactions.len() < txs.len() - 1 {
             // Some non-coinbase transactions are invalid
             return Err(InvalidTransactionsInUtxoContext(txs.len() - 1 - validated_transactions.len(), txs.len() - 1));
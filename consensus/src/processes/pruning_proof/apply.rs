@@ -6,7 +6,6 @@ use std::{
 };
 
 use itertools::Itertools;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{
     blockhash::{BlockHashes, ORIGIN},
     errors::pruning::{PruningImportError, PruningImportResult},
@@ -133,7 +132,7 @@ impl PruningProofManager {
         });
         self.virtual_stores.write().state.set(virtual_state).unwrap();
 
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         self.body_tips_store.write().init_batch(&mut batch, &virtual_parents).unwrap();
         self.headers_selected_tip_store
             .write()
@@ -225,7 +224,7 @@ impl PruningProofManager {
             let selected_parent = reachability_parents.iter().max().map(|parent| parent.hash).unwrap_or(ORIGIN);
 
             // Prepare batch
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             let mut reachability_relations_write = self.reachability_relations_store.write();
             let mut staging_reachability = StagingReachabilityStore::new(reachability_read);
             let mut staging_reachability_relations = StagingRelationsStore::new(&mut reachability_relations_write);
@@ -13,7 +13,6 @@ use std::{
 use itertools::Itertools;
 use parking_lot::{Mutex, RwLock};
 use rayon::prelude::*;
-use rocksdb::WriteBatch;
 
 use spectre_consensus_core::{
     blockhash::{self, BlockHashExtensions},
@@ -199,7 +198,7 @@ impl PruningProofManager {
         info!("Setting {new_pruning_point} as the staging pruning point");
 
         let mut pruning_point_write = self.pruning_point_store.write();
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         pruning_point_write.set_batch(&mut batch, new_pruning_point, new_pruning_point, (pruning_points.len() - 1) as u64).unwrap();
         pruning_point_write.set_history_root(&mut batch, new_pruning_point).unwrap();
         self.db.write(batch).unwrap();
@@ -6,7 +6,6 @@ use std::{
 
 use itertools::Itertools;
 use parking_lot::lock_api::RwLock;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{
     blockhash::{BlockHashExtensions, BlockHashes, ORIGIN},
     errors::pruning::{PruningImportError, PruningImportResult},
@@ -212,7 +211,7 @@ impl PruningProofManager {
             .collect_vec();
 
         {
-            let mut batch = WriteBatch::default();
+            let mut batch = self.db.new_batch();
             for level in 0..=self.max_block_level {
                 let level = level as usize;
                 reachability::init(reachability_stores[level].write().deref_mut()).unwrap();
@@ -268,10 +268,9 @@ mod tests {
     use itertools::Itertools;
     use parking_lot::RwLock;
     use rand::seq::IteratorRandom;
-    use rocksdb::WriteBatch;
     use spectre_consensus_core::blockhash::ORIGIN;
     use spectre_database::prelude::ConnBuilder;
-    use spectre_database::{create_temp_db, prelude::CachePolicy};
+    use spectre_database::{create_memory_db, prelude::CachePolicy};
     use std::{iter::once, ops::Deref};
 
     #[test]
@@ -386,7 +385,7 @@ mod tests {
     /// Runs a DAG test-case with full verification using the staging store mechanism.
     /// Note: runtime is quadratic in the number of blocks so should be used with mildly small DAGs (~50)
     fn run_dag_test_case_with_staging(test: &DagTestCase) {
-        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
         let cache_policy = CachePolicy::Count(test.blocks.len() / 3);
         let reachability = RwLock::new(DbReachabilityStore::new(db.clone(), cache_policy, cache_policy));
         let mut relations = DbRelationsStore::with_prefix(db.clone(), &[], CachePolicy::Empty, CachePolicy::Empty);
@@ -404,7 +403,7 @@ mod tests {
 
             // Commit the staging changes
             {
-                let mut batch = WriteBatch::default();
+                let mut batch = db.new_batch();
                 let reachability_write = staging_reachability.commit(&mut batch).unwrap();
                 staging_relations.commit(&mut batch).unwrap();
                 db.write(batch).unwrap();
@@ -441,7 +440,7 @@ mod tests {
 
         drop(reachability_read);
 
-        let mut batch = WriteBatch::default();
+        let mut batch = db.new_batch();
         let mut staging_reachability = StagingReachabilityStore::new(reachability.upgradable_read());
         let mut staging_relations = StagingRelationsStore::new(&mut relations);
 
@@ -473,7 +472,7 @@ mod tests {
                 }
 
                 // Recapture staging stores
-                batch = WriteBatch::default();
+                batch = db.new_batch();
                 staging_reachability = StagingReachabilityStore::new(reachability.upgradable_read());
                 staging_relations = StagingRelationsStore::new(&mut relations);
             }
@@ -520,7 +519,7 @@ mod tests {
             run_dag_test_case(&mut relations, &mut reachability, &test);
 
             // Run with direct DB stores
-            let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+            let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
             let cache_policy = CachePolicy::Count(test.blocks.len() / 3);
             let mut reachability = DbReachabilityStore::new(db.clone(), cache_policy, cache_policy);
             let mut relations = DbRelationsStore::new(db, 0, cache_policy, cache_policy);
@@ -4,12 +4,11 @@ use crate::model::{
     stores::{children::ChildrenStore, relations::RelationsStore},
 };
 use itertools::Itertools;
-use rocksdb::WriteBatch;
 use spectre_consensus_core::{
     blockhash::{BlockHashIteratorExtensions, BlockHashes, ORIGIN},
     BlockHashSet,
 };
-use spectre_database::prelude::{BatchDbWriter, DbWriter, DirectWriter, StoreError};
+use spectre_database::prelude::{BatchDbWriter, DbWriter, DirectWriter, StoreError, WriteBatch};
 use spectre_hashes::Hash;
 
 /// Initializes this relations store with an `origin` root
@@ -154,12 +153,12 @@ mod tests {
     use crate::model::stores::relations::{DbRelationsStore, RelationsStoreReader, StagingRelationsStore};
     use spectre_core::assert_match;
     use spectre_database::prelude::{CachePolicy, ConnBuilder};
-    use spectre_database::{create_temp_db, prelude::MemoryWriter};
+    use spectre_database::{create_memory_db, prelude::MemoryWriter};
     use std::sync::Arc;
 
     #[test]
     fn test_delete_level_relations_zero_cache() {
-        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
         let mut relations = DbRelationsStore::new(db.clone(), 0, CachePolicy::Empty, CachePolicy::Empty);
         relations.insert(ORIGIN, Default::default()).unwrap();
         relations.insert(1.into(), Arc::new(vec![ORIGIN])).unwrap();
@@ -181,7 +180,7 @@ mod tests {
             BlockHashSet::from_iter([])
         );
 
-        let mut batch = WriteBatch::default();
+        let mut batch = db.new_batch();
         let mut staging_relations = StagingRelationsStore::new(&mut relations);
         delete_level_relations(MemoryWriter, &mut staging_relations, 1.into()).unwrap();
         staging_relations.commit(&mut batch).unwrap();
@@ -201,7 +200,7 @@ mod tests {
             BlockHashSet::from_iter([])
         );
 
-        let mut batch = WriteBatch::default();
+        let mut batch = db.new_batch();
         let mut staging_relations = StagingRelationsStore::new(&mut relations);
         delete_level_relations(MemoryWriter, &mut staging_relations, 2.into()).unwrap();
         staging_relations.commit(&mut batch).unwrap();
@@ -24,7 +24,8 @@ pub enum TxValidationFlags {
     Full,
 
     /// Perform fee and sequence/maturity validations but skip script checks. This is usually
-    /// an optimization to be applied when it is known that scripts were already checked
+    /// an optimization to be applied when it is known that scripts were already checked, or
+    /// assumed valid for being in the selected chain past of the configured assume-valid block
     SkipScriptChecks,
 
     /// When validating mempool transactions, we just set this value ourselves
@@ -181,6 +182,30 @@ impl TransactionValidator {
             self.runtime_sig_op_counting.is_active(pov_daa_score),
         )
     }
+
+    /// Executes the script of every input without stopping at the first failure, returning one result per input
+    pub fn check_scripts_per_input(&self, tx: &impl VerifiableTransaction, pov_daa_score: u64) -> Vec<TxResult<()>> {
+        let kip10_enabled = self.kip10_activation.is_active(pov_daa_score);
+        let runtime_sig_op_counting = self.runtime_sig_op_counting.is_active(pov_daa_score);
+        let reused_values = SigHashReusedValuesUnsync::new();
+        tx.populated_inputs()
+            .enumerate()
+            .map(|(i, (input, entry))| {
+                TxScriptEngine::from_transaction_input(
+                    tx,
+                    input,
+                    i,
+                    entry,
+                    &reused_values,
+                    &self.sig_cache,
+                    kip10_enabled,
+                    runtime_sig_op_counting,
+                )
+                .execute()
+                .map_err(|err| map_script_err(err, input))
+            })
+            .collect()
+    }
 }
 
 pub fn check_scripts(
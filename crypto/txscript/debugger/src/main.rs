@@ -0,0 +1,200 @@
+//!
+//! Runs the scripts of a transaction input step by step, printing the engine state after each opcode.
+//!
+//! The input file holds the spending transaction and the UTXO entries it spends, in input order:
+//!
+//! ```json
+//! {
+//!   "transaction": {
+//!     "version": 0,
+//!     "inputs": [{
+//!       "previousOutpoint": { "transactionId": "<hex>", "index": 0 },
+//!       "signatureScript": "<hex>",
+//!       "sequence": 0,
+//!       "sigOpCount": 1
+//!     }],
+//!     "outputs": [{ "value": 1000, "scriptPublicKey": "<version><script hex>" }],
+//!     "lockTime": 0
+//!   },
+//!   "utxoEntries": [{ "amount": 2000, "scriptPublicKey": "<version><script hex>", "blockDaaScore": 0, "isCoinbase": false }]
+//! }
+//! ```
+//!
+
+use clap::{Arg, ArgAction, Command};
+use serde::Deserialize;
+use spectre_consensus_core::{
+    hashing::sighash::SigHashReusedValuesUnsync,
+    subnets::SubnetworkId,
+    tx::{PopulatedTransaction, Transaction, TransactionInput, TransactionOutput, UtxoEntry},
+};
+use spectre_txscript::{
+    caches::Cache,
+    debugger::{ScriptDebugger, Step},
+    opcodes::OpCond,
+    TxScriptEngine,
+};
+use spectre_utils::{hex::ToHex, serde_bytes};
+use std::{fs::File, io::BufReader, process::ExitCode};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionJson {
+    #[serde(default)]
+    version: u16,
+    inputs: Vec<TransactionInput>,
+    #[serde(default)]
+    outputs: Vec<TransactionOutput>,
+    #[serde(default)]
+    lock_time: u64,
+    #[serde(default)]
+    subnetwork_id: SubnetworkId,
+    #[serde(default)]
+    gas: u64,
+    #[serde(default, with = "serde_bytes")]
+    payload: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Input {
+    transaction: TransactionJson,
+    utxo_entries: Vec<UtxoEntry>,
+}
+
+struct Args {
+    file: String,
+    input_index: usize,
+    kip10_enabled: bool,
+    runtime_sig_op_counting: bool,
+    quiet: bool,
+}
+
+impl Args {
+    fn parse() -> Self {
+        let m = cli().get_matches();
+        Args {
+            file: m.get_one::<String>("file").cloned().unwrap(),
+            input_index: m.get_one::<usize>("input").cloned().unwrap(),
+            kip10_enabled: !m.get_flag("no-kip10"),
+            runtime_sig_op_counting: m.get_flag("runtime-sig-op-counting"),
+            quiet: m.get_flag("quiet"),
+        }
+    }
+}
+
+fn cli() -> Command {
+    Command::new("txscript-debugger")
+        .about(format!("{} (txscript-debugger) v{}", env!("CARGO_PKG_DESCRIPTION"), env!("CARGO_PKG_VERSION")))
+        .version(env!("CARGO_PKG_VERSION"))
+        .arg(
+            Arg::new("file")
+                .required(true)
+                .value_name("FILE")
+                .help("JSON file describing the transaction and the UTXO entries it spends"),
+        )
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .short('i')
+                .value_name("INDEX")
+                .default_value("0")
+                .value_parser(clap::value_parser!(usize))
+                .help("Index of the transaction input to execute"),
+        )
+        .arg(Arg::new("no-kip10").long("no-kip10").action(ArgAction::SetTrue).help("Disable the KIP-10 introspection opcodes"))
+        .arg(
+            Arg::new("runtime-sig-op-counting")
+                .long("runtime-sig-op-counting")
+                .action(ArgAction::SetTrue)
+                .help("Limit the executed signature operations to the input sig op count"),
+        )
+        .arg(Arg::new("quiet").long("quiet").short('q').action(ArgAction::SetTrue).help("Only print the execution result"))
+}
+
+fn load(path: &str) -> Result<(Transaction, Vec<UtxoEntry>), String> {
+    let file = File::open(path).map_err(|err| format!("cannot open {path}: {err}"))?;
+    let input: Input = serde_json::from_reader(BufReader::new(file)).map_err(|err| format!("cannot parse {path}: {err}"))?;
+    let TransactionJson { version, inputs, outputs, lock_time, subnetwork_id, gas, payload } = input.transaction;
+    if inputs.len() != input.utxo_entries.len() {
+        return Err(format!("the transaction has {} inputs but {} UTXO entries are provided", inputs.len(), input.utxo_entries.len()));
+    }
+    Ok((Transaction::new(version, inputs, outputs, lock_time, subnetwork_id, gas, payload), input.utxo_entries))
+}
+
+fn format_stack(stack: &[Vec<u8>]) -> String {
+    stack.iter().map(|item| if item.is_empty() { "<empty>".to_string() } else { item.to_hex() }).collect::<Vec<_>>().join(" ")
+}
+
+fn format_cond_stack(cond_stack: &[OpCond]) -> String {
+    cond_stack.iter().map(|cond| format!("{cond:?}")).collect::<Vec<_>>().join(" ")
+}
+
+fn print_step(step: &Step) {
+    let skipped = if step.executed { "" } else { " (skipped)" };
+    if step.data.is_empty() {
+        println!("[{} @{}] {}{}", step.script, step.position, step.name(), skipped);
+    } else {
+        println!("[{} @{}] {} 0x{}{}", step.script, step.position, step.name(), step.data.to_hex(), skipped);
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let (tx, entries) = match load(&args.file) {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if args.input_index >= tx.inputs.len() {
+        eprintln!("input index {} is out of range, the transaction has {} inputs", args.input_index, tx.inputs.len());
+        return ExitCode::FAILURE;
+    }
+
+    let populated_tx = PopulatedTransaction::new(&tx, entries);
+    let sig_cache = Cache::new(10_000);
+    let reused_values = SigHashReusedValuesUnsync::new();
+    let engine = TxScriptEngine::from_transaction_input(
+        &populated_tx,
+        &populated_tx.tx.inputs[args.input_index],
+        args.input_index,
+        &populated_tx.entries[args.input_index],
+        &reused_values,
+        &sig_cache,
+        args.kip10_enabled,
+        args.runtime_sig_op_counting,
+    );
+    let mut debugger = ScriptDebugger::new(engine);
+
+    while let Some(step) = debugger.step() {
+        if args.quiet {
+            continue;
+        }
+        match step {
+            Ok(step) => {
+                print_step(&step);
+                println!("    stack:      {}", format_stack(debugger.data_stack()));
+                println!("    alt stack:  {}", format_stack(debugger.alt_stack()));
+                println!("    cond stack: {}", format_cond_stack(debugger.cond_stack()));
+                match debugger.used_sig_ops() {
+                    Some(used_sig_ops) => println!("    ops: {}, sig ops: {}", debugger.num_ops(), used_sig_ops),
+                    None => println!("    ops: {}", debugger.num_ops()),
+                }
+            }
+            Err(err) => println!("error: {err}"),
+        }
+    }
+
+    match debugger.result().expect("the execution has completed") {
+        Ok(()) => {
+            println!("Input {} successfully validated", args.input_index);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            println!("Input {} failed validation: {}", args.input_index, err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,351 @@
+//!
+//! Step-through execution of scripts, exposing the state of the engine between opcodes.
+//!
+//! The debugger runs the exact same checks as [`TxScriptEngine::execute`], one opcode at a time, so
+//! it can be used to trace why a script fails or what a covenant observes while being evaluated.
+//!
+
+use crate::{
+    data_stack::Stack,
+    opcodes::{deserialize_next_opcode, opcode_name, OpCond},
+    ScriptSource, TxScriptEngine,
+};
+use spectre_consensus_core::{hashing::sighash::SigHashReusedValues, tx::VerifiableTransaction};
+use spectre_txscript_errors::TxScriptError;
+use std::fmt::{Display, Formatter};
+
+/// Role of a script in the execution of a transaction input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptKind {
+    SignatureScript,
+    ScriptPublicKey,
+    /// Script revealed by the signature script of a pay-to-script-hash input
+    RedeemScript,
+    /// Script executed outside of a transaction input context
+    StandAlone,
+}
+
+impl Display for ScriptKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ScriptKind::SignatureScript => "signature script",
+            ScriptKind::ScriptPublicKey => "script public key",
+            ScriptKind::RedeemScript => "redeem script",
+            ScriptKind::StandAlone => "script",
+        };
+        f.write_str(name)
+    }
+}
+
+/// An opcode processed by the debugger
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// Script the opcode belongs to
+    pub script: ScriptKind,
+    /// Byte offset of the opcode in the script
+    pub position: usize,
+    pub opcode: u8,
+    /// Data pushed by the opcode, if any
+    pub data: Vec<u8>,
+    /// Whether the opcode was executed, as opposed to skipped by a non-executing conditional branch
+    pub executed: bool,
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        opcode_name(self.opcode)
+    }
+}
+
+/// Executes the scripts of a [`TxScriptEngine`] one opcode at a time.
+///
+/// Each call to [`ScriptDebugger::step`] executes the next opcode, after which the stacks, the conditional stack
+/// and the operation counters of the engine can be inspected. Checks run between scripts, such as the conditional
+/// balance or the pay-to-script-hash validation, are performed at the beginning of the following step.
+pub struct ScriptDebugger<'a, T: VerifiableTransaction, Reused: SigHashReusedValues> {
+    engine: TxScriptEngine<'a, T, Reused>,
+    scripts: Vec<(ScriptKind, Vec<u8>)>,
+    is_p2sh: bool,
+    saved_stack: Option<Stack>,
+    script_index: usize,
+    position: usize,
+    result: Option<Result<(), TxScriptError>>,
+}
+
+impl<'a, T: VerifiableTransaction, Reused: SigHashReusedValues> ScriptDebugger<'a, T, Reused> {
+    pub fn new(engine: TxScriptEngine<'a, T, Reused>) -> Self {
+        let mut debugger =
+            Self { engine, scripts: vec![], is_p2sh: false, saved_stack: None, script_index: 0, position: 0, result: None };
+        match debugger.engine.scripts() {
+            Ok(Some(scripts)) => {
+                let kinds: &[ScriptKind] = match debugger.engine.script_source {
+                    ScriptSource::TxInput { .. } => &[ScriptKind::SignatureScript, ScriptKind::ScriptPublicKey],
+                    ScriptSource::StandAloneScripts(_) => &[],
+                };
+                debugger.scripts = scripts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, script)| (kinds.get(i).copied().unwrap_or(ScriptKind::StandAlone), script.to_vec()))
+                    .collect();
+                debugger.is_p2sh = debugger.engine.is_p2sh();
+            }
+            Ok(None) => debugger.result = Some(Ok(())),
+            Err(err) => debugger.result = Some(Err(err)),
+        }
+        debugger
+    }
+
+    /// Executes the next opcode.
+    ///
+    /// Returns `None` once the execution has completed, in which case [`ScriptDebugger::result`] holds the outcome.
+    /// An error is returned by the step at which the execution failed.
+    pub fn step(&mut self) -> Option<Result<Step, TxScriptError>> {
+        if self.result.is_some() {
+            return None;
+        }
+        match self.advance() {
+            Ok(true) => {}
+            Ok(false) => {
+                self.result = Some(Ok(()));
+                return None;
+            }
+            Err(err) => {
+                self.result = Some(Err(err.clone()));
+                return Some(Err(err));
+            }
+        }
+
+        let (kind, script) = &self.scripts[self.script_index];
+        let mut it = script[self.position..].iter();
+        let opcode = deserialize_next_opcode(&mut it).expect("the script is not exhausted");
+        let length = script.len() - self.position - it.len();
+        let kind = *kind;
+
+        let result = opcode.and_then(|opcode| {
+            let step = Step {
+                script: kind,
+                position: self.position,
+                opcode: opcode.value(),
+                data: opcode.get_data().to_vec(),
+                executed: self.engine.is_executing() || opcode.is_conditional(),
+            };
+            self.engine.execute_next_opcode(opcode, kind == ScriptKind::SignatureScript).map(|_| step)
+        });
+        self.position += length;
+        if let Err(err) = &result {
+            self.result = Some(self.engine.end_script(Err(err.clone())));
+        }
+        Some(result)
+    }
+
+    /// Executes all the remaining opcodes and returns the outcome of the execution
+    pub fn run(&mut self) -> Result<(), TxScriptError> {
+        while self.step().is_some() {}
+        self.result.clone().expect("the execution has completed")
+    }
+
+    /// Moves to the next opcode to execute, completing the scripts on the way. Returns `false` if all the scripts
+    /// were successfully executed.
+    fn advance(&mut self) -> Result<bool, TxScriptError> {
+        loop {
+            if let Some((_, script)) = self.scripts.get(self.script_index) {
+                if self.position < script.len() {
+                    return Ok(true);
+                }
+                if !script.is_empty() {
+                    self.engine.end_script(Ok(()))?;
+                }
+                self.script_index += 1;
+                self.position = 0;
+                // Save script in p2sh
+                if self.is_p2sh && self.script_index == 1 {
+                    self.saved_stack = Some(self.engine.dstack.clone());
+                }
+                continue;
+            }
+
+            if self.is_p2sh && self.scripts.len() == 2 {
+                self.engine.check_error_condition(false)?;
+                self.engine.dstack = self.saved_stack.take().ok_or(TxScriptError::EmptyStack)?;
+                let script = self.engine.dstack.pop().ok_or(TxScriptError::EmptyStack)?;
+                self.scripts.push((ScriptKind::RedeemScript, script));
+                continue;
+            }
+
+            self.engine.check_error_condition(true)?;
+            return Ok(false);
+        }
+    }
+
+    /// Outcome of the execution, once completed
+    pub fn result(&self) -> Option<&Result<(), TxScriptError>> {
+        self.result.as_ref()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// Script holding the next opcode to execute
+    pub fn script(&self) -> Option<(ScriptKind, &[u8])> {
+        self.scripts.get(self.script_index).map(|(kind, script)| (*kind, script.as_slice()))
+    }
+
+    /// Byte offset of the next opcode to execute in the current script
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn data_stack(&self) -> &[Vec<u8>] {
+        &self.engine.dstack
+    }
+
+    pub fn alt_stack(&self) -> &[Vec<u8>] {
+        &self.engine.astack
+    }
+
+    pub fn cond_stack(&self) -> &[OpCond] {
+        &self.engine.cond_stack
+    }
+
+    /// Number of non-push operations executed so far in the current script
+    pub fn num_ops(&self) -> i32 {
+        self.engine.num_ops
+    }
+
+    /// Returns the number of signature operations used so far if runtime sig op counting is enabled
+    pub fn used_sig_ops(&self) -> Option<u8> {
+        self.engine.used_sig_ops()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        caches::Cache,
+        opcodes::codes::{OpAdd, OpDrop, OpElse, OpEndIf, OpEqual, OpFromAltStack, OpIf, OpToAltStack, OpTrue},
+        pay_to_script_hash_script, pay_to_script_hash_signature_script,
+        script_builder::ScriptBuilder,
+    };
+    use spectre_consensus_core::{
+        hashing::sighash::SigHashReusedValuesUnsync,
+        subnets::SUBNETWORK_ID_NATIVE,
+        tx::{PopulatedTransaction, Transaction, TransactionId, TransactionInput, TransactionOutpoint, UtxoEntry},
+    };
+
+    #[test]
+    fn test_step_stand_alone_script() {
+        let script = ScriptBuilder::new()
+            .add_i64(2)
+            .unwrap()
+            .add_op(OpToAltStack)
+            .unwrap()
+            .add_i64(0)
+            .unwrap()
+            .add_op(OpIf)
+            .unwrap()
+            .add_i64(5)
+            .unwrap()
+            .add_op(OpElse)
+            .unwrap()
+            .add_i64(3)
+            .unwrap()
+            .add_op(OpEndIf)
+            .unwrap()
+            .add_op(OpFromAltStack)
+            .unwrap()
+            .add_op(OpAdd)
+            .unwrap()
+            .add_i64(5)
+            .unwrap()
+            .add_op(OpEqual)
+            .unwrap()
+            .drain();
+        let sig_cache = Cache::new(10_000);
+        let reused_values = SigHashReusedValuesUnsync::new();
+        let engine = TxScriptEngine::<PopulatedTransaction, _>::from_script(&script, &reused_values, &sig_cache, false);
+        let mut debugger = ScriptDebugger::new(engine);
+
+        let step = debugger.step().unwrap().unwrap();
+        assert_eq!((step.script, step.position, step.name(), step.executed), (ScriptKind::StandAlone, 0, "Op2", true));
+        assert_eq!(debugger.data_stack(), &[vec![2]]);
+        debugger.step().unwrap().unwrap();
+        assert_eq!(debugger.data_stack(), &[] as &[Vec<u8>]);
+        assert_eq!(debugger.alt_stack(), &[vec![2]]);
+        debugger.step().unwrap().unwrap();
+        debugger.step().unwrap().unwrap();
+        assert_eq!(debugger.cond_stack(), &[OpCond::False]);
+
+        // The true branch is skipped
+        let step = debugger.step().unwrap().unwrap();
+        assert_eq!((step.name(), step.executed), ("Op5", false));
+        assert!(debugger.data_stack().is_empty());
+        let step = debugger.step().unwrap().unwrap();
+        assert_eq!((step.name(), step.executed), ("OpElse", true));
+        assert_eq!(debugger.cond_stack(), &[OpCond::True]);
+        debugger.step().unwrap().unwrap();
+        assert_eq!(debugger.data_stack(), &[vec![3]]);
+        debugger.step().unwrap().unwrap();
+        assert!(debugger.cond_stack().is_empty());
+        assert_eq!(debugger.num_ops(), 4);
+
+        assert_eq!(debugger.run(), Ok(()));
+        assert!(debugger.is_completed());
+        assert_eq!(debugger.step(), None);
+    }
+
+    #[test]
+    fn test_step_failure() {
+        let script = [OpTrue, OpIf, OpTrue];
+        let sig_cache = Cache::new(10_000);
+        let reused_values = SigHashReusedValuesUnsync::new();
+        let engine = TxScriptEngine::<PopulatedTransaction, _>::from_script(&script, &reused_values, &sig_cache, false);
+        let mut debugger = ScriptDebugger::new(engine);
+        for _ in 0..script.len() {
+            assert!(debugger.step().unwrap().is_ok());
+        }
+        // The conditional balance is checked when moving past the script
+        assert_eq!(debugger.step(), Some(Err(TxScriptError::ErrUnbalancedConditional)));
+        assert_eq!(debugger.result(), Some(&Err(TxScriptError::ErrUnbalancedConditional)));
+        assert_eq!(debugger.step(), None);
+    }
+
+    #[test]
+    fn test_step_pay_to_script_hash() {
+        let redeem_script = ScriptBuilder::new().add_op(OpDrop).unwrap().add_op(OpTrue).unwrap().drain();
+        let mut signature_script = ScriptBuilder::new().add_i64(7).unwrap().drain();
+        signature_script = pay_to_script_hash_signature_script(redeem_script.clone(), signature_script).unwrap();
+        let input = TransactionInput::new(TransactionOutpoint::new(TransactionId::from_bytes([1; 32]), 0), signature_script, 0, 0);
+        let tx = Transaction::new(0, vec![input], vec![], 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+        let entry = UtxoEntry::new(1000, pay_to_script_hash_script(&redeem_script), 0, false);
+        let tx = PopulatedTransaction::new(&tx, vec![entry]);
+
+        let sig_cache = Cache::new(10_000);
+        let reused_values = SigHashReusedValuesUnsync::new();
+        let engine =
+            TxScriptEngine::from_transaction_input(&tx, &tx.tx.inputs[0], 0, &tx.entries[0], &reused_values, &sig_cache, false, true);
+        let mut debugger = ScriptDebugger::new(engine);
+
+        let mut steps = vec![];
+        while let Some(step) = debugger.step() {
+            steps.push(step.unwrap());
+        }
+        assert_eq!(debugger.result(), Some(&Ok(())));
+        assert_eq!(debugger.used_sig_ops(), Some(0));
+        assert_eq!(
+            steps.iter().map(|step| (step.script, step.name())).collect::<Vec<_>>(),
+            vec![
+                (ScriptKind::SignatureScript, "Op7"),
+                (ScriptKind::SignatureScript, "OpData2"),
+                (ScriptKind::ScriptPublicKey, "OpBlake2b"),
+                (ScriptKind::ScriptPublicKey, "OpData32"),
+                (ScriptKind::ScriptPublicKey, "OpEqual"),
+                (ScriptKind::RedeemScript, "OpDrop"),
+                (ScriptKind::RedeemScript, "OpTrue"),
+            ]
+        );
+        assert_eq!(steps[1].data, redeem_script);
+        assert_eq!(steps[3].position, 1);
+    }
+}
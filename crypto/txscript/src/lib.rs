@@ -3,9 +3,11 @@ extern crate core;
 
 pub mod caches;
 mod data_stack;
+pub mod debugger;
 pub mod error;
 pub mod opcodes;
 pub mod result;
+pub mod script_asm;
 pub mod script_builder;
 pub mod script_class;
 pub mod standard;
@@ -337,30 +339,36 @@ impl<'a, T: VerifiableTransaction, Reused: SigHashReusedValues> TxScriptEngine<'
         }
     }
 
-    fn execute_script(&mut self, script: &[u8], verify_only_push: bool) -> Result<(), TxScriptError> {
-        let script_result = parse_script(script).try_for_each(|opcode| {
-            let opcode = opcode?;
-            if opcode.is_disabled() {
-                return Err(TxScriptError::OpcodeDisabled(format!("{:?}", opcode)));
-            }
+    /// Executes a single opcode of a script, after checking it is allowed in this context, and enforces the stack size limit
+    #[inline]
+    fn execute_next_opcode(
+        &mut self,
+        opcode: DynOpcodeImplementation<T, Reused>,
+        verify_only_push: bool,
+    ) -> Result<(), TxScriptError> {
+        if opcode.is_disabled() {
+            return Err(TxScriptError::OpcodeDisabled(format!("{:?}", opcode)));
+        }
 
-            if opcode.always_illegal() {
-                return Err(TxScriptError::OpcodeReserved(format!("{:?}", opcode)));
-            }
+        if opcode.always_illegal() {
+            return Err(TxScriptError::OpcodeReserved(format!("{:?}", opcode)));
+        }
 
-            if verify_only_push && !opcode.is_push_opcode() {
-                return Err(TxScriptError::SignatureScriptNotPushOnly);
-            }
+        if verify_only_push && !opcode.is_push_opcode() {
+            return Err(TxScriptError::SignatureScriptNotPushOnly);
+        }
 
-            self.execute_opcode(opcode)?;
+        self.execute_opcode(opcode)?;
 
-            let combined_size = self.astack.len() + self.dstack.len();
-            if combined_size > MAX_STACK_SIZE {
-                return Err(TxScriptError::StackSizeExceeded(combined_size, MAX_STACK_SIZE));
-            }
-            Ok(())
-        });
+        let combined_size = self.astack.len() + self.dstack.len();
+        if combined_size > MAX_STACK_SIZE {
+            return Err(TxScriptError::StackSizeExceeded(combined_size, MAX_STACK_SIZE));
+        }
+        Ok(())
+    }
 
+    /// Completes the execution of a script, resetting the state which does not persist between scripts
+    fn end_script(&mut self, script_result: Result<(), TxScriptError>) -> Result<(), TxScriptError> {
         // Moving between scripts - we can't be inside an if
         if script_result.is_ok() && !self.cond_stack.is_empty() {
             return Err(TxScriptError::ErrUnbalancedConditional);
@@ -373,16 +381,23 @@ impl<'a, T: VerifiableTransaction, Reused: SigHashReusedValues> TxScriptEngine<'
         script_result
     }
 
-    pub fn execute(&mut self) -> Result<(), TxScriptError> {
-        let (scripts, is_p2sh) = match &self.script_source {
-            ScriptSource::TxInput { input, utxo_entry, is_p2sh, .. } => {
+    fn execute_script(&mut self, script: &[u8], verify_only_push: bool) -> Result<(), TxScriptError> {
+        let script_result = parse_script(script).try_for_each(|opcode| self.execute_next_opcode(opcode?, verify_only_push));
+        self.end_script(script_result)
+    }
+
+    /// Returns the scripts to execute in order, or `None` if the script public key has an unknown version
+    /// and thus always succeeds.
+    fn scripts(&self) -> Result<Option<Vec<&'a [u8]>>, TxScriptError> {
+        let scripts = match self.script_source {
+            ScriptSource::TxInput { input, utxo_entry, .. } => {
                 if utxo_entry.script_public_key.version() > MAX_SCRIPT_PUBLIC_KEY_VERSION {
                     trace!("The version of the scriptPublicKey is higher than the known version - the Execute function returns true.");
-                    return Ok(());
+                    return Ok(None);
                 }
-                (vec![input.signature_script.as_slice(), utxo_entry.script_public_key.script()], *is_p2sh)
+                vec![input.signature_script.as_slice(), utxo_entry.script_public_key.script()]
             }
-            ScriptSource::StandAloneScripts(scripts) => (scripts.clone(), false),
+            ScriptSource::StandAloneScripts(ref scripts) => scripts.clone(),
         };
 
         // TODO: run all in same iterator?
@@ -400,6 +415,19 @@ impl<'a, T: VerifiableTransaction, Reused: SigHashReusedValues> TxScriptEngine<'
         if let Some(s) = scripts.iter().find(|e| e.len() > MAX_SCRIPTS_SIZE) {
             return Err(TxScriptError::ScriptSize(s.len(), MAX_SCRIPTS_SIZE));
         }
+        Ok(Some(scripts))
+    }
+
+    #[inline]
+    fn is_p2sh(&self) -> bool {
+        matches!(self.script_source, ScriptSource::TxInput { is_p2sh: true, .. })
+    }
+
+    pub fn execute(&mut self) -> Result<(), TxScriptError> {
+        let Some(scripts) = self.scripts()? else {
+            return Ok(());
+        };
+        let is_p2sh = self.is_p2sh();
 
         let mut saved_stack: Option<Vec<Vec<u8>>> = None;
         // try_for_each quits only if an error occurred. So, we always run over all scripts if
@@ -1418,7 +1446,21 @@ mod bitcoind_tests {
                 kip10_enabled,
                 runtime_sig_op_counting,
             );
-            vm.execute().map_err(UnifiedError::TxScriptError)
+            let result = vm.execute().map_err(UnifiedError::TxScriptError);
+
+            // Stepping through the scripts must lead to the same outcome
+            let vm = TxScriptEngine::from_transaction_input(
+                &populated_tx,
+                &populated_tx.tx().inputs[0],
+                0,
+                &populated_tx.entries[0],
+                &reused_values,
+                &sig_cache,
+                kip10_enabled,
+                runtime_sig_op_counting,
+            );
+            assert_eq!(debugger::ScriptDebugger::new(vm).run().map_err(UnifiedError::TxScriptError), result);
+            result
         }
 
         /*
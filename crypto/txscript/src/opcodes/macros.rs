@@ -122,6 +122,25 @@ macro_rules! opcode_list {
             }
         }
 
+        /// Returns the name of an opcode, as defined in [`codes`]
+        pub fn opcode_name(opcode: u8) -> &'static str {
+            match opcode {
+                $(
+                    $num => stringify!($name),
+                )*
+            }
+        }
+
+        /// Returns the opcode with the given name or alias, as defined in [`codes`]
+        pub fn opcode_by_name(name: &str) -> Option<u8> {
+            match name {
+                $(
+                    stringify!($name) $(| stringify!($alias))? => Some($num),
+                )*
+                _ => None,
+            }
+        }
+
         #[cfg(test)]
         use crate::script_builder::{ScriptBuilder, ScriptBuilderResult};
 
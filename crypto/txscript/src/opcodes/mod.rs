@@ -27,8 +27,9 @@ pub const OP_DATA_MAX_VAL: u8 = self::codes::OpData75;
 /// Minus 1 value
 pub const OP_1_NEGATE_VAL: u8 = 0x81;
 
-#[derive(Debug, PartialEq, Eq)]
-pub(crate) enum OpCond {
+/// State of a conditional branch on the conditional stack
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpCond {
     False,
     True,
     Skip,
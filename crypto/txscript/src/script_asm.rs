@@ -0,0 +1,230 @@
+//!
+//! Human-readable text format of scripts.
+//!
+//! A script is written as a whitespace separated list of tokens:
+//!
+//! - opcodes, by their name in [`crate::opcodes::codes`], e.g. `OpDup` or `OpCheckSig`
+//! - data pushes, as `0x`-prefixed hex, e.g. `0x0123`, encoded with the canonical push opcode
+//! - numbers, in decimal, e.g. `-1` or `1000`, encoded as canonical numeric pushes
+//!
+//! Data pushed with a non-canonical opcode is written with the push opcode followed by the data, e.g.
+//! `OpPushData1 0x0123`, so that [`assemble`] reverts [`disassemble`] for any well-formed script.
+//!
+
+use crate::{
+    opcodes::{codes, opcode_by_name, opcode_name, OP_1_NEGATE_VAL, OP_DATA_MAX_VAL, OP_SMALL_INT_MAX_VAL},
+    script_builder::{ScriptBuilder, ScriptBuilderError},
+    MAX_SCRIPT_ELEMENT_SIZE,
+};
+use spectre_utils::hex::{FromHex, ToHex};
+use thiserror::Error;
+
+/// Token appended by [`disassemble`] when the script ends within a data push
+pub const MALFORMED_PUSH_TOKEN: &str = "[error]";
+
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum ScriptAsmError {
+    #[error("unknown token `{0}`")]
+    UnknownToken(String),
+
+    #[error("invalid hex data `{0}`")]
+    InvalidHex(String),
+
+    #[error("opcode {0} must be followed by the hex data it pushes")]
+    MissingData(&'static str),
+
+    #[error("opcode {0} cannot push {1} bytes")]
+    InvalidDataLength(&'static str, usize),
+
+    #[error(transparent)]
+    ScriptBuilder(#[from] ScriptBuilderError),
+}
+
+pub type ScriptAsmResult<T> = std::result::Result<T, ScriptAsmError>;
+
+/// Returns the size of the length prefix following a push opcode, or `None` if the opcode is not a data push
+fn length_prefix_size(opcode: u8) -> Option<usize> {
+    match opcode {
+        codes::OpData1..=codes::OpData75 => Some(0),
+        codes::OpPushData1 => Some(1),
+        codes::OpPushData2 => Some(2),
+        codes::OpPushData4 => Some(4),
+        _ => None,
+    }
+}
+
+/// Returns whether `opcode` is the push opcode the script builder would use to push `data`
+fn is_canonical_push(opcode: u8, data: &[u8]) -> bool {
+    let len = data.len();
+    if len == 0 || len > MAX_SCRIPT_ELEMENT_SIZE || (len == 1 && (data[0] <= OP_SMALL_INT_MAX_VAL || data[0] == OP_1_NEGATE_VAL)) {
+        return false;
+    }
+    match opcode {
+        codes::OpData1..=codes::OpData75 => true,
+        codes::OpPushData1 => len > OP_DATA_MAX_VAL as usize && len <= u8::MAX as usize,
+        codes::OpPushData2 => len > u8::MAX as usize && len <= u16::MAX as usize,
+        _ => false,
+    }
+}
+
+/// Converts a script to its text format.
+///
+/// Malformed scripts, ending within a data push, are disassembled up to the malformed push, which is replaced
+/// by [`MALFORMED_PUSH_TOKEN`].
+pub fn disassemble(script: &[u8]) -> String {
+    let mut tokens = Vec::new();
+    let mut rest = script;
+    while let Some((&opcode, tail)) = rest.split_first() {
+        let Some(prefix_size) = length_prefix_size(opcode) else {
+            tokens.push(opcode_name(opcode).to_string());
+            rest = tail;
+            continue;
+        };
+        let len = match prefix_size {
+            0 => Some(opcode as usize),
+            _ => tail.get(..prefix_size).map(|prefix| {
+                let mut bytes = [0u8; 4];
+                bytes[..prefix_size].copy_from_slice(prefix);
+                u32::from_le_bytes(bytes) as usize
+            }),
+        };
+        let Some(data) = len.and_then(|len| tail.get(prefix_size..prefix_size + len)) else {
+            tokens.push(MALFORMED_PUSH_TOKEN.to_string());
+            break;
+        };
+        if !is_canonical_push(opcode, data) {
+            tokens.push(opcode_name(opcode).to_string());
+        }
+        tokens.push(format!("0x{}", data.to_hex()));
+        rest = &tail[prefix_size + data.len()..];
+    }
+    tokens.join(" ")
+}
+
+fn parse_hex(token: &str) -> Option<ScriptAsmResult<Vec<u8>>> {
+    let hex = token.strip_prefix("0x")?;
+    Some(Vec::<u8>::from_hex(hex).ok().filter(|_| hex.len() % 2 == 0).ok_or_else(|| ScriptAsmError::InvalidHex(token.to_string())))
+}
+
+/// Converts a script from its text format
+pub fn assemble(text: &str) -> ScriptAsmResult<Vec<u8>> {
+    let mut builder = ScriptBuilder::new();
+    let mut tokens = text.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if let Some(data) = parse_hex(token) {
+            builder.add_data(&data?)?;
+        } else if let Ok(value) = token.parse::<i64>() {
+            builder.add_i64(value)?;
+        } else if let Some(opcode) = opcode_by_name(token) {
+            let Some(prefix_size) = length_prefix_size(opcode) else {
+                builder.add_op(opcode)?;
+                continue;
+            };
+            let name = opcode_name(opcode);
+            let data = tokens.next().and_then(parse_hex).ok_or(ScriptAsmError::MissingData(name))??;
+            let valid_length = match prefix_size {
+                0 => data.len() == opcode as usize,
+                _ => prefix_size == 4 || data.len() < 1 << (8 * prefix_size),
+            };
+            if !valid_length {
+                return Err(ScriptAsmError::InvalidDataLength(name, data.len()));
+            }
+            let length_prefix = (data.len() as u32).to_le_bytes();
+            let push = [&[opcode], &length_prefix[..prefix_size], data.as_slice()].concat();
+            builder.add_ops(&push)?;
+        } else {
+            return Err(ScriptAsmError::UnknownToken(token.to_string()));
+        }
+    }
+    Ok(builder.drain())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        opcodes::codes::{OpBlake2b, OpCheckSig, OpDup, OpEqualVerify, OpFalse, OpIf, OpUnknown196},
+        pay_to_address_script, pay_to_script_hash_script,
+    };
+    use spectre_addresses::{Address, Prefix, Version};
+
+    #[test]
+    fn test_disassemble() {
+        let pub_key = [0x1au8; 32];
+        let script = pay_to_address_script(&Address::new(Prefix::Mainnet, Version::PubKey, &pub_key));
+        assert_eq!(disassemble(script.script()), format!("0x{} OpCheckSig", pub_key.as_slice().to_hex()));
+
+        let script = pay_to_script_hash_script(&[OpDup]);
+        assert!(disassemble(script.script()).starts_with("OpBlake2b 0x"));
+
+        let script = ScriptBuilder::new()
+            .add_ops(&[OpDup, OpBlake2b])
+            .unwrap()
+            .add_data(&[2; 32])
+            .unwrap()
+            .add_ops(&[OpEqualVerify, OpCheckSig])
+            .unwrap()
+            .drain();
+        assert_eq!(disassemble(&script), format!("OpDup OpBlake2b 0x{} OpEqualVerify OpCheckSig", [2u8; 32].as_slice().to_hex()));
+
+        let script = ScriptBuilder::new().add_i64(-1).unwrap().add_i64(0).unwrap().add_i64(16).unwrap().add_i64(1000).unwrap().drain();
+        assert_eq!(disassemble(&script), "Op1Negate OpFalse Op16 0xe803");
+
+        // Non-canonical pushes
+        assert_eq!(disassemble(&[codes::OpData1, 5]), "OpData1 0x05");
+        assert_eq!(disassemble(&[codes::OpPushData1, 2, 1, 2]), "OpPushData1 0x0102");
+        assert_eq!(disassemble(&[codes::OpPushData2, 0, 0]), "OpPushData2 0x");
+
+        // Malformed pushes
+        assert_eq!(disassemble(&[OpIf, codes::OpData2, 1]), "OpIf [error]");
+        assert_eq!(disassemble(&[codes::OpPushData2, 1]), "[error]");
+        assert_eq!(disassemble(&[]), "");
+    }
+
+    #[test]
+    fn test_assemble() {
+        assert_eq!(
+            assemble("OpDup 0x0102 OpFalse -1 5 1000").unwrap(),
+            vec![OpDup, codes::OpData2, 1, 2, OpFalse, codes::Op1Negate, codes::Op5, codes::OpData2, 0xe8, 0x03]
+        );
+        assert_eq!(assemble("Op0 Op1 OpTrue").unwrap(), vec![OpFalse, codes::OpTrue, codes::OpTrue]);
+        assert_eq!(assemble("  OpUnknown196 \n").unwrap(), vec![OpUnknown196]);
+        assert_eq!(assemble("").unwrap(), Vec::<u8>::new());
+
+        assert_eq!(assemble("OpDupp"), Err(ScriptAsmError::UnknownToken("OpDupp".to_string())));
+        assert_eq!(assemble("0x123"), Err(ScriptAsmError::InvalidHex("0x123".to_string())));
+        assert_eq!(assemble("0xzz"), Err(ScriptAsmError::InvalidHex("0xzz".to_string())));
+        assert_eq!(assemble("OpData2"), Err(ScriptAsmError::MissingData("OpData2")));
+        assert_eq!(assemble("OpData2 OpDup"), Err(ScriptAsmError::MissingData("OpData2")));
+        assert_eq!(assemble("OpData2 0x01"), Err(ScriptAsmError::InvalidDataLength("OpData2", 1)));
+        assert_eq!(
+            assemble(&format!("OpPushData1 0x{}", [0u8; 256].as_slice().to_hex())),
+            Err(ScriptAsmError::InvalidDataLength("OpPushData1", 256))
+        );
+        assert_eq!(
+            assemble(&format!("0x{}", [0u8; 521].as_slice().to_hex())),
+            Err(ScriptAsmError::ScriptBuilder(ScriptBuilderError::ElementExceedsMaxSize(521)))
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let scripts: Vec<Vec<u8>> = vec![
+            vec![],
+            (0..=u8::MAX).filter(|opcode| length_prefix_size(*opcode).is_none()).collect(),
+            vec![codes::OpData1, 0],
+            vec![codes::OpData1, 0x81],
+            vec![codes::OpData1, 0x80],
+            vec![codes::OpPushData1, 0],
+            vec![codes::OpPushData4, 1, 0, 0, 0, 7],
+            [&[codes::OpPushData1, 76][..], &[9; 76]].concat(),
+            [&[codes::OpPushData2, 0x09, 0x02][..], &[9; 521]].concat(),
+            [&[codes::OpPushData2, 0x08, 0x02][..], &[9; 520]].concat(),
+            ScriptBuilder::new().add_data(&[3; 300]).unwrap().add_i64(i64::MAX).unwrap().add_op(OpCheckSig).unwrap().drain(),
+        ];
+        for script in scripts {
+            let text = disassemble(&script);
+            assert_eq!(assemble(&text).unwrap(), script, "{text}");
+        }
+    }
+}
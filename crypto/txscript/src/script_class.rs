@@ -1,4 +1,11 @@
-use crate::{opcodes, MAX_SCRIPT_PUBLIC_KEY_VERSION};
+use crate::{
+    opcodes,
+    standard::{
+        template::{match_hash_time_lock, match_multisig, match_time_locked_pub_key},
+        TimeLock,
+    },
+    MAX_SCRIPT_PUBLIC_KEY_VERSION,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use spectre_addresses::Version;
@@ -28,12 +35,27 @@ pub enum ScriptClass {
     PubKeyECDSA,
     /// Pay to script hash
     ScriptHash,
+    /// Bare multisig, paying to M of N pubkeys
+    MultiSig,
+    /// Bare multisig, paying to M of N ECDSA pubkeys
+    MultiSigECDSA,
+    /// Pay to pubkey, spendable once the transaction lock time is reached (OpCheckLockTimeVerify)
+    TimeLockedPubKey,
+    /// Pay to pubkey, spendable once the relative lock of the input sequence is reached (OpCheckSequenceVerify)
+    SequenceLockedPubKey,
+    /// Hash time-locked contract, spendable either by revealing a hash preimage or after a lock time
+    HashTimeLock,
 }
 
 const NON_STANDARD: &str = "nonstandard";
 const PUB_KEY: &str = "pubkey";
 const PUB_KEY_ECDSA: &str = "pubkeyecdsa";
 const SCRIPT_HASH: &str = "scripthash";
+const MULTI_SIG: &str = "multisig";
+const MULTI_SIG_ECDSA: &str = "multisigecdsa";
+const TIME_LOCKED_PUB_KEY: &str = "timelockedpubkey";
+const SEQUENCE_LOCKED_PUB_KEY: &str = "sequencelockedpubkey";
+const HASH_TIME_LOCK: &str = "hashtimelock";
 
 impl ScriptClass {
     pub fn from_script(script_public_key: &ScriptPublicKey) -> Self {
@@ -45,6 +67,16 @@ impl ScriptClass {
                 Self::PubKeyECDSA
             } else if Self::is_pay_to_script_hash(script_public_key_) {
                 Self::ScriptHash
+            } else if let Some(multisig) = match_multisig(script_public_key_) {
+                if multisig.ecdsa {
+                    Self::MultiSigECDSA
+                } else {
+                    Self::MultiSig
+                }
+            } else if let Some((lock, _)) = match_time_locked_pub_key(script_public_key_) {
+                lock.script_class()
+            } else if Self::is_hash_time_lock(script_public_key_) {
+                Self::HashTimeLock
             } else {
                 ScriptClass::NonStandard
             }
@@ -81,12 +113,42 @@ impl ScriptClass {
         (script_public_key[34] == opcodes::codes::OpEqual)
     }
 
+    /// Returns true if the script is a bare multisig paying to 32-byte pubkeys, false otherwise.
+    pub fn is_multisig(script_public_key: &[u8]) -> bool {
+        match_multisig(script_public_key).is_some_and(|multisig| !multisig.ecdsa)
+    }
+
+    /// Returns true if the script is a bare multisig paying to 33-byte ECDSA pubkeys, false otherwise.
+    pub fn is_multisig_ecdsa(script_public_key: &[u8]) -> bool {
+        match_multisig(script_public_key).is_some_and(|multisig| multisig.ecdsa)
+    }
+
+    /// Returns true if the script is a pay-to-pubkey locked by OpCheckLockTimeVerify, false otherwise.
+    pub fn is_time_locked_pay_to_pubkey(script_public_key: &[u8]) -> bool {
+        matches!(match_time_locked_pub_key(script_public_key), Some((TimeLock::LockTime(_), _)))
+    }
+
+    /// Returns true if the script is a pay-to-pubkey locked by OpCheckSequenceVerify, false otherwise.
+    pub fn is_sequence_locked_pay_to_pubkey(script_public_key: &[u8]) -> bool {
+        matches!(match_time_locked_pub_key(script_public_key), Some((TimeLock::Sequence(_), _)))
+    }
+
+    /// Returns true if the script is a hash time-locked contract, false otherwise.
+    pub fn is_hash_time_lock(script_public_key: &[u8]) -> bool {
+        match_hash_time_lock(script_public_key).is_some()
+    }
+
     fn as_str(&self) -> &'static str {
         match self {
             ScriptClass::NonStandard => NON_STANDARD,
             ScriptClass::PubKey => PUB_KEY,
             ScriptClass::PubKeyECDSA => PUB_KEY_ECDSA,
             ScriptClass::ScriptHash => SCRIPT_HASH,
+            ScriptClass::MultiSig => MULTI_SIG,
+            ScriptClass::MultiSigECDSA => MULTI_SIG_ECDSA,
+            ScriptClass::TimeLockedPubKey => TIME_LOCKED_PUB_KEY,
+            ScriptClass::SequenceLockedPubKey => SEQUENCE_LOCKED_PUB_KEY,
+            ScriptClass::HashTimeLock => HASH_TIME_LOCK,
         }
     }
 
@@ -96,6 +158,11 @@ impl ScriptClass {
             ScriptClass::PubKey => MAX_SCRIPT_PUBLIC_KEY_VERSION,
             ScriptClass::PubKeyECDSA => MAX_SCRIPT_PUBLIC_KEY_VERSION,
             ScriptClass::ScriptHash => MAX_SCRIPT_PUBLIC_KEY_VERSION,
+            ScriptClass::MultiSig => MAX_SCRIPT_PUBLIC_KEY_VERSION,
+            ScriptClass::MultiSigECDSA => MAX_SCRIPT_PUBLIC_KEY_VERSION,
+            ScriptClass::TimeLockedPubKey => MAX_SCRIPT_PUBLIC_KEY_VERSION,
+            ScriptClass::SequenceLockedPubKey => MAX_SCRIPT_PUBLIC_KEY_VERSION,
+            ScriptClass::HashTimeLock => MAX_SCRIPT_PUBLIC_KEY_VERSION,
         }
     }
 }
@@ -115,6 +182,11 @@ impl FromStr for ScriptClass {
             PUB_KEY => Ok(ScriptClass::PubKey),
             PUB_KEY_ECDSA => Ok(ScriptClass::PubKeyECDSA),
             SCRIPT_HASH => Ok(ScriptClass::ScriptHash),
+            MULTI_SIG => Ok(ScriptClass::MultiSig),
+            MULTI_SIG_ECDSA => Ok(ScriptClass::MultiSigECDSA),
+            TIME_LOCKED_PUB_KEY => Ok(ScriptClass::TimeLockedPubKey),
+            SEQUENCE_LOCKED_PUB_KEY => Ok(ScriptClass::SequenceLockedPubKey),
+            HASH_TIME_LOCK => Ok(ScriptClass::HashTimeLock),
             _ => Err(Error::InvalidScriptClass(script_class.to_string())),
         }
     }
@@ -173,6 +245,49 @@ mod tests {
                 version: 0,
                 class: ScriptClass::ScriptHash,
             },
+            Test {
+                name: "valid bare multisig script (1 of 2)",
+                script: hex::decode("51204a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f815120fd4a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f8152ae").unwrap(),
+                version: 0,
+                class: ScriptClass::MultiSig,
+            },
+            Test {
+                name: "valid bare multisig ecdsa script (1 of 1)",
+                script: hex::decode("5121fd4a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f815151a9").unwrap(),
+                version: 0,
+                class: ScriptClass::MultiSigECDSA,
+            },
+            Test {
+                name: "non standard bare multisig script (more required signatures than keys)",
+                script: hex::decode("52204a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f815151ae").unwrap(),
+                version: 0,
+                class: ScriptClass::NonStandard,
+            },
+            Test {
+                name: "valid lock time locked pubkey script",
+                script: hex::decode("0340420fb0204a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f8151ac").unwrap(),
+                version: 0,
+                class: ScriptClass::TimeLockedPubKey,
+            },
+            Test {
+                name: "valid sequence locked pubkey script",
+                script: hex::decode("60b1204a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f8151ac").unwrap(),
+                version: 0,
+                class: ScriptClass::SequenceLockedPubKey,
+            },
+            Test {
+                name: "valid hash time lock script",
+                script: hex::decode(concat!(
+                    "63a820a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebfc088",
+                    "204a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f8151",
+                    "670340420fb0",
+                    "20fd4a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f81",
+                    "68ac"
+                ))
+                .unwrap(),
+                version: 0,
+                class: ScriptClass::HashTimeLock,
+            },
             Test {
                 name: "non standard script (unexpected version)",
                 script: hex::decode("204a23f5eef4b2dead811c7efb4f1afbd8df845e804b6c36a4001fc096e13f8151ac").unwrap(),
@@ -199,4 +314,22 @@ mod tests {
             assert_eq!(test.class, ScriptClass::from_script(&script_public_key), "{} wrong script class", test.name);
         }
     }
+
+    #[test]
+    fn test_script_class_str_round_trip() {
+        let classes = [
+            ScriptClass::NonStandard,
+            ScriptClass::PubKey,
+            ScriptClass::PubKeyECDSA,
+            ScriptClass::ScriptHash,
+            ScriptClass::MultiSig,
+            ScriptClass::MultiSigECDSA,
+            ScriptClass::TimeLockedPubKey,
+            ScriptClass::SequenceLockedPubKey,
+            ScriptClass::HashTimeLock,
+        ];
+        for class in classes {
+            assert_eq!(class.to_string().parse::<ScriptClass>(), Ok(class));
+        }
+    }
 }
@@ -10,9 +10,20 @@ use spectre_consensus_core::tx::{ScriptPublicKey, ScriptVec};
 use spectre_txscript_errors::TxScriptError;
 use std::iter::once;
 
+mod htlc;
 mod multisig;
+pub(crate) mod template;
+mod timelock;
 
-pub use multisig::{multisig_redeem_script, multisig_redeem_script_ecdsa, Error as MultisigCreateError};
+pub use htlc::{
+    extract_hash_time_lock, hash_time_lock_claim_signature_script, hash_time_lock_refund_signature_script, hash_time_lock_script,
+    pay_to_hash_time_lock_script, HashTimeLock,
+};
+pub use multisig::{
+    extract_multisig_addresses, multisig_redeem_script, multisig_redeem_script_ecdsa, pay_to_multisig_script,
+    pay_to_multisig_script_ecdsa, Error as MultisigCreateError,
+};
+pub use timelock::{extract_time_locked_address, pay_to_pub_key_with_time_lock_script, TimeLock};
 
 /// Creates a new script to pay a transaction output to a 32-byte pubkey.
 fn pay_to_pub_key(address_payload: &[u8]) -> ScriptVec {
@@ -69,6 +80,11 @@ pub fn pay_to_script_hash_signature_script(redeem_script: Vec<u8>, signature: Ve
 ///  - In case a ScriptClass is needed by the caller, call `ScriptClass::from(address.version)`
 ///    or use `address.version` directly instead, where address is the successfully
 ///    returned address.
+///
+///  - Standard scripts not paying to a single address, i.e. bare multisig, time-locked
+///    pay-to-pubkey and hash time-locked contracts, return the `TxScriptError::PubKeyFormat`
+///    error. Use [`extract_multisig_addresses`], [`extract_time_locked_address`] and
+///    [`extract_hash_time_lock`] instead.
 pub fn extract_script_pub_key_address(script_public_key: &ScriptPublicKey, prefix: Prefix) -> Result<Address, TxScriptError> {
     let class = ScriptClass::from_script(script_public_key);
     if script_public_key.version() > class.version() {
@@ -76,7 +92,12 @@ pub fn extract_script_pub_key_address(script_public_key: &ScriptPublicKey, prefi
     }
     let script = script_public_key.script();
     match class {
-        ScriptClass::NonStandard => Err(TxScriptError::PubKeyFormat),
+        ScriptClass::NonStandard
+        | ScriptClass::MultiSig
+        | ScriptClass::MultiSigECDSA
+        | ScriptClass::TimeLockedPubKey
+        | ScriptClass::SequenceLockedPubKey
+        | ScriptClass::HashTimeLock => Err(TxScriptError::PubKeyFormat),
         ScriptClass::PubKey => Ok(Address::new(prefix, Version::PubKey, &script[1..33])),
         ScriptClass::PubKeyECDSA => Ok(Address::new(prefix, Version::PubKeyECDSA, &script[1..34])),
         ScriptClass::ScriptHash => Ok(Address::new(prefix, Version::ScriptHash, &script[2..34])),
@@ -0,0 +1,190 @@
+use super::template::match_hash_time_lock;
+use crate::{
+    opcodes::codes::{OpCheckLockTimeVerify, OpCheckSig, OpElse, OpEndIf, OpEqualVerify, OpFalse, OpIf, OpSHA256, OpTrue},
+    script_builder::{ScriptBuilder, ScriptBuilderResult},
+    script_class::ScriptClass,
+};
+use spectre_addresses::{Address, Prefix, Version};
+use spectre_consensus_core::tx::ScriptPublicKey;
+use spectre_txscript_errors::TxScriptError;
+
+/// The terms of a hash time-locked contract (HTLC)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashTimeLock {
+    /// SHA-256 hash of the secret preimage
+    pub hash: [u8; 32],
+    /// Address which can claim the funds by revealing the preimage
+    pub recipient: Address,
+    /// Address which can reclaim the funds once the lock time is reached
+    pub refund: Address,
+    /// Lock time after which the refund path can be used, either a DAA score or a timestamp
+    pub lock_time: u64,
+}
+
+/// Creates a hash time-locked contract script. The funds can be claimed by `recipient` revealing the preimage
+/// of `hash`, or reclaimed by `refund` once `lock_time` is reached.
+///
+/// The script can either be used as a bare script public key, see [`pay_to_hash_time_lock_script`],
+/// or as a pay-to-script-hash redeem script.
+pub fn hash_time_lock_script(
+    hash: &[u8; 32],
+    recipient: &[u8; 32],
+    refund: &[u8; 32],
+    lock_time: u64,
+) -> ScriptBuilderResult<Vec<u8>> {
+    let mut builder = ScriptBuilder::new();
+    builder
+        .add_op(OpIf)?
+        .add_op(OpSHA256)?
+        .add_data(hash)?
+        .add_op(OpEqualVerify)?
+        .add_data(recipient)?
+        .add_op(OpElse)?
+        .add_lock_time(lock_time)?
+        .add_op(OpCheckLockTimeVerify)?
+        .add_data(refund)?
+        .add_op(OpEndIf)?
+        .add_op(OpCheckSig)?;
+    Ok(builder.drain())
+}
+
+/// Creates a new script to pay a transaction output to a bare hash time-locked contract.
+pub fn pay_to_hash_time_lock_script(
+    hash: &[u8; 32],
+    recipient: &[u8; 32],
+    refund: &[u8; 32],
+    lock_time: u64,
+) -> ScriptBuilderResult<ScriptPublicKey> {
+    Ok(ScriptPublicKey::from_vec(ScriptClass::HashTimeLock.version(), hash_time_lock_script(hash, recipient, refund, lock_time)?))
+}
+
+/// Generates a signature script claiming a hash time-locked contract with the recipient signature and the hash preimage.
+///
+/// When the contract is used as a pay-to-script-hash redeem script, the redeem script must be appended, see
+/// [`super::pay_to_script_hash_signature_script`].
+pub fn hash_time_lock_claim_signature_script(signature: &[u8], preimage: &[u8]) -> ScriptBuilderResult<Vec<u8>> {
+    Ok(ScriptBuilder::new().add_data(signature)?.add_data(preimage)?.add_op(OpTrue)?.drain())
+}
+
+/// Generates a signature script reclaiming a hash time-locked contract with the refund signature.
+///
+/// The spending transaction lock time must be at least the contract lock time, and the input must not be finalized.
+pub fn hash_time_lock_refund_signature_script(signature: &[u8]) -> ScriptBuilderResult<Vec<u8>> {
+    Ok(ScriptBuilder::new().add_data(signature)?.add_op(OpFalse)?.drain())
+}
+
+/// Returns the terms of the hash time-locked contract encoded in a script public key.
+///
+/// Any other script class returns the `TxScriptError::PubKeyFormat` error.
+pub fn extract_hash_time_lock(script_public_key: &ScriptPublicKey, prefix: Prefix) -> Result<HashTimeLock, TxScriptError> {
+    if script_public_key.version() > ScriptClass::HashTimeLock.version() {
+        return Err(TxScriptError::PubKeyFormat);
+    }
+    let contract = match_hash_time_lock(script_public_key.script()).ok_or(TxScriptError::PubKeyFormat)?;
+    Ok(HashTimeLock {
+        hash: contract.hash.try_into().expect("matched a 32-byte hash"),
+        recipient: Address::new(prefix, Version::PubKey, contract.recipient),
+        refund: Address::new(prefix, Version::PubKey, contract.refund),
+        lock_time: contract.lock_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{caches::Cache, TxScriptEngine, MAX_TX_IN_SEQUENCE_NUM};
+    use secp256k1::Keypair;
+    use sha2::{Digest, Sha256};
+    use spectre_consensus_core::{
+        hashing::{
+            sighash::{calc_schnorr_signature_hash, SigHashReusedValuesUnsync},
+            sighash_type::SIG_HASH_ALL,
+        },
+        subnets::SUBNETWORK_ID_NATIVE,
+        tx::{
+            MutableTransaction, Transaction, TransactionId, TransactionInput, TransactionOutpoint, UtxoEntry, VerifiableTransaction,
+        },
+    };
+
+    const LOCK_TIME: u64 = 36_000_000;
+
+    enum Spend<'a> {
+        Claim(&'a [u8]),
+        Refund,
+    }
+
+    fn keypair(secret: u8) -> Keypair {
+        Keypair::from_seckey_slice(secp256k1::SECP256K1, &[secret; 32]).unwrap()
+    }
+
+    fn check_spend(signer: &Keypair, spend: Spend, lock_time: u64, sequence: u64) -> bool {
+        let (recipient, refund) = (keypair(1), keypair(2));
+        let hash: [u8; 32] = Sha256::digest(b"secret").into();
+        let script_public_key = pay_to_hash_time_lock_script(
+            &hash,
+            &recipient.x_only_public_key().0.serialize(),
+            &refund.x_only_public_key().0.serialize(),
+            LOCK_TIME,
+        )
+        .unwrap();
+
+        let input = TransactionInput::new(TransactionOutpoint::new(TransactionId::from_bytes([7; 32]), 0), vec![], sequence, 1);
+        let tx = Transaction::new(0, vec![input], vec![], lock_time, SUBNETWORK_ID_NATIVE, 0, vec![]);
+        let entry = UtxoEntry::new(1_000_000, script_public_key, 0, false);
+        let mut tx = MutableTransaction::with_entries(tx, vec![entry]);
+
+        let reused_values = SigHashReusedValuesUnsync::new();
+        let sig_hash = calc_schnorr_signature_hash(&tx.as_verifiable(), 0, SIG_HASH_ALL, &reused_values);
+        let msg = secp256k1::Message::from_digest_slice(sig_hash.as_bytes().as_slice()).unwrap();
+        let signature: Vec<u8> = signer.sign_schnorr(msg).as_ref().iter().copied().chain([SIG_HASH_ALL.to_u8()]).collect();
+        tx.tx.inputs[0].signature_script = match spend {
+            Spend::Claim(preimage) => hash_time_lock_claim_signature_script(&signature, preimage).unwrap(),
+            Spend::Refund => hash_time_lock_refund_signature_script(&signature).unwrap(),
+        };
+
+        let tx = tx.as_verifiable();
+        let (input, entry) = tx.populated_input(0);
+        let cache = Cache::new(10_000);
+        let mut engine = TxScriptEngine::from_transaction_input(&tx, input, 0, entry, &reused_values, &cache, false, false);
+        engine.execute().is_ok()
+    }
+
+    #[test]
+    fn test_hash_time_lock_spend() {
+        let (recipient, refund) = (keypair(1), keypair(2));
+
+        // Claim path
+        assert!(check_spend(&recipient, Spend::Claim(b"secret"), 0, MAX_TX_IN_SEQUENCE_NUM));
+        assert!(!check_spend(&recipient, Spend::Claim(b"wrong secret"), 0, MAX_TX_IN_SEQUENCE_NUM));
+        assert!(!check_spend(&refund, Spend::Claim(b"secret"), 0, MAX_TX_IN_SEQUENCE_NUM));
+
+        // Refund path
+        assert!(check_spend(&refund, Spend::Refund, LOCK_TIME, 0));
+        assert!(!check_spend(&refund, Spend::Refund, LOCK_TIME - 1, 0));
+        assert!(!check_spend(&refund, Spend::Refund, LOCK_TIME, MAX_TX_IN_SEQUENCE_NUM));
+        assert!(!check_spend(&recipient, Spend::Refund, LOCK_TIME, 0));
+    }
+
+    #[test]
+    fn test_hash_time_lock_round_trip() {
+        let (hash, recipient, refund) = ([3u8; 32], [4u8; 32], [5u8; 32]);
+        let script_public_key = pay_to_hash_time_lock_script(&hash, &recipient, &refund, LOCK_TIME).unwrap();
+        assert_eq!(ScriptClass::from_script(&script_public_key), ScriptClass::HashTimeLock);
+        assert_eq!(
+            extract_hash_time_lock(&script_public_key, Prefix::Testnet),
+            Ok(HashTimeLock {
+                hash,
+                recipient: Address::new(Prefix::Testnet, Version::PubKey, &recipient),
+                refund: Address::new(Prefix::Testnet, Version::PubKey, &refund),
+                lock_time: LOCK_TIME,
+            })
+        );
+
+        // Swapping the branches breaks the template
+        let mut script = script_public_key.script().to_vec();
+        script.swap(0, 1);
+        let script_public_key = ScriptPublicKey::from_vec(0, script);
+        assert_eq!(ScriptClass::from_script(&script_public_key), ScriptClass::NonStandard);
+        assert_eq!(extract_hash_time_lock(&script_public_key, Prefix::Testnet), Err(TxScriptError::PubKeyFormat));
+    }
+}
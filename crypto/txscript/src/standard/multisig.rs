@@ -1,5 +1,10 @@
+use super::template::match_multisig;
 use crate::opcodes::codes::{OpCheckMultiSig, OpCheckMultiSigECDSA};
 use crate::script_builder::{ScriptBuilder, ScriptBuilderError};
+use crate::script_class::ScriptClass;
+use spectre_addresses::{Address, Prefix, Version};
+use spectre_consensus_core::tx::ScriptPublicKey;
+use spectre_txscript_errors::TxScriptError;
 use std::borrow::Borrow;
 use thiserror::Error;
 
@@ -67,6 +72,38 @@ pub fn multisig_redeem_script_ecdsa(pub_keys: impl Iterator<Item = impl Borrow<[
     Ok(builder.drain())
 }
 
+/// Creates a new script to pay a transaction output to a bare multisig, requiring `required` signatures of the provided pubkeys.
+pub fn pay_to_multisig_script(
+    pub_keys: impl Iterator<Item = impl Borrow<[u8; 32]>>,
+    required: usize,
+) -> Result<ScriptPublicKey, Error> {
+    Ok(ScriptPublicKey::from_vec(ScriptClass::MultiSig.version(), multisig_redeem_script(pub_keys, required)?))
+}
+
+/// Creates a new script to pay a transaction output to a bare multisig, requiring `required` signatures of the provided ECDSA pubkeys.
+pub fn pay_to_multisig_script_ecdsa(
+    pub_keys: impl Iterator<Item = impl Borrow<[u8; 33]>>,
+    required: usize,
+) -> Result<ScriptPublicKey, Error> {
+    Ok(ScriptPublicKey::from_vec(ScriptClass::MultiSigECDSA.version(), multisig_redeem_script_ecdsa(pub_keys, required)?))
+}
+
+/// Returns the number of required signatures and the addresses of the pubkeys encoded in a bare multisig script public key.
+///
+/// Any other script class returns the `TxScriptError::PubKeyFormat` error.
+pub fn extract_multisig_addresses(
+    script_public_key: &ScriptPublicKey,
+    prefix: Prefix,
+) -> Result<(usize, Vec<Address>), TxScriptError> {
+    let multisig = match_multisig(script_public_key.script()).ok_or(TxScriptError::PubKeyFormat)?;
+    let (class, version) =
+        if multisig.ecdsa { (ScriptClass::MultiSigECDSA, Version::PubKeyECDSA) } else { (ScriptClass::MultiSig, Version::PubKey) };
+    if script_public_key.version() > class.version() {
+        return Err(TxScriptError::PubKeyFormat);
+    }
+    Ok((multisig.required, multisig.pub_keys.into_iter().map(|pub_key| Address::new(prefix, version, pub_key)).collect()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +150,31 @@ mod tests {
         assert_eq!(result, Err(Error::ErrTooManyRequiredSigs));
     }
 
+    #[test]
+    fn test_bare_multisig_round_trip() {
+        let pub_keys = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let script_public_key = pay_to_multisig_script(pub_keys.iter(), 2).unwrap();
+        assert_eq!(ScriptClass::from_script(&script_public_key), ScriptClass::MultiSig);
+        let (required, addresses) = extract_multisig_addresses(&script_public_key, Prefix::Mainnet).unwrap();
+        assert_eq!(required, 2);
+        assert_eq!(addresses, pub_keys.iter().map(|key| Address::new(Prefix::Mainnet, Version::PubKey, key)).collect::<Vec<_>>());
+
+        let pub_keys = [[4u8; 33]; 17];
+        let script_public_key = pay_to_multisig_script_ecdsa(pub_keys.iter(), 17).unwrap();
+        assert_eq!(ScriptClass::from_script(&script_public_key), ScriptClass::MultiSigECDSA);
+        let (required, addresses) = extract_multisig_addresses(&script_public_key, Prefix::Testnet).unwrap();
+        assert_eq!(required, 17);
+        assert!(addresses.iter().all(|address| address.version == Version::PubKeyECDSA && address.payload.as_slice() == [4u8; 33]));
+
+        // A count of keys not matching the actual number of keys is not a multisig
+        let mut script = multisig_redeem_script([[5u8; 32]; 2].iter(), 1).unwrap();
+        let count = script.len() - 2;
+        script[count] += 1;
+        let script_public_key = ScriptPublicKey::from_vec(0, script);
+        assert_eq!(ScriptClass::from_script(&script_public_key), ScriptClass::NonStandard);
+        assert_eq!(extract_multisig_addresses(&script_public_key, Prefix::Mainnet), Err(TxScriptError::PubKeyFormat));
+    }
+
     #[test]
     fn test_empty_keys() {
         let result = multisig_redeem_script(empty::<[u8; 32]>(), 0);
@@ -0,0 +1,189 @@
+//! Matching of scripts against the standard templates which, unlike pay-to-pubkey and pay-to-script-hash,
+//! have no fixed layout: bare multisig, time-locked pay-to-pubkey and hash time-locked contracts.
+
+use super::timelock::TimeLock;
+use crate::{opcodes::codes, MAX_PUB_KEYS_PER_MUTLTISIG};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Instruction<'a> {
+    Op(u8),
+    Data(&'a [u8]),
+}
+
+use Instruction::{Data, Op};
+
+/// Splits a script into instructions, returning `None` if a data push exceeds the script length
+fn parse(script: &[u8]) -> Option<Vec<Instruction<'_>>> {
+    let mut instructions = Vec::new();
+    let mut rest = script;
+    while let Some((&opcode, tail)) = rest.split_first() {
+        let (len, tail) = match opcode {
+            codes::OpFalse => (0, tail),
+            codes::OpData1..=codes::OpData75 => (opcode as usize, tail),
+            codes::OpPushData1 => tail.split_first().map(|(len, tail)| (*len as usize, tail))?,
+            codes::OpPushData2 => (tail.len() >= 2).then(|| (u16::from_le_bytes([tail[0], tail[1]]) as usize, &tail[2..]))?,
+            codes::OpPushData4 => {
+                (tail.len() >= 4).then(|| (u32::from_le_bytes([tail[0], tail[1], tail[2], tail[3]]) as usize, &tail[4..]))?
+            }
+            _ => {
+                instructions.push(Op(opcode));
+                rest = tail;
+                continue;
+            }
+        };
+        if tail.len() < len {
+            return None;
+        }
+        let (data, tail) = tail.split_at(len);
+        instructions.push(Data(data));
+        rest = tail;
+    }
+    Some(instructions)
+}
+
+/// Reads a number pushed with either a small integer opcode or a single byte of data
+fn small_int(instruction: &Instruction) -> Option<usize> {
+    match *instruction {
+        Op(opcode @ codes::Op1..=codes::Op16) => Some((opcode - codes::Op1 + 1) as usize),
+        Data([value]) if *value < 0x80 => Some(*value as usize),
+        _ => None,
+    }
+}
+
+/// Reads a lock time or a sequence, encoded as an unsigned little-endian number of at most 8 bytes
+fn lock_value(instruction: &Instruction) -> Option<u64> {
+    match *instruction {
+        Op(opcode @ codes::Op1..=codes::Op16) => Some((opcode - codes::Op1 + 1) as u64),
+        Op(codes::Op1Negate) => Some(0x81),
+        Data(data) if data.len() <= 8 => {
+            let mut bytes = [0u8; 8];
+            bytes[..data.len()].copy_from_slice(data);
+            Some(u64::from_le_bytes(bytes))
+        }
+        _ => None,
+    }
+}
+
+/// A bare multisig script: `<required> <pubkey>... <count> codes::OpCheckMultiSig[ECDSA]`
+pub(crate) struct MultiSig<'a> {
+    pub(crate) required: usize,
+    pub(crate) pub_keys: Vec<&'a [u8]>,
+    pub(crate) ecdsa: bool,
+}
+
+pub(crate) fn match_multisig(script: &[u8]) -> Option<MultiSig<'_>> {
+    let (ecdsa, key_len) = match script.last() {
+        Some(&codes::OpCheckMultiSig) => (false, 32),
+        Some(&codes::OpCheckMultiSigECDSA) => (true, 33),
+        _ => return None,
+    };
+    let instructions = parse(script)?;
+    let [first, keys @ .., count, _] = instructions.as_slice() else {
+        return None;
+    };
+    let required = small_int(first)?;
+    let count = small_int(count)?;
+    if required == 0 || required > count || count > MAX_PUB_KEYS_PER_MUTLTISIG as usize || keys.len() != count {
+        return None;
+    }
+    let pub_keys = keys
+        .iter()
+        .map(|key| match *key {
+            Data(key) if key.len() == key_len => Some(key),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(MultiSig { required, pub_keys, ecdsa })
+}
+
+/// A time-locked pay-to-pubkey script: `<lock> codes::OpCheckLockTimeVerify|codes::OpCheckSequenceVerify <pubkey> codes::OpCheckSig`
+pub(crate) fn match_time_locked_pub_key(script: &[u8]) -> Option<(TimeLock, &[u8])> {
+    if script.last() != Some(&codes::OpCheckSig) {
+        return None;
+    }
+    match parse(script)?.as_slice() {
+        [lock, Op(verify), Data(pub_key), Op(codes::OpCheckSig)] if pub_key.len() == 32 => {
+            let lock = match *verify {
+                codes::OpCheckLockTimeVerify => TimeLock::LockTime(lock_value(lock)?),
+                codes::OpCheckSequenceVerify => TimeLock::Sequence(lock_value(lock)?),
+                _ => return None,
+            };
+            Some((lock, pub_key))
+        }
+        _ => None,
+    }
+}
+
+/// A hash time-locked contract
+pub(crate) struct HashTimeLock<'a> {
+    pub(crate) hash: &'a [u8],
+    pub(crate) recipient: &'a [u8],
+    pub(crate) refund: &'a [u8],
+    pub(crate) lock_time: u64,
+}
+
+/// Matches the script:
+///
+/// ```text
+/// codes::OpIf
+///     codes::OpSHA256 <hash> codes::OpEqualVerify <recipient pubkey>
+/// codes::OpElse
+///     <lock time> codes::OpCheckLockTimeVerify <refund pubkey>
+/// codes::OpEndIf
+/// codes::OpCheckSig
+/// ```
+pub(crate) fn match_hash_time_lock(script: &[u8]) -> Option<HashTimeLock<'_>> {
+    if script.first() != Some(&codes::OpIf) || script.last() != Some(&codes::OpCheckSig) {
+        return None;
+    }
+    match parse(script)?.as_slice() {
+        [Op(codes::OpIf), Op(codes::OpSHA256), Data(hash), Op(codes::OpEqualVerify), Data(recipient), Op(codes::OpElse), lock_time, Op(codes::OpCheckLockTimeVerify), Data(refund), Op(codes::OpEndIf), Op(codes::OpCheckSig)]
+            if hash.len() == 32 && recipient.len() == 32 && refund.len() == 32 =>
+        {
+            Some(HashTimeLock { hash, recipient, refund, lock_time: lock_value(lock_time)? })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        opcodes::codes::{OpData1, OpIf, OpPushData1, OpPushData2, OpPushData4},
+        script_builder::ScriptBuilder,
+    };
+
+    #[test]
+    fn test_parse() {
+        let script = ScriptBuilder::new()
+            .add_op(OpIf)
+            .unwrap()
+            .add_data(&[])
+            .unwrap()
+            .add_data(&[7; 3])
+            .unwrap()
+            .add_data(&[9; 80])
+            .unwrap()
+            .add_data(&[1; 300])
+            .unwrap()
+            .drain();
+        assert_eq!(parse(&script).unwrap(), vec![Op(OpIf), Data(&[]), Data(&[7; 3]), Data(&[9; 80]), Data(&[1; 300])]);
+
+        // Truncated data pushes
+        assert_eq!(parse(&[OpData1]), None);
+        assert_eq!(parse(&[OpPushData1, 2, 0]), None);
+        assert_eq!(parse(&[OpPushData2, 1]), None);
+        assert_eq!(parse(&[OpPushData4, 1, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_lock_value() {
+        for value in [0u64, 1, 16, 17, 0x80, 0x81, 0xff, 1 << 40, u64::MAX] {
+            let script = ScriptBuilder::new().add_lock_time(value).unwrap().drain();
+            let instructions = parse(&script).unwrap();
+            assert_eq!(instructions.len(), 1);
+            assert_eq!(lock_value(&instructions[0]), Some(value), "{value}");
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::template::match_time_locked_pub_key;
+use crate::{
+    opcodes::codes::{OpCheckLockTimeVerify, OpCheckSequenceVerify, OpCheckSig},
+    script_builder::{ScriptBuilder, ScriptBuilderResult},
+    script_class::ScriptClass,
+};
+use spectre_addresses::{Address, Prefix, Version};
+use spectre_consensus_core::tx::ScriptPublicKey;
+use spectre_txscript_errors::TxScriptError;
+
+/// Lock of a time-locked pay-to-pubkey script
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeLock {
+    /// Absolute lock verified by `OpCheckLockTimeVerify` against the transaction lock time,
+    /// either a DAA score or a timestamp depending on `LOCK_TIME_THRESHOLD`
+    LockTime(u64),
+    /// Relative lock verified by `OpCheckSequenceVerify` against the spending input sequence
+    Sequence(u64),
+}
+
+impl TimeLock {
+    pub fn script_class(&self) -> ScriptClass {
+        match self {
+            TimeLock::LockTime(_) => ScriptClass::TimeLockedPubKey,
+            TimeLock::Sequence(_) => ScriptClass::SequenceLockedPubKey,
+        }
+    }
+}
+
+/// Creates a new script to pay a transaction output to a 32-byte pubkey which can only be spent once `lock` is satisfied.
+pub fn pay_to_pub_key_with_time_lock_script(pub_key: &[u8; 32], lock: TimeLock) -> ScriptBuilderResult<ScriptPublicKey> {
+    let mut builder = ScriptBuilder::new();
+    match lock {
+        TimeLock::LockTime(lock_time) => builder.add_lock_time(lock_time)?.add_op(OpCheckLockTimeVerify)?,
+        TimeLock::Sequence(sequence) => builder.add_sequence(sequence)?.add_op(OpCheckSequenceVerify)?,
+    };
+    builder.add_data(pub_key)?.add_op(OpCheckSig)?;
+    Ok(ScriptPublicKey::from_vec(lock.script_class().version(), builder.drain()))
+}
+
+/// Returns the lock and the address of the pubkey encoded in a time-locked pay-to-pubkey script public key.
+///
+/// Any other script class returns the `TxScriptError::PubKeyFormat` error.
+pub fn extract_time_locked_address(script_public_key: &ScriptPublicKey, prefix: Prefix) -> Result<(TimeLock, Address), TxScriptError> {
+    let script = script_public_key.script();
+    let (lock, pub_key) = match_time_locked_pub_key(script).ok_or(TxScriptError::PubKeyFormat)?;
+    if script_public_key.version() > lock.script_class().version() {
+        return Err(TxScriptError::PubKeyFormat);
+    }
+    Ok((lock, Address::new(prefix, Version::PubKey, pub_key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pay_to_address_script, MAX_SCRIPT_PUBLIC_KEY_VERSION};
+
+    #[test]
+    fn test_time_locked_pub_key_round_trip() {
+        let pub_key = [0x42u8; 32];
+        for lock in [TimeLock::LockTime(0), TimeLock::LockTime(1_000_000), TimeLock::Sequence(16), TimeLock::Sequence(3600 * 10)] {
+            let script_public_key = pay_to_pub_key_with_time_lock_script(&pub_key, lock).unwrap();
+            assert_eq!(ScriptClass::from_script(&script_public_key), lock.script_class());
+            let (extracted_lock, address) = extract_time_locked_address(&script_public_key, Prefix::Mainnet).unwrap();
+            assert_eq!(extracted_lock, lock);
+            assert_eq!(address, Address::new(Prefix::Mainnet, Version::PubKey, &pub_key));
+
+            let unknown_version = ScriptPublicKey::from_vec(MAX_SCRIPT_PUBLIC_KEY_VERSION + 1, script_public_key.script().to_vec());
+            assert_eq!(extract_time_locked_address(&unknown_version, Prefix::Mainnet), Err(TxScriptError::PubKeyFormat));
+        }
+
+        let pay_to_pub_key = pay_to_address_script(&Address::new(Prefix::Mainnet, Version::PubKey, &pub_key));
+        assert_eq!(extract_time_locked_address(&pay_to_pub_key, Prefix::Mainnet), Err(TxScriptError::PubKeyFormat));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::result::Result;
-use crate::{script_builder as native, standard};
+use crate::{script_asm, script_builder as native, standard};
 use spectre_consensus_core::tx::ScriptPublicKey;
 use spectre_utils::hex::ToHex;
 use spectre_wasm_core::hex::{HexViewConfig, HexViewConfigT};
@@ -58,6 +58,18 @@ impl ScriptBuilder {
         Ok(builder)
     }
 
+    /// Creates a new ScriptBuilder from a script in its text format,
+    /// e.g. `OpDup OpBlake2b 0x<32 bytes hash> OpEqualVerify OpCheckSig`.
+    /// @see {@link ScriptBuilder.toAssembly}
+    #[wasm_bindgen(js_name = "fromAssembly")]
+    pub fn from_assembly(text: &str) -> Result<ScriptBuilder> {
+        let builder = ScriptBuilder::default();
+        let script = script_asm::assemble(text)?;
+        builder.inner_mut().extend(&script);
+
+        Ok(builder)
+    }
+
     /// Pushes the passed opcode to the end of the script. The script will not
     /// be modified if pushing the opcode would cause the script to exceed the
     /// maximum allowed script engine size.
@@ -138,6 +150,15 @@ impl ScriptBuilder {
         HexString::from(inner.script())
     }
 
+    /// Get the script in its human-readable text format, where opcodes are
+    /// represented by their names and data pushes by `0x`-prefixed hex strings.
+    #[wasm_bindgen(js_name = "toAssembly")]
+    pub fn to_assembly(&self) -> String {
+        let inner = self.inner();
+
+        script_asm::disassemble(inner.script())
+    }
+
     /// Drains (empties) the script builder, returning the
     /// script bytes represented by a hex string.
     pub fn drain(&self) -> HexString {
@@ -1,7 +1,7 @@
 use crate::{cache::CachePolicy, db::DB, errors::StoreError};
 
 use super::prelude::{Cache, DbKey, DbWriter};
-use rocksdb::{Direction, IterateBounds, IteratorMode, ReadOptions};
+use rocksdb::IterateBounds;
 use serde::{de::DeserializeOwned, Serialize};
 use spectre_utils::mem_size::MemSizeEstimator;
 use std::{collections::hash_map::RandomState, error::Error, hash::BuildHasher, sync::Arc};
@@ -53,7 +53,9 @@ where
         TKey: Clone + AsRef<[u8]> + ToString,
         TData: DeserializeOwned, // We need `DeserializeOwned` since the slice coming from `db.get_pinned` has short lifetime
     {
-        if let Some(data) = self.cache.get(&key) {
+        let cached = self.cache.get(&key);
+        self.db.cache_counters().get(&self.prefix).record(cached.is_some());
+        if let Some(data) = cached {
             Ok(data)
         } else {
             let db_key = DbKey::new(&self.prefix, key.clone());
@@ -73,16 +75,12 @@ where
         TData: DeserializeOwned, // We need `DeserializeOwned` since the slice coming from `db.get_pinned` has short lifetime
     {
         let prefix_key = DbKey::prefix_only(&self.prefix);
-        let mut read_opts = ReadOptions::default();
-        read_opts.set_iterate_range(rocksdb::PrefixRange(prefix_key.as_ref()));
-        self.db.iterator_opt(IteratorMode::From(prefix_key.as_ref(), Direction::Forward), read_opts).map(move |iter_result| {
-            match iter_result {
-                Ok((key, data_bytes)) => match bincode::deserialize(&data_bytes) {
-                    Ok(data) => Ok((key[prefix_key.prefix_len()..].into(), data)),
-                    Err(e) => Err(e.into()),
-                },
+        self.db.prefix_iterator(prefix_key.as_ref(), None).map(move |iter_result| match iter_result {
+            Ok((key, data_bytes)) => match bincode::deserialize(&data_bytes) {
+                Ok(data) => Ok((key[prefix_key.prefix_len()..].into(), data)),
                 Err(e) => Err(e.into()),
-            }
+            },
+            Err(e) => Err(e.into()),
         })
     }
 
@@ -189,15 +187,8 @@ where
             },
         );
 
-        let mut read_opts = ReadOptions::default();
-        read_opts.set_iterate_range(rocksdb::PrefixRange(db_key.as_ref()));
-
-        let mut db_iterator = match seek_from {
-            Some(seek_key) => {
-                self.db.iterator_opt(IteratorMode::From(DbKey::new(&self.prefix, seek_key).as_ref(), Direction::Forward), read_opts)
-            }
-            None => self.db.iterator_opt(IteratorMode::Start, read_opts),
-        };
+        let seek_key = seek_from.map(|seek_key| DbKey::new(&self.prefix, seek_key));
+        let mut db_iterator = self.db.prefix_iterator(db_key.as_ref(), seek_key.as_ref().map(|key| key.as_ref()));
 
         if skip_first {
             db_iterator.next();
@@ -221,15 +212,20 @@ where
 mod tests {
     use super::*;
     use crate::{
-        create_temp_db,
+        create_memory_db, create_temp_db,
         prelude::{BatchDbWriter, ConnBuilder, DirectDbWriter},
     };
-    use rocksdb::WriteBatch;
     use spectre_hashes::Hash;
 
     #[test]
     fn test_delete_all() {
         let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        test_delete_all_impl(db);
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
+        test_delete_all_impl(db);
+    }
+
+    fn test_delete_all_impl(db: Arc<DB>) {
         let access = CachedDbAccess::<Hash, u64>::new(db.clone(), CachePolicy::Count(2), vec![1, 2]);
 
         access.write_many(DirectDbWriter::new(&db), &mut (0..16).map(|i| (i.into(), 2))).unwrap();
@@ -239,7 +235,7 @@ mod tests {
 
         access.write_many(DirectDbWriter::new(&db), &mut (0..16).map(|i| (i.into(), 2))).unwrap();
         assert_eq!(16, access.iterator().count());
-        let mut batch = WriteBatch::default();
+        let mut batch = db.new_batch();
         access.delete_all(BatchDbWriter::new(&mut batch)).unwrap();
         assert_eq!(16, access.iterator().count());
         db.write(batch).unwrap();
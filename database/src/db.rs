@@ -1,38 +1,226 @@
-use rocksdb::{DBWithThreadMode, MultiThreaded};
-use std::ops::{Deref, DerefMut};
-use std::path::PathBuf;
+use crate::errors::{StoreError, StoreResult};
+use crate::stats::DbCacheCounters;
+use memory::{MemoryDb, MemoryDbIterator};
+use rocksdb::{
+    checkpoint::Checkpoint, DBIteratorWithThreadMode, DBPinnableSlice, DBWithThreadMode, Direction, IterateBounds, IteratorMode,
+    MultiThreaded, ReadOptions,
+};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
 
+pub use batch::WriteBatch;
 pub use conn_builder::ConnBuilder;
 use spectre_utils::fd_budget::FDGuard;
 
+mod batch;
 mod conn_builder;
+mod memory;
+
+/// The storage backends a [`DB`] can be built upon
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StorageBackend {
+    /// A persistent RocksDB database stored at the DB path
+    #[default]
+    RocksDb,
+    /// A volatile ordered map held in memory, mostly useful for tests and simulations which need no filesystem state
+    Memory,
+}
+
+enum Backend {
+    RocksDb(DBWithThreadMode<MultiThreaded>),
+    Memory(MemoryDb),
+}
 
 /// The DB type used for Spectred stores
 pub struct DB {
-    inner: DBWithThreadMode<MultiThreaded>,
-    _fd_guard: FDGuard,
+    backend: Backend,
+    path: PathBuf,
+    cache_counters: DbCacheCounters,
+    _fd_guard: Option<FDGuard>,
 }
 
 impl DB {
     pub fn new(inner: DBWithThreadMode<MultiThreaded>, fd_guard: FDGuard) -> Self {
-        Self { inner, _fd_guard: fd_guard }
+        let path = inner.path().to_owned();
+        Self { backend: Backend::RocksDb(inner), path, cache_counters: Default::default(), _fd_guard: Some(fd_guard) }
+    }
+
+    /// Creates an empty in-memory DB. The `path` only serves as the DB name and is never accessed
+    pub fn new_in_memory(path: PathBuf) -> Self {
+        Self { backend: Backend::Memory(MemoryDb::default()), path, cache_counters: Default::default(), _fd_guard: None }
+    }
+
+    pub fn storage_backend(&self) -> StorageBackend {
+        match self.backend {
+            Backend::RocksDb(_) => StorageBackend::RocksDb,
+            Backend::Memory(_) => StorageBackend::Memory,
+        }
+    }
+
+    /// Creates an empty write batch native to the backend of this DB.
+    ///
+    /// Batches written to this DB must be created by this method
+    pub fn new_batch(&self) -> WriteBatch {
+        WriteBatch::new(self.storage_backend())
+    }
+
+    /// Returns the underlying RocksDB instance, or `None` if the DB is held in memory
+    pub fn as_rocksdb(&self) -> Option<&DBWithThreadMode<MultiThreaded>> {
+        match &self.backend {
+            Backend::RocksDb(db) => Some(db),
+            Backend::Memory(_) => None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the cache counters of the stores accessing this DB
+    pub(crate) fn cache_counters(&self) -> &DbCacheCounters {
+        &self.cache_counters
+    }
+
+    pub fn get_pinned<K: AsRef<[u8]>>(&self, key: K) -> Result<Option<DbSlice<'_>>, rocksdb::Error> {
+        match &self.backend {
+            Backend::RocksDb(db) => Ok(db.get_pinned(key)?.map(DbSlice::Pinned)),
+            Backend::Memory(db) => Ok(db.get(key.as_ref()).map(DbSlice::Owned)),
+        }
+    }
+
+    pub fn put<K, V>(&self, key: K, value: V) -> Result<(), rocksdb::Error>
+    where
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        match &self.backend {
+            Backend::RocksDb(db) => db.put(key, value),
+            Backend::Memory(db) => {
+                db.put(key.as_ref(), value.as_ref());
+                Ok(())
+            }
+        }
+    }
+
+    pub fn delete<K: AsRef<[u8]>>(&self, key: K) -> Result<(), rocksdb::Error> {
+        match &self.backend {
+            Backend::RocksDb(db) => db.delete(key),
+            Backend::Memory(db) => {
+                db.delete(key.as_ref());
+                Ok(())
+            }
+        }
     }
-}
 
-impl DerefMut for DB {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.inner
+    /// Atomically applies all the operations of `batch`.
+    ///
+    /// Panics if `batch` was not created for the backend of this DB, see [`DB::new_batch`]
+    pub fn write(&self, batch: WriteBatch) -> Result<(), rocksdb::Error> {
+        match (&self.backend, batch) {
+            (Backend::RocksDb(db), WriteBatch::RocksDb(batch)) => db.write(batch),
+            (Backend::Memory(db), WriteBatch::Memory(ops)) => {
+                db.write(ops);
+                Ok(())
+            }
+            (_, batch) => panic!("a {:?} write batch cannot be written to a {:?} DB", batch.storage_backend(), self.storage_backend()),
+        }
+    }
+
+    /// Returns a forward iterator over the keys starting with `prefix`, starting at `seek_key` if provided
+    pub fn prefix_iterator(&self, prefix: &[u8], seek_key: Option<&[u8]>) -> DbIterator<'_> {
+        let start = seek_key.unwrap_or(prefix);
+        match &self.backend {
+            Backend::RocksDb(db) => {
+                let mut read_opts = ReadOptions::default();
+                read_opts.set_iterate_range(rocksdb::PrefixRange(prefix));
+                DbIterator(IteratorInner::RocksDb(db.iterator_opt(IteratorMode::From(start, Direction::Forward), read_opts)))
+            }
+            Backend::Memory(db) => {
+                let (_, upper_bound) = rocksdb::PrefixRange(prefix).into_bounds();
+                DbIterator(IteratorInner::Memory(db.iterator(start, upper_bound)))
+            }
+        }
+    }
+
+    /// Compacts the key range `[from, to)` of a RocksDB DB so that deletions are applied immediately.
+    /// A no-op for in-memory DBs
+    pub fn compact_range<K: AsRef<[u8]>>(&self, from: Option<K>, to: Option<K>) {
+        if let Backend::RocksDb(db) = &self.backend {
+            db.compact_range(from, to);
+        }
+    }
+
+    /// Flushes the memtables of a RocksDB DB to table files. A no-op for in-memory DBs
+    pub fn flush(&self) -> Result<(), rocksdb::Error> {
+        match &self.backend {
+            Backend::RocksDb(db) => db.flush(),
+            Backend::Memory(_) => Ok(()),
+        }
+    }
+
+    /// Returns the number of entries and their total size in bytes, grouped by the first key byte, of an in-memory DB
+    pub(crate) fn memory_usage_by_prefix(&self) -> Option<Vec<(u8, u64, u64)>> {
+        match &self.backend {
+            Backend::RocksDb(_) => None,
+            Backend::Memory(db) => Some(db.usage_by_prefix()),
+        }
     }
 }
 
-impl Deref for DB {
-    type Target = DBWithThreadMode<MultiThreaded>;
+/// A value read from the DB, either pinned in the RocksDB block cache or copied out of the in-memory map
+pub enum DbSlice<'a> {
+    Pinned(DBPinnableSlice<'a>),
+    Owned(Vec<u8>),
+}
+
+impl Deref for DbSlice<'_> {
+    type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
-        &self.inner
+        match self {
+            DbSlice::Pinned(slice) => slice,
+            DbSlice::Owned(vec) => vec,
+        }
+    }
+}
+
+impl AsRef<[u8]> for DbSlice<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self
     }
 }
 
+/// A forward iterator over a DB key range, yielding key-value pairs
+pub struct DbIterator<'a>(IteratorInner<'a>);
+
+enum IteratorInner<'a> {
+    RocksDb(DBIteratorWithThreadMode<'a, DBWithThreadMode<MultiThreaded>>),
+    Memory(MemoryDbIterator<'a>),
+}
+
+impl Iterator for DbIterator<'_> {
+    type Item = Result<(Box<[u8]>, Box<[u8]>), rocksdb::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IteratorInner::RocksDb(iter) => iter.next(),
+            IteratorInner::Memory(iter) => iter.next().map(Ok),
+        }
+    }
+}
+
+/// Creates a consistent point-in-time checkpoint of the DB at `checkpoint_dir`, which must not exist yet.
+/// The checkpoint is a regular DB directory which can be opened in place of the original one.
+///
+/// Table files are hard-linked when the checkpoint resides on the same filesystem, so creating it is cheap
+pub fn create_checkpoint(db: &DB, checkpoint_dir: &Path) -> StoreResult<()> {
+    let Some(rocksdb) = db.as_rocksdb() else {
+        return Err(StoreError::UnsupportedByBackend("checkpoints", db.storage_backend()));
+    };
+    Checkpoint::new(rocksdb)?.create_checkpoint(checkpoint_dir)?;
+    Ok(())
+}
+
 /// Deletes an existing DB if it exists
 pub fn delete_db(db_dir: PathBuf) {
     if !db_dir.exists() {
@@ -0,0 +1,102 @@
+use super::StorageBackend;
+
+/// A single operation recorded in a [`WriteBatch`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchOp {
+    Put(Box<[u8]>, Box<[u8]>),
+    Delete(Box<[u8]>),
+    /// Deletes the keys in the range `[from, to)`
+    DeleteRange(Box<[u8]>, Box<[u8]>),
+}
+
+/// An atomic batch of write operations, applied in order by [`DB::write`](crate::prelude::DB::write).
+///
+/// A batch is native to a storage backend: a RocksDB batch is a native RocksDB write batch, while an in-memory
+/// batch buffers the operations. A batch should be created by [`DB::new_batch`](crate::prelude::DB::new_batch)
+/// so it matches the backend of the DB it is written to
+pub enum WriteBatch {
+    RocksDb(rocksdb::WriteBatch),
+    Memory(Vec<BatchOp>),
+}
+
+impl WriteBatch {
+    /// Creates an empty batch native to `backend`
+    pub fn new(backend: StorageBackend) -> Self {
+        match backend {
+            StorageBackend::RocksDb => Self::RocksDb(Default::default()),
+            StorageBackend::Memory => Self::Memory(Default::default()),
+        }
+    }
+
+    /// Returns the storage backend this batch is native to
+    pub fn storage_backend(&self) -> StorageBackend {
+        match self {
+            Self::RocksDb(_) => StorageBackend::RocksDb,
+            Self::Memory(_) => StorageBackend::Memory,
+        }
+    }
+
+    pub fn put<K, V>(&mut self, key: K, value: V)
+    where
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        match self {
+            Self::RocksDb(batch) => batch.put(key, value),
+            Self::Memory(ops) => ops.push(BatchOp::Put(key.as_ref().into(), value.as_ref().into())),
+        }
+    }
+
+    pub fn delete<K: AsRef<[u8]>>(&mut self, key: K) {
+        match self {
+            Self::RocksDb(batch) => batch.delete(key),
+            Self::Memory(ops) => ops.push(BatchOp::Delete(key.as_ref().into())),
+        }
+    }
+
+    /// Deletes the keys in the range `[from, to)`
+    pub fn delete_range<K: AsRef<[u8]>>(&mut self, from: K, to: K) {
+        match self {
+            Self::RocksDb(batch) => batch.delete_range(from, to),
+            Self::Memory(ops) => ops.push(BatchOp::DeleteRange(from.as_ref().into(), to.as_ref().into())),
+        }
+    }
+
+    /// Returns the number of operations in the batch
+    pub fn len(&self) -> usize {
+        match self {
+            Self::RocksDb(batch) => batch.len(),
+            Self::Memory(ops) => ops.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        match self {
+            Self::RocksDb(batch) => batch.clear(),
+            Self::Memory(ops) => ops.clear(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_backends() {
+        for backend in [StorageBackend::RocksDb, StorageBackend::Memory] {
+            let mut batch = WriteBatch::new(backend);
+            assert_eq!(batch.storage_backend(), backend);
+            batch.put([1, 2], [3]);
+            batch.delete([1, 2]);
+            batch.delete_range([2], [3]);
+            assert_eq!(batch.len(), 3);
+            batch.clear();
+            assert!(batch.is_empty());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::db::DB;
+use crate::db::{StorageBackend, DB};
 use rocksdb::{DBWithThreadMode, MultiThreaded};
 use std::{path::PathBuf, sync::Arc};
 
@@ -13,6 +13,7 @@ pub struct ConnBuilder<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> {
     files_limit: FDLimit,
     mem_budget: usize,
     stats_period: StatsPeriod,
+    storage_backend: StorageBackend,
 }
 
 impl Default for ConnBuilder<Unspecified, false, Unspecified, Unspecified> {
@@ -24,6 +25,7 @@ impl Default for ConnBuilder<Unspecified, false, Unspecified, Unspecified> {
             mem_budget: 64 * 1024 * 1024,
             stats_period: Unspecified,
             files_limit: Unspecified,
+            storage_backend: StorageBackend::RocksDb,
         }
     }
 }
@@ -37,6 +39,7 @@ impl<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> ConnBuilder<Path, ST
             parallelism: self.parallelism,
             mem_budget: self.mem_budget,
             stats_period: self.stats_period,
+            storage_backend: self.storage_backend,
         }
     }
     pub fn with_create_if_missing(self, create_if_missing: bool) -> ConnBuilder<Path, STATS_ENABLED, StatsPeriod, FDLimit> {
@@ -48,6 +51,11 @@ impl<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> ConnBuilder<Path, ST
     pub fn with_mem_budget(self, mem_budget: impl Into<usize>) -> ConnBuilder<Path, STATS_ENABLED, StatsPeriod, FDLimit> {
         ConnBuilder { mem_budget: mem_budget.into(), ..self }
     }
+    /// Selects the storage backend of the DB. RocksDB options such as the files limit, memory budget and statistics
+    /// are ignored by the in-memory backend, which keeps no state on the filesystem and uses the DB path as a name only
+    pub fn with_storage_backend(self, storage_backend: StorageBackend) -> ConnBuilder<Path, STATS_ENABLED, StatsPeriod, FDLimit> {
+        ConnBuilder { storage_backend, ..self }
+    }
     pub fn with_files_limit(self, files_limit: impl Into<i32>) -> ConnBuilder<Path, STATS_ENABLED, StatsPeriod, i32> {
         ConnBuilder {
             db_path: self.db_path,
@@ -56,6 +64,7 @@ impl<Path, const STATS_ENABLED: bool, StatsPeriod, FDLimit> ConnBuilder<Path, ST
             parallelism: self.parallelism,
             mem_budget: self.mem_budget,
             stats_period: self.stats_period,
+            storage_backend: self.storage_backend,
         }
     }
 }
@@ -69,6 +78,7 @@ impl<Path, FDLimit> ConnBuilder<Path, false, Unspecified, FDLimit> {
             files_limit: self.files_limit,
             mem_budget: self.mem_budget,
             stats_period: self.stats_period,
+            storage_backend: self.storage_backend,
         }
     }
 }
@@ -82,6 +92,7 @@ impl<Path, StatsPeriod, FDLimit> ConnBuilder<Path, true, StatsPeriod, FDLimit> {
             files_limit: self.files_limit,
             mem_budget: self.mem_budget,
             stats_period: Unspecified,
+            storage_backend: self.storage_backend,
         }
     }
     pub fn with_stats_period(self, stats_period: impl Into<u32>) -> ConnBuilder<Path, true, u32, FDLimit> {
@@ -92,12 +103,17 @@ impl<Path, StatsPeriod, FDLimit> ConnBuilder<Path, true, StatsPeriod, FDLimit> {
             files_limit: self.files_limit,
             mem_budget: self.mem_budget,
             stats_period: stats_period.into(),
+            storage_backend: self.storage_backend,
         }
     }
 }
 
 macro_rules! default_opts {
     ($self: expr) => {{
+        if $self.storage_backend == StorageBackend::Memory {
+            return Ok(Arc::new(DB::new_in_memory($self.db_path)));
+        }
+
         let mut opts = rocksdb::Options::default();
         if $self.parallelism > 1 {
             opts.increase_parallelism($self.parallelism as i32);
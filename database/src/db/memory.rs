@@ -0,0 +1,145 @@
+use super::batch::BatchOp;
+use parking_lot::RwLock;
+use std::{collections::BTreeMap, ops::Bound};
+
+type Map = BTreeMap<Box<[u8]>, Box<[u8]>>;
+
+/// An ordered in-memory key-value map mirroring the subset of RocksDB semantics used by the stores
+#[derive(Default)]
+pub(crate) struct MemoryDb {
+    map: RwLock<Map>,
+}
+
+impl MemoryDb {
+    pub fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.map.read().get(key).map(|value| value.to_vec())
+    }
+
+    pub fn put(&self, key: &[u8], value: &[u8]) {
+        self.map.write().insert(key.into(), value.into());
+    }
+
+    pub fn delete(&self, key: &[u8]) {
+        self.map.write().remove(key);
+    }
+
+    /// Applies the batch operations `ops` under a single write lock, so readers never observe a partially written batch
+    pub fn write(&self, ops: Vec<BatchOp>) {
+        let mut map = self.map.write();
+        for op in ops {
+            match op {
+                BatchOp::Put(key, value) => {
+                    map.insert(key, value);
+                }
+                BatchOp::Delete(key) => {
+                    map.remove(&key);
+                }
+                BatchOp::DeleteRange(from, to) => Self::delete_range(&mut map, &from, &to),
+            }
+        }
+    }
+
+    fn delete_range(map: &mut Map, from: &[u8], to: &[u8]) {
+        if from >= to {
+            return;
+        }
+        let keys = map.range::<[u8], _>((Bound::Included(from), Bound::Excluded(to))).map(|(key, _)| key.clone()).collect::<Vec<_>>();
+        for key in keys {
+            map.remove(&key);
+        }
+    }
+
+    /// Returns an iterator over the keys in the range `[from, upper_bound)`, `upper_bound` being unbounded if `None`
+    pub fn iterator(&self, from: &[u8], upper_bound: Option<Vec<u8>>) -> MemoryDbIterator<'_> {
+        MemoryDbIterator { db: self, cursor: Bound::Included(from.into()), upper_bound }
+    }
+
+    /// Returns the number of entries and their total size in bytes, grouped by the first byte of the key
+    pub fn usage_by_prefix(&self) -> Vec<(u8, u64, u64)> {
+        let mut usage = [(0u64, 0u64); 256];
+        for (key, value) in self.map.read().iter() {
+            if let Some(&prefix) = key.first() {
+                usage[prefix as usize].0 += 1;
+                usage[prefix as usize].1 += (key.len() + value.len()) as u64;
+            }
+        }
+        usage
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (count, _))| *count > 0)
+            .map(|(prefix, (count, size))| (prefix as u8, count, size))
+            .collect()
+    }
+}
+
+/// A lazy iterator over a [`MemoryDb`] key range.
+///
+/// The read lock is only held while advancing to the next entry, which is looked up from the last returned key.
+/// Hence, concurrent writes do not block on an open iterator, and entries written behind the cursor are not visited
+pub(crate) struct MemoryDbIterator<'a> {
+    db: &'a MemoryDb,
+    cursor: Bound<Box<[u8]>>,
+    upper_bound: Option<Vec<u8>>,
+}
+
+impl Iterator for MemoryDbIterator<'_> {
+    type Item = (Box<[u8]>, Box<[u8]>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let upper_bound = match self.upper_bound.as_deref() {
+            Some(upper_bound) => Bound::Excluded(upper_bound),
+            None => Bound::Unbounded,
+        };
+        let lower_bound = match &self.cursor {
+            Bound::Included(key) => Bound::Included(key.as_ref()),
+            Bound::Excluded(key) => Bound::Excluded(key.as_ref()),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        if let (Bound::Included(lower) | Bound::Excluded(lower), Bound::Excluded(upper)) = (lower_bound, upper_bound) {
+            if lower >= upper {
+                return None;
+            }
+        }
+        let (key, value) =
+            self.db.map.read().range::<[u8], _>((lower_bound, upper_bound)).next().map(|(key, value)| (key.clone(), value.clone()))?;
+        self.cursor = Bound::Excluded(key.clone());
+        Some((key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{StorageBackend, WriteBatch};
+
+    #[test]
+    fn test_memory_db_batch_and_iteration() {
+        let db = MemoryDb::default();
+        let mut batch = WriteBatch::new(StorageBackend::Memory);
+        for i in 0..10u8 {
+            batch.put([1, i], [i]);
+            batch.put([2, i], [i]);
+        }
+        batch.delete([1, 0]);
+        batch.delete_range([2, 3], [2, 8]);
+        // Operations are applied in order, so a put following a range delete survives it
+        batch.put([2, 5], [55]);
+        let WriteBatch::Memory(ops) = batch else { unreachable!() };
+        db.write(ops);
+
+        assert_eq!(db.get(&[1, 0]), None);
+        assert_eq!(db.get(&[1, 1]), Some(vec![1]));
+        let keys = db.iterator(&[2], Some(vec![3])).map(|(key, _)| key[1]).collect::<Vec<_>>();
+        assert_eq!(keys, vec![0, 1, 2, 5, 8, 9]);
+        assert_eq!(db.get(&[2, 5]), Some(vec![55]));
+
+        // Iteration starts at the seek key and is not blocked by concurrent writes
+        let mut iter = db.iterator(&[1, 5], Some(vec![2]));
+        assert_eq!(iter.next().unwrap().0.as_ref(), &[1, 5]);
+        db.put(&[1, 7], &[77]);
+        db.delete(&[1, 6]);
+        assert_eq!(iter.map(|(key, value)| (key[1], value[0])).collect::<Vec<_>>(), vec![(7, 77), (8, 8), (9, 9)]);
+
+        assert_eq!(db.usage_by_prefix(), vec![(1, 8, 24), (2, 6, 18)]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::prelude::DbKey;
+use crate::prelude::{DbKey, StorageBackend};
 use spectre_hashes::Hash;
 use thiserror::Error;
 
@@ -21,6 +21,9 @@ pub enum StoreError {
     #[error("rocksdb error {0}")]
     DbError(#[from] rocksdb::Error),
 
+    #[error("{0} are not supported by the {1:?} storage backend")]
+    UnsupportedByBackend(&'static str, StorageBackend),
+
     #[error("bincode error {0}")]
     DeserializationError(#[from] Box<bincode::ErrorKind>),
 }
@@ -8,6 +8,7 @@ mod writer;
 
 pub mod registry;
 mod set_access;
+pub mod stats;
 pub mod utils;
 
 pub mod prelude {
@@ -19,6 +20,6 @@ pub mod prelude {
     pub use super::key::DbKey;
     pub use super::set_access::{CachedDbSetAccess, DbSetAccess, ReadLock};
     pub use super::writer::{BatchDbWriter, DbWriter, DirectDbWriter, DirectWriter, MemoryWriter};
-    pub use db::{delete_db, ConnBuilder, DB};
+    pub use db::{create_checkpoint, delete_db, ConnBuilder, DbIterator, DbSlice, StorageBackend, WriteBatch, DB};
     pub use errors::{StoreError, StoreResult, StoreResultEmptyTuple, StoreResultExtensions};
 }
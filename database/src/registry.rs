@@ -52,6 +52,7 @@ pub enum DatabaseStorePrefixes {
     // ---- Components ----
     Addresses = 128,
     BannedAddresses = 129,
+    TransactionPriorityDeltas = 130,
 
     // ---- Indexes ----
     UtxoIndex = 192,
@@ -2,7 +2,7 @@ use crate::{cache::CachePolicy, db::DB, errors::StoreError};
 
 use super::prelude::{Cache, DbKey, DbWriter};
 use parking_lot::{RwLock, RwLockReadGuard};
-use rocksdb::{IterateBounds, IteratorMode, ReadOptions};
+use rocksdb::IterateBounds;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     collections::{hash_map::RandomState, HashSet},
@@ -162,10 +162,7 @@ where
         TData: DeserializeOwned,
     {
         let db_key = DbKey::new_with_bucket(&self.prefix, &key, []);
-        let mut read_opts = ReadOptions::default();
-        read_opts.set_iterate_range(rocksdb::PrefixRange(db_key.as_ref()));
-
-        let mut db_iterator = self.db.iterator_opt(IteratorMode::Start, read_opts);
+        let mut db_iterator = self.db.prefix_iterator(db_key.as_ref(), None);
 
         if skip_first {
             db_iterator.next();
@@ -197,15 +194,20 @@ where
 mod tests {
     use super::*;
     use crate::{
-        create_temp_db,
+        create_memory_db, create_temp_db,
         prelude::{BatchDbWriter, ConnBuilder, DirectDbWriter},
     };
-    use rocksdb::WriteBatch;
     use spectre_hashes::Hash;
 
     #[test]
     fn test_delete_bucket() {
         let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        test_delete_bucket_impl(db);
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
+        test_delete_bucket_impl(db);
+    }
+
+    fn test_delete_bucket_impl(db: Arc<DB>) {
         let access = DbSetAccess::<Hash, u64>::new(db.clone(), vec![1, 2]);
 
         for i in 0..16 {
@@ -219,7 +221,7 @@ mod tests {
         access.delete_bucket(DirectDbWriter::new(&db), 3.into()).unwrap();
         assert_eq!(0, access.bucket_iterator(3.into()).count());
 
-        let mut batch = WriteBatch::default();
+        let mut batch = db.new_batch();
         access.delete_bucket(BatchDbWriter::new(&mut batch), 6.into()).unwrap();
         db.write(batch).unwrap();
         assert_eq!(0, access.bucket_iterator(6.into()).count());
@@ -0,0 +1,253 @@
+use crate::{errors::StoreResult, prelude::DB, registry::DatabaseStorePrefixes};
+use num_traits::FromPrimitive;
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Cache access counters of the stores sharing a prefix.
+///
+/// Each instance occupies its own cache lines so that readers of distinct stores do not contend.
+#[derive(Debug, Default)]
+#[repr(align(128))]
+pub struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheCounters {
+    pub(crate) fn record(&self, hit: bool) {
+        if hit {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+/// Cache counters of the stores of a DB, indexed by the first byte of the store prefix
+pub(crate) struct DbCacheCounters(Box<[CacheCounters]>);
+
+impl DbCacheCounters {
+    /// Returns the cache counters of the stores whose prefix starts with `prefix`
+    pub(crate) fn get(&self, prefix: &[u8]) -> &CacheCounters {
+        &self.0[prefix.first().copied().unwrap_or_default() as usize]
+    }
+
+    /// Returns the cache counters of all the store prefixes which were accessed since the DB was opened
+    pub(crate) fn stats(&self) -> Vec<StoreCacheStats> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(prefix, counters)| StoreCacheStats { prefix: prefix as u8, hits: counters.hits(), misses: counters.misses() })
+            .filter(|stats| stats.hits + stats.misses > 0)
+            .collect()
+    }
+}
+
+impl Default for DbCacheCounters {
+    fn default() -> Self {
+        Self((0..256).map(|_| CacheCounters::default()).collect())
+    }
+}
+
+/// Returns a readable name of a store prefix
+pub fn prefix_name(prefix: u8) -> String {
+    match DatabaseStorePrefixes::from_u8(prefix) {
+        Some(prefix) => format!("{prefix:?}"),
+        None => format!("Unknown({prefix})"),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StoreCacheStats {
+    pub prefix: u8,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Sums the cache counters of `databases` by store prefix, ordered by prefix
+pub fn aggregate_cache_stats(databases: &[DbStats]) -> Vec<StoreCacheStats> {
+    let mut totals = BTreeMap::<u8, (u64, u64)>::new();
+    for stats in databases.iter().flat_map(|db| db.cache_stats.iter()) {
+        let (hits, misses) = totals.entry(stats.prefix).or_default();
+        *hits += stats.hits;
+        *misses += stats.misses;
+    }
+    totals.into_iter().map(|(prefix, (hits, misses))| StoreCacheStats { prefix, hits, misses }).collect()
+}
+
+/// Approximate on-disk usage of the stores sharing a prefix
+#[derive(Clone, Debug, Default)]
+pub struct StoreStats {
+    pub prefix: u8,
+    pub approximate_key_count: u64,
+    pub approximate_size: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DbStats {
+    /// Directory name of the database
+    pub name: String,
+    pub estimated_key_count: u64,
+    pub total_sst_files_size: u64,
+    pub live_sst_files_size: u64,
+    pub memtables_size: u64,
+    pub pending_compaction_bytes: u64,
+    pub running_compactions: u64,
+    pub is_compaction_pending: bool,
+    /// Number of table files at each level of the LSM tree
+    pub files_per_level: Vec<u64>,
+    /// Usage of the stores holding flushed data, ordered by prefix
+    pub stores: Vec<StoreStats>,
+    /// Cache counters of the stores accessed since the DB was opened, ordered by prefix
+    pub cache_stats: Vec<StoreCacheStats>,
+}
+
+/// Collects the statistics of `db` from RocksDB properties and from the metadata of its table files.
+///
+/// Store usage is derived from the key range of every table file: a file whose keys share a first prefix byte is
+/// attributed to that prefix, while a file spanning several prefixes is split evenly among the known prefixes
+/// of its range. Data not yet flushed from the memtables is not attributed to any store.
+///
+/// In-memory DBs report exact entry counts and sizes, accounted as memtable usage
+pub fn collect_db_stats(db: &DB) -> StoreResult<DbStats> {
+    let name = db.path().file_name().unwrap_or_default().to_string_lossy().into_owned();
+    let Some(rocksdb) = db.as_rocksdb() else {
+        let stores = db
+            .memory_usage_by_prefix()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(prefix, approximate_key_count, approximate_size)| StoreStats { prefix, approximate_key_count, approximate_size })
+            .collect::<Vec<_>>();
+        return Ok(DbStats {
+            name,
+            estimated_key_count: stores.iter().map(|stats| stats.approximate_key_count).sum(),
+            memtables_size: stores.iter().map(|stats| stats.approximate_size).sum(),
+            stores,
+            cache_stats: db.cache_counters().stats(),
+            ..Default::default()
+        });
+    };
+    let property = |name: &str| -> StoreResult<u64> { Ok(rocksdb.property_int_value(name)?.unwrap_or_default()) };
+
+    let mut stores = [(); 256].map(|_| StoreStats::default());
+    let mut files_per_level = Vec::new();
+    for file in rocksdb.live_files()? {
+        let level = file.level.max(0) as usize;
+        if files_per_level.len() <= level {
+            files_per_level.resize(level + 1, 0);
+        }
+        files_per_level[level] += 1;
+
+        let (Some(start), Some(end)) =
+            (file.start_key.as_ref().and_then(|k| k.first()), file.end_key.as_ref().and_then(|k| k.first()))
+        else {
+            continue;
+        };
+        let mut prefixes = (*start..=*end).filter(|&p| DatabaseStorePrefixes::from_u8(p).is_some()).collect::<Vec<_>>();
+        if prefixes.is_empty() {
+            prefixes.push(*start);
+        }
+        let share = prefixes.len() as u64;
+        for prefix in prefixes {
+            stores[prefix as usize].approximate_key_count += file.num_entries / share;
+            stores[prefix as usize].approximate_size += file.size as u64 / share;
+        }
+    }
+
+    Ok(DbStats {
+        name,
+        estimated_key_count: property("rocksdb.estimate-num-keys")?,
+        total_sst_files_size: property("rocksdb.total-sst-files-size")?,
+        live_sst_files_size: property("rocksdb.live-sst-files-size")?,
+        memtables_size: property("rocksdb.cur-size-all-mem-tables")?,
+        pending_compaction_bytes: property("rocksdb.estimate-pending-compaction-bytes")?,
+        running_compactions: property("rocksdb.num-running-compactions")?,
+        is_compaction_pending: property("rocksdb.compaction-pending")? > 0,
+        files_per_level,
+        stores: stores
+            .into_iter()
+            .enumerate()
+            .filter(|(_, stats)| stats.approximate_size > 0)
+            .map(|(prefix, stats)| StoreStats { prefix: prefix as u8, ..stats })
+            .collect(),
+        cache_stats: db.cache_counters().stats(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        create_memory_db, create_temp_db,
+        prelude::{CachePolicy, CachedDbAccess, ConnBuilder, DirectDbWriter},
+    };
+    use spectre_hashes::Hash;
+
+    #[test]
+    fn test_collect_db_stats() {
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        for i in 0..1000u32 {
+            db.put([&[DatabaseStorePrefixes::Headers as u8][..], &i.to_be_bytes()].concat(), [0u8; 64]).unwrap();
+        }
+        db.flush().unwrap();
+
+        let stats = collect_db_stats(&db).unwrap();
+        assert!(stats.total_sst_files_size > 0);
+        assert_eq!(stats.files_per_level.iter().sum::<u64>(), 1);
+        assert_eq!(stats.stores.len(), 1);
+        assert_eq!(stats.stores[0].prefix, DatabaseStorePrefixes::Headers as u8);
+        assert_eq!(stats.stores[0].approximate_key_count, 1000);
+        assert_eq!(prefix_name(stats.stores[0].prefix), "Headers");
+        assert_eq!(prefix_name(100), "Unknown(100)");
+    }
+
+    #[test]
+    fn test_collect_memory_db_stats() {
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
+        for i in 0..1000u32 {
+            db.put([&[DatabaseStorePrefixes::Headers as u8][..], &i.to_be_bytes()].concat(), [0u8; 64]).unwrap();
+        }
+
+        let stats = collect_db_stats(&db).unwrap();
+        assert_eq!(stats.name, "memory");
+        assert_eq!(stats.estimated_key_count, 1000);
+        assert_eq!(stats.stores.len(), 1);
+        assert_eq!(stats.stores[0].prefix, DatabaseStorePrefixes::Headers as u8);
+        assert_eq!(stats.stores[0].approximate_size, 1000 * (5 + 64));
+    }
+
+    #[test]
+    fn test_cache_stats() {
+        let prefix = DatabaseStorePrefixes::Headers as u8;
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let access = CachedDbAccess::<Hash, u64>::new(db.clone(), CachePolicy::Count(10), vec![prefix]);
+        access.write(DirectDbWriter::new(&db), 1.into(), 7).unwrap();
+        access.read(1.into()).unwrap();
+        assert!(access.read(2.into()).is_err());
+
+        // The counters of another DB are kept apart
+        let (_other_lifetime, other_db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
+        let other_access = CachedDbAccess::<Hash, u64>::new(other_db.clone(), CachePolicy::Count(10), vec![prefix]);
+        assert!(other_access.read(1.into()).is_err());
+
+        let stats = collect_db_stats(&db).unwrap();
+        assert_eq!(stats.cache_stats.len(), 1);
+        assert_eq!((stats.cache_stats[0].prefix, stats.cache_stats[0].hits, stats.cache_stats[0].misses), (prefix, 1, 1));
+        let other_stats = collect_db_stats(&other_db).unwrap();
+        assert_eq!((other_stats.cache_stats[0].hits, other_stats.cache_stats[0].misses), (0, 1));
+
+        let aggregated = aggregate_cache_stats(&[stats, other_stats]);
+        assert_eq!(aggregated.len(), 1);
+        assert_eq!((aggregated[0].prefix, aggregated[0].hits, aggregated[0].misses), (prefix, 1, 2));
+    }
+}
@@ -61,6 +61,20 @@ macro_rules! create_temp_db {
     }};
 }
 
+/// Creates a DB held in memory, which keeps no state on the filesystem and is dropped with its last reference.
+/// Callers must keep the `TempDbLifetime` guard for as long as they wish the DB to exist.
+#[macro_export]
+macro_rules! create_memory_db {
+    ($conn_builder: expr) => {{
+        let db = $conn_builder
+            .with_db_path(std::path::PathBuf::from("memory"))
+            .with_storage_backend($crate::prelude::StorageBackend::Memory)
+            .build()
+            .unwrap();
+        ($crate::utils::DbLifetime::without_destroy(std::sync::Arc::downgrade(&db)), db)
+    }};
+}
+
 /// Creates a DB within the provided directory path.
 /// Callers must keep the `TempDbLifetime` guard for as long as they wish the DB instance to exist.
 #[macro_export]
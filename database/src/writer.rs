@@ -1,7 +1,6 @@
-use rocksdb::WriteBatch;
 use spectre_utils::refs::Refs;
 
-use crate::prelude::DB;
+use crate::prelude::{WriteBatch, DB};
 
 /// Abstraction over direct/batched DB writing
 pub trait DbWriter {
@@ -50,7 +49,7 @@ impl DbWriter for DirectDbWriter<'_> {
     where
         K: AsRef<[u8]>,
     {
-        let mut batch = WriteBatch::default();
+        let mut batch = self.db.new_batch();
         batch.delete_range(from, to);
         self.db.write(batch)
     }
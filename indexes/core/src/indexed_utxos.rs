@@ -17,7 +17,7 @@ pub type BalanceByScriptPublicKey = HashMap<ScriptPublicKey, u64>;
 // Unlike `consensus_core::tx::UtxoEntry` the utxoindex utilizes a compacted utxo form, where `script_public_key` field is removed.
 // This utxo structure can be utilized in the utxoindex, since utxos are implicitly key'd via its script public key (and outpoint) at all times.
 /// A compacted form of [`UtxoEntry`] without reference to [`ScriptPublicKey`] or [`TransactionOutpoint`]
-#[derive(Clone, Copy, Deserialize, Serialize, Debug)]
+#[derive(Clone, Copy, Deserialize, Serialize, Debug, PartialEq, Eq)]
 pub struct CompactUtxoEntry {
     pub amount: u64,
     pub block_daa_score: u64,
@@ -52,3 +52,84 @@ impl UtxoChanges {
         Self { added, removed }
     }
 }
+
+/// The maximal number of entries the queried script public keys may hold for a [`UtxoPageQuery`] to be ordered by DAA score.
+///
+/// The utxoindex is not indexed by DAA score, so every page of such a query scans all the entries of the queried script public keys.
+pub const MAX_DAA_SCORE_ORDERED_UTXOS: usize = 100_000;
+
+/// Order of the entries returned by a [`UtxoPageQuery`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UtxoOrder {
+    /// Grouped by script public key in query order, and ordered by outpoint within each script public key
+    #[default]
+    Outpoint,
+    /// Ordered by block DAA score, then as in [`UtxoOrder::Outpoint`]. Limited to script public keys holding
+    /// at most [`MAX_DAA_SCORE_ORDERED_UTXOS`] entries
+    DaaScoreAscending,
+    /// The reverse of [`UtxoOrder::DaaScoreAscending`]
+    DaaScoreDescending,
+}
+
+/// Criteria the entries returned by a [`UtxoPageQuery`] must match
+#[derive(Clone, Debug, Default)]
+pub struct UtxoFilter {
+    pub min_amount: Option<u64>,
+    pub max_amount: Option<u64>,
+    /// Excludes the coinbase entries with a block DAA score of at least this one, which are the immature coinbase
+    /// entries when set to `virtual DAA score + 1 - coinbase maturity`
+    pub min_immature_coinbase_daa_score: Option<u64>,
+}
+
+impl UtxoFilter {
+    pub fn matches(&self, entry: &CompactUtxoEntry) -> bool {
+        self.min_amount.is_none_or(|min_amount| entry.amount >= min_amount)
+            && self.max_amount.is_none_or(|max_amount| entry.amount <= max_amount)
+            && !(entry.is_coinbase && self.min_immature_coinbase_daa_score.is_some_and(|score| entry.block_daa_score >= score))
+    }
+}
+
+/// Position of an entry in the results of a [`UtxoPageQuery`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UtxoCursor {
+    pub script_public_key: ScriptPublicKey,
+    pub outpoint: TransactionOutpoint,
+    pub block_daa_score: u64,
+}
+
+/// A query for a page of the utxos of some script public keys
+#[derive(Clone, Debug, Default)]
+pub struct UtxoPageQuery {
+    pub script_public_keys: Vec<ScriptPublicKey>,
+    pub filter: UtxoFilter,
+    pub order: UtxoOrder,
+    /// The page starts right after this position, usually the `next_cursor` of the previous page
+    pub cursor: Option<UtxoCursor>,
+    /// The maximal number of entries in the page, `None` for no limit
+    pub limit: Option<usize>,
+}
+
+/// A utxo returned by a [`UtxoPageQuery`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedUtxo {
+    pub script_public_key: ScriptPublicKey,
+    pub outpoint: TransactionOutpoint,
+    pub entry: CompactUtxoEntry,
+}
+
+impl IndexedUtxo {
+    pub fn cursor(&self) -> UtxoCursor {
+        UtxoCursor {
+            script_public_key: self.script_public_key.clone(),
+            outpoint: self.outpoint,
+            block_daa_score: self.entry.block_daa_score,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct UtxoPage {
+    pub entries: Vec<IndexedUtxo>,
+    /// The cursor of the last entry if more entries match the query, `None` if the page is the last one
+    pub next_cursor: Option<UtxoCursor>,
+}
@@ -6,7 +6,9 @@ use spectre_notify::{
     notification::Notification as NotificationTrait,
     subscription::{
         context::SubscriptionContext,
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        single::{
+            AcceptedTransactionsChangedSubscription, OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription,
+        },
         Subscription,
     },
 };
@@ -53,6 +55,14 @@ impl NotificationTrait for Notification {
         }
     }
 
+    fn apply_accepted_transactions_changed_subscription(
+        &self,
+        _subscription: &AcceptedTransactionsChangedSubscription,
+        _context: &SubscriptionContext,
+    ) -> Option<Self> {
+        Some(self.clone())
+    }
+
     fn event_type(&self) -> EventType {
         self.into()
     }
@@ -133,7 +133,7 @@ mod tests {
     use spectre_consensus::{config::Config, consensus::test_consensus::TestConsensus, params::DEVNET_PARAMS, test_helpers::*};
     use spectre_consensus_core::utxo::{utxo_collection::UtxoCollection, utxo_diff::UtxoDiff};
     use spectre_consensusmanager::ConsensusManager;
-    use spectre_database::create_temp_db;
+    use spectre_database::create_memory_db;
     use spectre_database::prelude::ConnBuilder;
     use spectre_database::utils::DbLifetime;
     use spectre_notify::notifier::test_helpers::NotifyMock;
@@ -154,7 +154,7 @@ mod tests {
     impl NotifyPipeline {
         fn new() -> Self {
             let (consensus_sender, consensus_receiver) = unbounded();
-            let (utxoindex_db_lifetime, utxoindex_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+            let (utxoindex_db_lifetime, utxoindex_db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
             let config = Arc::new(Config::new(DEVNET_PARAMS));
             let tc = TestConsensus::new(&config);
             tc.init();
@@ -5,14 +5,19 @@ use spectre_consensus_core::{
     BlockHashSet,
 };
 use spectre_consensusmanager::spawn_blocking;
-use spectre_database::prelude::StoreResult;
+use spectre_database::{prelude::StoreResult, stats::DbStats};
 use spectre_hashes::Hash;
 use spectre_index_core::indexed_utxos::BalanceByScriptPublicKey;
-use std::{collections::HashSet, fmt::Debug, sync::Arc};
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use crate::{
     errors::UtxoIndexResult,
-    model::{UtxoChanges, UtxoSetByScriptPublicKey},
+    model::{UtxoChanges, UtxoPage, UtxoPageQuery, UtxoSetByScriptPublicKey},
 };
 
 ///Utxoindex API targeted at retrieval calls.
@@ -27,6 +32,11 @@ pub trait UtxoIndexApi: Send + Sync + Debug {
     /// Note: Use a read lock when accessing this method
     fn get_utxos_by_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey>;
 
+    /// Retrieve a page of the utxos matching the query from the utxoindex db.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_utxo_page(&self, query: UtxoPageQuery) -> UtxoIndexResult<UtxoPage>;
+
     fn get_balance_by_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey>;
 
     // This can have a big memory footprint, so it should be used only for tests.
@@ -44,6 +54,14 @@ pub trait UtxoIndexApi: Send + Sync + Debug {
     /// 2) due to potential sync-gaps is_synced is unreliable while consensus is actively resolving virtual states.  
     fn is_synced(&self) -> UtxoIndexResult<bool>;
 
+    /// Create a consistent checkpoint of the utxoindex database within `checkpoint_dir`, under the directory name of the database.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn create_checkpoint(&self, checkpoint_dir: &Path) -> StoreResult<()>;
+
+    /// Collect the statistics of the utxoindex database.
+    fn database_stats(&self) -> StoreResult<DbStats>;
+
     /// Update the utxoindex with the given utxo_diff, and tips.
     ///
     /// Note: Use a write lock when accessing this method
@@ -74,6 +92,10 @@ impl UtxoIndexProxy {
         spawn_blocking(move || self.inner.read().get_utxos_by_script_public_keys(script_public_keys)).await.unwrap()
     }
 
+    pub async fn get_utxo_page(self, query: UtxoPageQuery) -> UtxoIndexResult<UtxoPage> {
+        spawn_blocking(move || self.inner.read().get_utxo_page(query)).await.unwrap()
+    }
+
     pub async fn get_balance_by_script_public_keys(
         self,
         script_public_keys: ScriptPublicKeys,
@@ -81,6 +103,14 @@ impl UtxoIndexProxy {
         spawn_blocking(move || self.inner.read().get_balance_by_script_public_keys(script_public_keys)).await.unwrap()
     }
 
+    pub async fn create_checkpoint(self, checkpoint_dir: PathBuf) -> StoreResult<()> {
+        spawn_blocking(move || self.inner.read().create_checkpoint(&checkpoint_dir)).await.unwrap()
+    }
+
+    pub async fn database_stats(self) -> StoreResult<DbStats> {
+        spawn_blocking(move || self.inner.read().database_stats()).await.unwrap()
+    }
+
     pub async fn update(self, utxo_diff: Arc<UtxoDiff>, tips: Arc<Vec<Hash>>) -> UtxoIndexResult<UtxoChanges> {
         spawn_blocking(move || self.inner.write().update(utxo_diff, tips)).await.unwrap()
     }
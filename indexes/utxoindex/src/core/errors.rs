@@ -12,6 +12,9 @@ pub enum UtxoIndexError {
 
     #[error("[{IDENT}]: {0}")]
     DBResetError(#[from] io::Error),
+
+    #[error("[{IDENT}]: ordering by DAA score is limited to addresses holding at most {0} UTXOs")]
+    TooManyUtxosForDaaScoreOrder(usize),
 }
 
 /// Results originating from the [`UtxoIndex`](crate::UtxoIndex).
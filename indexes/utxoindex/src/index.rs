@@ -1,21 +1,28 @@
 use crate::{
     api::UtxoIndexApi,
     errors::{UtxoIndexError, UtxoIndexResult},
-    model::{CirculatingSupply, UtxoChanges, UtxoSetByScriptPublicKey},
+    model::{
+        CirculatingSupply, CompactUtxoEntry, UtxoChanges, UtxoOrder, UtxoPage, UtxoPageQuery, UtxoSetByScriptPublicKey,
+        MAX_DAA_SCORE_ORDERED_UTXOS,
+    },
     stores::store_manager::Store,
     update_container::UtxoIndexChanges,
     IDENT,
 };
 use parking_lot::RwLock;
-use spectre_consensus_core::{tx::ScriptPublicKeys, utxo::utxo_diff::UtxoDiff, BlockHashSet};
+use spectre_consensus_core::{api::DbInconsistency, tx::ScriptPublicKeys, utxo::utxo_diff::UtxoDiff, BlockHashSet};
 use spectre_consensusmanager::{ConsensusManager, ConsensusResetHandler};
 use spectre_core::{info, trace};
-use spectre_database::prelude::{StoreError, StoreResult, DB};
+use spectre_database::{
+    prelude::{StoreError, StoreResult, StoreResultExtensions, DB},
+    stats::DbStats,
+};
 use spectre_hashes::Hash;
 use spectre_index_core::indexed_utxos::BalanceByScriptPublicKey;
 use spectre_utils::arc::ArcExtensions;
 use std::{
     fmt::Debug,
+    path::Path,
     sync::{Arc, Weak},
 };
 
@@ -47,6 +54,66 @@ impl UtxoIndex {
         consensus_manager.register_consensus_reset_handler(Arc::new(UtxoIndexConsensusResetHandler::new(Arc::downgrade(&utxoindex))));
         Ok(utxoindex)
     }
+
+    /// Compares the utxoindex database with the virtual UTXO set of consensus, returning the inconsistencies found. If `repair`
+    /// is set and the utxoindex drifted from consensus, it is resynced.
+    ///
+    /// **Note:** consensus must not be processing new blocks while the verification runs.
+    pub fn verify(consensus_manager: Arc<ConsensusManager>, db: Arc<DB>, repair: bool) -> UtxoIndexResult<Vec<DbInconsistency>> {
+        let mut utxoindex = Self { consensus_manager, store: Store::new(db), monotonic_circulating_supply: 0 };
+        let mut inconsistencies = Vec::new();
+        if !utxoindex.is_synced()? {
+            inconsistencies.push(DbInconsistency::new("utxoindex", "the utxoindex tips do not match the virtual parents".into()));
+        }
+
+        let consensus = utxoindex.consensus_manager.consensus();
+        let session = futures::executor::block_on(consensus.session_blocking());
+        let (mut utxo_count, mut mismatch_count, mut supply) = (0usize, 0usize, 0 as CirculatingSupply);
+        let mut virtual_utxo_batch = session.get_virtual_utxos(None, RESYNC_CHUNK_SIZE, false);
+        while let Some((last_outpoint, _)) = virtual_utxo_batch.last().cloned() {
+            let batch_len = virtual_utxo_batch.len();
+            for (outpoint, entry) in virtual_utxo_batch {
+                utxo_count += 1;
+                supply += entry.amount;
+                let expected = CompactUtxoEntry::new(entry.amount, entry.block_daa_score, entry.is_coinbase);
+                if utxoindex.store.get_utxo_entry(&entry.script_public_key, &outpoint).unwrap_option() != Some(expected) {
+                    mismatch_count += 1;
+                }
+            }
+            if batch_len < RESYNC_CHUNK_SIZE {
+                break;
+            }
+            virtual_utxo_batch = session.get_virtual_utxos(Some(last_outpoint), RESYNC_CHUNK_SIZE, true);
+        }
+        drop(session);
+
+        if mismatch_count > 0 {
+            inconsistencies.push(DbInconsistency::new(
+                "utxoindex",
+                format!("{mismatch_count} out of {utxo_count} virtual UTXOs are missing from the utxoindex or differ from it"),
+            ));
+        }
+        let indexed_count = utxoindex.store.count_utxo_entries()?;
+        if indexed_count != utxo_count {
+            inconsistencies.push(DbInconsistency::new(
+                "utxoindex",
+                format!("the utxoindex holds {indexed_count} UTXOs while the virtual UTXO set holds {utxo_count}"),
+            ));
+        }
+        let indexed_supply = utxoindex.store.get_circulating_supply().unwrap_option();
+        if indexed_supply != Some(supply) {
+            inconsistencies.push(DbInconsistency::new(
+                "circulating supply",
+                format!("the utxoindex circulating supply {indexed_supply:?} does not match the virtual UTXO set supply {supply}"),
+            ));
+        }
+
+        if repair && !inconsistencies.is_empty() {
+            utxoindex.resync()?;
+            inconsistencies.iter_mut().for_each(|inconsistency| inconsistency.repaired = true);
+        }
+        Ok(inconsistencies)
+    }
 }
 
 impl UtxoIndexApi for UtxoIndex {
@@ -64,6 +131,22 @@ impl UtxoIndexApi for UtxoIndex {
         self.store.get_utxos_by_script_public_key(script_public_keys)
     }
 
+    /// Retrieve a page of the utxos matching the query from the utxoindex db.
+    ///
+    /// Queries ordered by DAA score scan all the entries of their script public keys, so they are rejected
+    /// if these hold more than [`MAX_DAA_SCORE_ORDERED_UTXOS`] entries.
+    fn get_utxo_page(&self, query: UtxoPageQuery) -> UtxoIndexResult<UtxoPage> {
+        trace!("[{0}] retrieving a page of utxos from {1} script public keys", IDENT, query.script_public_keys.len());
+
+        if query.order != UtxoOrder::Outpoint
+            && self.store.count_utxo_entries_up_to(&query.script_public_keys, MAX_DAA_SCORE_ORDERED_UTXOS + 1)?
+                > MAX_DAA_SCORE_ORDERED_UTXOS
+        {
+            return Err(UtxoIndexError::TooManyUtxosForDaaScoreOrder(MAX_DAA_SCORE_ORDERED_UTXOS));
+        }
+        Ok(self.store.get_utxo_page(&query)?)
+    }
+
     /// Retrieve utxos by script public keys from the utxoindex db.
     fn get_balance_by_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey> {
         trace!("[{0}] retrieving utxos from {1} script public keys", IDENT, script_public_keys.len());
@@ -78,6 +161,18 @@ impl UtxoIndexApi for UtxoIndex {
         self.store.get_tips()
     }
 
+    /// Creates a consistent checkpoint of the utxoindex database.
+    fn create_checkpoint(&self, checkpoint_dir: &Path) -> StoreResult<()> {
+        trace!("[{0}] creating a checkpoint at {1}", IDENT, checkpoint_dir.display());
+
+        self.store.create_checkpoint(checkpoint_dir)
+    }
+
+    /// Collects the statistics of the utxoindex database.
+    fn database_stats(&self) -> StoreResult<DbStats> {
+        self.store.database_stats()
+    }
+
     /// Updates the [UtxoIndex] via the virtual state supplied:
     /// 1) Saves updated utxo differences, virtual parent hashes and circulating supply to the database.
     /// 2) returns an event about utxoindex changes.
@@ -238,7 +333,7 @@ mod tests {
     };
     use spectre_consensusmanager::ConsensusManager;
     use spectre_core::info;
-    use spectre_database::create_temp_db;
+    use spectre_database::create_memory_db;
     use spectre_database::prelude::ConnBuilder;
     use std::{collections::HashSet, sync::Arc, time::Instant};
 
@@ -253,11 +348,11 @@ mod tests {
 
         // Initialize all components, and virtual change emulator proxy.
         let mut virtual_change_emulator = VirtualChangeEmulator::new();
-        let (_utxoindex_db_lifetime, utxoindex_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (_utxoindex_db_lifetime, utxoindex_db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
         let config = Config::new(DEVNET_PARAMS);
         let tc = Arc::new(TestConsensus::new(&config));
         let consensus_manager = Arc::new(ConsensusManager::from_consensus(tc.consensus_clone()));
-        let utxoindex = UtxoIndex::new(consensus_manager, utxoindex_db).unwrap();
+        let utxoindex = UtxoIndex::new(consensus_manager.clone(), utxoindex_db.clone()).unwrap();
 
         // Fill initial utxo collection in emulator.
         virtual_change_emulator.fill_utxo_collection(resync_utxo_collection_size, script_public_key_pool_size); //10_000 utxos belonging to 100 script public keys
@@ -308,6 +403,7 @@ mod tests {
         assert_eq!(i, consensus_utxo_set_size);
         assert_eq!(utxoindex.read().get_circulating_supply().expect("expected circulating supply"), consensus_supply);
         assert_eq!(*utxoindex.read().get_utxo_index_tips().expect("expected circulating supply"), tc.get_virtual_parents());
+        assert!(UtxoIndex::verify(consensus_manager.clone(), utxoindex_db.clone(), false).unwrap().is_empty());
 
         // Test update: Change and signal new virtual state.
         virtual_change_emulator.clear_virtual_state();
@@ -359,6 +455,12 @@ mod tests {
         );
         assert_eq!(*utxoindex.read().get_utxo_index_tips().expect("expected circulating supply"), virtual_change_emulator.tips);
 
+        // The utxoindex drifted from the test-consensus db, which verification is expected to detect and repair.
+        assert!(!UtxoIndex::verify(consensus_manager.clone(), utxoindex_db.clone(), false).unwrap().is_empty());
+        let inconsistencies = UtxoIndex::verify(consensus_manager.clone(), utxoindex_db.clone(), true).unwrap();
+        assert!(inconsistencies.iter().all(|inconsistency| inconsistency.repaired));
+        assert!(UtxoIndex::verify(consensus_manager, utxoindex_db, false).unwrap().is_empty());
+
         //test if resync clears db.
 
         utxoindex.write().resync().expect("expected resync");
@@ -1,4 +1,6 @@
-use crate::core::model::{CompactUtxoCollection, CompactUtxoEntry, UtxoSetByScriptPublicKey};
+use crate::core::model::{
+    CompactUtxoCollection, CompactUtxoEntry, IndexedUtxo, UtxoOrder, UtxoPage, UtxoPageQuery, UtxoSetByScriptPublicKey,
+};
 
 use serde::{Deserialize, Serialize};
 use spectre_consensus_core::tx::{
@@ -9,7 +11,8 @@ use spectre_database::prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, Sto
 use spectre_database::registry::DatabaseStorePrefixes;
 use spectre_hashes::Hash;
 use spectre_index_core::indexed_utxos::BalanceByScriptPublicKey;
-use std::collections::HashSet;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
 use std::fmt::Display;
 use std::sync::Arc;
 
@@ -59,7 +62,7 @@ pub const TRANSACTION_OUTPOINT_KEY_SIZE: usize = spectre_hashes::HASH_SIZE + siz
 
 /// [TransactionOutpoint] key which references the [CompactUtxoEntry] within a [ScriptPublicKeyBucket]
 /// Consists of 32 bytes of [TransactionId], followed by 4 bytes of little endian [TransactionIndexType]
-#[derive(Eq, Hash, PartialEq, Debug, Copy, Clone)]
+#[derive(Eq, Hash, PartialEq, Ord, PartialOrd, Debug, Copy, Clone)]
 struct TransactionOutpointKey([u8; TRANSACTION_OUTPOINT_KEY_SIZE]);
 
 impl From<TransactionOutpointKey> for TransactionOutpoint {
@@ -125,6 +128,38 @@ pub trait UtxoSetByScriptPublicKeyStoreReader {
     fn get_utxos_from_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey>;
     fn get_balance_from_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey>;
     fn get_all_outpoints(&self) -> StoreResult<HashSet<TransactionOutpoint>>; // This can have a big memory footprint, so it should be used only for tests.
+    fn get_utxo_entry(&self, script_public_key: &ScriptPublicKey, outpoint: &TransactionOutpoint) -> StoreResult<CompactUtxoEntry>;
+    fn count_utxo_entries(&self) -> StoreResult<usize>;
+    /// Count the entries of the distinct `script_public_keys`, stopping at `max`
+    fn count_utxo_entries_up_to(&self, script_public_keys: &[ScriptPublicKey], max: usize) -> StoreResult<usize>;
+    /// Get the [UtxoPage] of the entries matching the [UtxoPageQuery]
+    fn get_utxo_page(&self, query: &UtxoPageQuery) -> StoreResult<UtxoPage>;
+}
+
+/// An entry collected by a page query sorted by DAA score, ordered by its sort key only
+struct DaaScoreSortedEntry {
+    key: (u64, usize, TransactionOutpointKey), // (block DAA score, script public key index, outpoint)
+    entry: CompactUtxoEntry,
+}
+
+impl PartialEq for DaaScoreSortedEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for DaaScoreSortedEntry {}
+
+impl PartialOrd for DaaScoreSortedEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DaaScoreSortedEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
 }
 
 pub trait UtxoSetByScriptPublicKeyStore: UtxoSetByScriptPublicKeyStoreReader {
@@ -202,6 +237,128 @@ impl UtxoSetByScriptPublicKeyStoreReader for DbUtxoSetByScriptPublicKeyStore {
             self.access.iterator().map(|res| UtxoEntryFullAccessKey(Arc::new(res.unwrap().0.to_vec())).extract_outpoint()),
         ))
     }
+
+    fn get_utxo_entry(&self, script_public_key: &ScriptPublicKey, outpoint: &TransactionOutpoint) -> StoreResult<CompactUtxoEntry> {
+        self.access
+            .read(UtxoEntryFullAccessKey::new(ScriptPublicKeyBucket::from(script_public_key), TransactionOutpointKey::from(outpoint)))
+    }
+
+    fn count_utxo_entries(&self) -> StoreResult<usize> {
+        Ok(self.access.iterator().count())
+    }
+
+    fn count_utxo_entries_up_to(&self, script_public_keys: &[ScriptPublicKey], max: usize) -> StoreResult<usize> {
+        let mut unique = HashSet::with_capacity(script_public_keys.len());
+        let mut count = 0;
+        for script_public_key in script_public_keys.iter().filter(|&spk| unique.insert(spk)) {
+            let bucket = ScriptPublicKeyBucket::from(script_public_key);
+            count += self.access.seek_iterator(Some(bucket.as_ref()), None, max - count, false).count();
+            if count == max {
+                break;
+            }
+        }
+        Ok(count)
+    }
+
+    fn get_utxo_page(&self, query: &UtxoPageQuery) -> StoreResult<UtxoPage> {
+        let mut unique = HashSet::with_capacity(query.script_public_keys.len());
+        let script_public_keys = query.script_public_keys.iter().filter(|&spk| unique.insert(spk)).collect::<Vec<_>>();
+        let cursor = match query.cursor.as_ref() {
+            Some(cursor) => match script_public_keys.iter().position(|&spk| *spk == cursor.script_public_key) {
+                Some(index) => Some((cursor.block_daa_score, index, TransactionOutpointKey::from(&cursor.outpoint))),
+                // The cursor does not point into the queried script public keys, so no entry follows it
+                None => return Ok(UtxoPage::default()),
+            },
+            None => None,
+        };
+        // One entry beyond the limit is collected in order to know if the page is the last one
+        let capacity = query.limit.map_or(usize::MAX, |limit| limit.saturating_add(1));
+
+        let mut entries = Vec::new();
+        match query.order {
+            UtxoOrder::Outpoint => {
+                // Entries are keyed by script public key bucket and then by outpoint, so the page can be read sequentially from the cursor
+                let start = cursor.map_or(0, |(_, index, _)| index);
+                'scripts: for (index, &script_public_key) in script_public_keys.iter().enumerate().skip(start) {
+                    let bucket = ScriptPublicKeyBucket::from(script_public_key);
+                    let seek_key =
+                        cursor.filter(|&(_, cursor_index, _)| cursor_index == index).map(|(_, _, outpoint_key)| outpoint_key);
+                    let seek_from = seek_key.map(|outpoint_key| UtxoEntryFullAccessKey::new(bucket.clone(), outpoint_key));
+                    for res in self.access.seek_iterator(Some(bucket.as_ref()), seek_from, usize::MAX, false) {
+                        let (key, entry) = res.unwrap();
+                        let outpoint_key = TransactionOutpointKey(<[u8; TRANSACTION_OUTPOINT_KEY_SIZE]>::try_from(&key[..]).unwrap());
+                        // The cursor entry itself belongs to the previous page, if it was not spent since
+                        if Some(outpoint_key) == seek_key || !query.filter.matches(&entry) {
+                            continue;
+                        }
+                        entries.push(IndexedUtxo {
+                            script_public_key: script_public_key.clone(),
+                            outpoint: outpoint_key.into(),
+                            entry,
+                        });
+                        if entries.len() == capacity {
+                            break 'scripts;
+                        }
+                    }
+                }
+            }
+            UtxoOrder::DaaScoreAscending | UtxoOrder::DaaScoreDescending => {
+                // The store is not ordered by DAA score, so all entries of the script public keys are scanned while only retaining
+                // the `capacity` first ones past the cursor, bounding the memory footprint by the page size. The scan cost is bounded
+                // by the caller, which rejects such queries over more than `MAX_DAA_SCORE_ORDERED_UTXOS` entries
+                let ascending = query.order == UtxoOrder::DaaScoreAscending;
+                let mut lowest = BinaryHeap::new(); // Max-heap retaining the lowest keys when ascending
+                let mut highest = BinaryHeap::new(); // Min-heap retaining the highest keys when descending
+                for (index, &script_public_key) in script_public_keys.iter().enumerate() {
+                    let bucket = ScriptPublicKeyBucket::from(script_public_key);
+                    for res in self.access.seek_iterator(Some(bucket.as_ref()), None, usize::MAX, false) {
+                        let (key, entry) = res.unwrap();
+                        let outpoint_key = TransactionOutpointKey(<[u8; TRANSACTION_OUTPOINT_KEY_SIZE]>::try_from(&key[..]).unwrap());
+                        let key = (entry.block_daa_score, index, outpoint_key);
+                        let past_cursor = cursor.is_none_or(|cursor| if ascending { key > cursor } else { key < cursor });
+                        if !past_cursor || !query.filter.matches(&entry) {
+                            continue;
+                        }
+                        if ascending {
+                            lowest.push(DaaScoreSortedEntry { key, entry });
+                            if lowest.len() > capacity {
+                                lowest.pop();
+                            }
+                        } else {
+                            highest.push(Reverse(DaaScoreSortedEntry { key, entry }));
+                            if highest.len() > capacity {
+                                highest.pop();
+                            }
+                        }
+                    }
+                }
+                let sorted = if ascending {
+                    lowest.into_sorted_vec()
+                } else {
+                    highest.into_sorted_vec().into_iter().map(|Reverse(sorted_entry)| sorted_entry).collect()
+                };
+                entries.extend(sorted.into_iter().map(|DaaScoreSortedEntry { key: (_, index, outpoint_key), entry }| IndexedUtxo {
+                    script_public_key: script_public_keys[index].clone(),
+                    outpoint: outpoint_key.into(),
+                    entry,
+                }));
+            }
+        }
+
+        let next_cursor = match query.limit {
+            Some(limit) if entries.len() > limit => {
+                entries.truncate(limit);
+                entries.last().map(|utxo| utxo.cursor())
+            }
+            _ => None,
+        };
+        debug!(
+            "IDXPRC, Executed a query for a page of {} entries over {} script public keys",
+            entries.len(),
+            script_public_keys.len()
+        );
+        Ok(UtxoPage { entries, next_cursor })
+    }
 }
 
 impl UtxoSetByScriptPublicKeyStore for DbUtxoSetByScriptPublicKeyStore {
@@ -255,3 +412,104 @@ impl UtxoSetByScriptPublicKeyStore for DbUtxoSetByScriptPublicKeyStore {
         self.access.delete_all(DirectDbWriter::new(&self.db))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{UtxoCursor, UtxoFilter};
+    use spectre_database::{create_memory_db, prelude::ConnBuilder};
+
+    fn collect_pages(store: &DbUtxoSetByScriptPublicKeyStore, mut query: UtxoPageQuery) -> Vec<IndexedUtxo> {
+        let mut entries = Vec::new();
+        loop {
+            let page = store.get_utxo_page(&query).unwrap();
+            assert!(page.entries.len() <= query.limit.unwrap_or(usize::MAX));
+            entries.extend(page.entries);
+            match page.next_cursor {
+                Some(cursor) => query.cursor = Some(cursor),
+                None => return entries,
+            }
+        }
+    }
+
+    #[test]
+    fn test_utxo_page() {
+        let (_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
+        let mut store = DbUtxoSetByScriptPublicKeyStore::new(db, CachePolicy::Empty);
+
+        let script_public_keys = (0..3u8).map(|i| ScriptPublicKey::from_vec(0, vec![i; 34])).collect::<Vec<_>>();
+        let mut utxos = UtxoSetByScriptPublicKey::new();
+        for (i, script_public_key) in script_public_keys.iter().enumerate() {
+            let collection = (0..20u64)
+                .map(|j| {
+                    let outpoint = TransactionOutpoint::new(Hash::from_u64_word(j * 7 + i as u64), j as TransactionIndexType);
+                    (outpoint, CompactUtxoEntry::new(j * 100 + i as u64, (j * 37 + i as u64) % 50, j % 4 == 0))
+                })
+                .collect();
+            utxos.insert(script_public_key.clone(), collection);
+        }
+        store.add_utxo_entries(&utxos).unwrap();
+
+        // The last script public key is left out, and the first one is queried twice
+        let query_keys = vec![script_public_keys[1].clone(), script_public_keys[0].clone(), script_public_keys[1].clone()];
+        let filter = UtxoFilter { min_amount: Some(150), max_amount: Some(1800), min_immature_coinbase_daa_score: Some(20) };
+        let expected = query_keys[..2]
+            .iter()
+            .flat_map(|spk| utxos[spk].iter().map(|(outpoint, entry)| (spk.clone(), *outpoint, *entry)))
+            .filter(|(_, _, entry)| filter.matches(entry))
+            .collect::<Vec<_>>();
+        assert!(expected.len() > 10 && expected.len() < 40);
+        let as_tuples = |entries: Vec<IndexedUtxo>| -> Vec<_> {
+            entries.into_iter().map(|utxo| (utxo.script_public_key, utxo.outpoint, utxo.entry)).collect()
+        };
+
+        // Outpoint order groups the entries by script public key in query order
+        let query = UtxoPageQuery { script_public_keys: query_keys.clone(), filter: filter.clone(), ..Default::default() };
+        let unpaged = as_tuples(store.get_utxo_page(&query).unwrap().entries);
+        assert_eq!(unpaged.len(), expected.len());
+        let (first, second) = unpaged.split_at(expected.iter().filter(|(spk, _, _)| *spk == query_keys[0]).count());
+        assert!(first.iter().all(|(spk, _, _)| *spk == query_keys[0]) && second.iter().all(|(spk, _, _)| *spk == query_keys[1]));
+        for limit in [1, 3, 7, expected.len(), expected.len() + 1] {
+            let paged = as_tuples(collect_pages(&store, UtxoPageQuery { limit: Some(limit), ..query.clone() }));
+            assert_eq!(paged, unpaged);
+        }
+
+        // Ordered by DAA score
+        for (order, descending) in [(UtxoOrder::DaaScoreAscending, false), (UtxoOrder::DaaScoreDescending, true)] {
+            let query = UtxoPageQuery { order, ..query.clone() };
+            let unpaged = as_tuples(store.get_utxo_page(&query).unwrap().entries);
+            assert_eq!(unpaged.len(), expected.len());
+            let scores = unpaged.iter().map(|(_, _, entry)| entry.block_daa_score).collect::<Vec<_>>();
+            assert!(if descending { scores.windows(2).all(|w| w[0] >= w[1]) } else { scores.is_sorted() });
+            for limit in [1, 4, expected.len()] {
+                let paged = as_tuples(collect_pages(&store, UtxoPageQuery { limit: Some(limit), ..query.clone() }));
+                assert_eq!(paged, unpaged);
+            }
+        }
+
+        // Spending the cursor entry does not affect the next page
+        let page = store.get_utxo_page(&UtxoPageQuery { limit: Some(5), ..query.clone() }).unwrap();
+        let cursor = page.next_cursor.clone().unwrap();
+        let next = store.get_utxo_page(&UtxoPageQuery { limit: Some(5), cursor: Some(cursor.clone()), ..query.clone() }).unwrap();
+        let spent = UtxoSetByScriptPublicKey::from([(
+            cursor.script_public_key.clone(),
+            CompactUtxoCollection::from([(cursor.outpoint, page.entries.last().unwrap().entry)]),
+        )]);
+        store.remove_utxo_entries(&spent).unwrap();
+        let after_spend = store.get_utxo_page(&UtxoPageQuery { limit: Some(5), cursor: Some(cursor), ..query.clone() }).unwrap();
+        assert_eq!(after_spend.entries, next.entries);
+
+        // A cursor outside of the queried script public keys yields no entries
+        let cursor = UtxoCursor {
+            script_public_key: script_public_keys[2].clone(),
+            outpoint: TransactionOutpoint::default(),
+            block_daa_score: 0,
+        };
+        assert!(store.get_utxo_page(&UtxoPageQuery { cursor: Some(cursor), ..query }).unwrap().entries.is_empty());
+
+        // Counting stops at the maximum and ignores duplicate script public keys
+        assert_eq!(store.count_utxo_entries_up_to(&query_keys, usize::MAX).unwrap(), 39);
+        assert_eq!(store.count_utxo_entries_up_to(&query_keys, 25).unwrap(), 25);
+        assert_eq!(store.count_utxo_entries_up_to(&script_public_keys[2..], 25).unwrap(), 20);
+    }
+}
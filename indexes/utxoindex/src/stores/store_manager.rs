@@ -1,15 +1,18 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, path::Path, sync::Arc};
 
 use spectre_consensus_core::{
-    tx::{ScriptPublicKeys, TransactionOutpoint},
+    tx::{ScriptPublicKey, ScriptPublicKeys, TransactionOutpoint},
     BlockHashSet,
 };
 use spectre_core::trace;
-use spectre_database::prelude::{CachePolicy, StoreResult, DB};
+use spectre_database::{
+    prelude::{create_checkpoint, CachePolicy, StoreResult, DB},
+    stats::{collect_db_stats, DbStats},
+};
 use spectre_index_core::indexed_utxos::BalanceByScriptPublicKey;
 
 use crate::{
-    model::UtxoSetByScriptPublicKey,
+    model::{CompactUtxoEntry, UtxoPage, UtxoPageQuery, UtxoSetByScriptPublicKey},
     stores::{
         indexed_utxos::{DbUtxoSetByScriptPublicKeyStore, UtxoSetByScriptPublicKeyStore, UtxoSetByScriptPublicKeyStoreReader},
         supply::{CirculatingSupplyStore, CirculatingSupplyStoreReader, DbCirculatingSupplyStore},
@@ -20,6 +23,7 @@ use crate::{
 
 #[derive(Clone)]
 pub struct Store {
+    db: Arc<DB>,
     utxoindex_tips_store: DbUtxoIndexTipsStore,
     circulating_supply_store: DbCirculatingSupplyStore,
     utxos_by_script_public_key_store: DbUtxoSetByScriptPublicKeyStore,
@@ -28,6 +32,7 @@ pub struct Store {
 impl Store {
     pub fn new(db: Arc<DB>) -> Self {
         Self {
+            db: db.clone(),
             utxoindex_tips_store: DbUtxoIndexTipsStore::new(db.clone()),
             circulating_supply_store: DbCirculatingSupplyStore::new(db.clone()),
             utxos_by_script_public_key_store: DbUtxoSetByScriptPublicKeyStore::new(db, CachePolicy::Empty),
@@ -38,6 +43,15 @@ impl Store {
         self.utxos_by_script_public_key_store.get_utxos_from_script_public_keys(script_public_keys)
     }
 
+    pub fn get_utxo_page(&self, query: &UtxoPageQuery) -> StoreResult<UtxoPage> {
+        self.utxos_by_script_public_key_store.get_utxo_page(query)
+    }
+
+    /// Counts the entries of the distinct `script_public_keys`, stopping at `max`
+    pub fn count_utxo_entries_up_to(&self, script_public_keys: &[ScriptPublicKey], max: usize) -> StoreResult<usize> {
+        self.utxos_by_script_public_key_store.count_utxo_entries_up_to(script_public_keys, max)
+    }
+
     pub fn get_balance_by_script_public_key(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey> {
         self.utxos_by_script_public_key_store.get_balance_from_script_public_keys(script_public_keys)
     }
@@ -47,6 +61,18 @@ impl Store {
         self.utxos_by_script_public_key_store.get_all_outpoints()
     }
 
+    pub fn get_utxo_entry(
+        &self,
+        script_public_key: &ScriptPublicKey,
+        outpoint: &TransactionOutpoint,
+    ) -> StoreResult<CompactUtxoEntry> {
+        self.utxos_by_script_public_key_store.get_utxo_entry(script_public_key, outpoint)
+    }
+
+    pub fn count_utxo_entries(&self) -> StoreResult<usize> {
+        self.utxos_by_script_public_key_store.count_utxo_entries()
+    }
+
     pub fn update_utxo_state(
         &mut self,
         to_add: &UtxoSetByScriptPublicKey,
@@ -106,6 +132,16 @@ impl Store {
         res
     }
 
+    /// Creates a consistent checkpoint of the utxoindex database within `checkpoint_dir`, under the directory name of the database
+    pub fn create_checkpoint(&self, checkpoint_dir: &Path) -> StoreResult<()> {
+        create_checkpoint(&self.db, &checkpoint_dir.join(self.db.path().file_name().unwrap()))
+    }
+
+    /// Collects the statistics of the utxoindex database
+    pub fn database_stats(&self) -> StoreResult<DbStats> {
+        collect_db_stats(&self.db)
+    }
+
     /// Resets the utxoindex database:
     pub fn delete_all(&mut self) -> StoreResult<()> {
         // TODO: explore possibility of deleting and replacing whole db, currently there is an issue because of file lock and db being in an arc.
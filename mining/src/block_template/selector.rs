@@ -256,6 +256,32 @@ impl TemplateTransactionSelector for RebalancingWeightedTransactionSelector {
     }
 }
 
+/// A selector offering a fixed list of transactions at once, considered successful only if none of them is rejected
+pub struct FixedTransactionSelector {
+    transactions: Option<Vec<Transaction>>,
+    rejections: usize,
+}
+
+impl FixedTransactionSelector {
+    pub fn new(transactions: Vec<Transaction>) -> Self {
+        Self { transactions: Some(transactions), rejections: 0 }
+    }
+}
+
+impl TemplateTransactionSelector for FixedTransactionSelector {
+    fn select_transactions(&mut self) -> Vec<Transaction> {
+        self.transactions.take().unwrap_or_default()
+    }
+
+    fn reject_selection(&mut self, _tx_id: TransactionId) {
+        self.rejections += 1;
+    }
+
+    fn is_successful(&self) -> bool {
+        self.rejections == 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,221 @@
+//! Historical tracking of the time mempool transactions wait before being accepted by the DAG.
+//!
+//! Accepted transactions are grouped in geometrically spaced feerate buckets and, within each bucket, in bins
+//! of observed waiting time. Older observations are exponentially decayed so the history follows the recent
+//! network conditions while remaining insensitive to short bursts.
+//!
+//! Transactions leaving the mempool without being accepted, by expiring or by being evicted, are recorded as
+//! censored observations: they only tell that the transaction was not accepted within the time it waited, so
+//! they count as failures for shorter targets and are ignored for longer ones.
+
+/// Upper bounds, in seconds, of the waiting time bins. The last bin collects all longer waits.
+pub const WAIT_TIME_BINS: [f64; 12] = [1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0, f64::INFINITY];
+
+/// Ratio between the lower bounds of two consecutive feerate buckets
+const FEERATE_BUCKET_SPACING: f64 = 1.2;
+
+/// Ratio between the highest tracked feerate and the minimum feerate
+const MAX_FEERATE_RATIO: f64 = 1e7;
+
+/// Time in seconds after which the weight of an observation is halved
+const HALF_LIFE_SECONDS: f64 = 1800.0;
+
+/// Minimal decayed count of observations a group of buckets must hold for an estimation to be derived from it
+pub const MIN_SAMPLES: f64 = 20.0;
+
+#[derive(Clone, Debug)]
+pub struct FeerateHistory {
+    /// Lower feerate bound of each bucket, in increasing order
+    bucket_bounds: Vec<f64>,
+
+    /// Decayed count of accepted transactions by feerate bucket and waiting time bin
+    counts: Vec<[f64; WAIT_TIME_BINS.len()]>,
+
+    /// Decayed count of transactions removed without being accepted, by feerate bucket and waiting time bin
+    censored: Vec<[f64; WAIT_TIME_BINS.len()]>,
+
+    network_blocks_per_second: u64,
+
+    /// DAA score up to which the counts are decayed
+    decayed_at_daa_score: u64,
+}
+
+impl FeerateHistory {
+    pub fn new(minimum_feerate: f64, network_blocks_per_second: u64) -> Self {
+        assert!(network_blocks_per_second > 0);
+        // The lowest bucket also holds all the transactions paying less than its bound, so with no minimum
+        // feerate required, a bound of 1 sompi/gram is used
+        let lowest = if minimum_feerate > 0.0 { minimum_feerate } else { 1.0 };
+        let bucket_bounds = std::iter::successors(Some(lowest), |bound| Some(bound * FEERATE_BUCKET_SPACING))
+            .take_while(|bound| *bound <= lowest * MAX_FEERATE_RATIO)
+            .collect::<Vec<_>>();
+        let counts = vec![[0.0; WAIT_TIME_BINS.len()]; bucket_bounds.len()];
+        let censored = counts.clone();
+        Self { bucket_bounds, counts, censored, network_blocks_per_second, decayed_at_daa_score: 0 }
+    }
+
+    /// Records the acceptance at `accepted_at_daa_score` of a transaction with `feerate` which entered the mempool
+    /// at `added_at_daa_score`
+    pub fn record(&mut self, feerate: f64, added_at_daa_score: u64, accepted_at_daa_score: u64) {
+        let (bucket, bin) = self.observation(feerate, added_at_daa_score, accepted_at_daa_score);
+        self.counts[bucket][bin] += 1.0;
+    }
+
+    /// Records the removal at `removed_at_daa_score`, without being accepted, of a transaction with `feerate` which
+    /// entered the mempool at `added_at_daa_score`
+    pub fn record_censored(&mut self, feerate: f64, added_at_daa_score: u64, removed_at_daa_score: u64) {
+        let (bucket, bin) = self.observation(feerate, added_at_daa_score, removed_at_daa_score);
+        self.censored[bucket][bin] += 1.0;
+    }
+
+    /// Returns the lowest feerate for which at least a `confidence` fraction of the observed transactions were
+    /// accepted within `target_seconds`, or `None` if the history holds too few observations to tell.
+    ///
+    /// Buckets are scanned from the highest feerate down, merging consecutive buckets until they hold enough
+    /// observations. The scan stops at the first group failing the confidence requirement. Censored observations
+    /// only count, as failures, if the transaction waited longer than `target_seconds`.
+    pub fn feerate_for(&self, target_seconds: f64, confidence: f64) -> Option<f64> {
+        assert!((0f64..=1f64).contains(&confidence));
+        let within_bins = WAIT_TIME_BINS.iter().take_while(|bound| **bound <= target_seconds).count();
+        if within_bins == 0 {
+            return None;
+        }
+        let mut feerate = None;
+        let (mut total, mut within) = (0.0, 0.0);
+        for ((bound, counts), censored) in self.bucket_bounds.iter().zip(self.counts.iter()).zip(self.censored.iter()).rev() {
+            total += counts.iter().sum::<f64>() + censored[within_bins..].iter().sum::<f64>();
+            within += counts[..within_bins].iter().sum::<f64>();
+            if total < MIN_SAMPLES {
+                continue;
+            }
+            if within / total < confidence {
+                break;
+            }
+            feerate = Some(*bound);
+            (total, within) = (0.0, 0.0);
+        }
+        feerate
+    }
+
+    /// Decayed count of all the observations, censored ones included
+    pub fn sample_count(&self) -> f64 {
+        self.counts.iter().chain(self.censored.iter()).flatten().sum()
+    }
+
+    /// Decays the history up to `daa_score` and returns the bucket and the waiting time bin of an observation
+    fn observation(&mut self, feerate: f64, added_at_daa_score: u64, daa_score: u64) -> (usize, usize) {
+        self.decay(daa_score);
+        let wait_time = daa_score.saturating_sub(added_at_daa_score) as f64 / self.network_blocks_per_second as f64;
+        let bin = WAIT_TIME_BINS.iter().position(|bound| wait_time <= *bound).unwrap_or(WAIT_TIME_BINS.len() - 1);
+        (self.bucket_index(feerate), bin)
+    }
+
+    fn bucket_index(&self, feerate: f64) -> usize {
+        self.bucket_bounds.partition_point(|bound| *bound <= feerate).saturating_sub(1)
+    }
+
+    fn decay(&mut self, daa_score: u64) {
+        if daa_score <= self.decayed_at_daa_score {
+            return;
+        }
+        let elapsed = (daa_score - self.decayed_at_daa_score) as f64 / self.network_blocks_per_second as f64;
+        self.decayed_at_daa_score = daa_score;
+        let factor = 0.5f64.powf(elapsed / HALF_LIFE_SECONDS);
+        self.counts.iter_mut().chain(self.censored.iter_mut()).flatten().for_each(|count| *count *= factor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BPS: u64 = 10;
+
+    fn record_many(history: &mut FeerateHistory, count: usize, feerate: f64, wait_seconds: u64, accepted_at_daa_score: u64) {
+        for _ in 0..count {
+            history.record(feerate, accepted_at_daa_score - wait_seconds * BPS, accepted_at_daa_score);
+        }
+    }
+
+    #[test]
+    fn test_feerate_for() {
+        let mut history = FeerateHistory::new(1.0, BPS);
+        let daa_score = 1_000_000;
+        assert_eq!(history.feerate_for(60.0, 0.5), None);
+
+        // High feerate transactions get in fast, low feerate ones wait for several minutes
+        record_many(&mut history, 50, 100.0, 1, daa_score);
+        record_many(&mut history, 50, 10.0, 20, daa_score);
+        record_many(&mut history, 50, 1.0, 400, daa_score);
+
+        let high = history.feerate_for(1.0, 0.9).unwrap();
+        assert!(high <= 100.0 && high > 100.0 / FEERATE_BUCKET_SPACING, "{high}");
+        let normal = history.feerate_for(60.0, 0.9).unwrap();
+        assert!(normal <= 10.0 && normal > 10.0 / FEERATE_BUCKET_SPACING, "{normal}");
+        assert_eq!(history.feerate_for(3600.0, 0.9), Some(1.0));
+        // No observation of sub-second waits
+        assert_eq!(history.feerate_for(0.5, 0.9), None);
+
+        // Lower confidence levels never require higher feerates
+        for target in WAIT_TIME_BINS {
+            if let (Some(low), Some(high)) = (history.feerate_for(target, 0.5), history.feerate_for(target, 0.95)) {
+                assert!(low <= high);
+            }
+        }
+    }
+
+    #[test]
+    fn test_too_few_samples() {
+        let mut history = FeerateHistory::new(1.0, BPS);
+        record_many(&mut history, (MIN_SAMPLES as usize) - 1, 5.0, 1, 1000);
+        assert_eq!(history.feerate_for(60.0, 0.5), None);
+        record_many(&mut history, 1, 5.0, 1, 1000);
+        assert!(history.feerate_for(60.0, 0.5).is_some());
+    }
+
+    #[test]
+    fn test_censored() {
+        let daa_score = 1_000_000;
+        let mut history = FeerateHistory::new(1.0, BPS);
+        record_many(&mut history, 50, 10.0, 1, daa_score);
+        let feerate = history.feerate_for(5.0, 0.9);
+        assert!(feerate.is_some());
+
+        // Transactions evicted after waiting ten seconds are failures for the shorter targets...
+        for _ in 0..50 {
+            history.record_censored(10.0, daa_score - 10 * BPS, daa_score);
+        }
+        assert_eq!(history.sample_count(), 100.0);
+        assert_eq!(history.feerate_for(5.0, 0.9), None);
+        assert_eq!(history.feerate_for(5.0, 0.5), feerate);
+
+        // ...while they do not tell anything about the longer ones
+        assert_eq!(history.feerate_for(60.0, 0.9), feerate);
+    }
+
+    #[test]
+    fn test_decay() {
+        let mut history = FeerateHistory::new(1.0, BPS);
+        record_many(&mut history, 100, 2.0, 1, 1000);
+        assert_eq!(history.sample_count(), 100.0);
+
+        // A single new observation one half-life later
+        let daa_score = 1000 + HALF_LIFE_SECONDS as u64 * BPS;
+        history.record(2.0, daa_score - BPS, daa_score);
+        assert!((history.sample_count() - 51.0).abs() < 1e-9, "{}", history.sample_count());
+
+        // Observations recorded out of order are never decayed backwards
+        history.record(2.0, 0, 1000);
+        assert!((history.sample_count() - 52.0).abs() < 1e-9, "{}", history.sample_count());
+    }
+
+    #[test]
+    fn test_bucket_index() {
+        let history = FeerateHistory::new(1.0, BPS);
+        assert_eq!(history.bucket_index(0.5), 0);
+        assert_eq!(history.bucket_index(1.0), 0);
+        assert_eq!(history.bucket_index(1.1), 0);
+        assert_eq!(history.bucket_index(1.2), 1);
+        assert_eq!(history.bucket_index(f64::MAX), history.bucket_bounds.len() - 1);
+    }
+}
@@ -2,9 +2,24 @@
 //! behind this fee estimator.
 
 use crate::block_template::selector::ALPHA;
+use history::FeerateHistory;
 use itertools::Itertools;
 use std::fmt::Display;
 
+pub mod history;
+
+/// Confidence level required from the historical model when blending it with the mempool state model
+const BLEND_CONFIDENCE: f64 = 0.8;
+
+/// Weight of the historical model in the (geometric) blend of both models
+const HISTORY_WEIGHT: f64 = 0.5;
+
+/// Inclusion time targets, in seconds, of the historical estimations reported to clients
+pub const HISTORICAL_TARGETS: [f64; 3] = [60.0, 600.0, 3600.0];
+
+/// Confidence levels of the historical estimations reported to clients
+pub const CONFIDENCE_LEVELS: [f64; 3] = [0.5, 0.8, 0.95];
+
 /// A type representing fee/mass of a transaction in `sompi/gram` units.
 /// Given a feerate value recommendation, calculate the required fee by
 /// taking the transaction mass and multiplying it by feerate: `fee = feerate * mass(tx)`
@@ -154,6 +169,52 @@ impl FeerateEstimator {
             low_buckets: vec![FeerateBucket { feerate: low, estimated_seconds: self.feerate_to_time(low) }],
         }
     }
+
+    /// Calculates the estimations of the current mempool state, blended with the feerates historically observed
+    /// for the same inclusion times. This dampens the estimations during short transaction bursts.
+    ///
+    /// Buckets for which the history holds too few observations are left as is.
+    pub fn calc_blended_estimations(&self, minimum_standard_feerate: f64, history: &FeerateHistory) -> FeerateEstimations {
+        let estimations = self.calc_estimations(minimum_standard_feerate);
+        let mut upper = f64::INFINITY;
+        let mut blend = |bucket: FeerateBucket| {
+            let feerate = match history.feerate_for(bucket.estimated_seconds.ceil(), BLEND_CONFIDENCE) {
+                Some(historical) => bucket.feerate.powf(1.0 - HISTORY_WEIGHT) * historical.powf(HISTORY_WEIGHT),
+                None => bucket.feerate,
+            };
+            // Keep the buckets ordered by decreasing feerate
+            let feerate = feerate.min(upper).max(minimum_standard_feerate);
+            upper = feerate;
+            FeerateBucket { feerate, estimated_seconds: self.feerate_to_time(feerate) }
+        };
+        FeerateEstimations {
+            priority_bucket: blend(estimations.priority_bucket),
+            normal_buckets: estimations.normal_buckets.into_iter().map(&mut blend).collect(),
+            low_buckets: estimations.low_buckets.into_iter().map(&mut blend).collect(),
+        }
+    }
+}
+
+/// The lowest feerate at which at least a `confidence` fraction of the historically observed transactions
+/// were accepted within `target_seconds`
+#[derive(Clone, Copy, Debug)]
+pub struct HistoricalFeerateEstimate {
+    pub target_seconds: f64,
+    pub confidence: f64,
+    pub feerate: f64,
+}
+
+impl HistoricalFeerateEstimate {
+    /// Returns the estimations for all [`HISTORICAL_TARGETS`] and [`CONFIDENCE_LEVELS`] the history holds enough observations for
+    pub fn collect(history: &FeerateHistory) -> Vec<Self> {
+        HISTORICAL_TARGETS
+            .into_iter()
+            .cartesian_product(CONFIDENCE_LEVELS)
+            .filter_map(|(target_seconds, confidence)| {
+                history.feerate_for(target_seconds, confidence).map(|feerate| Self { target_seconds, confidence, feerate })
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -167,6 +228,10 @@ pub struct FeeEstimateVerbose {
     pub next_block_template_feerate_min: f64,
     pub next_block_template_feerate_median: f64,
     pub next_block_template_feerate_max: f64,
+
+    /// Decayed count of the accepted transactions the history is built from
+    pub historical_sample_count: f64,
+    pub historical_estimates: Vec<HistoricalFeerateEstimate>,
 }
 
 #[cfg(test)]
@@ -199,6 +264,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_blended_estimations() {
+        let estimator = FeerateEstimator { total_weight: 1002283.659, inclusion_interval: 0.004f64 };
+        let minimum_feerate = 1.0;
+        let mut history = FeerateHistory::new(minimum_feerate, 10);
+        let estimations = estimator.calc_estimations(minimum_feerate);
+
+        // Without history, the blend is the mempool state estimation
+        let blended = estimator.calc_blended_estimations(minimum_feerate, &history);
+        for (i, j) in estimations.ordered_buckets().into_iter().zip(blended.ordered_buckets()) {
+            assert_eq!(i.feerate, j.feerate);
+        }
+
+        // The history shows all transactions, including those paying the minimum feerate, getting accepted within a second
+        for _ in 0..100 {
+            history.record(minimum_feerate, 1000, 1000);
+        }
+        let blended = estimator.calc_blended_estimations(minimum_feerate, &history);
+        for (i, j) in estimations.ordered_buckets().into_iter().zip(blended.ordered_buckets()) {
+            assert!(j.feerate >= minimum_feerate);
+            assert!(j.feerate <= i.feerate, "{i} {j}");
+        }
+        assert!(blended.priority_bucket.feerate < estimations.priority_bucket.feerate);
+        for (i, j) in blended.ordered_buckets().into_iter().tuple_windows() {
+            assert!(i.feerate >= j.feerate);
+            assert!(i.estimated_seconds <= j.estimated_seconds);
+        }
+
+        let historical = HistoricalFeerateEstimate::collect(&history);
+        assert_eq!(historical.len(), HISTORICAL_TARGETS.len() * CONFIDENCE_LEVELS.len());
+        assert!(historical.iter().all(|estimate| estimate.feerate == minimum_feerate));
+    }
+
     #[test]
     fn test_zero_values() {
         let estimator = FeerateEstimator { total_weight: 0.0, inclusion_interval: 0.0 };
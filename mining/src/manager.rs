@@ -1,11 +1,13 @@
 use crate::{
-    block_template::{builder::BlockTemplateBuilder, errors::BuilderError},
+    block_template::{builder::BlockTemplateBuilder, errors::BuilderError, selector::FixedTransactionSelector},
     cache::BlockTemplateCache,
     errors::MiningManagerResult,
-    feerate::{FeeEstimateVerbose, FeerateEstimations, FeerateEstimatorArgs},
+    feerate::{FeeEstimateVerbose, FeerateEstimations, FeerateEstimatorArgs, HistoricalFeerateEstimate},
     mempool::{
         config::Config,
-        model::tx::{MempoolTransaction, TransactionPostValidation, TransactionPreValidation, TxRemovalReason},
+        model::tx::{
+            MempoolTransaction, TransactionDryRunValidation, TransactionPostValidation, TransactionPreValidation, TxRemovalReason,
+        },
         populate_entries_and_try_validate::{
             populate_mempool_transactions_in_parallel, validate_mempool_transaction, validate_mempool_transactions_in_parallel,
         },
@@ -15,9 +17,12 @@ use crate::{
     model::{
         owner_txs::{GroupedOwnerTransactions, ScriptPublicKeySet},
         topological_sort::IntoIterTopologically,
+        tx_dry_run::TransactionDryRun,
         tx_insert::TransactionInsertion,
+        tx_priority::{TransactionPriorityDelta, TransactionPriorityDeltas},
         tx_query::TransactionQuery,
     },
+    stores::tx_priority_store::DbTransactionPriorityDeltasStore,
     MempoolCountersSnapshot, MiningCounters, P2pTxCountSample,
 };
 use itertools::Itertools;
@@ -29,13 +34,18 @@ use spectre_consensus_core::{
     },
     block::{BlockTemplate, TemplateBuildMode, TemplateTransactionSelector},
     coinbase::MinerData,
+    constants::UNACCEPTED_DAA_SCORE,
     errors::{block::RuleError as BlockRuleError, tx::TxRuleError},
-    tx::{MutableTransaction, Transaction, TransactionId, TransactionOutput},
+    tx::{MutableTransaction, Transaction, TransactionId, TransactionOutpoint, TransactionOutput, UtxoEntry},
 };
 use spectre_consensusmanager::{spawn_blocking, ConsensusProxy};
 use spectre_core::{debug, error, info, time::Stopwatch, warn};
-use spectre_mining_errors::{manager::MiningManagerError, mempool::RuleError};
-use std::sync::Arc;
+use spectre_database::prelude::DB;
+use spectre_mining_errors::{
+    manager::MiningManagerError,
+    mempool::{RuleError, RuleResult},
+};
+use std::{collections::HashMap, sync::Arc};
 use tokio::sync::mpsc::UnboundedSender;
 
 pub struct MiningManager {
@@ -54,25 +64,35 @@ impl MiningManager {
         counters: Arc<MiningCounters>,
     ) -> Self {
         let config = Config::build_default(target_time_per_block, relay_non_std_transactions, max_block_mass);
-        Self::with_config(config, cache_lifetime, counters)
+        Self::with_config(config, cache_lifetime, None, counters)
     }
 
+    /// Builds a mining manager persisting the transaction priority deltas in `meta_db`, if provided,
+    /// and loading the deltas persisted by a previous run
     pub fn new_with_extended_config(
         target_time_per_block: u64,
         relay_non_std_transactions: bool,
         max_block_mass: u64,
         ram_scale: f64,
         cache_lifetime: Option<u64>,
+        meta_db: Option<Arc<DB>>,
         counters: Arc<MiningCounters>,
     ) -> Self {
         let config =
             Config::build_default(target_time_per_block, relay_non_std_transactions, max_block_mass).apply_ram_scale(ram_scale);
-        Self::with_config(config, cache_lifetime, counters)
+        Self::with_config(config, cache_lifetime, meta_db, counters)
     }
 
-    pub(crate) fn with_config(config: Config, cache_lifetime: Option<u64>, counters: Arc<MiningCounters>) -> Self {
+    pub(crate) fn with_config(
+        config: Config,
+        cache_lifetime: Option<u64>,
+        meta_db: Option<Arc<DB>>,
+        counters: Arc<MiningCounters>,
+    ) -> Self {
         let config = Arc::new(config);
-        let mempool = RwLock::new(Mempool::new(config.clone(), counters.clone()));
+        let priority_deltas =
+            meta_db.map(|db| TransactionPriorityDeltas::new(DbTransactionPriorityDeltasStore::new(db))).unwrap_or_default();
+        let mempool = RwLock::new(Mempool::new(config.clone(), priority_deltas, counters.clone()));
         let block_template_cache = BlockTemplateCache::new(cache_lifetime);
         Self { config, block_template_cache, mempool, counters }
     }
@@ -197,11 +217,51 @@ impl MiningManager {
         }
     }
 
+    /// Builds a block template holding exactly the mempool transactions `transaction_ids`, in this order, bypassing the
+    /// regular transaction selection and the template cache. Fails if any transaction is missing or invalid.
+    pub fn get_block_template_with_transactions(
+        &self,
+        consensus: &dyn ConsensusApi,
+        miner_data: &MinerData,
+        transaction_ids: &[TransactionId],
+    ) -> MiningManagerResult<BlockTemplate> {
+        let transactions = {
+            let mempool_read = self.mempool.read();
+            transaction_ids
+                .iter()
+                .map(|id| {
+                    mempool_read
+                        .get_transaction(id, TransactionQuery::TransactionsOnly)
+                        .map(|tx| tx.tx.as_ref().clone())
+                        .ok_or(RuleError::RejectMissingTransaction(*id))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let selector = Box::new(FixedTransactionSelector::new(transactions));
+        Ok(BlockTemplateBuilder::new().build_block_template(consensus, miner_data, selector, TemplateBuildMode::Standard)?)
+    }
+
     /// Dynamically builds a transaction selector based on the specific state of the ready transactions frontier
     pub(crate) fn build_selector(&self) -> Box<dyn TemplateTransactionSelector> {
         self.mempool.read().build_selector()
     }
 
+    /// Sets the priority delta of a transaction, applied when selecting the transactions of subsequent
+    /// block templates. Setting a neutral delta clears any previously set delta.
+    ///
+    /// The transaction does not need to be in the mempool yet. The delta is dropped once the
+    /// transaction gets accepted in a block. Deltas are persisted if the manager was built with a meta DB.
+    pub fn set_transaction_priority_delta(&self, transaction_id: TransactionId, delta: TransactionPriorityDelta) {
+        self.mempool.write().set_transaction_priority_delta(transaction_id, delta);
+        // The cached template was selected without the new delta, so we force the next request to build a new one
+        self.block_template_cache.clear();
+    }
+
+    /// Returns all registered transaction priority deltas
+    pub fn get_transaction_priority_deltas(&self) -> Vec<(TransactionId, TransactionPriorityDelta)> {
+        self.mempool.read().transaction_priority_deltas()
+    }
+
     /// Returns realtime feerate estimations based on internal mempool state
     pub(crate) fn get_realtime_feerate_estimations(&self) -> FeerateEstimations {
         let args = FeerateEstimatorArgs::new(self.config.network_blocks_per_second, self.config.maximum_mass_per_block);
@@ -209,7 +269,16 @@ impl MiningManager {
         estimator.calc_estimations(self.config.minimum_feerate())
     }
 
-    /// Returns realtime feerate estimations based on internal mempool state with additional verbose data
+    /// Returns realtime feerate estimations based on internal mempool state, blended with the historically observed
+    /// transaction inclusion times. Experimental, only served by the experimental fee estimate RPC
+    pub(crate) fn get_blended_feerate_estimations(&self) -> FeerateEstimations {
+        let args = FeerateEstimatorArgs::new(self.config.network_blocks_per_second, self.config.maximum_mass_per_block);
+        let mempool_read = self.mempool.read();
+        let estimator = mempool_read.build_feerate_estimator(args);
+        estimator.calc_blended_estimations(self.config.minimum_feerate(), mempool_read.feerate_history())
+    }
+
+    /// Returns blended feerate estimations based on internal mempool state and history with additional verbose data
     pub(crate) fn get_realtime_feerate_estimations_verbose(
         &self,
         consensus: &dyn ConsensusApi,
@@ -221,9 +290,12 @@ impl MiningManager {
         let estimator = mempool_read.build_feerate_estimator(args);
         let ready_transactions_count = mempool_read.ready_transaction_count();
         let ready_transaction_total_mass = mempool_read.ready_transaction_total_mass();
+        let estimations = estimator.calc_blended_estimations(self.config.minimum_feerate(), mempool_read.feerate_history());
+        let historical_sample_count = mempool_read.feerate_history().sample_count();
+        let historical_estimates = HistoricalFeerateEstimate::collect(mempool_read.feerate_history());
         drop(mempool_read);
         let mut resp = FeeEstimateVerbose {
-            estimations: estimator.calc_estimations(self.config.minimum_feerate()),
+            estimations,
             network_mass_per_second,
             mempool_ready_transactions_count: ready_transactions_count as u64,
             mempool_ready_transactions_total_mass: ready_transaction_total_mass,
@@ -231,6 +303,9 @@ impl MiningManager {
             next_block_template_feerate_min: -1.0,
             next_block_template_feerate_median: -1.0,
             next_block_template_feerate_max: -1.0,
+
+            historical_sample_count,
+            historical_estimates,
         };
         // calculate next_block_template_feerate_xxx
         {
@@ -508,6 +583,101 @@ impl MiningManager {
         insert_results
     }
 
+    /// Populates the UTXO entries of a transaction with the outputs of its mempool parents and with the
+    /// entries of the virtual UTXO set, leaving the entries which could not be found empty.
+    pub fn populate_transaction_entries(&self, consensus: &dyn ConsensusApi, transaction: &mut MutableTransaction) {
+        self.mempool.read().populate_mempool_entries(transaction);
+        // A missing outpoint is not an error here, the caller inspects which entries are still empty
+        let _ = consensus.populate_mempool_transaction(transaction);
+    }
+
+    /// Validates a batch of transactions as if they were submitted one after the other through
+    /// [`Self::validate_and_insert_transaction`], without inserting any of them in the mempool.
+    ///
+    /// A transaction of the batch may spend outputs of preceding transactions of the batch that
+    /// would be accepted, exactly as if those were already in the mempool.
+    ///
+    /// Returns a report for every transaction of the batch, in the same order.
+    pub fn dry_run_transaction_batch(
+        &self,
+        consensus: &dyn ConsensusApi,
+        transactions: Vec<Transaction>,
+        orphan: Orphan,
+        rbf_policy: RbfPolicy,
+    ) -> Vec<TransactionDryRun> {
+        let mut accepted: HashMap<TransactionId, Arc<Transaction>> = HashMap::with_capacity(transactions.len());
+        let mut spent: HashMap<TransactionOutpoint, TransactionId> = HashMap::new();
+        transactions
+            .into_iter()
+            .map(|transaction| {
+                let mut dry_run = TransactionDryRun::new(transaction.id(), consensus.calculate_transaction_compute_mass(&transaction));
+                let mut transaction = MutableTransaction::from_tx(transaction);
+                match self.dry_run_transaction(consensus, &mut transaction, &accepted, &spent, orphan, rbf_policy) {
+                    Ok(TransactionDryRunValidation { replaced, is_orphan }) => {
+                        dry_run.is_orphan = is_orphan;
+                        dry_run.replaced_transaction_id = replaced;
+                        if !is_orphan {
+                            spent.extend(transaction.tx.inputs.iter().map(|input| (input.previous_outpoint, transaction.id())));
+                            accepted.insert(transaction.id(), transaction.tx.clone());
+                        }
+                    }
+                    Err(err) => dry_run.result = Err(err),
+                }
+                // The storage mass is only well-defined for transactions passing the consensus validation
+                if transaction.calculated_fee.is_some() {
+                    dry_run.storage_mass = consensus.calculate_transaction_storage_mass(&transaction);
+                }
+                dry_run.fee = transaction.calculated_fee;
+                dry_run.feerate = transaction.calculated_feerate();
+                dry_run
+            })
+            .collect()
+    }
+
+    /// Validates `transaction` in the context of the mempool extended by the `accepted` transactions
+    /// of the current dry-run batch, which are spending the `spent` outpoints.
+    fn dry_run_transaction(
+        &self,
+        consensus: &dyn ConsensusApi,
+        transaction: &mut MutableTransaction,
+        accepted: &HashMap<TransactionId, Arc<Transaction>>,
+        spent: &HashMap<TransactionOutpoint, TransactionId>,
+        orphan: Orphan,
+        rbf_policy: RbfPolicy,
+    ) -> RuleResult<TransactionDryRunValidation> {
+        let transaction_id = transaction.id();
+        if accepted.contains_key(&transaction_id) {
+            return Err(RuleError::RejectDuplicate(transaction_id));
+        }
+
+        // read lock on mempool
+        let TransactionPreValidation { transaction: populated, feerate_threshold } =
+            self.mempool.read().pre_validate_and_populate_transaction(consensus, transaction.clone(), rbf_policy)?;
+        *transaction = populated;
+
+        // Treat the transactions accepted earlier in the batch as if they were in the mempool
+        for (i, input) in transaction.tx.inputs.iter().enumerate() {
+            if let Some(owner_id) = spent.get(&input.previous_outpoint) {
+                return Err(RuleError::RejectDoubleSpendInMempool(input.previous_outpoint, *owner_id));
+            }
+            if transaction.entries[i].is_none() {
+                if let Some(output) = accepted
+                    .get(&input.previous_outpoint.transaction_id)
+                    .and_then(|parent| parent.outputs.get(input.previous_outpoint.index as usize))
+                {
+                    transaction.entries[i] =
+                        Some(UtxoEntry::new(output.value, output.script_public_key.clone(), UNACCEPTED_DAA_SCORE, false));
+                }
+            }
+        }
+
+        let args = TransactionValidationArgs::new(feerate_threshold);
+        // no lock on mempool
+        let validation_result = validate_mempool_transaction(consensus, transaction, &args);
+        // read lock on mempool
+        self.mempool.read().post_validate_transaction_dry_run(validation_result, transaction, orphan, rbf_policy)
+    }
+
     fn next_transaction_chunk_upper_bound(&self, transactions: &[MutableTransaction], lower_bound: usize) -> Option<usize> {
         if lower_bound >= transactions.len() {
             return None;
@@ -611,9 +781,11 @@ impl MiningManager {
 
         // mempool
         let expired_low_priority_transactions = self.mempool.write().collect_expired_low_priority_transactions(consensus);
+        let virtual_daa_score = consensus.get_virtual_daa_score();
         for chunk in &expired_low_priority_transactions.iter().chunks(24) {
             let mut mempool = self.mempool.write();
             chunk.into_iter().for_each(|tx| {
+                mempool.record_unaccepted_transaction(tx, virtual_daa_score);
                 if let Err(err) = mempool.remove_transaction(tx, true, TxRemovalReason::Muted, "") {
                     warn!("Failed to remove transaction {} from mempool: {}", tx, err);
                 }
@@ -853,12 +1025,42 @@ impl MiningManagerProxy {
         consensus.clone().spawn_blocking(move |c| self.inner.get_block_template(c, &miner_data)).await
     }
 
+    /// Builds a block template holding exactly the given mempool transactions, in this order
+    pub async fn get_block_template_with_transactions(
+        self,
+        consensus: &ConsensusProxy,
+        miner_data: MinerData,
+        transaction_ids: Vec<TransactionId>,
+    ) -> MiningManagerResult<BlockTemplate> {
+        consensus
+            .clone()
+            .spawn_blocking(move |c| self.inner.get_block_template_with_transactions(c, &miner_data, &transaction_ids))
+            .await
+    }
+
+    /// Sets the priority delta of a transaction, applied when selecting the transactions of subsequent
+    /// block templates. Setting a neutral delta clears any previously set delta.
+    pub async fn set_transaction_priority_delta(self, transaction_id: TransactionId, delta: TransactionPriorityDelta) {
+        spawn_blocking(move || self.inner.set_transaction_priority_delta(transaction_id, delta)).await.unwrap()
+    }
+
+    /// Returns all registered transaction priority deltas
+    pub async fn get_transaction_priority_deltas(self) -> Vec<(TransactionId, TransactionPriorityDelta)> {
+        spawn_blocking(move || self.inner.get_transaction_priority_deltas()).await.unwrap()
+    }
+
     /// Returns realtime feerate estimations based on internal mempool state
     pub async fn get_realtime_feerate_estimations(self) -> FeerateEstimations {
         spawn_blocking(move || self.inner.get_realtime_feerate_estimations()).await.unwrap()
     }
 
-    /// Returns realtime feerate estimations based on internal mempool state with additional verbose data
+    /// Returns realtime feerate estimations based on internal mempool state, blended with the historically observed
+    /// transaction inclusion times
+    pub async fn get_blended_feerate_estimations(self) -> FeerateEstimations {
+        spawn_blocking(move || self.inner.get_blended_feerate_estimations()).await.unwrap()
+    }
+
+    /// Returns blended feerate estimations based on internal mempool state and history with additional verbose data
     pub async fn get_realtime_feerate_estimations_verbose(
         self,
         consensus: &ConsensusProxy,
@@ -910,6 +1112,35 @@ impl MiningManagerProxy {
             .await
     }
 
+    /// Validates a batch of transactions as if they were submitted one after the other, without
+    /// inserting any of them in the mempool.
+    ///
+    /// See [`MiningManager::dry_run_transaction_batch`]
+    pub async fn dry_run_transaction_batch(
+        self,
+        consensus: &ConsensusProxy,
+        transactions: Vec<Transaction>,
+        orphan: Orphan,
+        rbf_policy: RbfPolicy,
+    ) -> Vec<TransactionDryRun> {
+        consensus.clone().spawn_blocking(move |c| self.inner.dry_run_transaction_batch(c, transactions, orphan, rbf_policy)).await
+    }
+
+    /// See [`MiningManager::populate_transaction_entries`]
+    pub async fn populate_transaction_entries(
+        self,
+        consensus: &ConsensusProxy,
+        mut transaction: MutableTransaction,
+    ) -> MutableTransaction {
+        consensus
+            .clone()
+            .spawn_blocking(move |c| {
+                self.inner.populate_transaction_entries(c, &mut transaction);
+                transaction
+            })
+            .await
+    }
+
     pub async fn handle_new_block_transactions(
         self,
         consensus: &ConsensusProxy,
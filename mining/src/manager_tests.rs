@@ -10,7 +10,7 @@ mod tests {
             model::frontier::selectors::TakeAllSelector,
             tx::{Orphan, Priority, RbfPolicy},
         },
-        model::{tx_insert::TransactionInsertion, tx_query::TransactionQuery},
+        model::{tx_insert::TransactionInsertion, tx_priority::TransactionPriorityDelta, tx_query::TransactionQuery},
         testutils::consensus_mock::ConsensusMock,
         MiningCounters,
     };
@@ -29,6 +29,7 @@ mod tests {
             TransactionOutput, UtxoEntry,
         },
     };
+    use spectre_database::{create_temp_db, prelude::ConnBuilder};
     use spectre_hashes::Hash;
     use spectre_mining_errors::mempool::RuleResult;
     use spectre_txscript::{
@@ -603,6 +604,135 @@ mod tests {
         }
     }
 
+    /// test_transaction_priority_deltas verifies that excluded transactions are never selected into a block template
+    /// and that priority deltas are dropped once their transaction is accepted in a block.
+    #[test]
+    fn test_transaction_priority_deltas() {
+        let consensus = Arc::new(ConsensusMock::new());
+        let counters = Arc::new(MiningCounters::default());
+        let mining_manager = MiningManager::new(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS, None, counters);
+
+        // Parent transactions are funded by the consensus and hence are ready for selection
+        const TX_COUNT: usize = 10;
+        let (parent_txs, _) = create_arrays_of_parent_and_children_transactions(&consensus, TX_COUNT);
+        for transaction in parent_txs.iter() {
+            let result = mining_manager.validate_and_insert_transaction(
+                consensus.as_ref(),
+                transaction.clone(),
+                Priority::Low,
+                Orphan::Allowed,
+                RbfPolicy::Forbidden,
+            );
+            assert!(result.is_ok(), "the insertion of a new valid transaction in the mempool failed");
+        }
+
+        let excluded_id = parent_txs[0].id();
+        let prioritized_id = parent_txs[1].id();
+        mining_manager.set_transaction_priority_delta(excluded_id, TransactionPriorityDelta::new(0, true));
+        mining_manager.set_transaction_priority_delta(prioritized_id, TransactionPriorityDelta::new(1_000_000, false));
+        // A neutral delta registers nothing
+        mining_manager.set_transaction_priority_delta(parent_txs[2].id(), TransactionPriorityDelta::default());
+        assert_eq!(2, mining_manager.get_transaction_priority_deltas().len());
+
+        let transactions = mining_manager.build_selector().select_transactions();
+        assert_eq!(TX_COUNT - 1, transactions.len(), "all transactions but the excluded one should be selected");
+        assert!(!contained_by(excluded_id, &transactions), "the excluded transaction {excluded_id} should not be selected");
+        assert!(contained_by(prioritized_id, &transactions), "the prioritized transaction {prioritized_id} should be selected");
+
+        // Clearing the exclusion makes the transaction selectable again
+        mining_manager.set_transaction_priority_delta(excluded_id, TransactionPriorityDelta::default());
+        let transactions = mining_manager.build_selector().select_transactions();
+        assert_eq!(TX_COUNT, transactions.len(), "all transactions should be selected");
+
+        let block_transactions = build_block_transactions(once(&parent_txs[1]));
+        let result = mining_manager.handle_new_block_transactions(consensus.as_ref(), 2, &block_transactions);
+        assert!(result.is_ok(), "the handling of the block transactions should succeed but returned {result:?}");
+        assert!(
+            mining_manager.get_transaction_priority_deltas().is_empty(),
+            "the priority delta of an accepted transaction should be dropped"
+        );
+    }
+
+    /// test_transaction_priority_deltas_persistence verifies that priority deltas stored in the meta DB
+    /// are loaded by a new mining manager and that the dropped deltas are removed from the store.
+    #[test]
+    fn test_transaction_priority_deltas_persistence() {
+        let consensus = Arc::new(ConsensusMock::new());
+        let (_lifetime, meta_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let build_mining_manager = || {
+            let counters = Arc::new(MiningCounters::default());
+            MiningManager::new_with_extended_config(
+                TARGET_TIME_PER_BLOCK,
+                false,
+                MAX_BLOCK_MASS,
+                1.0,
+                None,
+                Some(meta_db.clone()),
+                counters,
+            )
+        };
+
+        let (parent_txs, _) = create_arrays_of_parent_and_children_transactions(&consensus, 2);
+        let mining_manager = build_mining_manager();
+        mining_manager.set_transaction_priority_delta(parent_txs[0].id(), TransactionPriorityDelta::new(0, true));
+        mining_manager.set_transaction_priority_delta(parent_txs[1].id(), TransactionPriorityDelta::new(-1_000, false));
+        drop(mining_manager);
+
+        let mining_manager = build_mining_manager();
+        let mut deltas = mining_manager.get_transaction_priority_deltas();
+        deltas.sort_by_key(|(_, delta)| delta.excluded);
+        assert_eq!(
+            deltas,
+            vec![
+                (parent_txs[1].id(), TransactionPriorityDelta::new(-1_000, false)),
+                (parent_txs[0].id(), TransactionPriorityDelta::new(0, true))
+            ],
+            "the priority deltas should be loaded from the meta DB"
+        );
+
+        // Accepting a transaction drops its delta from the store as well
+        let block_transactions = build_block_transactions(once(&parent_txs[0]));
+        let result = mining_manager.handle_new_block_transactions(consensus.as_ref(), 2, &block_transactions);
+        assert!(result.is_ok(), "the handling of the block transactions should succeed but returned {result:?}");
+        mining_manager.set_transaction_priority_delta(parent_txs[1].id(), TransactionPriorityDelta::default());
+        drop(mining_manager);
+        assert!(build_mining_manager().get_transaction_priority_deltas().is_empty(), "the meta DB should hold no priority delta");
+    }
+
+    /// test_block_template_with_transactions verifies that a block template can be built with a fixed list of mempool
+    /// transactions, and that missing transactions are reported.
+    #[test]
+    fn test_block_template_with_transactions() {
+        let consensus = Arc::new(ConsensusMock::new());
+        let counters = Arc::new(MiningCounters::default());
+        let mining_manager = MiningManager::new(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS, None, counters);
+
+        const TX_COUNT: usize = 5;
+        let (parent_txs, _) = create_arrays_of_parent_and_children_transactions(&consensus, TX_COUNT);
+        for transaction in parent_txs.iter() {
+            let result = mining_manager.validate_and_insert_transaction(
+                consensus.as_ref(),
+                transaction.clone(),
+                Priority::Low,
+                Orphan::Allowed,
+                RbfPolicy::Forbidden,
+            );
+            assert!(result.is_ok(), "the insertion of a new valid transaction in the mempool failed");
+        }
+
+        let miner_data = get_miner_data(Prefix::Testnet);
+        let transaction_ids = vec![parent_txs[3].id(), parent_txs[1].id()];
+        let template = mining_manager.get_block_template_with_transactions(consensus.as_ref(), &miner_data, &transaction_ids).unwrap();
+        assert_eq!(transaction_ids, template.block.transactions.iter().skip(1).map(|tx| tx.id()).collect::<Vec<_>>());
+
+        let missing_id = TransactionId::from_bytes([1; 32]);
+        let result = mining_manager.get_block_template_with_transactions(consensus.as_ref(), &miner_data, &[missing_id]);
+        assert!(
+            matches!(result, Err(MiningManagerError::MempoolError(RuleError::RejectMissingTransaction(id))) if id == missing_id),
+            "a missing transaction should be reported but got {result:?}"
+        );
+    }
+
     #[test]
     /// test_double_spend_with_block verifies that any transactions which are now double spends as a result of the block's new transactions
     /// will be removed from the mempool.
@@ -905,7 +1035,7 @@ mod tests {
         // Limit the orphan pool to 2 transactions
         config.maximum_orphan_transaction_count = 2;
         let counters = Arc::new(MiningCounters::default());
-        let mining_manager = MiningManager::with_config(config.clone(), None, counters);
+        let mining_manager = MiningManager::with_config(config.clone(), None, None, counters);
 
         // Create pairs of transaction parent-and-child pairs according to the test vector
         let (parent_txs, child_txs) = create_arrays_of_parent_and_children_transactions(&consensus, tests.len());
@@ -1117,6 +1247,88 @@ mod tests {
         // TODO: extend the test according to the golang scenario
     }
 
+    // test_dry_run_transaction_batch verifies that a batch of dependent transactions is reported
+    // as it would be accepted by the mempool while leaving the mempool untouched.
+    #[test]
+    fn test_dry_run_transaction_batch() {
+        let consensus = Arc::new(ConsensusMock::new());
+        let counters = Arc::new(MiningCounters::default());
+        let mining_manager = MiningManager::new(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS, None, counters);
+
+        let funding_tx = create_transaction_without_input(vec![500 * SOMPI_PER_SPECTRE, 3_000 * SOMPI_PER_SPECTRE]);
+        consensus.add_transaction(funding_tx.clone(), 1);
+        let parent_tx = create_transaction(&funding_tx, DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE);
+        let child_tx = create_transaction(&parent_tx, DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE);
+        let double_spending_tx = create_transaction(&funding_tx, DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE * 2);
+        let (_, orphan_tx) = create_parent_and_children_transactions(&consensus, vec![600 * SOMPI_PER_SPECTRE]);
+
+        let batch = vec![parent_tx.clone(), child_tx.clone(), child_tx.clone(), double_spending_tx.clone(), orphan_tx.clone()];
+        let dry_runs =
+            mining_manager.dry_run_transaction_batch(consensus.as_ref(), batch.clone(), Orphan::Allowed, RbfPolicy::Forbidden);
+        assert_eq!(batch.len(), dry_runs.len(), "every transaction of the batch should be reported");
+        for (transaction, dry_run) in batch.iter().zip(dry_runs.iter()) {
+            assert_eq!(transaction.id(), dry_run.transaction_id);
+            assert_eq!(consensus.calculate_transaction_compute_mass(transaction), dry_run.compute_mass);
+        }
+
+        // The parent is funded by consensus and the child by the parent which precedes it in the batch
+        for dry_run in dry_runs[0..2].iter() {
+            assert_eq!(Ok(()), dry_run.result, "transaction {} should be accepted", dry_run.transaction_id);
+            assert!(!dry_run.is_orphan);
+            assert_eq!(Some(DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE), dry_run.fee);
+            assert!(dry_run.storage_mass.is_some());
+            assert!(dry_run.feerate.is_some());
+            assert!(dry_run.replaced_transaction_id.is_none());
+        }
+        assert_eq!(Err(RuleError::RejectDuplicate(child_tx.id())), dry_runs[2].result);
+        assert_eq!(
+            Err(RuleError::RejectDoubleSpendInMempool(double_spending_tx.inputs[0].previous_outpoint, parent_tx.id())),
+            dry_runs[3].result
+        );
+        assert_eq!(Ok(()), dry_runs[4].result);
+        assert!(dry_runs[4].is_orphan);
+        assert!(dry_runs[4].fee.is_none());
+        assert!(dry_runs[4].storage_mass.is_none());
+        assert_transaction_count(&mining_manager, 0, "a dry run should not insert any transaction");
+
+        // Orphans are reported as rejected when they are forbidden
+        let dry_runs = mining_manager.dry_run_transaction_batch(
+            consensus.as_ref(),
+            vec![orphan_tx.clone()],
+            Orphan::Forbidden,
+            RbfPolicy::Forbidden,
+        );
+        assert_eq!(Err(RuleError::RejectDisallowedOrphan(orphan_tx.id())), dry_runs[0].result);
+
+        // Once the parent is in the mempool, the double spend is only accepted as a replacement
+        validate_and_insert_transactions(
+            &mining_manager,
+            consensus.as_ref(),
+            once(&parent_tx),
+            Priority::High,
+            Orphan::Forbidden,
+            RbfPolicy::Forbidden,
+        );
+        let dry_runs = mining_manager.dry_run_transaction_batch(
+            consensus.as_ref(),
+            vec![double_spending_tx.clone()],
+            Orphan::Forbidden,
+            RbfPolicy::Forbidden,
+        );
+        assert!(matches!(dry_runs[0].result, Err(RuleError::RejectDoubleSpendInMempool(_, id)) if id == parent_tx.id()));
+        let dry_runs = mining_manager.dry_run_transaction_batch(
+            consensus.as_ref(),
+            vec![double_spending_tx.clone(), child_tx.clone()],
+            Orphan::Forbidden,
+            RbfPolicy::Mandatory,
+        );
+        assert_eq!(Ok(()), dry_runs[0].result);
+        assert_eq!(Some(parent_tx.id()), dry_runs[0].replaced_transaction_id);
+        assert_eq!(Err(RuleError::RejectRbfNoDoubleSpend), dry_runs[1].result);
+        assert!(mining_manager.has_transaction(&parent_tx.id(), TransactionQuery::TransactionsOnly));
+        assert_transaction_count(&mining_manager, 1, "a dry run should not replace any transaction");
+    }
+
     // This is a sanity test for the mempool eviction policy. We check that if the mempool reached to its maximum
     // (in bytes) a high paying transaction will evict as much transactions as needed so it can enter the
     // mempool.
@@ -1133,7 +1345,7 @@ mod tests {
         let tx_size = txs[0].mempool_estimated_bytes();
         let size_limit = TX_COUNT * tx_size;
         config.mempool_size_limit = size_limit;
-        let mining_manager = MiningManager::with_config(config, None, counters);
+        let mining_manager = MiningManager::with_config(config, None, None, counters);
 
         for tx in txs {
             validate_and_insert_mutable_transaction(&mining_manager, consensus.as_ref(), tx).unwrap();
@@ -1174,6 +1386,48 @@ mod tests {
         assert!(validate_and_insert_mutable_transaction(&mining_manager, consensus.as_ref(), too_big_tx.clone()).is_err());
     }
 
+    /// test_evict_with_priority_deltas verifies that the eviction policy relies on the actual fee rates of the
+    /// transactions, regardless of their priority deltas, and that transactions excluded from selection are evictable.
+    #[test]
+    fn test_evict_with_priority_deltas() {
+        const TX_COUNT: usize = 10;
+        let mut txs = (0..TX_COUNT).map(|i| create_transaction_with_utxo_entry(i as u32, 0)).collect_vec();
+        // The first transaction pays a high fee but is deprioritized down to a zero modified fee
+        txs[0].calculated_fee = Some(500_000);
+
+        let consensus = Arc::new(ConsensusMock::new());
+        let counters = Arc::new(MiningCounters::default());
+        let mut config = Config::build_default(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS);
+        config.mempool_size_limit = TX_COUNT * txs[0].mempool_estimated_bytes();
+        let mining_manager = MiningManager::with_config(config, None, None, counters);
+
+        let (high_fee_id, excluded_ids) = (txs[0].id(), txs[1..].iter().map(|tx| tx.id()).collect_vec());
+        for tx in txs {
+            validate_and_insert_mutable_transaction(&mining_manager, consensus.as_ref(), tx).unwrap();
+        }
+        mining_manager.set_transaction_priority_delta(high_fee_id, TransactionPriorityDelta::new(-500_000, false));
+        for id in excluded_ids.iter().copied() {
+            mining_manager.set_transaction_priority_delta(id, TransactionPriorityDelta::new(0, true));
+        }
+
+        let tx = {
+            let mut tx = create_transaction_with_utxo_entry(TX_COUNT as u32, 0);
+            tx.calculated_fee = Some(2 * DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE);
+            tx
+        };
+        validate_and_insert_mutable_transaction(&mining_manager, consensus.as_ref(), tx).unwrap();
+        assert_eq!(mining_manager.get_all_transactions(TransactionQuery::TransactionsOnly).0.len(), TX_COUNT);
+        assert!(
+            mining_manager.get_transaction(&high_fee_id, TransactionQuery::TransactionsOnly).is_some(),
+            "the deprioritized transaction paying the highest fee should not be evicted"
+        );
+        assert_eq!(
+            excluded_ids.iter().filter(|id| mining_manager.get_transaction(id, TransactionQuery::TransactionsOnly).is_none()).count(),
+            1,
+            "exactly one transaction excluded from selection should be evicted"
+        );
+    }
+
     fn validate_and_insert_mutable_transaction(
         mining_manager: &MiningManager,
         consensus: &dyn ConsensusApi,
@@ -187,6 +187,8 @@ impl Mempool {
                 }
                 ScriptClass::PubKey => {}
                 ScriptClass::PubKeyECDSA => {}
+                ScriptClass::MultiSig | ScriptClass::MultiSigECDSA => {}
+                ScriptClass::TimeLockedPubKey | ScriptClass::SequenceLockedPubKey | ScriptClass::HashTimeLock => {}
                 ScriptClass::ScriptHash => {
                     // todo relax due to on fly calculation
                     let num_sig_ops = get_sig_op_count_upper_bound::<PopulatedTransaction, SigHashReusedValuesUnsync>(
@@ -295,7 +297,7 @@ mod tests {
                 let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
                 config.minimum_relay_transaction_fee = test.minimum_relay_transaction_fee;
                 let counters = Arc::new(MiningCounters::default());
-                let mempool = Mempool::new(Arc::new(config), counters);
+                let mempool = Mempool::new(Arc::new(config), Default::default(), counters);
 
                 let got = mempool.minimum_required_transaction_relay_fee(test.size);
                 if got != test.want {
@@ -380,7 +382,7 @@ mod tests {
                 let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
                 config.minimum_relay_transaction_fee = test.minimum_relay_transaction_fee;
                 let counters = Arc::new(MiningCounters::default());
-                let mempool = Mempool::new(Arc::new(config), counters);
+                let mempool = Mempool::new(Arc::new(config), Default::default(), counters);
 
                 println!("test_is_transaction_output_dust test '{}' ", test.name);
                 let res = mempool.is_transaction_output_dust(&test.tx_out);
@@ -559,7 +561,7 @@ mod tests {
                 let params: Params = net.into();
                 let config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
                 let counters = Arc::new(MiningCounters::default());
-                let mempool = Mempool::new(Arc::new(config), counters);
+                let mempool = Mempool::new(Arc::new(config), Default::default(), counters);
 
                 // Ensure standard-ness is as expected.
                 println!("test_check_transaction_standard_in_isolation test '{}' ", test.name);
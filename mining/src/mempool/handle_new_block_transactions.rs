@@ -30,11 +30,15 @@ impl Mempool {
             // If the transaction got accepted via a peer but is still an orphan here, do not remove
             // its redeemers in the orphan pool. We give those a chance to be unorphaned and included
             // in the next block template.
+            if let Some(accepted) = self.transaction_pool.get(&transaction_id) {
+                self.feerate_history.record(accepted.fee_rate(), accepted.added_at_daa_score, block_daa_score);
+            }
             if !self.orphan_pool.has(&transaction_id) {
                 self.remove_transaction(&transaction_id, false, TxRemovalReason::Accepted, "")?;
             }
             self.remove_double_spends(transaction)?;
             self.orphan_pool.remove_orphan(&transaction_id, false, TxRemovalReason::Accepted, "")?;
+            self.transaction_pool.set_priority_delta(transaction_id, Default::default());
             if self.accepted_transactions.add(transaction_id, block_daa_score) {
                 tx_accepted_counts += 1;
                 input_counts += transaction.inputs.len();
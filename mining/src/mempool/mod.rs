@@ -1,7 +1,8 @@
 use crate::{
-    feerate::{FeerateEstimator, FeerateEstimatorArgs},
+    feerate::{history::FeerateHistory, FeerateEstimator, FeerateEstimatorArgs},
     model::{
         owner_txs::{GroupedOwnerTransactions, ScriptPublicKeySet},
+        tx_priority::{TransactionPriorityDelta, TransactionPriorityDeltas},
         tx_query::TransactionQuery,
     },
     MiningCounters,
@@ -50,15 +51,17 @@ pub(crate) struct Mempool {
     transaction_pool: TransactionsPool,
     orphan_pool: OrphanPool,
     accepted_transactions: AcceptedTransactions,
+    feerate_history: FeerateHistory,
     counters: Arc<MiningCounters>,
 }
 
 impl Mempool {
-    pub(crate) fn new(config: Arc<Config>, counters: Arc<MiningCounters>) -> Self {
-        let transaction_pool = TransactionsPool::new(config.clone());
+    pub(crate) fn new(config: Arc<Config>, priority_deltas: TransactionPriorityDeltas, counters: Arc<MiningCounters>) -> Self {
+        let transaction_pool = TransactionsPool::new(config.clone(), priority_deltas);
         let orphan_pool = OrphanPool::new(config.clone());
         let accepted_transactions = AcceptedTransactions::new(config.clone());
-        Self { config, transaction_pool, orphan_pool, accepted_transactions, counters }
+        let feerate_history = FeerateHistory::new(config.minimum_feerate(), config.network_blocks_per_second);
+        Self { config, transaction_pool, orphan_pool, accepted_transactions, feerate_history, counters }
     }
 
     pub(crate) fn get_transaction(&self, transaction_id: &TransactionId, query: TransactionQuery) -> Option<MutableTransaction> {
@@ -129,11 +132,32 @@ impl Mempool {
         self.transaction_pool.build_selector()
     }
 
+    pub(crate) fn set_transaction_priority_delta(&mut self, transaction_id: TransactionId, delta: TransactionPriorityDelta) {
+        self.transaction_pool.set_priority_delta(transaction_id, delta);
+    }
+
+    pub(crate) fn transaction_priority_deltas(&self) -> Vec<(TransactionId, TransactionPriorityDelta)> {
+        self.transaction_pool.priority_deltas()
+    }
+
     /// Builds a feerate estimator based on internal state of the ready transactions frontier
     pub(crate) fn build_feerate_estimator(&self, args: FeerateEstimatorArgs) -> FeerateEstimator {
         self.transaction_pool.build_feerate_estimator(args)
     }
 
+    /// The history of the time transactions waited in the mempool before being accepted
+    pub(crate) fn feerate_history(&self) -> &FeerateHistory {
+        &self.feerate_history
+    }
+
+    /// Records in the feerate history the removal at `daa_score` of a transaction which expired or was evicted
+    /// before being accepted
+    pub(crate) fn record_unaccepted_transaction(&mut self, transaction_id: &TransactionId, daa_score: u64) {
+        if let Some(transaction) = self.transaction_pool.get(transaction_id) {
+            self.feerate_history.record_censored(transaction.fee_rate(), transaction.added_at_daa_score, daa_score);
+        }
+    }
+
     pub(crate) fn all_transaction_ids_with_priority(&self, priority: Priority) -> Vec<TransactionId> {
         let _sw = Stopwatch::<15>::with_threshold("all_transaction_ids_with_priority op");
         self.transaction_pool.all_transaction_ids_with_priority(priority)
@@ -79,6 +79,22 @@ impl OrphanPool {
         Ok(())
     }
 
+    /// Checks that `transaction` could be added to the pool, without adding it nor evicting any orphan.
+    pub(crate) fn check_orphan(&self, transaction: &MutableTransaction) -> RuleResult<()> {
+        if self.config.maximum_orphan_transaction_count == 0 {
+            return Ok(());
+        }
+        self.check_orphan_duplicate(transaction)?;
+        self.check_orphan_mass(transaction)?;
+        self.check_orphan_double_spend(transaction)?;
+        if self.all_orphans.len() + 1 > self.config.maximum_orphan_transaction_count as usize
+            && self.get_random_low_priority_orphan().is_none()
+        {
+            return Err(RuleError::RejectOrphanPoolIsFull(self.all_orphans.len(), self.config.maximum_orphan_transaction_count));
+        }
+        Ok(())
+    }
+
     /// Make room in the pool for at least `free_slots` new transactions.
     ///
     /// An error is returned if the pool is filled with high priority transactions.
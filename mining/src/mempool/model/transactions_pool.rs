@@ -11,7 +11,11 @@ use crate::{
         },
         tx::Priority,
     },
-    model::{topological_index::TopologicalIndex, TransactionIdSet},
+    model::{
+        topological_index::TopologicalIndex,
+        tx_priority::{TransactionPriorityDelta, TransactionPriorityDeltas},
+        TransactionIdSet,
+    },
     Policy,
 };
 use spectre_consensus_core::{
@@ -67,6 +71,13 @@ pub(crate) struct TransactionsPool {
     /// Transactions with no parents in the mempool -- ready to be inserted into a block template
     ready_transactions: Frontier,
 
+    /// Ready transactions eligible for block template selection, weighted by their fee modified by the
+    /// operator-defined priority deltas. Transactions excluded by the operator are kept out
+    selectable_transactions: Frontier,
+
+    /// Operator-defined transaction priority deltas
+    priority_deltas: TransactionPriorityDeltas,
+
     last_expire_scan_daa_score: u64,
 
     /// last expire scan time in milliseconds
@@ -80,13 +91,15 @@ pub(crate) struct TransactionsPool {
 }
 
 impl TransactionsPool {
-    pub(crate) fn new(config: Arc<Config>) -> Self {
+    pub(crate) fn new(config: Arc<Config>, priority_deltas: TransactionPriorityDeltas) -> Self {
         Self {
             config,
             all_transactions: MempoolTransactionCollection::default(),
             parent_transactions: TransactionsEdges::default(),
             chained_transactions: TransactionsEdges::default(),
             ready_transactions: Default::default(),
+            selectable_transactions: Default::default(),
+            priority_deltas,
             last_expire_scan_daa_score: 0,
             last_expire_scan_time: unix_now(),
             utxo_set: MempoolUtxoSet::new(),
@@ -123,6 +136,9 @@ impl TransactionsPool {
         self.parent_transactions.insert(id, parents.clone());
         if parents.is_empty() {
             self.ready_transactions.insert((&transaction).into());
+            if let Some(key) = self.priority_deltas.selection_key(&transaction) {
+                self.selectable_transactions.insert(key);
+            }
         }
         for parent_id in parents {
             let entry = self.chained_transactions.entry(parent_id).or_default();
@@ -153,6 +169,9 @@ impl TransactionsPool {
                     if parents.is_empty() {
                         let tx = self.all_transactions.get(chain).unwrap();
                         self.ready_transactions.insert(tx.into());
+                        if let Some(key) = self.priority_deltas.selection_key(tx) {
+                            self.selectable_transactions.insert(key);
+                        }
                     }
                 }
             }
@@ -164,6 +183,9 @@ impl TransactionsPool {
         let removed_tx = self.all_transactions.remove(transaction_id).ok_or(RuleError::RejectMissingTransaction(*transaction_id))?;
 
         self.ready_transactions.remove(&(&removed_tx).into());
+        if let Some(key) = self.priority_deltas.selection_key(&removed_tx) {
+            self.selectable_transactions.remove(&key);
+        }
 
         // TODO: consider using `self.parent_transactions.get(transaction_id)`
         // The tradeoff to consider is whether it might be possible that a parent tx exists in the pool
@@ -202,9 +224,29 @@ impl TransactionsPool {
         self.ready_transactions.total_mass()
     }
 
-    /// Dynamically builds a transaction selector based on the specific state of the ready transactions frontier
+    /// Dynamically builds a transaction selector based on the specific state of the selectable transactions frontier
     pub(crate) fn build_selector(&self) -> Box<dyn TemplateTransactionSelector> {
-        self.ready_transactions.build_selector(&Policy::new(self.config.maximum_mass_per_block))
+        self.selectable_transactions.build_selector(&Policy::new(self.config.maximum_mass_per_block))
+    }
+
+    /// Sets the priority delta of a transaction, re-keying it in the selectable transactions frontier if needed.
+    /// Setting a neutral delta clears any previously set delta.
+    pub(crate) fn set_priority_delta(&mut self, transaction_id: TransactionId, delta: TransactionPriorityDelta) {
+        let ready_transaction = self
+            .all_transactions
+            .get(&transaction_id)
+            .filter(|_| self.parent_transactions.get(&transaction_id).is_some_and(|parents| parents.is_empty()));
+        if let Some(key) = ready_transaction.and_then(|tx| self.priority_deltas.selection_key(tx)) {
+            self.selectable_transactions.remove(&key);
+        }
+        self.priority_deltas.set(transaction_id, delta);
+        if let Some(key) = ready_transaction.and_then(|tx| self.priority_deltas.selection_key(tx)) {
+            self.selectable_transactions.insert(key);
+        }
+    }
+
+    pub(crate) fn priority_deltas(&self) -> Vec<(TransactionId, TransactionPriorityDelta)> {
+        self.priority_deltas.get_all()
     }
 
     /// Builds a feerate estimator based on internal state of the ready transactions frontier
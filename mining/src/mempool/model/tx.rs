@@ -74,6 +74,12 @@ pub(crate) struct TransactionPostValidation {
     pub accepted: Option<Arc<Transaction>>,
 }
 
+#[derive(Default)]
+pub(crate) struct TransactionDryRunValidation {
+    pub replaced: Option<TransactionId>,
+    pub is_orphan: bool,
+}
+
 #[derive(PartialEq, Eq)]
 pub(crate) enum TxRemovalReason {
     Muted,
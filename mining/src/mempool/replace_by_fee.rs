@@ -4,7 +4,7 @@ use crate::mempool::{
     tx::RbfPolicy,
     Mempool,
 };
-use spectre_consensus_core::tx::{MutableTransaction, Transaction};
+use spectre_consensus_core::tx::{MutableTransaction, Transaction, TransactionId};
 use std::sync::Arc;
 
 impl Mempool {
@@ -117,6 +117,40 @@ impl Mempool {
         }
     }
 
+    /// Validates replace by fee (RBF) for an incoming transaction and a policy without removing any transaction.
+    ///
+    /// On success, returns the id of the transaction [`Self::execute_replace_by_fee`] would report as removed.
+    pub(super) fn validate_replace_by_fee(
+        &self,
+        transaction: &MutableTransaction,
+        rbf_policy: RbfPolicy,
+    ) -> RuleResult<Option<TransactionId>> {
+        match rbf_policy {
+            RbfPolicy::Forbidden => {
+                self.transaction_pool.check_double_spends(transaction)?;
+                Ok(None)
+            }
+
+            RbfPolicy::Allowed => {
+                let mut replaced = None;
+                for double_spend in self.transaction_pool.get_double_spend_transaction_ids(transaction).iter() {
+                    let owner_id = self.validate_double_spending_transaction(transaction, double_spend)?.id();
+                    replaced.get_or_insert(owner_id);
+                }
+                Ok(replaced)
+            }
+
+            RbfPolicy::Mandatory => {
+                let double_spends = self.transaction_pool.get_double_spend_transaction_ids(transaction);
+                match double_spends.len() {
+                    0 => Err(RuleError::RejectRbfNoDoubleSpend),
+                    1 => Ok(Some(self.validate_double_spending_transaction(transaction, &double_spends[0])?.id())),
+                    _ => Err(RuleError::RejectRbfTooManyDoubleSpendingTransactions),
+                }
+            }
+        }
+    }
+
     fn get_double_spend_feerate(&self, double_spend: &DoubleSpend) -> RuleResult<f64> {
         let owner = self.transaction_pool.get_double_spend_owner(double_spend)?;
         match owner.mtx.calculated_feerate() {
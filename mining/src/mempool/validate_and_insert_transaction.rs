@@ -4,7 +4,7 @@ use crate::mempool::{
     errors::{RuleError, RuleResult},
     model::{
         pool::Pool,
-        tx::{MempoolTransaction, TransactionPostValidation, TransactionPreValidation, TxRemovalReason},
+        tx::{MempoolTransaction, TransactionDryRunValidation, TransactionPostValidation, TransactionPreValidation, TxRemovalReason},
     },
     tx::{Orphan, Priority, RbfPolicy},
     Mempool,
@@ -88,7 +88,9 @@ impl Mempool {
         let txs_to_remove = self.transaction_pool.limit_transaction_count(&transaction, transaction_size)?;
         if !txs_to_remove.is_empty() {
             let transaction_pool_len_before = self.transaction_pool.len();
+            let virtual_daa_score = consensus.get_virtual_daa_score();
             for x in txs_to_remove.iter() {
+                self.record_unaccepted_transaction(x, virtual_daa_score);
                 self.remove_transaction(x, true, TxRemovalReason::MakingRoom, format!(" for {}", transaction_id).as_str())?;
                 // self.transaction_pool.limit_transaction_count(&transaction) returns the
                 // smallest prefix of `ready_transactions` (sorted by ascending fee-rate)
@@ -129,6 +131,44 @@ impl Mempool {
         Ok(TransactionPostValidation { removed: removed_transaction, accepted: Some(accepted_transaction) })
     }
 
+    /// Runs the checks of [`Self::post_validate_and_insert_transaction`] without modifying the mempool.
+    ///
+    /// Replacements and evictions the insertion would trigger are validated but not applied.
+    pub(crate) fn post_validate_transaction_dry_run(
+        &self,
+        validation_result: RuleResult<()>,
+        transaction: &MutableTransaction,
+        orphan: Orphan,
+        rbf_policy: RbfPolicy,
+    ) -> RuleResult<TransactionDryRunValidation> {
+        let transaction_id = transaction.id();
+        if self.transaction_pool.has(&transaction_id) {
+            return Err(RuleError::RejectDuplicate(transaction_id));
+        }
+
+        self.validate_transaction_unacceptance(transaction)?;
+
+        match validation_result {
+            Ok(_) => {}
+            Err(RuleError::RejectMissingOutpoint) => {
+                if orphan == Orphan::Forbidden {
+                    return Err(RuleError::RejectDisallowedOrphan(transaction_id));
+                }
+                let _ = self.get_replace_by_fee_constraint(transaction, rbf_policy)?;
+                self.orphan_pool.check_orphan(transaction)?;
+                return Ok(TransactionDryRunValidation { replaced: None, is_orphan: true });
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+
+        self.validate_transaction_in_context(transaction)?;
+        let replaced = self.validate_replace_by_fee(transaction, rbf_policy)?;
+        let _ = self.transaction_pool.limit_transaction_count(transaction, transaction.mempool_estimated_bytes())?;
+        Ok(TransactionDryRunValidation { replaced, is_orphan: false })
+    }
+
     /// Validates that the transaction wasn't already accepted into the DAG
     fn validate_transaction_unacceptance(&self, transaction: &MutableTransaction) -> RuleResult<()> {
         // Reject if the transaction is registered as an accepted transaction
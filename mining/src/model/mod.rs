@@ -5,7 +5,9 @@ pub mod candidate_tx;
 pub mod owner_txs;
 pub mod topological_index;
 pub mod topological_sort;
+pub mod tx_dry_run;
 pub mod tx_insert;
+pub mod tx_priority;
 pub mod tx_query;
 
 /// A set of unique transaction ids
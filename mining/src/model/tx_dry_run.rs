@@ -0,0 +1,44 @@
+use spectre_consensus_core::tx::TransactionId;
+use spectre_mining_errors::mempool::RuleResult;
+
+/// Outcome of validating a transaction against the mempool without inserting it
+#[derive(Debug)]
+pub struct TransactionDryRun {
+    pub transaction_id: TransactionId,
+
+    /// `Ok` if the transaction would be accepted, either by the mempool or by the orphan pool,
+    /// otherwise the rule error it would be rejected with
+    pub result: RuleResult<()>,
+
+    /// Whether the transaction would be added to the orphan pool
+    pub is_orphan: bool,
+
+    pub compute_mass: u64,
+
+    /// Storage mass, only known once the transaction has been validated against the UTXO set
+    pub storage_mass: Option<u64>,
+
+    /// Fee, only known once the transaction has been validated against the UTXO set
+    pub fee: Option<u64>,
+
+    /// Fee per gram of the full contextual (compute & storage) mass
+    pub feerate: Option<f64>,
+
+    /// The mempool transaction that would be replaced by fee
+    pub replaced_transaction_id: Option<TransactionId>,
+}
+
+impl TransactionDryRun {
+    pub fn new(transaction_id: TransactionId, compute_mass: u64) -> Self {
+        Self {
+            transaction_id,
+            result: Ok(()),
+            is_orphan: false,
+            compute_mass,
+            storage_mass: None,
+            fee: None,
+            feerate: None,
+            replaced_transaction_id: None,
+        }
+    }
+}
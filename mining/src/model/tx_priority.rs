@@ -0,0 +1,88 @@
+use crate::{
+    mempool::model::tx::MempoolTransaction, stores::tx_priority_store::DbTransactionPriorityDeltasStore, FeerateTransactionKey,
+};
+use serde::{Deserialize, Serialize};
+use spectre_consensus_core::tx::TransactionId;
+use spectre_utils::mem_size::MemSizeEstimator;
+use std::collections::HashMap;
+
+/// An operator-defined adjustment of a mempool transaction, applied when selecting
+/// the transactions of a block template
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionPriorityDelta {
+    /// Amount in sompi added to the transaction fee when weighting it for selection.
+    /// The modified fee is saturated at zero so a negative delta can only deprioritize.
+    pub fee_delta: i64,
+
+    /// If set, the transaction is never selected into a block template
+    pub excluded: bool,
+}
+
+impl TransactionPriorityDelta {
+    pub fn new(fee_delta: i64, excluded: bool) -> Self {
+        Self { fee_delta, excluded }
+    }
+
+    /// A neutral delta has no effect on the selection process
+    pub fn is_neutral(&self) -> bool {
+        self.fee_delta == 0 && !self.excluded
+    }
+}
+
+impl MemSizeEstimator for TransactionPriorityDelta {}
+
+/// Map of transaction priority deltas.
+///
+/// Entries are kept until cleared by the operator or until the transaction gets
+/// accepted in a block. A delta may be registered for a transaction not yet known
+/// by the mempool, in which case it applies as soon as the transaction is received.
+///
+/// Deltas only affect block template selection: the feerate estimations and the eviction
+/// order of the mempool keep relying on the actual transaction fees.
+///
+/// If backed by a store, the deltas are persisted and thus survive a node restart.
+#[derive(Default)]
+pub(crate) struct TransactionPriorityDeltas {
+    inner: HashMap<TransactionId, TransactionPriorityDelta>,
+    store: Option<DbTransactionPriorityDeltasStore>,
+}
+
+impl TransactionPriorityDeltas {
+    /// Builds a map backed by `store`, loaded with the deltas it holds
+    pub(crate) fn new(store: DbTransactionPriorityDeltasStore) -> Self {
+        let inner = store.iterator().collect::<Result<_, _>>().unwrap();
+        Self { inner, store: Some(store) }
+    }
+
+    /// Sets the delta of a transaction, removing any existing entry if the delta is neutral
+    pub(crate) fn set(&mut self, transaction_id: TransactionId, delta: TransactionPriorityDelta) {
+        if delta.is_neutral() {
+            if self.inner.remove(&transaction_id).is_some() {
+                if let Some(store) = self.store.as_ref() {
+                    store.remove(transaction_id).unwrap();
+                }
+            }
+        } else if self.inner.insert(transaction_id, delta) != Some(delta) {
+            if let Some(store) = self.store.as_ref() {
+                store.set(transaction_id, delta).unwrap();
+            }
+        }
+    }
+
+    pub(crate) fn get_all(&self) -> Vec<(TransactionId, TransactionPriorityDelta)> {
+        self.inner.iter().map(|(id, delta)| (*id, *delta)).collect()
+    }
+
+    /// Returns the key of a ready transaction in the selectable transactions frontier, weighted by its fee
+    /// modified by the registered delta, if any.
+    ///
+    /// Returns `None` if the transaction is excluded from selection, in which case it is kept out of the frontier.
+    pub(crate) fn selection_key(&self, transaction: &MempoolTransaction) -> Option<FeerateTransactionKey> {
+        let key = FeerateTransactionKey::from(transaction);
+        match self.inner.get(&key.tx.id()) {
+            Some(delta) if delta.excluded => None,
+            Some(delta) => Some(FeerateTransactionKey::new(key.fee.saturating_add_signed(delta.fee_delta), key.mass, key.tx)),
+            None => Some(key),
+        }
+    }
+}
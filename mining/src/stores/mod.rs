@@ -0,0 +1 @@
+pub(crate) mod tx_priority_store;
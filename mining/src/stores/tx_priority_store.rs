@@ -0,0 +1,39 @@
+use crate::model::tx_priority::TransactionPriorityDelta;
+use spectre_consensus_core::tx::TransactionId;
+use spectre_database::{
+    prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use std::{error::Error, sync::Arc};
+
+/// Store of the operator-defined transaction priority deltas, so they survive a node restart
+#[derive(Clone)]
+pub(crate) struct DbTransactionPriorityDeltasStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<TransactionId, TransactionPriorityDelta>,
+}
+
+impl DbTransactionPriorityDeltasStore {
+    pub(crate) fn new(db: Arc<DB>) -> Self {
+        // All deltas are held in memory by the mempool, so there is no need for a cache
+        Self {
+            db: Arc::clone(&db),
+            access: CachedDbAccess::new(db, CachePolicy::Empty, DatabaseStorePrefixes::TransactionPriorityDeltas.into()),
+        }
+    }
+
+    pub(crate) fn iterator(&self) -> impl Iterator<Item = Result<(TransactionId, TransactionPriorityDelta), Box<dyn Error>>> + '_ {
+        self.access.iterator().map(|iter_result| {
+            let (key_bytes, delta) = iter_result?;
+            Ok((TransactionId::try_from(&key_bytes[..])?, delta))
+        })
+    }
+
+    pub(crate) fn set(&self, transaction_id: TransactionId, delta: TransactionPriorityDelta) -> StoreResult<()> {
+        self.access.write(DirectDbWriter::new(&self.db), transaction_id, delta)
+    }
+
+    pub(crate) fn remove(&self, transaction_id: TransactionId) -> StoreResult<()> {
+        self.access.delete(DirectDbWriter::new(&self.db), transaction_id)
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{
+    difficulty::VarDiff,
+    error::Error,
+    job::{Job, Jobs},
+    protocol::{
+        parse_nonce, Notification, Request, Response, StratumError, AUTHORIZE, EXTRANONCE_SUBSCRIBE, PROTOCOL_VERSION, SUBMIT,
+        SUBSCRIBE,
+    },
+    result::Result,
+    service::StratumConfig,
+    stats::{ShareEvent, StratumStats},
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use spectre_addresses::Address;
+use spectre_consensus_core::{hashing::header::hash_override_nonce_time, header::Header};
+use spectre_core::{debug, info, warn};
+use spectre_rpc_core::{api::rpc::DynRpcService, RpcRawBlock};
+use spectre_utils::triggers::Listener;
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{tcp::OwnedWriteHalf, TcpStream},
+    sync::watch,
+    time::interval,
+};
+
+/// Max length of a single request line
+const MAX_LINE_LENGTH: usize = 4096;
+
+/// Interval at which the difficulty of a connection not submitting shares is re-evaluated
+const VARDIFF_TICK_INTERVAL: Duration = Duration::from_secs(5);
+
+struct Worker {
+    /// Address paid by the coinbase of the jobs
+    address: Address,
+    /// Full login, i.e. `<address>.<worker name>`
+    name: String,
+}
+
+/// A Stratum connection with a single miner
+pub(crate) struct Client {
+    peer: SocketAddr,
+    rpc: DynRpcService,
+    config: Arc<StratumConfig>,
+    stats: Arc<StratumStats>,
+    extranonce: String,
+    subscribed: bool,
+    worker: Option<Worker>,
+    vardiff: VarDiff,
+    /// A difficulty change yet to be notified to the miner
+    pending_difficulty: Option<f64>,
+    jobs: Jobs,
+    /// Serialized messages waiting to be written
+    outgoing: Vec<String>,
+}
+
+impl Client {
+    pub(crate) fn new(
+        peer: SocketAddr,
+        rpc: DynRpcService,
+        config: Arc<StratumConfig>,
+        stats: Arc<StratumStats>,
+        extranonce: String,
+    ) -> Self {
+        let vardiff = VarDiff::new(config.initial_difficulty, config.shares_per_minute, Instant::now());
+        Self {
+            peer,
+            rpc,
+            config,
+            stats,
+            extranonce,
+            subscribed: false,
+            worker: None,
+            vardiff,
+            pending_difficulty: None,
+            jobs: Default::default(),
+            outgoing: vec![],
+        }
+    }
+
+    /// Serves the connection until it is closed by the miner, a protocol error occurs or a shutdown is signaled
+    pub(crate) async fn run(mut self, stream: TcpStream, mut templates: watch::Receiver<()>, shutdown: Listener) -> Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        // The read limit is reset before each read so a single line cannot exceed MAX_LINE_LENGTH
+        let mut reader = BufReader::new(reader).take(MAX_LINE_LENGTH as u64);
+        let mut line = String::new();
+        let mut vardiff_ticker = interval(VARDIFF_TICK_INTERVAL);
+        loop {
+            reader.set_limit((MAX_LINE_LENGTH - line.len()) as u64);
+            tokio::select! {
+                biased;
+
+                _ = shutdown.clone() => break,
+
+                read = reader.read_line(&mut line) => {
+                    if read? == 0 {
+                        if line.len() >= MAX_LINE_LENGTH {
+                            return Err(Error::Custom(format!("request exceeding {MAX_LINE_LENGTH} bytes")));
+                        }
+                        break;
+                    }
+                    if !line.ends_with('\n') {
+                        continue;
+                    }
+                    let request = line.trim().to_owned();
+                    line.clear();
+                    if !request.is_empty() {
+                        match serde_json::from_str(&request) {
+                            Ok(request) => self.handle_request(request).await?,
+                            Err(err) => {
+                                // A malformed request is answered and the session kept, as the JSON-RPC spec requires
+                                debug!("Stratum client {} sent a malformed request: {}", self.peer, err);
+                                self.stats.record_malformed_request();
+                                self.respond(Value::Null, Err(StratumError::ParseError))?;
+                            }
+                        }
+                    }
+                }
+
+                changed = templates.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    self.new_job(false).await?;
+                }
+
+                _ = vardiff_ticker.tick() => {
+                    if self.worker.is_some() {
+                        self.pending_difficulty = self.vardiff.on_tick(Instant::now()).or(self.pending_difficulty);
+                        self.notify_difficulty().await?;
+                    }
+                }
+            }
+            self.flush(&mut writer).await?;
+        }
+        Ok(())
+    }
+
+    async fn handle_request(&mut self, request: Request) -> Result<()> {
+        match request.method.as_str() {
+            SUBSCRIBE => {
+                self.subscribed = true;
+                self.respond(request.id, Ok(json!([true, PROTOCOL_VERSION])))
+            }
+            EXTRANONCE_SUBSCRIBE => self.respond(request.id, Ok(Value::Bool(true))),
+            AUTHORIZE => {
+                let result = self.authorize(&request);
+                let authorized = result.is_ok();
+                self.respond(request.id, result)?;
+                if authorized {
+                    self.queue(&Notification::set_extranonce(&self.extranonce))?;
+                    self.queue(&Notification::set_difficulty(self.vardiff.difficulty()))?;
+                    self.new_job(true).await?;
+                }
+                Ok(())
+            }
+            SUBMIT => {
+                let result = self.submit(&request).await;
+                self.respond(request.id, result)?;
+                self.notify_difficulty().await
+            }
+            method => {
+                debug!("Stratum client {} sent an unknown method {}", self.peer, method);
+                self.respond(request.id, Err(StratumError::UnknownMethod))
+            }
+        }
+    }
+
+    fn authorize(&mut self, request: &Request) -> std::result::Result<Value, StratumError> {
+        if !self.subscribed {
+            return Err(StratumError::NotSubscribed);
+        }
+        let login = request.param_str(0).ok_or(StratumError::InvalidParams)?;
+        let address = login.split('.').next().unwrap_or_default();
+        let address = Address::try_from(address)
+            .ok()
+            .filter(|address| address.prefix == self.config.prefix)
+            .ok_or(StratumError::UnauthorizedWorker)?;
+        info!("Stratum worker {} authorized from {}", login, self.peer);
+        self.worker = Some(Worker { address, name: login.to_owned() });
+        Ok(Value::Bool(true))
+    }
+
+    async fn submit(&mut self, request: &Request) -> std::result::Result<Value, StratumError> {
+        let worker = self.worker.as_ref().ok_or(StratumError::UnauthorizedWorker)?.name.clone();
+        let (Some(job_id), Some(nonce)) = (request.param_str(1), request.param_str(2)) else {
+            return Err(StratumError::InvalidParams);
+        };
+        let nonce = parse_nonce(nonce, &self.extranonce).ok_or(StratumError::InvalidParams)?;
+
+        let Some(job) = self.jobs.get_mut(job_id) else {
+            self.stats.record(&worker, ShareEvent::Stale);
+            return Err(StratumError::JobNotFound);
+        };
+        if !job.insert_nonce(nonce) {
+            self.stats.record(&worker, ShareEvent::Duplicate);
+            return Err(StratumError::DuplicateShare);
+        }
+        let (difficulty, share_target, state) = (job.difficulty, job.share_target, job.state.clone());
+
+        let (is_block, pow) = tokio::task::spawn_blocking(move || state.check_pow(nonce)).await.unwrap();
+        if is_block {
+            let mut block = self.jobs.get(job_id).expect("the job was found above").block.clone();
+            block.header.nonce = nonce;
+            self.submit_block(&worker, block).await;
+        } else if pow > share_target {
+            self.stats.record(&worker, ShareEvent::Invalid);
+            return Err(StratumError::LowDifficultyShare);
+        }
+
+        self.stats.record(&worker, ShareEvent::Valid(difficulty));
+        self.pending_difficulty = self.vardiff.on_share(Instant::now()).or(self.pending_difficulty);
+        Ok(Value::Bool(true))
+    }
+
+    async fn submit_block(&self, worker: &str, block: RpcRawBlock) {
+        let hash = Header::from(&block.header).hash;
+        match self.rpc.submit_block(block, false).await {
+            Ok(response) if response.report.is_success() => {
+                info!("Stratum worker {} found block {}", worker, hash);
+                self.stats.record(worker, ShareEvent::BlockFound);
+            }
+            Ok(response) => {
+                warn!("Block {} found by stratum worker {} was rejected: {:?}", hash, worker, response.report);
+                self.stats.record(worker, ShareEvent::BlockRejected);
+            }
+            Err(err) => {
+                warn!("Failed submitting block {} found by stratum worker {}: {}", hash, worker, err);
+                self.stats.record(worker, ShareEvent::BlockRejected);
+            }
+        }
+    }
+
+    /// Notifies a pending difficulty change along with a new job applying it
+    async fn notify_difficulty(&mut self) -> Result<()> {
+        if let Some(difficulty) = self.pending_difficulty.take() {
+            debug!("Stratum client {} difficulty set to {}", self.peer, difficulty);
+            self.queue(&Notification::set_difficulty(difficulty))?;
+            self.new_job(true).await?;
+        }
+        Ok(())
+    }
+
+    /// Sends a job built from a fresh block template to an authorized miner.
+    ///
+    /// Unless `force` is set, nothing is sent if the template did not change since the last job.
+    async fn new_job(&mut self, force: bool) -> Result<()> {
+        let Some(worker) = self.worker.as_ref() else {
+            return Ok(());
+        };
+        let template = match self.rpc.get_block_template(worker.address.clone(), self.config.extra_data.clone()).await {
+            Ok(template) => template,
+            Err(err) => {
+                debug!("Stratum worker {} could not get a block template: {}", worker.name, err);
+                return Ok(());
+            }
+        };
+
+        let header = Header::from(&template.block.header);
+        let pre_pow_hash = hash_override_nonce_time(&header, 0, 0);
+        if !force && self.jobs.last().is_some_and(|job| job.pre_pow_hash == pre_pow_hash && job.timestamp() == header.timestamp) {
+            return Ok(());
+        }
+
+        let job = Job::new(self.jobs.next_id(), template.block, &header, pre_pow_hash, self.vardiff.difficulty());
+        self.queue(&Notification::notify(&job.id, job.pre_pow_hash, job.timestamp()))?;
+        self.jobs.push(job);
+        Ok(())
+    }
+
+    fn respond(&mut self, id: Value, result: std::result::Result<Value, StratumError>) -> Result<()> {
+        let response = match result {
+            Ok(result) => Response::ok(id, result),
+            Err(err) => Response::err(id, err),
+        };
+        self.queue(&response)
+    }
+
+    fn queue<T: Serialize>(&mut self, message: &T) -> Result<()> {
+        self.outgoing.push(serde_json::to_string(message)?);
+        Ok(())
+    }
+
+    async fn flush(&mut self, writer: &mut OwnedWriteHalf) -> Result<()> {
+        for mut message in self.outgoing.drain(..) {
+            message.push('\n');
+            writer.write_all(message.as_bytes()).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+//!
+//! Share difficulty and variable difficulty (vardiff) management.
+//!
+
+use spectre_math::Uint256;
+use std::time::{Duration, Instant};
+
+/// The target of a share of difficulty 1 is `0xffff * 2^208`, following the
+/// usual pool convention. A share of difficulty `d` thus represents an
+/// expected work of about `d * 2^32` hashes.
+const DIFF1_TARGET_MANTISSA: f64 = 65535.0;
+const DIFF1_TARGET_SHIFT: u32 = 208;
+
+/// Expected number of hashes per unit of share difficulty
+pub const HASHES_PER_DIFFICULTY: f64 = 4_294_967_296.0;
+
+/// Lowest difficulty a connection can be assigned
+pub const MIN_DIFFICULTY: f64 = 1e-9;
+
+/// Number of shares expected in a vardiff window
+const VARDIFF_WINDOW_SHARES: f64 = 10.0;
+
+/// Bounds of the difficulty adjustment factor applied at each retarget
+const VARDIFF_MAX_FACTOR: f64 = 4.0;
+const VARDIFF_MIN_FACTOR: f64 = 1.0 / VARDIFF_MAX_FACTOR;
+
+/// Adjustments below this relative change are ignored
+const VARDIFF_TOLERANCE: f64 = 0.1;
+
+/// Converts a share difficulty into the target the PoW hash must not exceed
+pub fn difficulty_to_target(difficulty: f64) -> Uint256 {
+    // target = quotient * 2^208 where quotient fits in 48 bits, so computing it
+    // with 52 fractional bits keeps it within a u128
+    const FRACTION_BITS: u32 = 52;
+    let quotient = DIFF1_TARGET_MANTISSA / difficulty;
+    if !quotient.is_finite() || quotient >= (1u64 << (256 - DIFF1_TARGET_SHIFT)) as f64 {
+        return Uint256::MAX;
+    }
+    let scaled = (quotient * (1u64 << FRACTION_BITS) as f64) as u128;
+    let target = Uint256::from_u128(scaled) << (DIFF1_TARGET_SHIFT - FRACTION_BITS);
+    if target.is_zero() {
+        Uint256::from_u64(1)
+    } else {
+        target
+    }
+}
+
+/// Adjusts the share difficulty of a connection so shares are submitted at a steady rate.
+///
+/// Shares are counted over a window which is closed either when the expected number of shares
+/// was received or when the time expected to receive them has elapsed. The difficulty is then
+/// scaled by the ratio of the observed rate to the target rate.
+pub struct VarDiff {
+    difficulty: f64,
+    /// Target interval between two shares, `None` if the difficulty is fixed
+    share_interval: Option<Duration>,
+    window_start: Instant,
+    window_shares: u32,
+}
+
+impl VarDiff {
+    pub fn new(initial_difficulty: f64, shares_per_minute: u32, now: Instant) -> Self {
+        let share_interval = (shares_per_minute > 0).then(|| Duration::from_secs(60) / shares_per_minute);
+        Self { difficulty: initial_difficulty.max(MIN_DIFFICULTY), share_interval, window_start: now, window_shares: 0 }
+    }
+
+    pub fn difficulty(&self) -> f64 {
+        self.difficulty
+    }
+
+    /// Records a valid share, returning the new difficulty if it changed
+    pub fn on_share(&mut self, now: Instant) -> Option<f64> {
+        self.window_shares += 1;
+        if self.window_shares as f64 >= VARDIFF_WINDOW_SHARES {
+            return self.retarget(now);
+        }
+        self.on_tick(now)
+    }
+
+    /// Checks whether the window expired without enough shares, returning the new difficulty if it changed
+    pub fn on_tick(&mut self, now: Instant) -> Option<f64> {
+        let share_interval = self.share_interval?;
+        if now.duration_since(self.window_start) >= share_interval.mul_f64(VARDIFF_WINDOW_SHARES) {
+            return self.retarget(now);
+        }
+        None
+    }
+
+    fn retarget(&mut self, now: Instant) -> Option<f64> {
+        let share_interval = self.share_interval?;
+        let elapsed = now.duration_since(self.window_start);
+        let expected_shares = elapsed.as_secs_f64() / share_interval.as_secs_f64();
+        let factor = if expected_shares > 0.0 {
+            (self.window_shares as f64 / expected_shares).clamp(VARDIFF_MIN_FACTOR, VARDIFF_MAX_FACTOR)
+        } else {
+            VARDIFF_MAX_FACTOR
+        };
+        self.window_start = now;
+        self.window_shares = 0;
+
+        let difficulty = (self.difficulty * factor).max(MIN_DIFFICULTY);
+        if (difficulty / self.difficulty - 1.0).abs() < VARDIFF_TOLERANCE {
+            return None;
+        }
+        self.difficulty = difficulty;
+        Some(difficulty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difficulty_to_target() {
+        let diff1 = Uint256::from_u64(0xffff) << DIFF1_TARGET_SHIFT;
+        assert_eq!(difficulty_to_target(1.0), diff1);
+        assert_eq!(difficulty_to_target(2.0), diff1 >> 1);
+        assert_eq!(difficulty_to_target(0.5), diff1 << 1);
+        assert_eq!(difficulty_to_target(0.0), Uint256::MAX);
+        assert_eq!(difficulty_to_target(1e-12), Uint256::MAX);
+        assert_eq!(difficulty_to_target(f64::MAX), Uint256::from_u64(1));
+        assert!(difficulty_to_target(1000.0) < difficulty_to_target(999.0));
+    }
+
+    #[test]
+    fn test_vardiff() {
+        let start = Instant::now();
+
+        // Fixed difficulty
+        let mut vardiff = VarDiff::new(1.0, 0, start);
+        for i in 0..100 {
+            assert_eq!(vardiff.on_share(start + Duration::from_millis(i)), None);
+        }
+        assert_eq!(vardiff.on_tick(start + Duration::from_secs(3600)), None);
+
+        // Shares arriving way too fast raise the difficulty by the max factor
+        let mut vardiff = VarDiff::new(1.0, 20, start);
+        let mut now = start;
+        for _ in 0..9 {
+            now += Duration::from_millis(10);
+            assert_eq!(vardiff.on_share(now), None);
+        }
+        now += Duration::from_millis(10);
+        assert_eq!(vardiff.on_share(now), Some(4.0));
+
+        // Shares arriving at the target rate keep the difficulty
+        for _ in 0..10 {
+            now += Duration::from_secs(3);
+            assert_eq!(vardiff.on_share(now), None);
+        }
+        assert_eq!(vardiff.difficulty(), 4.0);
+
+        // A full window without any share lowers the difficulty by the max factor
+        assert_eq!(vardiff.on_tick(now + Duration::from_secs(29)), None);
+        assert_eq!(vardiff.on_tick(now + Duration::from_secs(30)), Some(1.0));
+    }
+}
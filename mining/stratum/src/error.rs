@@ -0,0 +1,29 @@
+use spectre_rpc_core::RpcError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Custom(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Rpc(#[from] RpcError),
+}
+
+impl From<String> for Error {
+    fn from(s: String) -> Self {
+        Error::Custom(s)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(s: &str) -> Self {
+        Error::Custom(s.to_string())
+    }
+}
@@ -0,0 +1,88 @@
+//!
+//! Allocation of the extranonces of the connected clients.
+//!
+
+use parking_lot::Mutex;
+use std::{collections::BTreeSet, fmt::Display, sync::Arc};
+
+/// Allocates a distinct extranonce to each connected client, so that no two clients search the same nonce space.
+/// An extranonce is released once its client disconnects and is reused before any never allocated one
+#[derive(Default)]
+pub struct ExtranoncePool {
+    inner: Mutex<PoolInner>,
+}
+
+#[derive(Default)]
+struct PoolInner {
+    /// The lowest extranonce which was never allocated
+    next: u32,
+    /// Released extranonces
+    free: BTreeSet<u16>,
+}
+
+impl ExtranoncePool {
+    /// Allocates the lowest available extranonce, or returns `None` if all of them are in use
+    pub fn allocate(self: &Arc<Self>) -> Option<Extranonce> {
+        let mut inner = self.inner.lock();
+        let value = match inner.free.pop_first() {
+            Some(value) => value,
+            None => {
+                let value = u16::try_from(inner.next).ok()?;
+                inner.next += 1;
+                value
+            }
+        };
+        Some(Extranonce { value, pool: self.clone() })
+    }
+
+    fn release(&self, value: u16) {
+        self.inner.lock().free.insert(value);
+    }
+}
+
+/// An extranonce allocated to a client, released back to its pool when dropped
+pub struct Extranonce {
+    value: u16,
+    pool: Arc<ExtranoncePool>,
+}
+
+impl Display for Extranonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04x}", self.value)
+    }
+}
+
+impl Drop for Extranonce {
+    fn drop(&mut self) {
+        self.pool.release(self.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extranonce_allocation() {
+        let pool = Arc::new(ExtranoncePool::default());
+        let allocate = || pool.allocate().map(|extranonce| (extranonce.to_string(), extranonce));
+        let (first, first_guard) = allocate().unwrap();
+        let (second, second_guard) = allocate().unwrap();
+        let (third, third_guard) = allocate().unwrap();
+        assert_eq!([first.as_str(), second.as_str(), third.as_str()], ["0000", "0001", "0002"]);
+
+        // Released extranonces are reused lowest first, before any never allocated one
+        drop(third_guard);
+        drop(first_guard);
+        let reused = [allocate().unwrap(), allocate().unwrap(), allocate().unwrap()];
+        assert_eq!(reused.each_ref().map(|(extranonce, _)| extranonce.as_str()), ["0000", "0002", "0003"]);
+
+        // Allocation fails only while all the extranonces are in use
+        let _rest = (4..=u16::MAX).map(|_| allocate().unwrap()).collect::<Vec<_>>();
+        assert!(allocate().is_none());
+        drop(second_guard);
+        let (last, _last_guard) = allocate().unwrap();
+        assert_eq!(last, "0001");
+        assert!(allocate().is_none());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::difficulty::difficulty_to_target;
+use spectre_consensus_core::header::Header;
+use spectre_hashes::Hash;
+use spectre_math::Uint256;
+use spectre_pow::State;
+use spectre_rpc_core::RpcRawBlock;
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+};
+
+/// Number of recent jobs of a connection shares are accepted for
+const MAX_JOBS: usize = 8;
+
+/// A mining job derived from a block template
+pub(crate) struct Job {
+    pub(crate) id: String,
+    pub(crate) block: RpcRawBlock,
+    pub(crate) pre_pow_hash: Hash,
+    /// Pre-computed PoW state, shared with the blocking tasks validating the shares
+    pub(crate) state: Arc<State>,
+    /// Difficulty of the shares of this job
+    pub(crate) difficulty: f64,
+    pub(crate) share_target: Uint256,
+    /// Nonces already submitted for this job
+    nonces: HashSet<u64>,
+}
+
+impl Job {
+    pub(crate) fn new(id: String, block: RpcRawBlock, header: &Header, pre_pow_hash: Hash, difficulty: f64) -> Self {
+        let state = Arc::new(State::new(header));
+        let share_target = difficulty_to_target(difficulty);
+        Self { id, block, pre_pow_hash, state, difficulty, share_target, nonces: HashSet::new() }
+    }
+
+    pub(crate) fn timestamp(&self) -> u64 {
+        self.block.header.timestamp
+    }
+
+    /// Registers a submitted nonce, returning `false` if it was already submitted
+    pub(crate) fn insert_nonce(&mut self, nonce: u64) -> bool {
+        self.nonces.insert(nonce)
+    }
+}
+
+/// The recent jobs sent to a connection, newest last
+#[derive(Default)]
+pub(crate) struct Jobs {
+    jobs: VecDeque<Job>,
+    next_id: u64,
+}
+
+impl Jobs {
+    pub(crate) fn next_id(&mut self) -> String {
+        self.next_id += 1;
+        format!("{:x}", self.next_id)
+    }
+
+    pub(crate) fn push(&mut self, job: Job) {
+        if self.jobs.len() == MAX_JOBS {
+            self.jobs.pop_front();
+        }
+        self.jobs.push_back(job);
+    }
+
+    pub(crate) fn last(&self) -> Option<&Job> {
+        self.jobs.back()
+    }
+
+    pub(crate) fn get(&self, id: &str) -> Option<&Job> {
+        self.jobs.iter().rev().find(|job| job.id == id)
+    }
+
+    pub(crate) fn get_mut(&mut self, id: &str) -> Option<&mut Job> {
+        self.jobs.iter_mut().rev().find(|job| job.id == id)
+    }
+}
@@ -0,0 +1,22 @@
+//!
+//! Built-in Stratum v1 mining server.
+//!
+//! The server distributes mining jobs derived from the node block templates, adjusts the share
+//! difficulty of each connection (vardiff), validates the submitted shares and relays the found
+//! blocks directly to the node.
+//!
+
+mod client;
+pub mod difficulty;
+pub mod error;
+mod extranonce;
+mod job;
+pub mod protocol;
+pub mod result;
+pub mod service;
+pub mod stats;
+
+#[cfg(test)]
+mod tests;
+
+pub use service::{StratumConfig, StratumService};
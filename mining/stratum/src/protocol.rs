@@ -0,0 +1,190 @@
+//!
+//! Stratum v1 wire format: newline-delimited JSON-RPC messages.
+//!
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use spectre_hashes::Hash;
+
+pub const SUBSCRIBE: &str = "mining.subscribe";
+pub const EXTRANONCE_SUBSCRIBE: &str = "mining.extranonce.subscribe";
+pub const AUTHORIZE: &str = "mining.authorize";
+pub const SUBMIT: &str = "mining.submit";
+
+pub const NOTIFY: &str = "mining.notify";
+pub const SET_DIFFICULTY: &str = "mining.set_difficulty";
+pub const SET_EXTRANONCE: &str = "mining.set_extranonce";
+
+/// Protocol version announced in the subscription response
+pub const PROTOCOL_VERSION: &str = "EthereumStratum/1.0.0";
+
+/// Number of bytes of a header nonce
+pub const NONCE_SIZE: usize = 8;
+
+/// A request sent by a miner
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    #[serde(default)]
+    pub id: Value,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+impl Request {
+    /// Returns the string parameter at `index`, if any
+    pub fn param_str(&self, index: usize) -> Option<&str> {
+        self.params.get(index).and_then(Value::as_str)
+    }
+}
+
+/// A response to a miner request
+#[derive(Debug, Serialize)]
+pub struct Response {
+    pub id: Value,
+    pub result: Value,
+    pub error: Value,
+}
+
+impl Response {
+    pub fn ok(id: Value, result: Value) -> Self {
+        Self { id, result, error: Value::Null }
+    }
+
+    pub fn err(id: Value, error: StratumError) -> Self {
+        Self { id, result: Value::Null, error: json!([error.code(), error.to_string(), Value::Null]) }
+    }
+}
+
+/// A notification pushed by the server
+#[derive(Debug, Serialize)]
+pub struct Notification {
+    pub id: Value,
+    pub method: &'static str,
+    pub params: Value,
+}
+
+impl Notification {
+    pub fn new(method: &'static str, params: Value) -> Self {
+        Self { id: Value::Null, method, params }
+    }
+
+    pub fn set_difficulty(difficulty: f64) -> Self {
+        Self::new(SET_DIFFICULTY, json!([difficulty]))
+    }
+
+    pub fn set_extranonce(extranonce: &str) -> Self {
+        Self::new(SET_EXTRANONCE, json!([extranonce, NONCE_SIZE - extranonce.len() / 2]))
+    }
+
+    /// Builds a job notification carrying the pre-PoW hash as four little-endian
+    /// 64-bit words followed by the header timestamp
+    pub fn notify(job_id: &str, pre_pow_hash: Hash, timestamp: u64) -> Self {
+        Self::new(NOTIFY, json!([job_id, pre_pow_hash.to_le_u64(), timestamp]))
+    }
+}
+
+/// Errors reported to miners, using the conventional Stratum error codes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum StratumError {
+    #[error("Unknown method")]
+    UnknownMethod,
+
+    #[error("Invalid parameters")]
+    InvalidParams,
+
+    #[error("Job not found")]
+    JobNotFound,
+
+    #[error("Duplicate share")]
+    DuplicateShare,
+
+    #[error("Low difficulty share")]
+    LowDifficultyShare,
+
+    #[error("Unauthorized worker")]
+    UnauthorizedWorker,
+
+    #[error("Not subscribed")]
+    NotSubscribed,
+
+    #[error("Parse error")]
+    ParseError,
+}
+
+impl StratumError {
+    pub fn code(&self) -> i32 {
+        match self {
+            StratumError::UnknownMethod | StratumError::InvalidParams => 20,
+            StratumError::JobNotFound => 21,
+            StratumError::DuplicateShare => 22,
+            StratumError::LowDifficultyShare => 23,
+            StratumError::UnauthorizedWorker => 24,
+            StratumError::NotSubscribed => 25,
+            // The JSON-RPC code, Stratum defining none for malformed requests
+            StratumError::ParseError => -32700,
+        }
+    }
+}
+
+/// Parses a nonce submitted as a hex string, optionally `0x`-prefixed.
+///
+/// Miners working with an extranonce may only submit the remaining part of the
+/// nonce, in which case the extranonce is prepended.
+pub fn parse_nonce(nonce: &str, extranonce: &str) -> Option<u64> {
+    let nonce = nonce.strip_prefix("0x").unwrap_or(nonce);
+    if nonce.is_empty() || nonce.len() > NONCE_SIZE * 2 {
+        return None;
+    }
+    if nonce.len() < NONCE_SIZE * 2 && !extranonce.is_empty() {
+        let remaining = NONCE_SIZE * 2 - extranonce.len();
+        if nonce.len() > remaining {
+            return None;
+        }
+        return u64::from_str_radix(&format!("{extranonce}{nonce:0>remaining$}"), 16).ok();
+    }
+    u64::from_str_radix(nonce, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nonce() {
+        assert_eq!(parse_nonce("0x00000000000000ff", ""), Some(0xff));
+        assert_eq!(parse_nonce("ff", ""), Some(0xff));
+        assert_eq!(parse_nonce("0000000000ff", "abcd"), Some(0xabcd_0000_0000_00ff));
+        assert_eq!(parse_nonce("ff", "abcd"), Some(0xabcd_0000_0000_00ff));
+        assert_eq!(parse_nonce("00000000000000", "abcd"), None);
+        assert_eq!(parse_nonce("abcd0000000000ff", "abcd"), Some(0xabcd_0000_0000_00ff));
+        assert_eq!(parse_nonce("", ""), None);
+        assert_eq!(parse_nonce("0x", ""), None);
+        assert_eq!(parse_nonce("xyz", ""), None);
+        assert_eq!(parse_nonce("00000000000000000", ""), None);
+    }
+
+    #[test]
+    fn test_request_deserialization() {
+        let request: Request =
+            serde_json::from_str(r#"{"id":1,"method":"mining.submit","params":["worker","1a","0x00000000000000ff"]}"#).unwrap();
+        assert_eq!(request.method, SUBMIT);
+        assert_eq!(request.param_str(1), Some("1a"));
+        assert_eq!(request.param_str(3), None);
+
+        let request: Request = serde_json::from_str(r#"{"method":"mining.subscribe"}"#).unwrap();
+        assert_eq!(request.id, Value::Null);
+        assert_eq!(request.param_str(0), None);
+    }
+
+    #[test]
+    fn test_response_serialization() {
+        let response = Response::err(json!(7), StratumError::JobNotFound);
+        assert_eq!(serde_json::to_string(&response).unwrap(), r#"{"id":7,"result":null,"error":[21,"Job not found",null]}"#);
+        let notification = Notification::set_extranonce("abcd");
+        assert_eq!(
+            serde_json::to_string(&notification).unwrap(),
+            r#"{"id":null,"method":"mining.set_extranonce","params":["abcd",6]}"#
+        );
+    }
+}
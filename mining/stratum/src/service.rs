@@ -0,0 +1,176 @@
+use crate::{
+    client::Client,
+    extranonce::ExtranoncePool,
+    result::Result,
+    stats::{format_hashrate, StratumStats},
+};
+use spectre_addresses::Prefix;
+use spectre_core::{
+    debug, info,
+    task::{
+        service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
+        tick::{TickReason, TickService},
+    },
+    trace, warn,
+};
+use spectre_notify::scope::{NewBlockTemplateScope, Scope};
+use spectre_rpc_core::{
+    api::rpc::DynRpcService,
+    notify::{
+        channel::NotificationChannel,
+        connection::{ChannelConnection, ChannelType},
+    },
+    Notification,
+};
+use spectre_utils::{networking::NetAddress, triggers::SingleTrigger};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use tokio::{net::TcpListener, sync::watch};
+
+/// Default port of the Stratum server
+pub const DEFAULT_STRATUM_PORT: u16 = 5555;
+
+/// Interval at which the per-worker share accounting is logged
+const STATS_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug)]
+pub struct StratumConfig {
+    /// Address prefix of the network, miners must authorize with an address of this network
+    pub prefix: Prefix,
+    /// Share difficulty assigned to new connections
+    pub initial_difficulty: f64,
+    /// Target share rate per connection, `0` disabling the variable difficulty
+    pub shares_per_minute: u32,
+    /// Extra data added to the coinbase of the block templates
+    pub extra_data: Vec<u8>,
+}
+
+impl StratumConfig {
+    pub fn new(prefix: Prefix, initial_difficulty: f64, shares_per_minute: u32) -> Self {
+        Self { prefix, initial_difficulty, shares_per_minute, extra_data: b"spectred-stratum".to_vec() }
+    }
+}
+
+/// Stratum v1 server distributing jobs derived from the node block templates
+pub struct StratumService {
+    net_address: NetAddress,
+    config: Arc<StratumConfig>,
+    rpc: DynRpcService,
+    stats: Arc<StratumStats>,
+    tick_service: Arc<TickService>,
+    shutdown: SingleTrigger,
+}
+
+impl StratumService {
+    pub const IDENT: &'static str = "stratum-service";
+
+    pub fn new(
+        net_address: NetAddress,
+        config: StratumConfig,
+        rpc: DynRpcService,
+        stats: Arc<StratumStats>,
+        tick_service: Arc<TickService>,
+    ) -> Self {
+        Self { net_address, config: Arc::new(config), rpc, stats, tick_service, shutdown: Default::default() }
+    }
+
+    pub fn stats(&self) -> Arc<StratumStats> {
+        self.stats.clone()
+    }
+
+    async fn serve(self: Arc<Self>) -> Result<()> {
+        let listener = TcpListener::bind(SocketAddr::from(self.net_address)).await?;
+        info!("Stratum server listening on {}", self.net_address);
+
+        // Every new block template notification triggers a job refresh on all connections
+        let notification_channel = NotificationChannel::default();
+        let listener_id =
+            self.rpc.register_new_listener(ChannelConnection::new(Self::IDENT, notification_channel.sender(), ChannelType::Closable));
+        self.rpc.start_notify(listener_id, Scope::NewBlockTemplate(NewBlockTemplateScope {})).await?;
+        let (template_sender, template_receiver) = watch::channel(());
+        let notification_receiver = notification_channel.receiver();
+        tokio::spawn(async move {
+            while let Ok(notification) = notification_receiver.recv().await {
+                if let Notification::NewBlockTemplate(_) = notification {
+                    template_sender.send_replace(());
+                }
+            }
+        });
+
+        tokio::spawn(self.clone().stats_monitor());
+
+        let shutdown = self.shutdown.listener.clone();
+        let extranonces = Arc::new(ExtranoncePool::default());
+        loop {
+            tokio::select! {
+                _ = shutdown.clone() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, peer)) => {
+                        debug!("Stratum client {} connected", peer);
+                        // The extranonce is held by the client task and released once the client disconnects
+                        let Some(extranonce) = extranonces.allocate() else {
+                            warn!("Stratum server rejected client {}: all the extranonces are in use", peer);
+                            continue;
+                        };
+                        let client = Client::new(peer, self.rpc.clone(), self.config.clone(), self.stats.clone(), extranonce.to_string());
+                        let templates = template_receiver.clone();
+                        let shutdown = shutdown.clone();
+                        tokio::spawn(async move {
+                            match client.run(stream, templates, shutdown).await {
+                                Ok(()) => debug!("Stratum client {} disconnected", peer),
+                                Err(err) => debug!("Stratum client {} disconnected: {}", peer, err),
+                            }
+                            drop(extranonce);
+                        });
+                    }
+                    Err(err) => warn!("Stratum server failed accepting a connection: {}", err),
+                }
+            }
+        }
+
+        self.rpc.unregister_listener(listener_id).await?;
+        Ok(())
+    }
+
+    async fn stats_monitor(self: Arc<Self>) {
+        let mut last_snapshot = self.stats.snapshot();
+        loop {
+            if let TickReason::Shutdown = self.tick_service.tick(STATS_INTERVAL).await {
+                break;
+            }
+            let snapshot = self.stats.snapshot();
+            for (worker, stats) in snapshot.iter() {
+                let delta = match last_snapshot.get(worker) {
+                    Some(last) => stats - last,
+                    None => stats.clone(),
+                };
+                if delta.has_activity() {
+                    info!("Stratum worker {}: {} (~{})", worker, delta, format_hashrate(delta.hashrate(STATS_INTERVAL)));
+                }
+            }
+            last_snapshot = snapshot;
+        }
+    }
+}
+
+impl AsyncService for StratumService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        Self::IDENT
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", Self::IDENT);
+        Box::pin(async move { self.serve().await.map_err(|err| AsyncServiceError::Service(err.to_string())) })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", Self::IDENT);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", Self::IDENT);
+            Ok(())
+        })
+    }
+}
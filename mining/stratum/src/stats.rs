@@ -0,0 +1,204 @@
+//!
+//! Per-worker share accounting.
+//!
+
+use crate::difficulty::HASHES_PER_DIFFICULTY;
+use parking_lot::Mutex;
+use spectre_rpc_core::{StratumMetrics, StratumWorkerMetrics};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::Sub,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// Outcome of a share submitted by a worker
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShareEvent {
+    /// The share met the share target. The difficulty is used for estimating the worker hashrate.
+    Valid(f64),
+    /// The share refers to an unknown or expired job
+    Stale,
+    /// The share did not meet the share target
+    Invalid,
+    /// The share was already submitted
+    Duplicate,
+    /// The share met the network target and the block was accepted
+    BlockFound,
+    /// The share met the network target but the block was rejected
+    BlockRejected,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkerStats {
+    pub valid_shares: u64,
+    pub stale_shares: u64,
+    pub invalid_shares: u64,
+    pub duplicate_shares: u64,
+    pub blocks_found: u64,
+    pub blocks_rejected: u64,
+    /// Sum of the difficulties of the valid shares
+    pub work: f64,
+}
+
+impl WorkerStats {
+    fn record(&mut self, event: ShareEvent) {
+        match event {
+            ShareEvent::Valid(difficulty) => {
+                self.valid_shares += 1;
+                self.work += difficulty;
+            }
+            ShareEvent::Stale => self.stale_shares += 1,
+            ShareEvent::Invalid => self.invalid_shares += 1,
+            ShareEvent::Duplicate => self.duplicate_shares += 1,
+            ShareEvent::BlockFound => self.blocks_found += 1,
+            ShareEvent::BlockRejected => self.blocks_rejected += 1,
+        }
+    }
+
+    pub fn has_activity(&self) -> bool {
+        self.valid_shares + self.stale_shares + self.invalid_shares + self.duplicate_shares > 0
+    }
+
+    /// Estimated hashrate in hashes per second, assuming these stats cover `elapsed`
+    pub fn hashrate(&self, elapsed: Duration) -> f64 {
+        if elapsed.is_zero() {
+            return 0.0;
+        }
+        self.work * HASHES_PER_DIFFICULTY / elapsed.as_secs_f64()
+    }
+}
+
+impl Sub for &WorkerStats {
+    type Output = WorkerStats;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            valid_shares: self.valid_shares.saturating_sub(rhs.valid_shares),
+            stale_shares: self.stale_shares.saturating_sub(rhs.stale_shares),
+            invalid_shares: self.invalid_shares.saturating_sub(rhs.invalid_shares),
+            duplicate_shares: self.duplicate_shares.saturating_sub(rhs.duplicate_shares),
+            blocks_found: self.blocks_found.saturating_sub(rhs.blocks_found),
+            blocks_rejected: self.blocks_rejected.saturating_sub(rhs.blocks_rejected),
+            work: (self.work - rhs.work).max(0.0),
+        }
+    }
+}
+
+impl Display for WorkerStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} valid, {} stale, {} invalid, {} duplicate shares, {} blocks found, {} blocks rejected",
+            self.valid_shares, self.stale_shares, self.invalid_shares, self.duplicate_shares, self.blocks_found, self.blocks_rejected
+        )
+    }
+}
+
+/// Formats a hashrate using the largest fitting unit
+pub fn format_hashrate(hashrate: f64) -> String {
+    const UNITS: [&str; 6] = ["H/s", "KH/s", "MH/s", "GH/s", "TH/s", "PH/s"];
+    let mut value = hashrate;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+/// Share accounting of all the workers which ever connected to the server, indexed by worker name
+#[derive(Default)]
+pub struct StratumStats {
+    workers: Mutex<HashMap<String, WorkerStats>>,
+    /// Requests of any connection which could not be parsed
+    malformed_requests: AtomicU64,
+}
+
+impl StratumStats {
+    pub fn record(&self, worker: &str, event: ShareEvent) {
+        let mut workers = self.workers.lock();
+        match workers.get_mut(worker) {
+            Some(stats) => stats.record(event),
+            None => workers.entry(worker.to_owned()).or_default().record(event),
+        }
+    }
+
+    pub fn record_malformed_request(&self) {
+        self.malformed_requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn malformed_requests(&self) -> u64 {
+        self.malformed_requests.load(Ordering::Relaxed)
+    }
+
+    pub fn snapshot(&self) -> HashMap<String, WorkerStats> {
+        self.workers.lock().clone()
+    }
+
+    /// Returns the accounting as exposed by the `GetMetrics` RPC, the workers being sorted by name
+    pub fn metrics(&self) -> StratumMetrics {
+        let mut workers = self
+            .snapshot()
+            .into_iter()
+            .map(|(worker, stats)| StratumWorkerMetrics {
+                worker,
+                valid_shares: stats.valid_shares,
+                stale_shares: stats.stale_shares,
+                invalid_shares: stats.invalid_shares,
+                duplicate_shares: stats.duplicate_shares,
+                blocks_found: stats.blocks_found,
+                blocks_rejected: stats.blocks_rejected,
+                work: stats.work,
+            })
+            .collect::<Vec<_>>();
+        workers.sort_by(|a, b| a.worker.cmp(&b.worker));
+        StratumMetrics { malformed_requests: self.malformed_requests(), workers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_worker_stats() {
+        let stats = StratumStats::default();
+        stats.record("miner.1", ShareEvent::Valid(2.0));
+        stats.record("miner.1", ShareEvent::Valid(3.0));
+        stats.record("miner.1", ShareEvent::BlockFound);
+        stats.record("miner.1", ShareEvent::Stale);
+        stats.record("miner.2", ShareEvent::Invalid);
+        stats.record("miner.2", ShareEvent::Duplicate);
+
+        let first = stats.snapshot();
+        assert_eq!(first.len(), 2);
+        let miner1 = &first["miner.1"];
+        assert_eq!((miner1.valid_shares, miner1.stale_shares, miner1.blocks_found), (2, 1, 1));
+        assert_eq!(miner1.work, 5.0);
+        assert_eq!(miner1.hashrate(Duration::from_secs(5)), HASHES_PER_DIFFICULTY);
+        let miner2 = &first["miner.2"];
+        assert_eq!((miner2.valid_shares, miner2.invalid_shares, miner2.duplicate_shares), (0, 1, 1));
+
+        stats.record("miner.2", ShareEvent::Valid(1.0));
+        let second = stats.snapshot();
+        let delta = &second["miner.2"] - &first["miner.2"];
+        assert_eq!(delta, WorkerStats { valid_shares: 1, work: 1.0, ..Default::default() });
+        assert!(!(&second["miner.1"] - &first["miner.1"]).has_activity());
+
+        stats.record_malformed_request();
+        let metrics = stats.metrics();
+        assert_eq!(metrics.malformed_requests, 1);
+        assert_eq!(metrics.workers.iter().map(|worker| worker.worker.as_str()).collect::<Vec<_>>(), ["miner.1", "miner.2"]);
+        assert_eq!((metrics.workers[1].valid_shares, metrics.workers[1].work), (1, 1.0));
+    }
+
+    #[test]
+    fn test_format_hashrate() {
+        assert_eq!(format_hashrate(0.0), "0.00 H/s");
+        assert_eq!(format_hashrate(999.0), "999.00 H/s");
+        assert_eq!(format_hashrate(12_345.0), "12.35 KH/s");
+        assert_eq!(format_hashrate(2.5e18), "2500.00 PH/s");
+    }
+}
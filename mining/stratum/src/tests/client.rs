@@ -0,0 +1,135 @@
+use crate::{
+    client::Client,
+    difficulty::{difficulty_to_target, MIN_DIFFICULTY},
+    protocol::PROTOCOL_VERSION,
+    stats::StratumStats,
+    StratumConfig,
+};
+use serde_json::{json, Value};
+use spectre_addresses::{Address, Prefix, Version};
+use spectre_consensus_core::header::Header;
+use spectre_hashes::Hash;
+use spectre_pow::State;
+use spectre_rpc_core::api::mock::RpcCoreMock;
+use spectre_rpc_core::RpcRawBlock;
+use spectre_utils::triggers::SingleTrigger;
+use std::sync::Arc;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+    net::{tcp::OwnedReadHalf, tcp::OwnedWriteHalf, TcpListener, TcpStream},
+    sync::watch,
+};
+
+/// A miner connected to a [`Client`] served by a mocked node
+struct Miner {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+    login: String,
+    stats: Arc<StratumStats>,
+    rpc: Arc<RpcCoreMock>,
+    /// PoW state of the jobs, all derived from the same template
+    state: State,
+    _templates: watch::Sender<()>,
+    _shutdown: SingleTrigger,
+}
+
+impl Miner {
+    /// Connects to a client assigning `difficulty` to the shares, the node serving a template
+    /// no share can solve
+    async fn connect(difficulty: f64) -> Self {
+        let header = Header::from_precomputed_hash(Hash::from_u64_word(1), vec![]);
+        assert_eq!(header.bits, 0, "the template must not be solvable");
+        let state = State::new(&header);
+        let rpc = Arc::new(RpcCoreMock::with_block_template(RpcRawBlock { header: (&header).into(), transactions: vec![] }));
+        let stats = Arc::new(StratumStats::default());
+        let config = Arc::new(StratumConfig::new(Prefix::Testnet, difficulty, 0));
+        let login = format!("{}.rig", Address::new(Prefix::Testnet, Version::PubKey, &[0; 32]));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+        let (server_stream, peer) = listener.accept().await.unwrap();
+        let (templates, template_receiver) = watch::channel(());
+        let shutdown = SingleTrigger::default();
+        let client = Client::new(peer, rpc.clone(), config, stats.clone(), "0001".to_owned());
+        tokio::spawn(client.run(server_stream, template_receiver, shutdown.listener.clone()));
+
+        let (reader, writer) = stream.into_split();
+        Self { lines: BufReader::new(reader).lines(), writer, login, stats, rpc, state, _templates: templates, _shutdown: shutdown }
+    }
+
+    /// Returns the first nonces, starting at `start`, meeting the target of the shares of `difficulty` or not
+    fn nonces(&self, start: u64, difficulty: f64, meeting: bool, count: usize) -> Vec<u64> {
+        let target = difficulty_to_target(difficulty);
+        (start..).filter(|&nonce| (self.state.check_pow(nonce).1 <= target) == meeting).take(count).collect()
+    }
+
+    async fn send_line(&mut self, line: &str) {
+        self.writer.write_all(format!("{line}\n").as_bytes()).await.unwrap();
+    }
+
+    async fn send(&mut self, id: u64, method: &str, params: Value) {
+        self.send_line(&json!({ "id": id, "method": method, "params": params }).to_string()).await;
+    }
+
+    async fn recv(&mut self) -> Value {
+        serde_json::from_str(&self.lines.next_line().await.unwrap().expect("the connection was closed")).unwrap()
+    }
+
+    /// Subscribes and authorizes, returning the id of the first job
+    async fn login(&mut self) -> String {
+        self.send(1, "mining.subscribe", json!(["test-miner"])).await;
+        assert_eq!(self.recv().await, json!({ "id": 1, "result": [true, PROTOCOL_VERSION], "error": null }));
+
+        self.send(2, "mining.authorize", json!([self.login, "x"])).await;
+        assert_eq!(self.recv().await, json!({ "id": 2, "result": true, "error": null }));
+        assert_eq!(self.recv().await["method"], "mining.set_extranonce");
+        assert_eq!(self.recv().await["method"], "mining.set_difficulty");
+        let job = self.recv().await;
+        assert_eq!(job["method"], "mining.notify");
+        job["params"][0].as_str().unwrap().to_owned()
+    }
+
+    /// Submits a share, returning the error code if rejected
+    async fn submit(&mut self, id: u64, job_id: &str, nonce: u64) -> Option<i64> {
+        self.send(id, "mining.submit", json!([self.login, job_id, format!("{nonce:016x}")])).await;
+        let response = self.recv().await;
+        assert_eq!(response["id"], id);
+        match response["error"].as_array() {
+            Some(error) => Some(error[0].as_i64().unwrap()),
+            None => {
+                assert_eq!(response["result"], true);
+                None
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_client_shares() {
+    let mut miner = Miner::connect(MIN_DIFFICULTY).await;
+    let job_id = miner.login().await;
+    let valid = miner.nonces(1, MIN_DIFFICULTY, true, 2);
+    let low = miner.nonces(1, MIN_DIFFICULTY, false, 1);
+
+    // Valid share
+    assert_eq!(miner.submit(3, &job_id, valid[0]).await, None);
+    // Low difficulty share
+    assert_eq!(miner.submit(4, &job_id, low[0]).await, Some(23));
+    // Duplicate share
+    assert_eq!(miner.submit(5, &job_id, valid[0]).await, Some(22));
+    // Stale share, referring to an unknown job
+    assert_eq!(miner.submit(6, "ffff", valid[1]).await, Some(21));
+
+    // A malformed request is answered with a parse error and the session is kept
+    miner.send_line("{\"id\": 7, \"method\": ").await;
+    let response = miner.recv().await;
+    assert_eq!(response["id"], Value::Null);
+    assert_eq!(response["error"][0], -32700);
+    assert_eq!(miner.submit(8, &job_id, valid[1]).await, None);
+
+    let stats = &miner.stats.snapshot()[&miner.login];
+    assert_eq!((stats.valid_shares, stats.invalid_shares, stats.duplicate_shares, stats.stale_shares), (2, 1, 1, 1));
+    assert_eq!(stats.work, 2.0 * MIN_DIFFICULTY);
+    assert_eq!(miner.stats.malformed_requests(), 1);
+    assert!(miner.rpc.submitted_blocks().is_empty());
+}
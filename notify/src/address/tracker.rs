@@ -50,6 +50,11 @@ impl CounterMap {
         Self(HashMap::with_capacity(capacity))
     }
 
+    /// Returns the reference count of `index` if it is present
+    pub fn get(&self, index: Index) -> Option<RefCount> {
+        self.0.get(&index).copied()
+    }
+
     #[cfg(test)]
     pub fn with_counters(counters: Vec<Counter>) -> Self {
         Self(counters.into_iter().map(|x| (x.index, x.count)).collect())
@@ -128,7 +128,10 @@ mod tests {
         notifier::test_helpers::NotifyMock,
         subscription::{
             context::SubscriptionContext,
-            single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+            single::{
+                AcceptedTransactionsChangedSubscription, OverallSubscription, UtxosChangedSubscription,
+                VirtualChainChangedSubscription,
+            },
         },
     };
     use derive_more::Display;
@@ -171,6 +174,14 @@ mod tests {
             unimplemented!()
         }
 
+        fn apply_accepted_transactions_changed_subscription(
+            &self,
+            _: &AcceptedTransactionsChangedSubscription,
+            _: &SubscriptionContext,
+        ) -> Option<Self> {
+            unimplemented!()
+        }
+
         fn event_type(&self) -> EventType {
             unimplemented!()
         }
@@ -3,7 +3,9 @@ use crate::subscription::context::SubscriptionContext;
 use super::{
     events::EventType,
     subscription::{
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        single::{
+            AcceptedTransactionsChangedSubscription, OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription,
+        },
         Single,
     },
 };
@@ -22,6 +24,12 @@ pub trait Notification: Clone + Debug + Display + Send + Sync + 'static {
     fn apply_utxos_changed_subscription(&self, subscription: &UtxosChangedSubscription, context: &SubscriptionContext)
         -> Option<Self>;
 
+    fn apply_accepted_transactions_changed_subscription(
+        &self,
+        subscription: &AcceptedTransactionsChangedSubscription,
+        context: &SubscriptionContext,
+    ) -> Option<Self>;
+
     fn apply_subscription(&self, subscription: &dyn Single, context: &SubscriptionContext) -> Option<Self> {
         match subscription.event_type() {
             EventType::VirtualChainChanged => self.apply_virtual_chain_changed_subscription(
@@ -30,6 +38,10 @@ pub trait Notification: Clone + Debug + Display + Send + Sync + 'static {
             ),
             EventType::UtxosChanged => self
                 .apply_utxos_changed_subscription(subscription.as_any().downcast_ref::<UtxosChangedSubscription>().unwrap(), context),
+            EventType::AcceptedTransactionsChanged => self.apply_accepted_transactions_changed_subscription(
+                subscription.as_any().downcast_ref::<AcceptedTransactionsChangedSubscription>().unwrap(),
+                context,
+            ),
             _ => self.apply_overall_subscription(subscription.as_any().downcast_ref::<OverallSubscription>().unwrap(), context),
         }
     }
@@ -178,6 +190,17 @@ pub mod test_helpers {
             }
         }
 
+        fn apply_accepted_transactions_changed_subscription(
+            &self,
+            subscription: &AcceptedTransactionsChangedSubscription,
+            _: &SubscriptionContext,
+        ) -> Option<Self> {
+            match subscription.active() {
+                true => Some(self.clone()),
+                false => None,
+            }
+        }
+
         fn event_type(&self) -> EventType {
             self.into()
         }
@@ -12,9 +12,9 @@ use super::{
     events::{EventArray, EventSwitches, EventType},
     listener::{Listener, ListenerId},
     notification::Notification,
-    scope::Scope,
+    scope::{AddressesScope, Scope},
     subscriber::{Subscriber, SubscriptionManager},
-    subscription::{array::ArrayBuilder, Command, CompoundedSubscription, Mutation},
+    subscription::{array::ArrayBuilder, compounded::AddressesSubscription, Command, CompoundedSubscription, Mutation},
 };
 use async_channel::Sender;
 use async_trait::async_trait;
@@ -194,6 +194,22 @@ where
         self.inner.unregister_listener(id)
     }
 
+    /// Calls `f` with the compounded subscription of the address set event type of `S`, covering the addresses
+    /// subscribed by all the listeners of the notifier.
+    ///
+    /// The compounded subscriptions are locked during the call so `f` should be kept short.
+    pub fn with_addresses_subscription<S: AddressesScope, T>(
+        &self,
+        f: impl FnOnce(&AddressesSubscription<S>, &SubscriptionContext) -> T,
+    ) -> T {
+        let subscriptions = self.inner.subscriptions.lock();
+        let subscription = (*subscriptions[S::EVENT_TYPE])
+            .as_any()
+            .downcast_ref::<AddressesSubscription<S>>()
+            .expect("the compounded subscription of an address set event type is an addresses subscription");
+        f(subscription, &self.inner.subscription_context)
+    }
+
     pub async fn join(&self) -> Result<()> {
         self.inner.clone().join().await
     }
@@ -3,6 +3,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 use spectre_addresses::Address;
+use std::fmt::Debug;
 use workflow_serializer::prelude::*;
 
 macro_rules! scope_enum {
@@ -45,6 +46,7 @@ pub enum Scope {
     VirtualDaaScoreChanged,
     PruningPointUtxoSetOverride,
     NewBlockTemplate,
+    AcceptedTransactionsChanged,
 }
 }
 
@@ -54,6 +56,21 @@ impl Scope {
     }
 }
 
+/// A scope selecting the notifications related to a set of addresses tracked in the
+/// subscription context, an empty set meaning all addresses
+pub trait AddressesScope: Clone + Debug + Default + Eq + Into<Scope> + Send + Sync + 'static {
+    const EVENT_TYPE: EventType;
+
+    fn with_addresses(addresses: Vec<Address>) -> Self;
+
+    fn addresses(&self) -> &[Address];
+
+    fn into_addresses(self) -> Vec<Address>;
+
+    /// Extracts the inner scope of `scope` if it is of event type [`Self::EVENT_TYPE`]
+    fn try_from_scope(scope: Scope) -> Option<Self>;
+}
+
 impl Serializer for Scope {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
@@ -199,6 +216,29 @@ impl Deserializer for UtxosChangedScope {
     }
 }
 
+impl AddressesScope for UtxosChangedScope {
+    const EVENT_TYPE: EventType = EventType::UtxosChanged;
+
+    fn with_addresses(addresses: Vec<Address>) -> Self {
+        Self { addresses }
+    }
+
+    fn addresses(&self) -> &[Address] {
+        &self.addresses
+    }
+
+    fn into_addresses(self) -> Vec<Address> {
+        self.addresses
+    }
+
+    fn try_from_scope(scope: Scope) -> Option<Self> {
+        match scope {
+            Scope::UtxosChanged(scope) => Some(scope),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Display, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct SinkBlueScoreChangedScope {}
 
@@ -266,3 +306,72 @@ impl Deserializer for NewBlockTemplateScope {
         Ok(Self {})
     }
 }
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct AcceptedTransactionsChangedScope {
+    pub addresses: Vec<Address>,
+}
+
+impl std::fmt::Display for AcceptedTransactionsChangedScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let addresses = match self.addresses.len() {
+            0 => "all".to_string(),
+            1 => format!("{}", self.addresses[0]),
+            n => format!("{} addresses", n),
+        };
+        write!(f, "AcceptedTransactionsChangedScope ({})", addresses)
+    }
+}
+
+impl PartialEq for AcceptedTransactionsChangedScope {
+    fn eq(&self, other: &Self) -> bool {
+        self.addresses.len() == other.addresses.len() && self.addresses.iter().all(|x| other.addresses.contains(x))
+    }
+}
+
+impl Eq for AcceptedTransactionsChangedScope {}
+
+impl AcceptedTransactionsChangedScope {
+    pub fn new(addresses: Vec<Address>) -> Self {
+        Self { addresses }
+    }
+}
+
+impl AddressesScope for AcceptedTransactionsChangedScope {
+    const EVENT_TYPE: EventType = EventType::AcceptedTransactionsChanged;
+
+    fn with_addresses(addresses: Vec<Address>) -> Self {
+        Self { addresses }
+    }
+
+    fn addresses(&self) -> &[Address] {
+        &self.addresses
+    }
+
+    fn into_addresses(self) -> Vec<Address> {
+        self.addresses
+    }
+
+    fn try_from_scope(scope: Scope) -> Option<Self> {
+        match scope {
+            Scope::AcceptedTransactionsChanged(scope) => Some(scope),
+            _ => None,
+        }
+    }
+}
+
+impl Serializer for AcceptedTransactionsChangedScope {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<Address>, &self.addresses, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for AcceptedTransactionsChangedScope {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let addresses = load!(Vec<Address>, reader)?;
+        Ok(Self { addresses })
+    }
+}
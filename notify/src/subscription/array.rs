@@ -14,10 +14,13 @@ impl ArrayBuilder {
             let subscription: DynSubscription = match event_type {
                 EventType::VirtualChainChanged => Arc::<single::VirtualChainChangedSubscription>::default(),
                 EventType::UtxosChanged => Arc::new(single::UtxosChangedSubscription::with_capacity(
-                    single::UtxosChangedState::None,
+                    single::AddressesState::None,
                     listener_id,
                     utxos_changed_capacity.unwrap_or_default(),
                 )),
+                EventType::AcceptedTransactionsChanged => {
+                    Arc::new(single::AcceptedTransactionsChangedSubscription::new(single::AddressesState::None, listener_id))
+                }
                 _ => Arc::new(single::OverallSubscription::new(event_type, false)),
             };
             subscription
@@ -32,6 +35,7 @@ impl ArrayBuilder {
                 EventType::UtxosChanged => {
                     Box::new(compounded::UtxosChangedSubscription::with_capacity(utxos_changed_capacity.unwrap_or_default()))
                 }
+                EventType::AcceptedTransactionsChanged => Box::<compounded::AcceptedTransactionsChangedSubscription>::default(),
                 _ => Box::new(compounded::OverallSubscription::new(event_type)),
             };
             subscription
@@ -1,11 +1,13 @@
 use crate::{
     address::{error::Result, tracker::Counters},
     events::EventType,
-    scope::{Scope, UtxosChangedScope, VirtualChainChangedScope},
+    scope::{AcceptedTransactionsChangedScope, AddressesScope, Scope, UtxosChangedScope, VirtualChainChangedScope},
     subscription::{context::SubscriptionContext, Command, Compounded, Mutation, Subscription},
 };
 use itertools::Itertools;
 use spectre_addresses::{Address, Prefix};
+use spectre_consensus_core::tx::ScriptPublicKey;
+use std::marker::PhantomData;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OverallSubscription {
@@ -149,19 +151,25 @@ impl Subscription for VirtualChainChangedSubscription {
     }
 }
 
+/// Compounded subscription to the notifications related to a set of addresses, like [`UtxosChangedSubscription`]
+/// or [`AcceptedTransactionsChangedSubscription`]
 #[derive(Clone, Default, Debug, PartialEq, Eq)]
-pub struct UtxosChangedSubscription {
+pub struct AddressesSubscription<S: AddressesScope> {
     all: usize,
     indexes: Counters,
+    _scope: PhantomData<S>,
 }
 
-impl UtxosChangedSubscription {
+pub type UtxosChangedSubscription = AddressesSubscription<UtxosChangedScope>;
+pub type AcceptedTransactionsChangedSubscription = AddressesSubscription<AcceptedTransactionsChangedScope>;
+
+impl<S: AddressesScope> AddressesSubscription<S> {
     pub fn new() -> Self {
-        Self { all: 0, indexes: Counters::new() }
+        Self { all: 0, indexes: Counters::new(), _scope: PhantomData }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        Self { all: 0, indexes: Counters::with_capacity(capacity) }
+        Self { all: 0, indexes: Counters::with_capacity(capacity), _scope: PhantomData }
     }
 
     pub fn to_addresses(&self, prefix: Prefix, context: &SubscriptionContext) -> Vec<Address> {
@@ -173,6 +181,12 @@ impl UtxosChangedSubscription {
             .collect_vec()
     }
 
+    /// Returns true if the notifications involving `spk` are covered by at least one of the compounded subscriptions
+    pub fn contains(&self, spk: &ScriptPublicKey, context: &SubscriptionContext) -> bool {
+        self.all > 0
+            || context.address_tracker.get(spk).is_some_and(|(index, _)| self.indexes.get(index).is_some_and(|count| count > 0))
+    }
+
     pub fn register(&mut self, addresses: Vec<Address>, context: &SubscriptionContext) -> Result<Vec<Address>> {
         context.address_tracker.register(&mut self.indexes, addresses)
     }
@@ -182,32 +196,32 @@ impl UtxosChangedSubscription {
     }
 }
 
-impl Compounded for UtxosChangedSubscription {
+impl<S: AddressesScope> Compounded for AddressesSubscription<S> {
     fn compound(&mut self, mutation: Mutation, context: &SubscriptionContext) -> Option<Mutation> {
         assert_eq!(self.event_type(), mutation.event_type());
-        if let Scope::UtxosChanged(scope) = mutation.scope {
+        if let Some(scope) = S::try_from_scope(mutation.scope) {
             match mutation.command {
                 Command::Start => {
-                    if scope.addresses.is_empty() {
+                    if scope.addresses().is_empty() {
                         // Add All
                         self.all += 1;
                         if self.all == 1 {
-                            return Some(Mutation::new(Command::Start, UtxosChangedScope::default().into()));
+                            return Some(Mutation::new(Command::Start, S::default().into()));
                         }
                     } else {
                         // Add(A)
-                        let added = self.register(scope.addresses, context).expect("compounded always registers");
+                        let added = self.register(scope.into_addresses(), context).expect("compounded always registers");
                         if !added.is_empty() && self.all == 0 {
-                            return Some(Mutation::new(Command::Start, UtxosChangedScope::new(added).into()));
+                            return Some(Mutation::new(Command::Start, S::with_addresses(added).into()));
                         }
                     }
                 }
                 Command::Stop => {
-                    if !scope.addresses.is_empty() {
+                    if !scope.addresses().is_empty() {
                         // Remove(R)
-                        let removed = self.unregister(scope.addresses, context);
+                        let removed = self.unregister(scope.into_addresses(), context);
                         if !removed.is_empty() && self.all == 0 {
-                            return Some(Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into()));
+                            return Some(Mutation::new(Command::Stop, S::with_addresses(removed).into()));
                         }
                     } else {
                         // Remove All
@@ -216,9 +230,9 @@ impl Compounded for UtxosChangedSubscription {
                         if self.all == 0 {
                             let addresses = self.to_addresses(Prefix::Mainnet, context);
                             if !addresses.is_empty() {
-                                return Some(Mutation::new(Command::Start, UtxosChangedScope::new(addresses).into()));
+                                return Some(Mutation::new(Command::Start, S::with_addresses(addresses).into()));
                             } else {
-                                return Some(Mutation::new(Command::Stop, UtxosChangedScope::default().into()));
+                                return Some(Mutation::new(Command::Stop, S::default().into()));
                             }
                         }
                     }
@@ -229,10 +243,10 @@ impl Compounded for UtxosChangedSubscription {
     }
 }
 
-impl Subscription for UtxosChangedSubscription {
+impl<S: AddressesScope> Subscription for AddressesSubscription<S> {
     #[inline(always)]
     fn event_type(&self) -> EventType {
-        EventType::UtxosChanged
+        S::EVENT_TYPE
     }
 
     fn active(&self) -> bool {
@@ -241,7 +255,7 @@ impl Subscription for UtxosChangedSubscription {
 
     fn scope(&self, context: &SubscriptionContext) -> Scope {
         let addresses = if self.all > 0 { vec![] } else { self.to_addresses(Prefix::Mainnet, context) };
-        Scope::UtxosChanged(UtxosChangedScope::new(addresses))
+        S::with_addresses(addresses).into()
     }
 }
 
@@ -403,6 +417,7 @@ mod tests {
                     Counter { index: 0, count: 0, locked: true },
                     Counter { index: 1, count: 0, locked: false },
                 ]),
+                _scope: PhantomData,
             }),
         };
         let mut state = test.run();
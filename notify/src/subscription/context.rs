@@ -2,7 +2,7 @@ use crate::{
     address::tracker::Tracker,
     listener::ListenerId,
     subscription::{
-        single::{UtxosChangedState, UtxosChangedSubscription},
+        single::{AcceptedTransactionsChangedSubscription, AddressesState, UtxosChangedSubscription},
         DynSubscription,
     },
 };
@@ -15,6 +15,7 @@ use spectre_addresses::Address;
 pub struct SubscriptionContextInner {
     pub address_tracker: Tracker,
     pub utxos_changed_subscription_to_all: DynSubscription,
+    pub accepted_transactions_changed_subscription_to_all: DynSubscription,
 }
 
 impl SubscriptionContextInner {
@@ -25,18 +26,20 @@ impl SubscriptionContextInner {
     }
 
     pub fn with_options(max_addresses: Option<usize>) -> Self {
-        let address_tracker = Tracker::new(max_addresses);
-        let utxos_changed_subscription_all =
-            Arc::new(UtxosChangedSubscription::new(UtxosChangedState::All, Self::CONTEXT_LISTENER_ID));
-        Self { address_tracker, utxos_changed_subscription_to_all: utxos_changed_subscription_all }
+        Self::with_tracker(Tracker::new(max_addresses))
     }
 
     #[cfg(test)]
     pub fn with_addresses(addresses: &[Address]) -> Self {
-        let address_tracker = Tracker::with_addresses(addresses);
-        let utxos_changed_subscription_all =
-            Arc::new(UtxosChangedSubscription::new(UtxosChangedState::All, Self::CONTEXT_LISTENER_ID));
-        Self { address_tracker, utxos_changed_subscription_to_all: utxos_changed_subscription_all }
+        Self::with_tracker(Tracker::with_addresses(addresses))
+    }
+
+    fn with_tracker(address_tracker: Tracker) -> Self {
+        let utxos_changed_subscription_to_all =
+            Arc::new(UtxosChangedSubscription::new(AddressesState::All, Self::CONTEXT_LISTENER_ID));
+        let accepted_transactions_changed_subscription_to_all =
+            Arc::new(AcceptedTransactionsChangedSubscription::new(AddressesState::All, Self::CONTEXT_LISTENER_ID));
+        Self { address_tracker, utxos_changed_subscription_to_all, accepted_transactions_changed_subscription_to_all }
     }
 }
 
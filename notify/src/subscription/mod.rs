@@ -49,7 +49,8 @@ impl From<i32> for Command {
     }
 }
 
-/// Defines how an incoming UtxosChanged mutation must be propagated upwards
+/// Defines how an incoming mutation of an address set subscription (UtxosChanged or AcceptedTransactionsChanged)
+/// must be propagated upwards
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub enum UtxosChangedMutationPolicy {
     /// Mutation granularity defined at address level
@@ -63,11 +64,25 @@ pub enum UtxosChangedMutationPolicy {
 #[derive(Clone, Copy, Default, Debug)]
 pub struct MutationPolicies {
     pub utxo_changed: UtxosChangedMutationPolicy,
+    pub accepted_transactions_changed: UtxosChangedMutationPolicy,
 }
 
 impl MutationPolicies {
+    /// Creates policies applying `utxo_changed` to all the address set subscriptions
     pub fn new(utxo_changed: UtxosChangedMutationPolicy) -> Self {
-        Self { utxo_changed }
+        Self { utxo_changed, accepted_transactions_changed: utxo_changed }
+    }
+
+    pub fn with_accepted_transactions_changed(self, accepted_transactions_changed: UtxosChangedMutationPolicy) -> Self {
+        Self { accepted_transactions_changed, ..self }
+    }
+
+    /// Returns the policy of the address set subscriptions of event type `event`
+    pub fn addresses(&self, event: EventType) -> UtxosChangedMutationPolicy {
+        match event {
+            EventType::AcceptedTransactionsChanged => self.accepted_transactions_changed,
+            _ => self.utxo_changed,
+        }
     }
 }
 
@@ -200,7 +215,7 @@ impl MutateSingle for Arc<dyn Single> {
 pub trait BroadcastingSingle: Deref<Target = dyn Single> {
     /// Returns the broadcasting instance of the subscription.
     ///
-    /// This is used for grouping all the wildcard UtxosChanged subscriptions under
+    /// This is used for grouping all the wildcard address set subscriptions of an event type under
     /// the same unique instance in the broadcaster plans, allowing message optimizations
     /// during broadcasting of the notifications.
     fn broadcasting(self, context: &SubscriptionContext) -> DynSubscription;
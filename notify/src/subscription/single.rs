@@ -3,7 +3,7 @@ use crate::{
     error::Result,
     events::EventType,
     listener::ListenerId,
-    scope::{Scope, UtxosChangedScope, VirtualChainChangedScope},
+    scope::{AcceptedTransactionsChangedScope, AddressesScope, Scope, UtxosChangedScope, VirtualChainChangedScope},
     subscription::{
         context::SubscriptionContext, BroadcastingSingle, Command, DynSubscription, Mutation, MutationOutcome, MutationPolicies,
         Single, Subscription, UtxosChangedMutationPolicy,
@@ -18,6 +18,7 @@ use std::{
     collections::hash_set,
     fmt::{Debug, Display},
     hash::{Hash, Hasher},
+    marker::PhantomData,
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
@@ -172,19 +173,19 @@ impl Subscription for VirtualChainChangedSubscription {
     }
 }
 
-static UTXOS_CHANGED_SUBSCRIPTIONS: AtomicUsize = AtomicUsize::new(0);
+static ADDRESSES_SUBSCRIPTIONS: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum UtxosChangedMutation {
+enum AddressesMutation {
     None,
     Remove,
     Add,
     All,
 }
 
-impl From<(Command, &UtxosChangedScope)> for UtxosChangedMutation {
-    fn from((command, scope): (Command, &UtxosChangedScope)) -> Self {
-        match (command, scope.addresses.is_empty()) {
+impl<S: AddressesScope> From<(Command, &S)> for AddressesMutation {
+    fn from((command, scope): (Command, &S)) -> Self {
+        match (command, scope.addresses().is_empty()) {
             (Command::Stop, true) => Self::None,
             (Command::Stop, false) => Self::Remove,
             (Command::Start, false) => Self::Add,
@@ -194,7 +195,7 @@ impl From<(Command, &UtxosChangedScope)> for UtxosChangedMutation {
 }
 
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
-pub enum UtxosChangedState {
+pub enum AddressesState {
     /// Inactive
     #[default]
     None,
@@ -206,31 +207,34 @@ pub enum UtxosChangedState {
     All,
 }
 
-impl UtxosChangedState {
+impl AddressesState {
     pub fn active(&self) -> bool {
         match self {
-            UtxosChangedState::None => false,
-            UtxosChangedState::Selected | UtxosChangedState::All => true,
+            AddressesState::None => false,
+            AddressesState::Selected | AddressesState::All => true,
         }
     }
 }
 
-impl Display for UtxosChangedState {
+#[deprecated(note = "use `AddressesState` instead")]
+pub type UtxosChangedState = AddressesState;
+
+impl Display for AddressesState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            UtxosChangedState::None => write!(f, "none"),
-            UtxosChangedState::Selected => write!(f, "selected"),
-            UtxosChangedState::All => write!(f, "all"),
+            AddressesState::None => write!(f, "none"),
+            AddressesState::Selected => write!(f, "selected"),
+            AddressesState::All => write!(f, "all"),
         }
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct UtxosChangedSubscriptionData {
+pub struct AddressesSubscriptionData {
     /// State of the subscription
     ///
     /// Can be mutated without affecting neither equality nor hash of the struct
-    state: UtxosChangedState,
+    state: AddressesState,
 
     /// Address indexes in `SubscriptionContext`
     ///
@@ -238,14 +242,17 @@ pub struct UtxosChangedSubscriptionData {
     indexes: Indexes,
 }
 
-impl UtxosChangedSubscriptionData {
-    fn with_capacity(state: UtxosChangedState, capacity: usize) -> Self {
+#[deprecated(note = "use `AddressesSubscriptionData` instead")]
+pub type UtxosChangedSubscriptionData = AddressesSubscriptionData;
+
+impl AddressesSubscriptionData {
+    fn with_capacity(state: AddressesState, capacity: usize) -> Self {
         let indexes = Indexes::with_capacity(capacity);
         Self { state, indexes }
     }
 
     #[inline(always)]
-    pub fn update_state(&mut self, new_state: UtxosChangedState) {
+    pub fn update_state(&mut self, new_state: AddressesState) {
         self.state = new_state;
     }
 
@@ -293,41 +300,49 @@ impl UtxosChangedSubscriptionData {
     }
 
     pub fn to_all(&self) -> bool {
-        matches!(self.state, UtxosChangedState::All)
+        matches!(self.state, AddressesState::All)
     }
 }
 
-impl Display for UtxosChangedSubscriptionData {
+impl Display for AddressesSubscriptionData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.state {
-            UtxosChangedState::None | UtxosChangedState::All => write!(f, "{}", self.state),
-            UtxosChangedState::Selected => write!(f, "{}({})", self.state, self.indexes.len()),
+            AddressesState::None | AddressesState::All => write!(f, "{}", self.state),
+            AddressesState::Selected => write!(f, "{}({})", self.state, self.indexes.len()),
         }
     }
 }
 
+/// Subscription to the notifications related to a set of addresses, like [`UtxosChangedSubscription`]
+/// or [`AcceptedTransactionsChangedSubscription`]
 #[derive(Debug)]
-pub struct UtxosChangedSubscription {
+pub struct AddressesSubscription<S: AddressesScope> {
     /// Mutable inner data
-    data: RwLock<UtxosChangedSubscriptionData>,
+    data: RwLock<AddressesSubscriptionData>,
 
     /// ID of the listener owning this subscription
     ///
     /// This fully determines both equality and hash.
     listener_id: ListenerId,
+
+    _scope: PhantomData<S>,
 }
 
-impl UtxosChangedSubscription {
-    pub fn new(state: UtxosChangedState, listener_id: ListenerId) -> Self {
+pub type UtxosChangedSubscription = AddressesSubscription<UtxosChangedScope>;
+pub type AcceptedTransactionsChangedSubscription = AddressesSubscription<AcceptedTransactionsChangedScope>;
+
+impl<S: AddressesScope> AddressesSubscription<S> {
+    pub fn new(state: AddressesState, listener_id: ListenerId) -> Self {
         Self::with_capacity(state, listener_id, 0)
     }
 
-    pub fn with_capacity(state: UtxosChangedState, listener_id: ListenerId, capacity: usize) -> Self {
-        let data = RwLock::new(UtxosChangedSubscriptionData::with_capacity(state, capacity));
-        let subscription = Self { data, listener_id };
+    pub fn with_capacity(state: AddressesState, listener_id: ListenerId, capacity: usize) -> Self {
+        let data = RwLock::new(AddressesSubscriptionData::with_capacity(state, capacity));
+        let subscription = Self { data, listener_id, _scope: PhantomData };
         trace!(
-            "UtxosChangedSubscription: {} in total (new {})",
-            UTXOS_CHANGED_SUBSCRIPTIONS.fetch_add(1, Ordering::SeqCst) + 1,
+            "{}Subscription: {} in total (new {})",
+            S::EVENT_TYPE,
+            ADDRESSES_SUBSCRIPTIONS.fetch_add(1, Ordering::SeqCst) + 1,
             subscription
         );
         subscription
@@ -336,77 +351,79 @@ impl UtxosChangedSubscription {
     #[cfg(test)]
     pub fn with_addresses(active: bool, addresses: Vec<Address>, listener_id: ListenerId, context: &SubscriptionContext) -> Self {
         let state = match (active, addresses.is_empty()) {
-            (false, _) => UtxosChangedState::None,
-            (true, false) => UtxosChangedState::Selected,
-            (true, true) => UtxosChangedState::All,
+            (false, _) => AddressesState::None,
+            (true, false) => AddressesState::Selected,
+            (true, true) => AddressesState::All,
         };
         let subscription = Self::with_capacity(state, listener_id, addresses.len());
         let _ = subscription.data_mut().register(addresses, context);
         subscription
     }
 
-    pub fn data(&self) -> RwLockReadGuard<UtxosChangedSubscriptionData> {
+    pub fn data(&self) -> RwLockReadGuard<AddressesSubscriptionData> {
         self.data.read()
     }
 
-    pub fn data_mut(&self) -> RwLockWriteGuard<UtxosChangedSubscriptionData> {
+    pub fn data_mut(&self) -> RwLockWriteGuard<AddressesSubscriptionData> {
         self.data.write()
     }
 
     #[inline(always)]
-    pub fn state(&self) -> UtxosChangedState {
+    pub fn state(&self) -> AddressesState {
         self.data().state
     }
 
     pub fn to_all(&self) -> bool {
-        matches!(self.data().state, UtxosChangedState::All)
+        matches!(self.data().state, AddressesState::All)
     }
 }
 
-impl Clone for UtxosChangedSubscription {
+impl<S: AddressesScope> Clone for AddressesSubscription<S> {
     fn clone(&self) -> Self {
-        let subscription = Self { data: RwLock::new(self.data().clone()), listener_id: self.listener_id };
+        let subscription = Self { data: RwLock::new(self.data().clone()), listener_id: self.listener_id, _scope: PhantomData };
         trace!(
-            "UtxosChangedSubscription: {} in total (clone {})",
-            UTXOS_CHANGED_SUBSCRIPTIONS.fetch_add(1, Ordering::SeqCst) + 1,
+            "{}Subscription: {} in total (clone {})",
+            S::EVENT_TYPE,
+            ADDRESSES_SUBSCRIPTIONS.fetch_add(1, Ordering::SeqCst) + 1,
             subscription
         );
         subscription
     }
 }
 
-impl Display for UtxosChangedSubscription {
+impl<S: AddressesScope> Display for AddressesSubscription<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.data())
     }
 }
 
-impl Drop for UtxosChangedSubscription {
+impl<S: AddressesScope> Drop for AddressesSubscription<S> {
     fn drop(&mut self) {
         trace!(
-            "UtxosChangedSubscription: {} in total (drop {})",
-            UTXOS_CHANGED_SUBSCRIPTIONS.fetch_sub(1, Ordering::SeqCst) - 1,
+            "{}Subscription: {} in total (drop {})",
+            S::EVENT_TYPE,
+            ADDRESSES_SUBSCRIPTIONS.fetch_sub(1, Ordering::SeqCst) - 1,
             self
         );
     }
 }
 
-impl PartialEq for UtxosChangedSubscription {
+impl<S: AddressesScope> PartialEq for AddressesSubscription<S> {
     /// Equality is specifically bound to the listener ID
     fn eq(&self, other: &Self) -> bool {
         self.listener_id == other.listener_id
     }
 }
-impl Eq for UtxosChangedSubscription {}
+impl<S: AddressesScope> Eq for AddressesSubscription<S> {}
 
-impl Hash for UtxosChangedSubscription {
+impl<S: AddressesScope> Hash for AddressesSubscription<S> {
     /// Hash is specifically bound to the listener ID
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.listener_id.hash(state);
     }
 }
 
-impl Single for UtxosChangedSubscription {
+impl<S: AddressesScope> Single for AddressesSubscription<S> {
     fn apply_mutation(
         &self,
         current: &Arc<dyn Single>,
@@ -415,71 +432,71 @@ impl Single for UtxosChangedSubscription {
         context: &SubscriptionContext,
     ) -> Result<MutationOutcome> {
         assert_eq!(self.event_type(), mutation.event_type());
-        let outcome = if let Scope::UtxosChanged(scope) = mutation.scope {
+        let outcome = if let Some(scope) = S::try_from_scope(mutation.scope) {
             let mut data = self.data_mut();
             let state = data.state;
-            let mutation_type = UtxosChangedMutation::from((mutation.command, &scope));
+            let mutation_type = AddressesMutation::from((mutation.command, &scope));
             match (state, mutation_type) {
-                (UtxosChangedState::None, UtxosChangedMutation::None | UtxosChangedMutation::Remove) => {
+                (AddressesState::None, AddressesMutation::None | AddressesMutation::Remove) => {
                     // State None + Mutations None or Remove(R) => No change
                     MutationOutcome::new()
                 }
-                (UtxosChangedState::None, UtxosChangedMutation::Add) => {
+                (AddressesState::None, AddressesMutation::Add) => {
                     // State None + Mutation Add(A) => Mutated new state Selected(A)
-                    let addresses = data.register(scope.addresses, context)?;
-                    data.update_state(UtxosChangedState::Selected);
-                    let mutations = match policies.utxo_changed {
+                    let addresses = data.register(scope.into_addresses(), context)?;
+                    data.update_state(AddressesState::Selected);
+                    let mutations = match policies.addresses(S::EVENT_TYPE) {
                         UtxosChangedMutationPolicy::AddressSet => {
-                            vec![Mutation::new(mutation.command, UtxosChangedScope::new(addresses).into())]
+                            vec![Mutation::new(mutation.command, S::with_addresses(addresses).into())]
                         }
                         UtxosChangedMutationPolicy::Wildcard => {
-                            vec![Mutation::new(mutation.command, UtxosChangedScope::default().into())]
+                            vec![Mutation::new(mutation.command, S::default().into())]
                         }
                     };
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
-                (UtxosChangedState::None, UtxosChangedMutation::All) => {
+                (AddressesState::None, AddressesMutation::All) => {
                     // State None + Mutation All => Mutated new state All
-                    data.update_state(UtxosChangedState::All);
-                    let mutations = vec![Mutation::new(mutation.command, UtxosChangedScope::default().into())];
+                    data.update_state(AddressesState::All);
+                    let mutations = vec![Mutation::new(mutation.command, S::default().into())];
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
-                (UtxosChangedState::Selected, UtxosChangedMutation::None) => {
+                (AddressesState::Selected, AddressesMutation::None) => {
                     // State Selected(S) + Mutation None => Mutated new state None
-                    data.update_state(UtxosChangedState::None);
+                    data.update_state(AddressesState::None);
                     let removed = data.unregister_indexes(context);
                     assert!(!removed.is_empty(), "state Selected implies a non empty address set");
-                    let mutations = match policies.utxo_changed {
+                    let mutations = match policies.addresses(S::EVENT_TYPE) {
                         UtxosChangedMutationPolicy::AddressSet => {
-                            vec![Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into())]
+                            vec![Mutation::new(Command::Stop, S::with_addresses(removed).into())]
                         }
                         UtxosChangedMutationPolicy::Wildcard => {
-                            vec![Mutation::new(Command::Stop, UtxosChangedScope::default().into())]
+                            vec![Mutation::new(Command::Stop, S::default().into())]
                         }
                     };
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
-                (UtxosChangedState::Selected, UtxosChangedMutation::Remove) => {
+                (AddressesState::Selected, AddressesMutation::Remove) => {
                     // State Selected(S) + Mutation Remove(R) => Mutated state Selected(S – R) or mutated new state None or no change
-                    let removed = data.unregister(scope.addresses, context);
+                    let removed = data.unregister(scope.into_addresses(), context);
                     match (removed.is_empty(), data.indexes.is_empty()) {
                         (false, false) => {
-                            let mutations = match policies.utxo_changed {
+                            let mutations = match policies.addresses(S::EVENT_TYPE) {
                                 UtxosChangedMutationPolicy::AddressSet => {
-                                    vec![Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into())]
+                                    vec![Mutation::new(Command::Stop, S::with_addresses(removed).into())]
                                 }
                                 UtxosChangedMutationPolicy::Wildcard => vec![],
                             };
                             MutationOutcome::with_mutations(mutations)
                         }
                         (false, true) => {
-                            data.update_state(UtxosChangedState::None);
-                            let mutations = match policies.utxo_changed {
+                            data.update_state(AddressesState::None);
+                            let mutations = match policies.addresses(S::EVENT_TYPE) {
                                 UtxosChangedMutationPolicy::AddressSet => {
-                                    vec![Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into())]
+                                    vec![Mutation::new(Command::Stop, S::with_addresses(removed).into())]
                                 }
                                 UtxosChangedMutationPolicy::Wildcard => {
-                                    vec![Mutation::new(Command::Stop, UtxosChangedScope::default().into())]
+                                    vec![Mutation::new(Command::Stop, S::default().into())]
                                 }
                             };
                             MutationOutcome::with_mutated(current.clone(), mutations)
@@ -487,14 +504,14 @@ impl Single for UtxosChangedSubscription {
                         (true, _) => MutationOutcome::new(),
                     }
                 }
-                (UtxosChangedState::Selected, UtxosChangedMutation::Add) => {
+                (AddressesState::Selected, AddressesMutation::Add) => {
                     // State Selected(S) + Mutation Add(A) => Mutated state Selected(A ∪ S)
-                    let added = data.register(scope.addresses, context)?;
+                    let added = data.register(scope.into_addresses(), context)?;
                     match added.is_empty() {
                         false => {
-                            let mutations = match policies.utxo_changed {
+                            let mutations = match policies.addresses(S::EVENT_TYPE) {
                                 UtxosChangedMutationPolicy::AddressSet => {
-                                    vec![Mutation::new(Command::Start, UtxosChangedScope::new(added).into())]
+                                    vec![Mutation::new(Command::Start, S::with_addresses(added).into())]
                                 }
                                 UtxosChangedMutationPolicy::Wildcard => vec![],
                             };
@@ -503,44 +520,44 @@ impl Single for UtxosChangedSubscription {
                         true => MutationOutcome::new(),
                     }
                 }
-                (UtxosChangedState::Selected, UtxosChangedMutation::All) => {
+                (AddressesState::Selected, AddressesMutation::All) => {
                     // State Selected(S) + Mutation All => Mutated new state All
                     let removed = data.unregister_indexes(context);
                     assert!(!removed.is_empty(), "state Selected implies a non empty address set");
-                    data.update_state(UtxosChangedState::All);
-                    let mutations = match policies.utxo_changed {
+                    data.update_state(AddressesState::All);
+                    let mutations = match policies.addresses(S::EVENT_TYPE) {
                         UtxosChangedMutationPolicy::AddressSet => vec![
-                            Mutation::new(Command::Stop, UtxosChangedScope::new(removed).into()),
-                            Mutation::new(Command::Start, UtxosChangedScope::default().into()),
+                            Mutation::new(Command::Stop, S::with_addresses(removed).into()),
+                            Mutation::new(Command::Start, S::default().into()),
                         ],
                         UtxosChangedMutationPolicy::Wildcard => vec![],
                     };
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
-                (UtxosChangedState::All, UtxosChangedMutation::None) => {
+                (AddressesState::All, AddressesMutation::None) => {
                     // State All + Mutation None => Mutated new state None
-                    data.update_state(UtxosChangedState::None);
-                    let mutations = vec![Mutation::new(Command::Stop, UtxosChangedScope::default().into())];
+                    data.update_state(AddressesState::None);
+                    let mutations = vec![Mutation::new(Command::Stop, S::default().into())];
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
-                (UtxosChangedState::All, UtxosChangedMutation::Remove) => {
+                (AddressesState::All, AddressesMutation::Remove) => {
                     // State All + Mutation Remove(R) => No change
                     MutationOutcome::new()
                 }
-                (UtxosChangedState::All, UtxosChangedMutation::Add) => {
+                (AddressesState::All, AddressesMutation::Add) => {
                     // State All + Mutation Add(A) => Mutated new state Selectee(A)
-                    let added = data.register(scope.addresses, context)?;
-                    data.update_state(UtxosChangedState::Selected);
-                    let mutations = match policies.utxo_changed {
+                    let added = data.register(scope.into_addresses(), context)?;
+                    data.update_state(AddressesState::Selected);
+                    let mutations = match policies.addresses(S::EVENT_TYPE) {
                         UtxosChangedMutationPolicy::AddressSet => vec![
-                            Mutation::new(Command::Start, UtxosChangedScope::new(added).into()),
-                            Mutation::new(Command::Stop, UtxosChangedScope::default().into()),
+                            Mutation::new(Command::Start, S::with_addresses(added).into()),
+                            Mutation::new(Command::Stop, S::default().into()),
                         ],
                         UtxosChangedMutationPolicy::Wildcard => vec![],
                     };
                     MutationOutcome::with_mutated(current.clone(), mutations)
                 }
-                (UtxosChangedState::All, UtxosChangedMutation::All) => {
+                (AddressesState::All, AddressesMutation::All) => {
                     // State All <= Mutation All
                     MutationOutcome::new()
                 }
@@ -552,9 +569,9 @@ impl Single for UtxosChangedSubscription {
     }
 }
 
-impl Subscription for UtxosChangedSubscription {
+impl<S: AddressesScope> Subscription for AddressesSubscription<S> {
     fn event_type(&self) -> EventType {
-        EventType::UtxosChanged
+        S::EVENT_TYPE
     }
 
     fn active(&self) -> bool {
@@ -563,7 +580,7 @@ impl Subscription for UtxosChangedSubscription {
 
     fn scope(&self, context: &SubscriptionContext) -> Scope {
         // TODO: consider using a provided prefix
-        UtxosChangedScope::new(self.data().to_addresses(Prefix::Mainnet, context)).into()
+        S::with_addresses(self.data().to_addresses(Prefix::Mainnet, context)).into()
     }
 }
 
@@ -577,6 +594,14 @@ impl BroadcastingSingle for DynSubscription {
                     false => self,
                 }
             }
+            EventType::AcceptedTransactionsChanged => {
+                let accepted_transactions_changed_subscription =
+                    self.as_any().downcast_ref::<AcceptedTransactionsChangedSubscription>().unwrap();
+                match accepted_transactions_changed_subscription.to_all() {
+                    true => context.accepted_transactions_changed_subscription_to_all.clone(),
+                    false => self,
+                }
+            }
             _ => self,
         }
     }
@@ -1076,4 +1101,161 @@ mod tests {
         ]);
         tests.run(&context)
     }
+
+    #[test]
+    fn test_accepted_transactions_changed_mutation() {
+        let context = SubscriptionContext::new();
+        let a_stock = get_3_addresses(true);
+
+        let av = |indexes: &[usize]| indexes.iter().map(|idx| (a_stock[*idx]).clone()).collect::<Vec<_>>();
+        let ah = |indexes: &[usize]| indexes.iter().map(|idx| (a_stock[*idx]).clone()).collect::<Vec<_>>();
+        let s = |active: bool, indexes: &[usize]| {
+            Arc::new(AcceptedTransactionsChangedSubscription::with_addresses(
+                active,
+                ah(indexes).to_vec(),
+                MutationTests::LISTENER_ID,
+                &context,
+            )) as DynSubscription
+        };
+        let m = |command: Command, indexes: &[usize]| -> Mutation {
+            Mutation { command, scope: Scope::AcceptedTransactionsChanged(AcceptedTransactionsChangedScope::new(av(indexes))) }
+        };
+
+        // Subscriptions
+        let none = || s(false, &[]);
+        let selected_0 = || s(true, &[0]);
+        let selected_1 = || s(true, &[1]);
+        let selected_2 = || s(true, &[2]);
+        let selected_01 = || s(true, &[0, 1]);
+        let selected_02 = || s(true, &[0, 2]);
+        let selected_012 = || s(true, &[0, 1, 2]);
+        let all = || s(true, &[]);
+
+        // Mutations
+        let start_all = || m(Command::Start, &[]);
+        let stop_all = || m(Command::Stop, &[]);
+        let start_0 = || m(Command::Start, &[0]);
+        let start_1 = || m(Command::Start, &[1]);
+        let start_01 = || m(Command::Start, &[0, 1]);
+        let stop_0 = || m(Command::Stop, &[0]);
+        let stop_1 = || m(Command::Stop, &[1]);
+        let stop_01 = || m(Command::Stop, &[0, 1]);
+
+        // Tests
+        let tests = MutationTests::new(vec![
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription None to All (add all)",
+                state: none(),
+                mutation: start_all(),
+                new_state: all(),
+                outcome: MutationOutcome::with_mutated(all(), vec![start_all()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription None to Selected 0 (add set)",
+                state: none(),
+                mutation: start_0(),
+                new_state: selected_0(),
+                outcome: MutationOutcome::with_mutated(selected_0(), vec![start_0()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription None to None (stop set)",
+                state: none(),
+                mutation: stop_0(),
+                new_state: none(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription None to None (stop all)",
+                state: none(),
+                mutation: stop_all(),
+                new_state: none(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 01 to All (add all)",
+                state: selected_01(),
+                mutation: start_all(),
+                new_state: all(),
+                outcome: MutationOutcome::with_mutated(all(), vec![stop_01(), start_all()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 01 to 01 (add set with total intersection)",
+                state: selected_01(),
+                mutation: start_1(),
+                new_state: selected_01(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 0 to 01 (add set with partial intersection)",
+                state: selected_0(),
+                mutation: start_01(),
+                new_state: selected_01(),
+                outcome: MutationOutcome::with_mutations(vec![start_1()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 2 to 012 (add set with no intersection)",
+                state: selected_2(),
+                mutation: start_01(),
+                new_state: selected_012(),
+                outcome: MutationOutcome::with_mutations(vec![start_01()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 01 to None (remove superset)",
+                state: selected_1(),
+                mutation: stop_01(),
+                new_state: none(),
+                outcome: MutationOutcome::with_mutated(none(), vec![stop_1()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 01 to None (remove set with total intersection)",
+                state: selected_01(),
+                mutation: stop_01(),
+                new_state: none(),
+                outcome: MutationOutcome::with_mutated(none(), vec![stop_01()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 02 to 2 (remove set with partial intersection)",
+                state: selected_02(),
+                mutation: stop_01(),
+                new_state: selected_2(),
+                outcome: MutationOutcome::with_mutations(vec![stop_0()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription Selected 02 to 02 (remove set with no intersection)",
+                state: selected_02(),
+                mutation: stop_1(),
+                new_state: selected_02(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription All to All (add all)",
+                state: all(),
+                mutation: start_all(),
+                new_state: all(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription All to Selected 01 (add set)",
+                state: all(),
+                mutation: start_01(),
+                new_state: selected_01(),
+                outcome: MutationOutcome::with_mutated(selected_01(), vec![start_01(), stop_all()]),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription All to All (remove set)",
+                state: all(),
+                mutation: stop_01(),
+                new_state: all(),
+                outcome: MutationOutcome::new(),
+            },
+            MutationTest {
+                name: "AcceptedTransactionsChangedSubscription All to None (remove all)",
+                state: all(),
+                mutation: stop_all(),
+                new_state: none(),
+                outcome: MutationOutcome::with_mutated(none(), vec![stop_all()]),
+            },
+        ]);
+        tests.run(&context)
+    }
 }
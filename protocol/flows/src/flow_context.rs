@@ -1,4 +1,5 @@
 use crate::flowcontext::{
+    ibd_helpers::{IbdHelper, IbdHelperJob, IbdHelpers},
     orphans::{OrphanBlocksPool, OrphanOutput},
     process_queue::ProcessQueue,
     transactions::TransactionsSpread,
@@ -57,8 +58,8 @@ use tokio::sync::{
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use uuid::Uuid;
 
-/// The P2P protocol version. Currently the only one supported.
-const PROTOCOL_VERSION: u32 = 6;
+/// The P2P protocol version. Version 7 adds ranged requests of the pruning point UTXO set on top of version 6
+const PROTOCOL_VERSION: u32 = 7;
 
 /// See `check_orphan_resolution_range`
 const BASELINE_ORPHAN_RESOLUTION_RANGE: u32 = 5;
@@ -219,6 +220,7 @@ pub struct FlowContextInner {
     shared_transaction_requests: Arc<Mutex<HashMap<TransactionId, RequestScopeMetadata>>>,
     is_ibd_running: Arc<AtomicBool>,
     ibd_metadata: Arc<RwLock<Option<IbdMetadata>>>,
+    ibd_helpers: IbdHelpers,
     pub address_manager: Arc<Mutex<AddressManager>>,
     connection_manager: RwLock<Option<Arc<ConnectionManager>>>,
     mining_manager: MiningManagerProxy,
@@ -321,6 +323,7 @@ impl FlowContext {
                 shared_transaction_requests: Arc::new(Mutex::new(HashMap::new())),
                 is_ibd_running: Default::default(),
                 ibd_metadata: Default::default(),
+                ibd_helpers: Default::default(),
                 hub,
                 address_manager,
                 connection_manager: Default::default(),
@@ -408,6 +411,26 @@ impl FlowContext {
         }
     }
 
+    /// Registers the peer of `router` as a source of block bodies and UTXO set ranges for IBDs run with other peers.
+    /// Returns the receiver of the download jobs assigned to the peer
+    pub fn register_ibd_helper(&self, router: Arc<Router>) -> tokio::sync::mpsc::Receiver<IbdHelperJob> {
+        self.ibd_helpers.register(router)
+    }
+
+    /// Returns the connected peers which can help the IBD run with `syncer` by downloading block bodies and UTXO set ranges
+    pub fn ibd_helpers(&self, syncer: PeerKey) -> Vec<IbdHelper> {
+        self.ibd_helpers.helpers_for(syncer)
+    }
+
+    pub(crate) fn record_ibd_helper_job(&self) {
+        self.ibd_helpers.record_completed_job()
+    }
+
+    /// The number of body batches and UTXO set ranges which IBDs downloaded from helper peers since startup
+    pub fn ibd_helper_completed_jobs(&self) -> u64 {
+        self.ibd_helpers.completed_jobs()
+    }
+
     fn try_adding_request_impl(req: Hash, map: &Arc<Mutex<HashMap<Hash, RequestScopeMetadata>>>) -> Option<RequestScope<Hash>> {
         match map.lock().entry(req) {
             Entry::Occupied(mut e) => {
@@ -733,6 +756,7 @@ impl ConnectionInitializer for FlowContext {
         // Register all flows according to version
         let (flows, applied_protocol_version) = match peer_version.protocol_version {
             v if v >= PROTOCOL_VERSION => (v6::register(self.clone(), router.clone()), PROTOCOL_VERSION),
+            6 => (v6::register(self.clone(), router.clone()), 6),
             5 => (v5::register(self.clone(), router.clone()), 5),
             v => return Err(ProtocolError::VersionMismatch(PROTOCOL_VERSION, v)),
         };
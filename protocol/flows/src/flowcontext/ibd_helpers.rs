@@ -0,0 +1,110 @@
+use crate::v5::ibd::UtxosetChunk;
+use parking_lot::Mutex;
+use spectre_consensus_core::block::Block;
+use spectre_consensusmanager::ConsensusProxy;
+use spectre_hashes::Hash;
+use spectre_p2p_lib::{convert::model::utxo_set::UtxoSetRange, PeerKey, Router};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::{
+    mpsc::{channel, Receiver, Sender},
+    oneshot,
+};
+
+/// The maximal number of jobs which can be pending on a single IBD helper peer
+pub const IBD_HELPER_WINDOW: usize = 2;
+
+/// A download requested from an IBD helper peer on behalf of the peer currently syncing us
+pub enum IbdHelperJob {
+    Bodies(IbdBodiesJob),
+    PruningPointUtxoSet(IbdUtxoSetJob),
+}
+
+/// A request to download a batch of block bodies
+pub struct IbdBodiesJob {
+    /// The consensus being synced. The helper peer is qualified against it rather than against the current consensus
+    pub consensus: ConsensusProxy,
+    /// The syncer chain block in whose past the requested blocks reside. The helper peer only serves the request
+    /// if this block is on its selected chain, which guarantees it holds the bodies
+    pub high: Hash,
+    pub hashes: Vec<Hash>,
+    pub response: oneshot::Sender<IbdHelperResponse>,
+}
+
+/// A request to download a range of the pruning point UTXO set. The chunks are forwarded to the IBD as they arrive
+/// and imported by it, so that the IBD alone writes to the staging consensus
+pub struct IbdUtxoSetJob {
+    pub pruning_point: Hash,
+    pub range: UtxoSetRange,
+    pub chunks: Sender<UtxosetChunk>,
+    pub response: oneshot::Sender<IbdHelperResponse>,
+}
+
+pub enum IbdHelperResponse {
+    /// The requested blocks, in request order
+    Blocks(Vec<Block>),
+    /// All the chunks of the requested UTXO set range were forwarded
+    UtxoSetRange,
+    /// The peer cannot serve the job, either since it does not have `high` on its selected chain or since
+    /// its pruning point differs from the requested one
+    NotQualified,
+}
+
+/// A peer which can be requested to download block bodies or UTXO set ranges during IBD
+#[derive(Clone)]
+pub struct IbdHelper {
+    pub router: Arc<Router>,
+    sender: Sender<IbdHelperJob>,
+}
+
+impl IbdHelper {
+    /// Queues `job` on the helper. Returns the job back if the helper window is full or if the peer disconnected
+    pub fn try_assign(&self, job: IbdHelperJob) -> Result<(), IbdHelperJob> {
+        self.sender.try_send(job).map_err(|err| err.into_inner())
+    }
+
+    pub fn is_connected(&self) -> bool {
+        !self.sender.is_closed()
+    }
+}
+
+/// Registry of the connected peers which can serve downloads to the IBD flow of another peer
+#[derive(Default)]
+pub struct IbdHelpers {
+    helpers: Mutex<HashMap<PeerKey, IbdHelper>>,
+    completed_jobs: AtomicU64,
+}
+
+impl IbdHelpers {
+    /// Registers the peer of `router` as an IBD helper and returns the receiver for the jobs assigned to it.
+    /// The peer is implicitly unregistered once the receiver is dropped
+    pub fn register(&self, router: Arc<Router>) -> Receiver<IbdHelperJob> {
+        let (sender, receiver) = channel(IBD_HELPER_WINDOW);
+        let mut helpers = self.helpers.lock();
+        helpers.retain(|_, helper| helper.is_connected());
+        helpers.insert(router.key(), IbdHelper { router, sender });
+        receiver
+    }
+
+    /// Returns all connected helpers other than `syncer`
+    pub fn helpers_for(&self, syncer: PeerKey) -> Vec<IbdHelper> {
+        let mut helpers = self.helpers.lock();
+        helpers.retain(|_, helper| helper.is_connected());
+        helpers.iter().filter(|(&key, _)| key != syncer).map(|(_, helper)| helper.clone()).collect()
+    }
+
+    /// Records a job completed by a helper peer and used by the IBD
+    pub fn record_completed_job(&self) {
+        self.completed_jobs.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The number of body batches and UTXO set ranges downloaded from helper peers since startup
+    pub fn completed_jobs(&self) -> u64 {
+        self.completed_jobs.load(Ordering::Relaxed)
+    }
+}
@@ -12,8 +12,6 @@ use spectre_utils_tower::counters::TowerConnectionCounters;
 
 use crate::flow_context::FlowContext;
 
-const P2P_CORE_SERVICE: &str = "p2p-service";
-
 pub struct P2pService {
     flow_context: Arc<FlowContext>,
     connect_peers: Vec<NetAddress>,
@@ -28,6 +26,8 @@ pub struct P2pService {
 }
 
 impl P2pService {
+    pub const IDENT: &'static str = "p2p-service";
+
     pub fn new(
         flow_context: Arc<FlowContext>,
         connect_peers: Vec<NetAddress>,
@@ -52,15 +52,19 @@ impl P2pService {
             counters,
         }
     }
+
+    pub fn flow_context(&self) -> Arc<FlowContext> {
+        self.flow_context.clone()
+    }
 }
 
 impl AsyncService for P2pService {
     fn ident(self: Arc<Self>) -> &'static str {
-        P2P_CORE_SERVICE
+        Self::IDENT
     }
 
     fn start(self: Arc<Self>) -> AsyncServiceFuture {
-        trace!("{} starting", P2P_CORE_SERVICE);
+        trace!("{} starting", Self::IDENT);
 
         // Prepare a shutdown signal receiver
         let shutdown_signal = self.shutdown.listener.clone();
@@ -98,13 +102,13 @@ impl AsyncService for P2pService {
     }
 
     fn signal_exit(self: Arc<Self>) {
-        trace!("sending an exit signal to {}", P2P_CORE_SERVICE);
+        trace!("sending an exit signal to {}", Self::IDENT);
         self.shutdown.trigger.trigger();
     }
 
     fn stop(self: Arc<Self>) -> AsyncServiceFuture {
         Box::pin(async move {
-            trace!("{} stopped", P2P_CORE_SERVICE);
+            trace!("{} stopped", Self::IDENT);
             Ok(())
         })
     }
@@ -0,0 +1,343 @@
+use super::{progress::ProgressReporter, IbdFlow, IBD_BATCH_SIZE};
+use crate::flowcontext::ibd_helpers::{IbdBodiesJob, IbdHelper, IbdHelperJob, IbdHelperResponse, IBD_HELPER_WINDOW};
+use futures::{
+    future::{pending, select, try_join_all, Either, TryJoinAll},
+    stream::{unfold, FuturesUnordered},
+    FutureExt, StreamExt,
+};
+use spectre_consensus_core::{api::BlockValidationFuture, block::Block, errors::block::RuleError};
+use spectre_consensusmanager::ConsensusProxy;
+use spectre_core::{debug, info, warn};
+use spectre_hashes::Hash;
+use spectre_p2p_lib::{
+    common::ProtocolError,
+    dequeue_with_timeout, make_message,
+    pb::{spectred_message::Payload, RequestIbdBlocksMessage},
+    Router,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    sync::{mpsc::unbounded_channel, oneshot},
+    time::{sleep, timeout},
+};
+
+/// The time a helper peer is given to deliver a batch of block bodies before it is considered stalled and the
+/// batch is reassigned
+const IBD_HELPER_STALL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The max number of downloaded batches which are concurrently validated by consensus
+const MAX_VALIDATING_BATCHES: usize = 2;
+
+enum BodiesDownloadOutcome {
+    Completed,
+    /// A helper peer sent a block body which does not match its header. The peer was disconnected and the
+    /// download should be restarted
+    HelperMisbehaved,
+}
+
+struct HelperState {
+    helper: IbdHelper,
+    pending: usize,
+    retired: bool,
+}
+
+/// A batch of block bodies queued for validation, along with the helper peer it was downloaded from, if any
+struct ValidatingBatch {
+    jobs: TryJoinAll<BlockValidationFuture>,
+    len: usize,
+    daa_score: u64,
+    timestamp: u64,
+    helper: Option<Arc<Router>>,
+}
+
+impl ValidatingBatch {
+    fn new(consensus: &ConsensusProxy, blocks: Vec<Block>, helper: Option<Arc<Router>>) -> Self {
+        let last_header = blocks.last().expect("batches are never empty").header.clone();
+        let len = blocks.len();
+        let jobs = try_join_all(blocks.into_iter().map(|block| consensus.validate_and_insert_block(block).virtual_state_task));
+        Self { jobs, len, daa_score: last_header.daa_score, timestamp: last_header.timestamp, helper }
+    }
+}
+
+impl IbdFlow {
+    /// Downloads and processes the missing block bodies in the past of `high`.
+    ///
+    /// Bodies are downloaded in batches from the syncer and from any other connected peer which has `high` on its
+    /// selected chain in `consensus`. Peers connecting during the download join it. Each helper peer holds up to
+    /// [`IBD_HELPER_WINDOW`] pending batches, and batches held by a stalled or disconnected helper are reassigned.
+    /// Downloaded batches are validated in order, so headers remain single-source
+    pub(super) async fn sync_missing_block_bodies(&mut self, consensus: &ConsensusProxy, high: Hash) -> Result<(), ProtocolError> {
+        loop {
+            let hashes = self.get_missing_block_body_hashes(consensus, high).await?;
+            if hashes.is_empty() {
+                return Ok(());
+            }
+            match self.download_block_bodies(consensus, high, &hashes).await? {
+                BodiesDownloadOutcome::Completed => return Ok(()),
+                BodiesDownloadOutcome::HelperMisbehaved => {
+                    info!("IBD: restarting the block bodies download with peer {} without the misbehaving helper", self.router)
+                }
+            }
+        }
+    }
+
+    async fn get_missing_block_body_hashes(&self, consensus: &ConsensusProxy, high: Hash) -> Result<Vec<Hash>, ProtocolError> {
+        // TODO: query consensus in batches
+        let sleep_task = sleep(Duration::from_secs(2));
+        let hashes_task = consensus.async_get_missing_block_body_hashes(high);
+        tokio::pin!(sleep_task);
+        tokio::pin!(hashes_task);
+        let hashes = match select(sleep_task, hashes_task).await {
+            Either::Left((_, hashes_task)) => {
+                // We select between the tasks in order to inform the user if this operation is taking too long. On full IBD
+                // this operation requires traversing the full DAG which indeed might take several seconds or even minutes.
+                info!(
+                    "IBD: searching for missing block bodies to request from peer {}. This operation might take several seconds.",
+                    self.router
+                );
+                // Now re-await the original task
+                hashes_task.await
+            }
+            Either::Right((hashes_result, _)) => hashes_result,
+        }?;
+        Ok(hashes)
+    }
+
+    async fn download_block_bodies(
+        &mut self,
+        consensus: &ConsensusProxy,
+        high: Hash,
+        hashes: &[Hash],
+    ) -> Result<BodiesDownloadOutcome, ProtocolError> {
+        let low_header = consensus.async_get_header(*hashes.first().expect("hashes was non empty")).await?;
+        let high_header = consensus.async_get_header(*hashes.last().expect("hashes was non empty")).await?;
+        let mut progress_reporter = ProgressReporter::new(low_header.daa_score, high_header.daa_score, "blocks");
+
+        let ctx = self.ctx.clone();
+        let syncer = self.router.clone();
+        let mut helpers: Vec<HelperState> = Vec::new();
+        let mut helper_keys = HashSet::new();
+
+        let batches = hashes.chunks(IBD_BATCH_SIZE).collect::<Vec<_>>();
+        let mut unassigned = (0..batches.len()).collect::<BTreeSet<_>>();
+        let mut downloaded: BTreeMap<usize, (Vec<Block>, Option<Arc<Router>>)> = BTreeMap::new();
+        let mut validating: VecDeque<ValidatingBatch> = VecDeque::with_capacity(MAX_VALIDATING_BATCHES);
+        let mut next_batch = 0;
+        let mut helper_downloads = FuturesUnordered::new();
+
+        // The syncer downloads one batch at a time through its own route
+        let (syncer_sender, syncer_receiver) = unbounded_channel::<usize>();
+        let mut syncer_busy = false;
+        let batches_ref = &batches;
+        let syncer_downloads = unfold((&mut *self, syncer_receiver), move |(flow, mut receiver)| async move {
+            let index = receiver.recv().await?;
+            let result = flow.download_ibd_blocks(batches_ref[index]).await;
+            Some(((index, result), (flow, receiver)))
+        });
+        tokio::pin!(syncer_downloads);
+
+        loop {
+            while validating.len() < MAX_VALIDATING_BATCHES {
+                let Some((blocks, helper)) = downloaded.remove(&next_batch) else { break };
+                validating.push_back(ValidatingBatch::new(consensus, blocks, helper));
+                next_batch += 1;
+            }
+            if next_batch == batches.len() && validating.is_empty() {
+                break;
+            }
+
+            // Peers which connected since the last iteration join the download
+            for helper in ctx.ibd_helpers(syncer.key()) {
+                if helper_keys.insert(helper.router.key()) {
+                    info!("IBD: peer {} joins the block bodies download from peer {}", helper.router, syncer);
+                    helpers.push(HelperState { helper, pending: 0, retired: false });
+                }
+            }
+
+            // Batches are validated in order, so downloads are bounded to a window ahead of the next batch to validate
+            let lookahead = (helpers.len() + 1) * IBD_HELPER_WINDOW * 2;
+            let assignable_bound = next_batch + lookahead;
+            if !syncer_busy {
+                if let Some(index) = pop_assignable(&mut unassigned, assignable_bound) {
+                    syncer_sender.send(index).expect("the receiver is owned by the stream");
+                    syncer_busy = true;
+                }
+            }
+            for (helper_index, state) in helpers.iter_mut().enumerate().filter(|(_, state)| !state.retired) {
+                while state.pending < IBD_HELPER_WINDOW {
+                    let Some(index) = pop_assignable(&mut unassigned, assignable_bound) else { break };
+                    let (response_sender, response_receiver) = oneshot::channel();
+                    let job = IbdHelperJob::Bodies(IbdBodiesJob {
+                        consensus: consensus.clone(),
+                        high,
+                        hashes: batches[index].to_vec(),
+                        response: response_sender,
+                    });
+                    if state.helper.try_assign(job).is_err() {
+                        unassigned.insert(index);
+                        break;
+                    }
+                    state.pending += 1;
+                    helper_downloads
+                        .push(timeout(IBD_HELPER_STALL_TIMEOUT, response_receiver).map(move |result| (helper_index, index, result)));
+                }
+            }
+
+            let validation = async {
+                match validating.front_mut() {
+                    Some(batch) => (&mut batch.jobs).await,
+                    None => pending().await,
+                }
+            };
+
+            tokio::select! {
+                Some((index, result)) = syncer_downloads.next() => {
+                    syncer_busy = false;
+                    downloaded.insert(index, (result?, None));
+                }
+                Some((helper_index, index, result)) = helper_downloads.next() => {
+                    let state = &mut helpers[helper_index];
+                    state.pending -= 1;
+                    match result {
+                        Ok(Ok(IbdHelperResponse::Blocks(blocks))) => {
+                            if index >= next_batch && !downloaded.contains_key(&index) {
+                                downloaded.insert(index, (blocks, Some(state.helper.router.clone())));
+                                ctx.record_ibd_helper_job();
+                            }
+                        }
+                        result => {
+                            match result {
+                                Ok(Ok(_)) => debug!("IBD: peer {} does not have the block bodies of peer {}", state.helper.router, syncer),
+                                Ok(Err(_)) => debug!("IBD: helper peer {} disconnected", state.helper.router),
+                                Err(_) => warn!("IBD: helper peer {} stalled, reassigning its block batches", state.helper.router),
+                            }
+                            state.retired = true;
+                            if index >= next_batch && !downloaded.contains_key(&index) {
+                                unassigned.insert(index);
+                            }
+                        }
+                    }
+                }
+                result = validation => {
+                    let batch = validating.pop_front().expect("validation of an empty queue never completes");
+                    match (result, batch.helper) {
+                        (Ok(_), _) => progress_reporter.report(batch.len, batch.daa_score, batch.timestamp),
+                        // A body which does not match its header is not marked invalid, so it can be downloaded again from another peer
+                        (Err(RuleError::BadMerkleRoot(..)), Some(helper)) => {
+                            warn!("IBD: helper peer {} sent a block body which does not match its header, disconnecting", helper);
+                            helper.close().await;
+                            if syncer_busy {
+                                // Drain the pending syncer batch so its blocks do not leak into the next download round
+                                syncer_downloads.next().await.expect("the stream never ends").1?;
+                            }
+                            return Ok(BodiesDownloadOutcome::HelperMisbehaved);
+                        }
+                        (Err(err), _) => return Err(err.into()),
+                    }
+                }
+            }
+        }
+
+        progress_reporter.report_completion(0);
+        Ok(BodiesDownloadOutcome::Completed)
+    }
+
+    /// Serves `job` along with the body jobs already pending in the helper window, downloading the block bodies
+    /// requested by the IBD of another peer from this peer. Returns the first pending job of another kind, if any
+    pub(super) async fn serve_ibd_bodies_jobs(&mut self, job: IbdBodiesJob) -> Result<Option<IbdHelperJob>, ProtocolError> {
+        let mut jobs = vec![job];
+        let mut next_job = None;
+        while let Ok(job) = self.helper_receiver.try_recv() {
+            match job {
+                IbdHelperJob::Bodies(job) => jobs.push(job),
+                job => {
+                    next_job = Some(job);
+                    break;
+                }
+            }
+        }
+
+        let mut requested = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            if job.response.is_closed() {
+                // The IBD no longer waits for this batch
+                continue;
+            }
+            if !self.has_syncer_chain_block(&job.consensus, job.high).await? {
+                let _ = job.response.send(IbdHelperResponse::NotQualified);
+                continue;
+            }
+            // All requests are sent upfront so that the peer streams the batches back to back
+            self.request_ibd_blocks(&job.hashes).await?;
+            requested.push(job);
+        }
+
+        for job in requested {
+            let blocks = self.receive_ibd_blocks(&job.hashes).await?;
+            let _ = job.response.send(IbdHelperResponse::Blocks(blocks));
+        }
+        Ok(next_job)
+    }
+
+    /// Checks whether `syncer_chain_block` is on the selected chain of this peer, in which case the peer holds all the
+    /// block bodies in its past above the pruning point
+    async fn has_syncer_chain_block(&mut self, consensus: &ConsensusProxy, syncer_chain_block: Hash) -> Result<bool, ProtocolError> {
+        if let Some((checked_block, result)) = self.helper_qualification {
+            if checked_block == syncer_chain_block {
+                return Ok(result);
+            }
+        }
+        let negotiation_output = self.negotiate_missing_syncer_chain_segment(consensus).await?;
+        let result = match negotiation_output.highest_known_syncer_chain_hash {
+            Some(highest_known) => {
+                highest_known == syncer_chain_block || consensus.async_is_chain_ancestor_of(syncer_chain_block, highest_known).await?
+            }
+            None => false,
+        };
+        self.helper_qualification = Some((syncer_chain_block, result));
+        Ok(result)
+    }
+
+    async fn download_ibd_blocks(&mut self, hashes: &[Hash]) -> Result<Vec<Block>, ProtocolError> {
+        self.request_ibd_blocks(hashes).await?;
+        self.receive_ibd_blocks(hashes).await
+    }
+
+    async fn request_ibd_blocks(&self, hashes: &[Hash]) -> Result<(), ProtocolError> {
+        self.router
+            .enqueue(make_message!(
+                Payload::RequestIbdBlocks,
+                RequestIbdBlocksMessage { hashes: hashes.iter().map(|h| h.into()).collect() }
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn receive_ibd_blocks(&mut self, hashes: &[Hash]) -> Result<Vec<Block>, ProtocolError> {
+        let mut blocks = Vec::with_capacity(hashes.len());
+        for &expected_hash in hashes {
+            let msg = dequeue_with_timeout!(self.incoming_route, Payload::IbdBlock)?;
+            let block: Block = msg.try_into()?;
+            if block.hash() != expected_hash {
+                return Err(ProtocolError::OtherOwned(format!("expected block {} but got {}", expected_hash, block.hash())));
+            }
+            if block.is_header_only() {
+                return Err(ProtocolError::OtherOwned(format!("sent header of {} where expected block with body", block.hash())));
+            }
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+}
+
+/// Pops the lowest unassigned batch index if it is below `bound`
+fn pop_assignable(unassigned: &mut BTreeSet<usize>, bound: usize) -> Option<usize> {
+    match unassigned.first() {
+        Some(&first) if first < bound => unassigned.pop_first(),
+        _ => None,
+    }
+}
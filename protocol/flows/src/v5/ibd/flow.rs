@@ -1,11 +1,12 @@
 use crate::{
     flow_context::FlowContext,
+    flowcontext::ibd_helpers::IbdHelperJob,
     v5::{
         ibd::{HeadersChunkStream, TrustedEntryStream},
         Flow,
     },
 };
-use futures::future::{join_all, select, try_join_all, Either};
+use futures::future::{join_all, try_join_all};
 use spectre_consensus_core::{
     api::BlockValidationFuture,
     block::Block,
@@ -16,14 +17,13 @@ use spectre_consensus_core::{
 use spectre_consensusmanager::{spawn_blocking, ConsensusProxy, StagingConsensus};
 use spectre_core::{debug, info, time::unix_now, warn};
 use spectre_hashes::Hash;
-use spectre_muhash::MuHash;
 use spectre_p2p_lib::{
     common::ProtocolError,
     convert::model::trusted::TrustedDataPackage,
     dequeue_with_timeout, make_message,
     pb::{
-        spectred_message::Payload, RequestAntipastMessage, RequestHeadersMessage, RequestIbdBlocksMessage,
-        RequestPruningPointAndItsAnticoneMessage, RequestPruningPointProofMessage, RequestPruningPointUtxoSetMessage,
+        spectred_message::Payload, RequestAntipastMessage, RequestHeadersMessage, RequestPruningPointAndItsAnticoneMessage,
+        RequestPruningPointProofMessage,
     },
     IncomingRoute, Router,
 };
@@ -32,9 +32,9 @@ use std::{
     sync::Arc,
     time::{Duration, Instant},
 };
-use tokio::time::sleep;
+use tokio::sync::mpsc::Receiver;
 
-use super::{progress::ProgressReporter, HeadersChunk, PruningPointUtxosetChunkStream, IBD_BATCH_SIZE};
+use super::{progress::ProgressReporter, HeadersChunk};
 
 /// Flow for managing IBD - Initial Block Download
 pub struct IbdFlow {
@@ -44,6 +44,12 @@ pub struct IbdFlow {
 
     // Receives relay blocks from relay flow which are out of orphan resolution range and hence trigger IBD
     relay_receiver: JobReceiver<Block>,
+
+    // Receives block body and UTXO set download jobs from IBDs run with other peers
+    pub(super) helper_receiver: Receiver<IbdHelperJob>,
+
+    // The last syncer chain block this peer was checked against for serving helper jobs, along with the check result
+    pub(super) helper_qualification: Option<(Hash, bool)>,
 }
 
 #[async_trait::async_trait]
@@ -63,36 +69,50 @@ pub enum IbdType {
     DownloadHeadersProof,
 }
 
-struct QueueChunkOutput {
-    jobs: Vec<BlockValidationFuture>,
-    daa_score: u64,
-    timestamp: u64,
-}
 // TODO: define a peer banning strategy
 
 impl IbdFlow {
     pub fn new(ctx: FlowContext, router: Arc<Router>, incoming_route: IncomingRoute, relay_receiver: JobReceiver<Block>) -> Self {
-        Self { ctx, router, incoming_route, relay_receiver }
+        let helper_receiver = ctx.register_ibd_helper(router.clone());
+        Self { ctx, router, incoming_route, relay_receiver, helper_receiver, helper_qualification: None }
     }
 
     async fn start_impl(&mut self) -> Result<(), ProtocolError> {
-        while let Ok(relay_block) = self.relay_receiver.recv().await {
-            if let Some(_guard) = self.ctx.try_set_ibd_running(self.router.key(), relay_block.header.daa_score) {
-                info!("IBD started with peer {}", self.router);
-
-                match self.ibd(relay_block).await {
-                    Ok(_) => info!("IBD with peer {} completed successfully", self.router),
-                    Err(e) => {
-                        info!("IBD with peer {} completed with error: {}", self.router, e);
-                        return Err(e);
+        loop {
+            tokio::select! {
+                relay_block = self.relay_receiver.recv() => {
+                    let Ok(relay_block) = relay_block else { break };
+                    if let Some(_guard) = self.ctx.try_set_ibd_running(self.router.key(), relay_block.header.daa_score) {
+                        info!("IBD started with peer {}", self.router);
+
+                        match self.ibd(relay_block).await {
+                            Ok(_) => info!("IBD with peer {} completed successfully", self.router),
+                            Err(e) => {
+                                info!("IBD with peer {} completed with error: {}", self.router, e);
+                                return Err(e);
+                            }
+                        }
                     }
                 }
+                Some(job) = self.helper_receiver.recv() => self.serve_ibd_helper_jobs(job).await?,
             }
         }
 
         Ok(())
     }
 
+    /// Serves `job` along with the jobs already pending in the helper window
+    async fn serve_ibd_helper_jobs(&mut self, job: IbdHelperJob) -> Result<(), ProtocolError> {
+        let mut next_job = Some(job);
+        while let Some(job) = next_job.take() {
+            match job {
+                IbdHelperJob::Bodies(job) => next_job = self.serve_ibd_bodies_jobs(job).await?,
+                IbdHelperJob::PruningPointUtxoSet(job) => self.serve_ibd_utxo_set_job(job).await?,
+            }
+        }
+        Ok(())
+    }
+
     async fn ibd(&mut self, relay_block: Block) -> Result<(), ProtocolError> {
         let mut session = self.ctx.consensus().session().await;
 
@@ -479,108 +499,4 @@ staging selected tip ({}) is too small or negative. Aborting IBD...",
             Ok(())
         }
     }
-
-    async fn sync_pruning_point_utxoset(&mut self, consensus: &ConsensusProxy, pruning_point: Hash) -> Result<(), ProtocolError> {
-        self.router
-            .enqueue(make_message!(
-                Payload::RequestPruningPointUtxoSet,
-                RequestPruningPointUtxoSetMessage { pruning_point_hash: Some(pruning_point.into()) }
-            ))
-            .await?;
-        let mut chunk_stream = PruningPointUtxosetChunkStream::new(&self.router, &mut self.incoming_route);
-        let mut multiset = MuHash::new();
-        while let Some(chunk) = chunk_stream.next().await? {
-            multiset = consensus
-                .clone()
-                .spawn_blocking(move |c| {
-                    c.append_imported_pruning_point_utxos(&chunk, &mut multiset);
-                    multiset
-                })
-                .await;
-        }
-        consensus.clone().spawn_blocking(move |c| c.import_pruning_point_utxo_set(pruning_point, multiset)).await?;
-        Ok(())
-    }
-
-    async fn sync_missing_block_bodies(&mut self, consensus: &ConsensusProxy, high: Hash) -> Result<(), ProtocolError> {
-        // TODO: query consensus in batches
-        let sleep_task = sleep(Duration::from_secs(2));
-        let hashes_task = consensus.async_get_missing_block_body_hashes(high);
-        tokio::pin!(sleep_task);
-        tokio::pin!(hashes_task);
-        let hashes = match select(sleep_task, hashes_task).await {
-            Either::Left((_, hashes_task)) => {
-                // We select between the tasks in order to inform the user if this operation is taking too long. On full IBD
-                // this operation requires traversing the full DAG which indeed might take several seconds or even minutes.
-                info!(
-                    "IBD: searching for missing block bodies to request from peer {}. This operation might take several seconds.",
-                    self.router
-                );
-                // Now re-await the original task
-                hashes_task.await
-            }
-            Either::Right((hashes_result, _)) => hashes_result,
-        }?;
-        if hashes.is_empty() {
-            return Ok(());
-        }
-
-        let low_header = consensus.async_get_header(*hashes.first().expect("hashes was non empty")).await?;
-        let high_header = consensus.async_get_header(*hashes.last().expect("hashes was non empty")).await?;
-        let mut progress_reporter = ProgressReporter::new(low_header.daa_score, high_header.daa_score, "blocks");
-
-        let mut iter = hashes.chunks(IBD_BATCH_SIZE);
-        let QueueChunkOutput { jobs: mut prev_jobs, daa_score: mut prev_daa_score, timestamp: mut prev_timestamp } =
-            self.queue_block_processing_chunk(consensus, iter.next().expect("hashes was non empty")).await?;
-
-        for chunk in iter {
-            let QueueChunkOutput { jobs: current_jobs, daa_score: current_daa_score, timestamp: current_timestamp } =
-                self.queue_block_processing_chunk(consensus, chunk).await?;
-            let prev_chunk_len = prev_jobs.len();
-            // Join the previous chunk so that we always concurrently process a chunk and receive another
-            try_join_all(prev_jobs).await?;
-            // Log the progress
-            progress_reporter.report(prev_chunk_len, prev_daa_score, prev_timestamp);
-            prev_daa_score = current_daa_score;
-            prev_timestamp = current_timestamp;
-            prev_jobs = current_jobs;
-        }
-
-        let prev_chunk_len = prev_jobs.len();
-        try_join_all(prev_jobs).await?;
-        progress_reporter.report_completion(prev_chunk_len);
-
-        Ok(())
-    }
-
-    async fn queue_block_processing_chunk(
-        &mut self,
-        consensus: &ConsensusProxy,
-        chunk: &[Hash],
-    ) -> Result<QueueChunkOutput, ProtocolError> {
-        let mut jobs = Vec::with_capacity(chunk.len());
-        let mut current_daa_score = 0;
-        let mut current_timestamp = 0;
-        self.router
-            .enqueue(make_message!(
-                Payload::RequestIbdBlocks,
-                RequestIbdBlocksMessage { hashes: chunk.iter().map(|h| h.into()).collect() }
-            ))
-            .await?;
-        for &expected_hash in chunk {
-            let msg = dequeue_with_timeout!(self.incoming_route, Payload::IbdBlock)?;
-            let block: Block = msg.try_into()?;
-            if block.hash() != expected_hash {
-                return Err(ProtocolError::OtherOwned(format!("expected block {} but got {}", expected_hash, block.hash())));
-            }
-            if block.is_header_only() {
-                return Err(ProtocolError::OtherOwned(format!("sent header of {} where expected block with body", block.hash())));
-            }
-            current_daa_score = block.header.daa_score;
-            current_timestamp = block.header.timestamp;
-            jobs.push(consensus.validate_and_insert_block(block).virtual_state_task);
-        }
-
-        Ok(QueueChunkOutput { jobs, daa_score: current_daa_score, timestamp: current_timestamp })
-    }
 }
@@ -1,7 +1,9 @@
+mod bodies;
 mod flow;
 mod negotiate;
 mod progress;
 mod streams;
+mod utxoset;
 
 pub use flow::*;
 pub use streams::*;
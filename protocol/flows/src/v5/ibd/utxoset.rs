@@ -0,0 +1,238 @@
+use super::{IbdFlow, PruningPointUtxosetChunkStream, UtxosetChunk, IBD_BATCH_SIZE};
+use crate::flowcontext::ibd_helpers::{IbdHelper, IbdHelperJob, IbdHelperResponse, IbdUtxoSetJob};
+use futures::{stream::unfold, FutureExt, StreamExt};
+use spectre_consensus_core::errors::consensus::ConsensusError;
+use spectre_consensusmanager::ConsensusProxy;
+use spectre_core::{debug, info};
+use spectre_hashes::Hash;
+use spectre_muhash::MuHash;
+use spectre_p2p_lib::{
+    common::ProtocolError,
+    convert::model::utxo_set::{UtxoSetRange, UTXO_SET_RANGE_PROTOCOL_VERSION},
+    make_message,
+    pb::{spectred_message::Payload, RequestPruningPointUtxoSetMessage},
+    Router,
+};
+use std::collections::{BTreeSet, HashSet};
+use tokio::sync::{
+    mpsc::{channel, unbounded_channel, Receiver},
+    oneshot,
+};
+
+/// The number of UTXO set ranges per peer taking part in the download at its start. Using more ranges than peers
+/// balances the load between fast and slow peers
+const UTXO_SET_RANGES_PER_PEER: usize = 4;
+
+/// A stream of the chunks of a UTXO set range, failing if the peer sends a UTXO outside of the range
+struct UtxoSetRangeStream<'a, 'b> {
+    inner: PruningPointUtxosetChunkStream<'a, 'b>,
+    range: UtxoSetRange,
+}
+
+impl UtxoSetRangeStream<'_, '_> {
+    async fn next(&mut self) -> Result<Option<UtxosetChunk>, ProtocolError> {
+        let chunk = self.inner.next().await?;
+        if let Some((outpoint, _)) = chunk.iter().flatten().find(|(outpoint, _)| !self.range.contains(&outpoint.transaction_id)) {
+            return Err(ProtocolError::OtherOwned(format!("sent UTXO {} which is outside of the requested UTXO set range", outpoint)));
+        }
+        Ok(chunk)
+    }
+}
+
+struct HelperState {
+    helper: IbdHelper,
+    busy: bool,
+    retired: bool,
+}
+
+impl IbdFlow {
+    /// Downloads the pruning point UTXO set and imports it into the staging `consensus`.
+    ///
+    /// When the syncer serves ranged requests, the set is split into ranges of transaction ids which are downloaded
+    /// from the syncer and from any other connected peer serving ranged requests, while the chunks are imported here
+    /// as they arrive. A range whose helper peer fails or has another pruning point is reassigned, unless some of its
+    /// chunks were already imported, in which case the IBD fails since a partial import cannot be rolled back
+    pub(super) async fn sync_pruning_point_utxoset(
+        &mut self,
+        consensus: &ConsensusProxy,
+        pruning_point: Hash,
+    ) -> Result<(), ProtocolError> {
+        let ctx = self.ctx.clone();
+        let syncer = self.router.clone();
+        let serves_ranges = |router: &Router| router.properties().protocol_version >= UTXO_SET_RANGE_PROTOCOL_VERSION;
+        let ranges = if serves_ranges(&syncer) {
+            let helpers_count = ctx.ibd_helpers(syncer.key()).iter().filter(|helper| serves_ranges(&helper.router)).count();
+            UtxoSetRange::split(UTXO_SET_RANGES_PER_PEER * (helpers_count + 1))
+        } else {
+            vec![UtxoSetRange::default()]
+        };
+        let ranges_ref = &ranges;
+
+        let mut helpers: Vec<HelperState> = Vec::new();
+        let mut helper_keys = HashSet::new();
+        let mut unassigned = (0..ranges.len()).collect::<BTreeSet<_>>();
+        let mut completed = 0;
+        let mut multiset = MuHash::new();
+        let mut helper_downloads = futures::stream::FuturesUnordered::new();
+
+        // The syncer downloads and imports one range at a time through its own route
+        let (syncer_sender, syncer_receiver) = unbounded_channel::<usize>();
+        let mut syncer_busy = false;
+        let syncer_downloads = unfold((&mut *self, syncer_receiver), move |(flow, mut receiver)| async move {
+            let index = receiver.recv().await?;
+            let result = flow.download_utxo_set_range(consensus, pruning_point, ranges_ref[index]).await;
+            Some((result, (flow, receiver)))
+        });
+        tokio::pin!(syncer_downloads);
+
+        while completed < ranges.len() {
+            if !syncer_busy {
+                if let Some(index) = unassigned.pop_first() {
+                    syncer_sender.send(index).expect("the receiver is owned by the stream");
+                    syncer_busy = true;
+                }
+            }
+
+            if ranges.len() > 1 {
+                // Peers which connected since the last iteration join the download
+                for helper in ctx.ibd_helpers(syncer.key()) {
+                    if serves_ranges(&helper.router) && helper_keys.insert(helper.router.key()) {
+                        info!("IBD: peer {} joins the UTXO set download from peer {}", helper.router, syncer);
+                        helpers.push(HelperState { helper, busy: false, retired: false });
+                    }
+                }
+            }
+            for (helper_index, state) in helpers.iter_mut().enumerate().filter(|(_, state)| !state.busy && !state.retired) {
+                let Some(index) = unassigned.pop_first() else { break };
+                let (chunks_sender, chunks_receiver) = channel(IBD_BATCH_SIZE);
+                let (response_sender, response_receiver) = oneshot::channel();
+                let job = IbdUtxoSetJob { pruning_point, range: ranges[index], chunks: chunks_sender, response: response_sender };
+                if state.helper.try_assign(IbdHelperJob::PruningPointUtxoSet(job)).is_err() {
+                    unassigned.insert(index);
+                    continue;
+                }
+                state.busy = true;
+                helper_downloads.push(
+                    import_utxo_set_chunks(consensus.clone(), chunks_receiver)
+                        .then(move |(range_multiset, imported)| {
+                            response_receiver.map(move |response| (range_multiset, imported, response))
+                        })
+                        .map(move |result| (helper_index, index, result)),
+                );
+            }
+
+            tokio::select! {
+                Some(result) = syncer_downloads.next() => {
+                    syncer_busy = false;
+                    multiset.combine(&result?);
+                    completed += 1;
+                }
+                Some((helper_index, index, (range_multiset, imported, response))) = helper_downloads.next() => {
+                    let state = &mut helpers[helper_index];
+                    state.busy = false;
+                    match response {
+                        Ok(IbdHelperResponse::UtxoSetRange) => {
+                            multiset.combine(&range_multiset);
+                            completed += 1;
+                            ctx.record_ibd_helper_job();
+                        }
+                        _ if imported => {
+                            return Err(ProtocolError::OtherOwned(format!(
+                                "helper peer {} failed after part of its UTXO set range was imported",
+                                state.helper.router
+                            )));
+                        }
+                        response => {
+                            match response {
+                                Ok(_) => debug!("IBD: peer {} does not have the pruning point {}", state.helper.router, pruning_point),
+                                Err(_) => debug!("IBD: helper peer {} disconnected", state.helper.router),
+                            }
+                            state.retired = true;
+                            unassigned.insert(index);
+                        }
+                    }
+                }
+            }
+        }
+
+        info!("IBD: finished receiving the UTXO set from peer {} in {} ranges", syncer, ranges.len());
+        consensus.clone().spawn_blocking(move |c| c.import_pruning_point_utxo_set(pruning_point, multiset)).await?;
+        Ok(())
+    }
+
+    /// Downloads `range` of the pruning point UTXO set from this peer, imports it into `consensus` and returns its multiset
+    async fn download_utxo_set_range(
+        &mut self,
+        consensus: &ConsensusProxy,
+        pruning_point: Hash,
+        range: UtxoSetRange,
+    ) -> Result<MuHash, ProtocolError> {
+        let mut stream = self.request_utxo_set_range(pruning_point, range).await?;
+        let mut multiset = MuHash::new();
+        while let Some(chunk) = stream.next().await? {
+            multiset = consensus
+                .clone()
+                .spawn_blocking(move |c| {
+                    c.append_imported_pruning_point_utxos(&chunk, &mut multiset);
+                    multiset
+                })
+                .await;
+        }
+        Ok(multiset)
+    }
+
+    /// Serves a UTXO set range requested by the IBD of another peer, forwarding the chunks downloaded from this peer
+    pub(super) async fn serve_ibd_utxo_set_job(&mut self, job: IbdUtxoSetJob) -> Result<(), ProtocolError> {
+        if job.response.is_closed() {
+            // The IBD no longer waits for this range
+            return Ok(());
+        }
+        let mut stream = self.request_utxo_set_range(job.pruning_point, job.range).await?;
+        loop {
+            match stream.next().await {
+                Ok(Some(chunk)) => {
+                    // The stream is drained even if the IBD stopped waiting, so that the peer completes the request
+                    let _ = job.chunks.send(chunk).await;
+                }
+                Ok(None) => {
+                    let _ = job.response.send(IbdHelperResponse::UtxoSetRange);
+                    return Ok(());
+                }
+                // The peer is honest, only with another pruning point
+                Err(ProtocolError::ConsensusError(ConsensusError::UnexpectedPruningPoint)) => {
+                    let _ = job.response.send(IbdHelperResponse::NotQualified);
+                    return Ok(());
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    async fn request_utxo_set_range(
+        &mut self,
+        pruning_point: Hash,
+        range: UtxoSetRange,
+    ) -> Result<UtxoSetRangeStream<'_, '_>, ProtocolError> {
+        let request: RequestPruningPointUtxoSetMessage = (pruning_point, range).into();
+        self.router.enqueue(make_message!(Payload::RequestPruningPointUtxoSet, request)).await?;
+        Ok(UtxoSetRangeStream { inner: PruningPointUtxosetChunkStream::new(&self.router, &mut self.incoming_route), range })
+    }
+}
+
+/// Imports the UTXO set chunks forwarded by a helper peer into `consensus` until the helper drops the sender.
+/// Returns the multiset of the imported UTXOs and whether any chunk was imported
+async fn import_utxo_set_chunks(consensus: ConsensusProxy, mut chunks: Receiver<UtxosetChunk>) -> (MuHash, bool) {
+    let mut multiset = MuHash::new();
+    let mut imported = false;
+    while let Some(chunk) = chunks.recv().await {
+        imported = true;
+        multiset = consensus
+            .clone()
+            .spawn_blocking(move |c| {
+                c.append_imported_pruning_point_utxos(&chunk, &mut multiset);
+                multiset
+            })
+            .await;
+    }
+    (multiset, imported)
+}
@@ -5,6 +5,7 @@ use spectre_core::debug;
 use spectre_hashes::Hash;
 use spectre_p2p_lib::{
     common::ProtocolError,
+    convert::model::utxo_set::UtxoSetRange,
     dequeue, make_message,
     pb::{
         spectred_message::Payload, DonePruningPointUtxoSetChunksMessage, PruningPointUtxoSetChunkMessage,
@@ -38,14 +39,14 @@ impl RequestPruningPointUtxoSetFlow {
 
     async fn start_impl(&mut self) -> Result<(), ProtocolError> {
         loop {
-            let expected_pp = dequeue!(self.incoming_route, Payload::RequestPruningPointUtxoSet)?.try_into()?;
-            self.handle_request(expected_pp).await?
+            let (expected_pp, range) = dequeue!(self.incoming_route, Payload::RequestPruningPointUtxoSet)?.try_into()?;
+            self.handle_request(expected_pp, range).await?
         }
     }
 
-    async fn handle_request(&mut self, expected_pp: Hash) -> Result<(), ProtocolError> {
+    async fn handle_request(&mut self, expected_pp: Hash, range: UtxoSetRange) -> Result<(), ProtocolError> {
         const CHUNK_SIZE: usize = 1000;
-        let mut from_outpoint = None;
+        let mut from_outpoint = range.first_outpoint();
         let mut chunks_sent = 0;
 
         let consensus = self.ctx.consensus();
@@ -60,6 +61,12 @@ impl RequestPruningPointUtxoSetFlow {
                 }?;
             debug!("Retrieved {} UTXOs for pruning point {}", pruning_point_utxos.len(), expected_pp);
 
+            // The set is ordered by transaction id, so the range ends with the first UTXO falling outside of it
+            let retrieved = pruning_point_utxos.len();
+            let pruning_point_utxos =
+                pruning_point_utxos.into_iter().take_while(|(outpoint, _)| range.contains(&outpoint.transaction_id)).collect_vec();
+            let range_exhausted = pruning_point_utxos.len() < retrieved;
+
             // Send the chunk
             self.router
                 .enqueue(make_message!(
@@ -81,7 +88,7 @@ impl RequestPruningPointUtxoSetFlow {
             }
 
             // This indicates that there are no more entries to query
-            if pruning_point_utxos.len() < CHUNK_SIZE {
+            if range_exhausted || pruning_point_utxos.len() < CHUNK_SIZE {
                 return self.send_done_message(expected_pp).await;
             }
 
@@ -2,6 +2,7 @@ use super::{
     error::ConversionError,
     model::{
         trusted::{TrustedDataEntry, TrustedDataPackage},
+        utxo_set::UtxoSetRange,
         version::Version,
     },
     option::TryIntoOptionEx,
@@ -37,6 +38,16 @@ impl From<Version> for protowire::VersionMessage {
     }
 }
 
+impl From<(Hash, UtxoSetRange)> for protowire::RequestPruningPointUtxoSetMessage {
+    fn from((pruning_point, range): (Hash, UtxoSetRange)) -> Self {
+        Self {
+            pruning_point_hash: Some(pruning_point.into()),
+            from_transaction_id: range.from.map(Into::into),
+            to_transaction_id: range.to.map(Into::into),
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // protowire to consensus_core
 // ----------------------------------------------------------------------------
@@ -135,11 +146,13 @@ impl TryFrom<protowire::PruningPointUtxoSetChunkMessage> for Vec<(TransactionOut
     }
 }
 
-impl TryFrom<protowire::RequestPruningPointUtxoSetMessage> for Hash {
+impl TryFrom<protowire::RequestPruningPointUtxoSetMessage> for (Hash, UtxoSetRange) {
     type Error = ConversionError;
 
     fn try_from(msg: protowire::RequestPruningPointUtxoSetMessage) -> Result<Self, Self::Error> {
-        msg.pruning_point_hash.try_into_ex()
+        let from = msg.from_transaction_id.map(TryInto::try_into).transpose()?;
+        let to = msg.to_transaction_id.map(TryInto::try_into).transpose()?;
+        Ok((msg.pruning_point_hash.try_into_ex()?, UtxoSetRange::new(from, to)))
     }
 }
 
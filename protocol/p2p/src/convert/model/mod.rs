@@ -1,2 +1,3 @@
 pub mod trusted;
+pub mod utxo_set;
 pub mod version;
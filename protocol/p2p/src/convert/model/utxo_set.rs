@@ -0,0 +1,80 @@
+//!
+//! Model structures related to the download of the pruning point UTXO set during IBD
+//!
+
+use spectre_consensus_core::tx::{TransactionId, TransactionOutpoint};
+use spectre_hashes::HASH_SIZE;
+
+/// The minimal protocol version of a peer serving requests for a range of the pruning point UTXO set
+pub const UTXO_SET_RANGE_PROTOCOL_VERSION: u32 = 7;
+
+/// A range of the pruning point UTXO set, made of the outpoints whose transaction id lies in `[from, to)`,
+/// where a `None` bound leaves the range unbounded on that side.
+///
+/// The UTXO set is ordered by outpoint, hence by transaction id first, so a range maps to a contiguous
+/// segment of the set. Since transaction ids are uniformly distributed, ranges of equal id spans hold
+/// about as many UTXOs each
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UtxoSetRange {
+    pub from: Option<TransactionId>,
+    pub to: Option<TransactionId>,
+}
+
+impl UtxoSetRange {
+    pub fn new(from: Option<TransactionId>, to: Option<TransactionId>) -> Self {
+        Self { from, to }
+    }
+
+    /// Splits the whole UTXO set into `count` consecutive ranges of equal transaction id spans
+    pub fn split(count: usize) -> Vec<Self> {
+        assert!(count > 0, "the UTXO set is split into at least one range");
+        let bound = |i: usize| {
+            // Bounds are spread over the 64 most significant bits of the transaction id
+            let mut bytes = [0u8; HASH_SIZE];
+            bytes[..8].copy_from_slice(&(((i as u128) << 64) / count as u128).to_be_bytes()[8..]);
+            TransactionId::from_bytes(bytes)
+        };
+        (0..count).map(|i| Self::new((i > 0).then(|| bound(i)), (i + 1 < count).then(|| bound(i + 1)))).collect()
+    }
+
+    /// Returns the first outpoint of the range in the UTXO set order, or `None` if the range starts with the set
+    pub fn first_outpoint(&self) -> Option<TransactionOutpoint> {
+        self.from.map(|from| TransactionOutpoint::new(from, 0))
+    }
+
+    pub fn contains(&self, transaction_id: &TransactionId) -> bool {
+        self.from.is_none_or(|from| from <= *transaction_id) && self.to.is_none_or(|to| *transaction_id < to)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utxo_set_range_split() {
+        assert_eq!(UtxoSetRange::split(1), vec![UtxoSetRange::default()]);
+        assert!(UtxoSetRange::split(1)[0].is_full());
+
+        let ranges = UtxoSetRange::split(3);
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(ranges[0].from, None);
+        assert_eq!(ranges[2].to, None);
+        for window in ranges.windows(2) {
+            assert_eq!(window[0].to, window[1].from);
+            assert!(window[0].to.unwrap() < window[1].to.unwrap_or(TransactionId::from_bytes([u8::MAX; HASH_SIZE])));
+        }
+
+        // Every transaction id belongs to exactly one range
+        for byte in [0u8, 0x55, 0x56, 0xaa, 0xab, u8::MAX] {
+            let id = TransactionId::from_bytes([byte; HASH_SIZE]);
+            assert_eq!(ranges.iter().filter(|range| range.contains(&id)).count(), 1, "transaction id {id}");
+        }
+        assert!(ranges[1].contains(&ranges[1].first_outpoint().unwrap().transaction_id));
+        assert!(!ranges[0].contains(&ranges[1].first_outpoint().unwrap().transaction_id));
+    }
+}
@@ -1,68 +1,98 @@
+//!
+//! A mock of the RPC core, serving notifications through a real notifier while most calls are not implemented.
+//! Available with the `testing` feature, for testing the components built on top of [`RpcApi`].
+//!
+
+use crate::{
+    api::{connection::DynRpcConnection, ctl::RpcCtl, rpc::RpcApi},
+    notify::connection::ChannelConnection,
+    *,
+};
 use async_channel::{unbounded, Receiver};
 use async_trait::async_trait;
-use spectre_notify::events::EVENT_TYPE_ARRAY;
-use spectre_notify::listener::{ListenerId, ListenerLifespan};
-use spectre_notify::notifier::{Notifier, Notify};
-use spectre_notify::scope::Scope;
-use spectre_notify::subscription::context::SubscriptionContext;
-use spectre_notify::subscription::{MutationPolicies, UtxosChangedMutationPolicy};
-use spectre_rpc_core::{api::connection::DynRpcConnection, api::rpc::RpcApi, *};
-use spectre_rpc_core::{notify::connection::ChannelConnection, RpcResult};
-use std::sync::Arc;
-
-pub(super) type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
-
-pub(super) struct RpcCoreMock {
+use spectre_notify::{
+    events::EVENT_TYPE_ARRAY,
+    listener::{ListenerId, ListenerLifespan},
+    notifier::{Notifier, Notify},
+    scope::Scope,
+    subscription::{context::SubscriptionContext, MutationPolicies, UtxosChangedMutationPolicy},
+};
+use std::sync::{Arc, Mutex};
+
+pub type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
+
+pub struct RpcCoreMock {
     core_notifier: Arc<RpcCoreNotifier>,
-    _sync_receiver: Receiver<()>,
+    sync_receiver: Receiver<()>,
+    ctl: RpcCtl,
+    /// The block template served by `get_block_template`, which is not implemented if not set
+    block_template: Option<RpcRawBlock>,
+    submitted_blocks: Mutex<Vec<RpcRawBlock>>,
 }
 
 impl RpcCoreMock {
-    pub(super) fn new() -> Self {
+    pub fn new() -> Self {
         let (sync_sender, sync_receiver) = unbounded();
         let policies = MutationPolicies::new(UtxosChangedMutationPolicy::AddressSet);
-        let subscription_context = SubscriptionContext::new();
         let core_notifier: Arc<RpcCoreNotifier> = Arc::new(Notifier::with_sync(
             "rpc-core",
             EVENT_TYPE_ARRAY[..].into(),
             vec![],
             vec![],
-            subscription_context,
+            SubscriptionContext::new(),
             10,
             policies,
             Some(sync_sender),
         ));
-        Self { core_notifier, _sync_receiver: sync_receiver }
+        Self { core_notifier, sync_receiver, ctl: RpcCtl::new(), block_template: None, submitted_blocks: Default::default() }
     }
 
-    pub(super) fn core_notifier(&self) -> Arc<RpcCoreNotifier> {
+    /// Serves `block_template` and accepts any submitted block
+    pub fn with_block_template(block_template: RpcRawBlock) -> Self {
+        Self { block_template: Some(block_template), ..Self::new() }
+    }
+
+    pub fn core_notifier(&self) -> Arc<RpcCoreNotifier> {
         self.core_notifier.clone()
     }
 
-    pub(super) fn subscription_context(&self) -> SubscriptionContext {
+    pub fn subscription_context(&self) -> SubscriptionContext {
         self.core_notifier.subscription_context().clone()
     }
 
-    #[allow(dead_code)]
-    pub(super) fn notify_new_block_template(&self) -> spectre_notify::error::Result<()> {
+    pub fn ctl(&self) -> RpcCtl {
+        self.ctl.clone()
+    }
+
+    pub fn submitted_blocks(&self) -> Vec<RpcRawBlock> {
+        self.submitted_blocks.lock().unwrap().clone()
+    }
+
+    pub fn notify_new_block_template(&self) -> spectre_notify::error::Result<()> {
         let notification = Notification::NewBlockTemplate(NewBlockTemplateNotification {});
         self.core_notifier.notify(notification)
     }
 
-    #[allow(dead_code)]
-    pub(super) async fn notify_complete(&self) {
-        assert!(self._sync_receiver.recv().await.is_ok(), "the notifier sync channel is unexpectedly empty and closed");
+    /// Waits for the notifier to complete the processing of a notification
+    pub async fn notify_complete(&self) {
+        assert!(self.sync_receiver.recv().await.is_ok(), "the notifier sync channel is unexpectedly empty and closed");
     }
 
-    pub(super) fn start(&self) {
+    pub fn start(&self) {
         self.core_notifier.clone().start();
     }
 
-    pub(super) async fn join(&self) {
+    pub async fn join(&self) {
         self.core_notifier.join().await.expect("core notifier shutdown")
     }
 }
 
+impl Default for RpcCoreMock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl RpcApi for RpcCoreMock {
     // This fn needs to succeed while the client connects
@@ -133,9 +163,13 @@ impl RpcApi for RpcCoreMock {
     async fn submit_block_call(
         &self,
         _connection: Option<&DynRpcConnection>,
-        _request: SubmitBlockRequest,
+        request: SubmitBlockRequest,
     ) -> RpcResult<SubmitBlockResponse> {
-        Err(RpcError::NotImplemented)
+        if self.block_template.is_none() {
+            return Err(RpcError::NotImplemented);
+        }
+        self.submitted_blocks.lock().unwrap().push(request.block);
+        Ok(SubmitBlockResponse { report: SubmitBlockReport::Success })
     }
 
     async fn get_block_template_call(
@@ -143,7 +177,10 @@ impl RpcApi for RpcCoreMock {
         _connection: Option<&DynRpcConnection>,
         _request: GetBlockTemplateRequest,
     ) -> RpcResult<GetBlockTemplateResponse> {
-        Err(RpcError::NotImplemented)
+        match &self.block_template {
+            Some(block) => Ok(GetBlockTemplateResponse { block: block.clone(), is_synced: true }),
+            None => Err(RpcError::NotImplemented),
+        }
     }
 
     async fn get_peer_addresses_call(
@@ -262,6 +299,94 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn invalidate_block_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: InvalidateBlockRequest,
+    ) -> RpcResult<InvalidateBlockResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn reconsider_block_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: ReconsiderBlockRequest,
+    ) -> RpcResult<ReconsiderBlockResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn generate_blocks_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GenerateBlocksRequest,
+    ) -> RpcResult<GenerateBlocksResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn create_backup_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: CreateBackupRequest,
+    ) -> RpcResult<CreateBackupResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_database_stats_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetDatabaseStatsRequest,
+    ) -> RpcResult<GetDatabaseStatsResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_chain_block_by_blue_score_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetChainBlockByBlueScoreRequest,
+    ) -> RpcResult<GetChainBlockByBlueScoreResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_chain_block_by_daa_score_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetChainBlockByDaaScoreRequest,
+    ) -> RpcResult<GetChainBlockByDaaScoreResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_chain_blocks_by_blue_score_range_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetChainBlocksByBlueScoreRangeRequest,
+    ) -> RpcResult<GetChainBlocksByBlueScoreRangeResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_chain_blocks_by_daa_score_range_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetChainBlocksByDaaScoreRangeRequest,
+    ) -> RpcResult<GetChainBlocksByDaaScoreRangeResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn test_mempool_accept_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: TestMempoolAcceptRequest,
+    ) -> RpcResult<TestMempoolAcceptResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn decode_transaction_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: DecodeTransactionRequest,
+    ) -> RpcResult<DecodeTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     async fn shutdown_call(&self, _connection: Option<&DynRpcConnection>, _request: ShutdownRequest) -> RpcResult<ShutdownResponse> {
         Err(RpcError::NotImplemented)
     }
@@ -370,6 +495,22 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn prioritize_transaction_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: PrioritizeTransactionRequest,
+    ) -> RpcResult<PrioritizeTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_prioritized_transactions_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetPrioritizedTransactionsRequest,
+    ) -> RpcResult<GetPrioritizedTransactionsResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
@@ -4,6 +4,8 @@
 
 pub mod connection;
 pub mod ctl;
+#[cfg(feature = "testing")]
+pub mod mock;
 pub mod notifications;
 pub mod ops;
 pub mod rpc;
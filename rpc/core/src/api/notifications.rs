@@ -10,7 +10,9 @@ use spectre_notify::{
     notification::{full_featured, Notification as NotificationTrait},
     subscription::{
         context::SubscriptionContext,
-        single::{OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription},
+        single::{
+            AcceptedTransactionsChangedSubscription, OverallSubscription, UtxosChangedSubscription, VirtualChainChangedSubscription,
+        },
         Subscription,
     },
 };
@@ -48,6 +50,9 @@ pub enum Notification {
 
     #[display(fmt = "NewBlockTemplate notification")]
     NewBlockTemplate(NewBlockTemplateNotification),
+
+    #[display(fmt = "AcceptedTransactionsChanged notification: {} accepting blocks, {} unaccepting blocks, incomplete: {}", "_0.accepted.len()", "_0.unaccepted.len()", "_0.incomplete")]
+    AcceptedTransactionsChanged(AcceptedTransactionsChangedNotification),
 }
 }
 
@@ -64,6 +69,7 @@ impl Notification {
             Notification::VirtualDaaScoreChanged(v) => to_value(&v),
             Notification::SinkBlueScoreChanged(v) => to_value(&v),
             Notification::VirtualChainChanged(v) => to_value(&v),
+            Notification::AcceptedTransactionsChanged(v) => to_value(&v),
         }
     }
 }
@@ -112,6 +118,22 @@ impl NotificationTrait for Notification {
         }
     }
 
+    fn apply_accepted_transactions_changed_subscription(
+        &self,
+        subscription: &AcceptedTransactionsChangedSubscription,
+        context: &SubscriptionContext,
+    ) -> Option<Self> {
+        match subscription.active() {
+            true => {
+                let Self::AcceptedTransactionsChanged(notification) = self else { return None };
+                notification
+                    .apply_accepted_transactions_changed_subscription(subscription, context)
+                    .map(Self::AcceptedTransactionsChanged)
+            }
+            false => None,
+        }
+    }
+
     fn event_type(&self) -> EventType {
         self.into()
     }
@@ -157,6 +179,10 @@ impl Serializer for Notification {
                 store!(u16, &8, writer)?;
                 serialize!(NewBlockTemplateNotification, notification, writer)?;
             }
+            Notification::AcceptedTransactionsChanged(notification) => {
+                store!(u16, &9, writer)?;
+                serialize!(AcceptedTransactionsChangedNotification, notification, writer)?;
+            }
         }
         Ok(())
     }
@@ -202,6 +228,10 @@ impl Deserializer for Notification {
                 let notification = deserialize!(NewBlockTemplateNotification, reader)?;
                 Ok(Notification::NewBlockTemplate(notification))
             }
+            9 => {
+                let notification = deserialize!(AcceptedTransactionsChangedNotification, reader)?;
+                Ok(Notification::AcceptedTransactionsChanged(notification))
+            }
             _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid variant")),
         }
     }
@@ -40,6 +40,7 @@ pub enum RpcApiOps {
     NotifyVirtualDaaScoreChanged = 16,
     NotifyVirtualChainChanged = 17,
     NotifySinkBlueScoreChanged = 18,
+    NotifyAcceptedTransactionsChanged = 19,
 
     // Notification ops required by wRPC
 
@@ -54,6 +55,7 @@ pub enum RpcApiOps {
     VirtualDaaScoreChangedNotification = 66,
     PruningPointUtxoSetOverrideNotification = 67,
     NewBlockTemplateNotification = 68,
+    AcceptedTransactionsChangedNotification = 69,
 
     // RPC methods
     /// Ping the node to check if connection is alive
@@ -138,6 +140,32 @@ pub enum RpcApiOps {
     GetCurrentBlockColor = 149,
     /// Get UTXO Return Addresses
     GetUtxoReturnAddress = 150,
+    /// Set the priority delta of a transaction for block template selection
+    PrioritizeTransaction = 151,
+    /// Get the priority deltas of all prioritized transactions
+    GetPrioritizedTransactions = 152,
+    /// Disqualify a block from the virtual chain
+    InvalidateBlock = 153,
+    /// Revert the disqualification of a block and its future from the virtual chain
+    ReconsiderBlock = 154,
+    /// Generate blocks on demand (simnet and devnet only)
+    GenerateBlocks = 155,
+    /// Create a backup of the node databases
+    CreateBackup = 156,
+    /// Get the size and cache statistics of the node databases
+    GetDatabaseStats = 157,
+    /// Get the chain block covering a blue score
+    GetChainBlockByBlueScore = 158,
+    /// Get the chain block covering a DAA score
+    GetChainBlockByDaaScore = 159,
+    /// Get the chain blocks within a blue score range
+    GetChainBlocksByBlueScoreRange = 160,
+    /// Get the chain blocks within a DAA score range
+    GetChainBlocksByDaaScoreRange = 161,
+    /// Test whether the mempool would accept a batch of transactions, without inserting them
+    TestMempoolAccept = 162,
+    /// Decode a serialized transaction and optionally verify its input scripts
+    DecodeTransaction = 163,
 }
 
 impl RpcApiOps {
@@ -153,6 +181,7 @@ impl RpcApiOps {
                 | RpcApiOps::NotifyFinalityConflictResolved
                 | RpcApiOps::NotifySinkBlueScoreChanged
                 | RpcApiOps::NotifyVirtualDaaScoreChanged
+                | RpcApiOps::NotifyAcceptedTransactionsChanged
                 | RpcApiOps::Subscribe
                 | RpcApiOps::Unsubscribe
         )
@@ -179,6 +208,7 @@ impl From<EventType> for RpcApiOps {
             EventType::VirtualDaaScoreChanged => RpcApiOps::VirtualDaaScoreChangedNotification,
             EventType::PruningPointUtxoSetOverride => RpcApiOps::PruningPointUtxoSetOverrideNotification,
             EventType::NewBlockTemplate => RpcApiOps::NewBlockTemplateNotification,
+            EventType::AcceptedTransactionsChanged => RpcApiOps::AcceptedTransactionsChangedNotification,
         }
     }
 }
@@ -14,6 +14,9 @@ use spectre_notify::{listener::ListenerId, scope::Scope, subscription::Command};
 use std::sync::Arc;
 
 pub const MAX_SAFE_WINDOW_SIZE: u32 = 10_000;
+pub const MAX_CHAIN_BLOCKS_RANGE_SIZE: usize = 1_000;
+pub const MAX_TEST_MEMPOOL_ACCEPT_TRANSACTIONS: usize = 100;
+pub const MAX_GENERATE_BLOCKS_COUNT: u32 = 1_000;
 
 /// Client RPC Api
 ///
@@ -71,6 +74,7 @@ pub trait RpcApi: Sync + Send + AnySync {
                 consensus_metrics,
                 storage_metrics,
                 custom_metrics,
+                stratum_metrics: false,
             },
         )
         .await
@@ -226,6 +230,47 @@ pub trait RpcApi: Sync + Send + AnySync {
         request: SubmitTransactionReplacementRequest,
     ) -> RpcResult<SubmitTransactionReplacementResponse>;
 
+    /// Tests whether the mempool would accept a batch of transactions, submitted one after the other,
+    /// without inserting any of them. A transaction may spend the outputs of preceding transactions of
+    /// the batch. At most [`MAX_TEST_MEMPOOL_ACCEPT_TRANSACTIONS`] can be tested at once.
+    ///
+    /// When `replace_by_fee` is set, the transactions are tested as submitted replacements.
+    async fn test_mempool_accept(
+        &self,
+        transactions: Vec<RpcTransaction>,
+        allow_orphan: bool,
+        replace_by_fee: bool,
+    ) -> RpcResult<Vec<RpcMempoolAcceptResult>> {
+        Ok(self
+            .test_mempool_accept_call(None, TestMempoolAcceptRequest::new(transactions, allow_orphan, replace_by_fee))
+            .await?
+            .results)
+    }
+    async fn test_mempool_accept_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: TestMempoolAcceptRequest,
+    ) -> RpcResult<TestMempoolAcceptResponse>;
+
+    /// Decodes a serialized transaction, reporting its masses as well as the class and disassembly of its
+    /// output scripts.
+    ///
+    /// When `verify_scripts` is set, the script of every input is also executed against the supplied
+    /// `utxo_entries`, in input order, or against the entries found by the node if none are supplied.
+    async fn decode_transaction(
+        &self,
+        transaction: RpcSerializedTransaction,
+        verify_scripts: bool,
+        utxo_entries: Vec<RpcUtxoEntry>,
+    ) -> RpcResult<DecodeTransactionResponse> {
+        self.decode_transaction_call(None, DecodeTransactionRequest::new(transaction, verify_scripts, utxo_entries)).await
+    }
+    async fn decode_transaction_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: DecodeTransactionRequest,
+    ) -> RpcResult<DecodeTransactionResponse>;
+
     /// Requests information about a specific block.
     async fn get_block(&self, hash: RpcHash, include_transactions: bool) -> RpcResult<RpcBlock> {
         Ok(self.get_block_call(None, GetBlockRequest::new(hash, include_transactions)).await?.block)
@@ -304,6 +349,126 @@ pub trait RpcApi: Sync + Send + AnySync {
         request: ResolveFinalityConflictRequest,
     ) -> RpcResult<ResolveFinalityConflictResponse>;
 
+    /// Disqualifies a block from the virtual chain, moving the sink to the best chain not containing it.
+    ///
+    /// The block must be in the future of the virtual finality point.
+    async fn invalidate_block(&self, hash: RpcHash) -> RpcResult<()> {
+        self.invalidate_block_call(None, InvalidateBlockRequest::new(hash)).await?;
+        Ok(())
+    }
+    async fn invalidate_block_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: InvalidateBlockRequest,
+    ) -> RpcResult<InvalidateBlockResponse>;
+
+    /// Reverts the disqualification of a block and of its future from the virtual chain, undoing previous invalidations.
+    ///
+    /// The block must be in the future of the virtual finality point.
+    async fn reconsider_block(&self, hash: RpcHash) -> RpcResult<()> {
+        self.reconsider_block_call(None, ReconsiderBlockRequest::new(hash)).await?;
+        Ok(())
+    }
+    async fn reconsider_block_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: ReconsiderBlockRequest,
+    ) -> RpcResult<ReconsiderBlockResponse>;
+
+    /// Mines `count` blocks paying to `pay_address` within the node, returning their hashes. The first block holds exactly
+    /// the mempool transactions `transaction_ids` if any are specified. At most [`MAX_GENERATE_BLOCKS_COUNT`] blocks
+    /// can be generated at once.
+    ///
+    /// Only available on simnet and devnet.
+    async fn generate_blocks(
+        &self,
+        count: u32,
+        pay_address: RpcAddress,
+        transaction_ids: Vec<RpcTransactionId>,
+    ) -> RpcResult<Vec<RpcHash>> {
+        Ok(self.generate_blocks_call(None, GenerateBlocksRequest::new(count, pay_address, transaction_ids)).await?.block_hashes)
+    }
+    async fn generate_blocks_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GenerateBlocksRequest,
+    ) -> RpcResult<GenerateBlocksResponse>;
+
+    /// Creates a consistent backup of the node databases within the backup directory of the node, returning the
+    /// path of the backup.
+    ///
+    /// Requires the node to run with `--unsaferpc`.
+    async fn create_backup(&self) -> RpcResult<String> {
+        Ok(self.create_backup_call(None, CreateBackupRequest {}).await?.path)
+    }
+    async fn create_backup_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: CreateBackupRequest,
+    ) -> RpcResult<CreateBackupResponse>;
+
+    /// Requests the RocksDB statistics of the node databases, including the approximate on-disk usage of
+    /// every store, and the cache statistics of the stores.
+    async fn get_database_stats(&self) -> RpcResult<GetDatabaseStatsResponse> {
+        self.get_database_stats_call(None, GetDatabaseStatsRequest {}).await
+    }
+    async fn get_database_stats_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetDatabaseStatsRequest,
+    ) -> RpcResult<GetDatabaseStatsResponse>;
+
+    /// Requests the first selected chain block whose blue score reaches `blue_score`.
+    async fn get_chain_block_by_blue_score(&self, blue_score: u64) -> RpcResult<RpcChainBlock> {
+        Ok(self.get_chain_block_by_blue_score_call(None, GetChainBlockByBlueScoreRequest::new(blue_score)).await?.chain_block)
+    }
+    async fn get_chain_block_by_blue_score_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetChainBlockByBlueScoreRequest,
+    ) -> RpcResult<GetChainBlockByBlueScoreResponse>;
+
+    /// Requests the first selected chain block whose DAA score reaches `daa_score`.
+    async fn get_chain_block_by_daa_score(&self, daa_score: u64) -> RpcResult<RpcChainBlock> {
+        Ok(self.get_chain_block_by_daa_score_call(None, GetChainBlockByDaaScoreRequest::new(daa_score)).await?.chain_block)
+    }
+    async fn get_chain_block_by_daa_score_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetChainBlockByDaaScoreRequest,
+    ) -> RpcResult<GetChainBlockByDaaScoreResponse>;
+
+    /// Requests the selected chain blocks with a blue score within `[low_blue_score, high_blue_score]`, up to
+    /// [`MAX_CHAIN_BLOCKS_RANGE_SIZE`] of them.
+    async fn get_chain_blocks_by_blue_score_range(&self, low_blue_score: u64, high_blue_score: u64) -> RpcResult<Vec<RpcChainBlock>> {
+        Ok(self
+            .get_chain_blocks_by_blue_score_range_call(
+                None,
+                GetChainBlocksByBlueScoreRangeRequest::new(low_blue_score, high_blue_score),
+            )
+            .await?
+            .chain_blocks)
+    }
+    async fn get_chain_blocks_by_blue_score_range_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetChainBlocksByBlueScoreRangeRequest,
+    ) -> RpcResult<GetChainBlocksByBlueScoreRangeResponse>;
+
+    /// Requests the selected chain blocks with a DAA score within `[low_daa_score, high_daa_score]`, up to
+    /// [`MAX_CHAIN_BLOCKS_RANGE_SIZE`] of them.
+    async fn get_chain_blocks_by_daa_score_range(&self, low_daa_score: u64, high_daa_score: u64) -> RpcResult<Vec<RpcChainBlock>> {
+        Ok(self
+            .get_chain_blocks_by_daa_score_range_call(None, GetChainBlocksByDaaScoreRangeRequest::new(low_daa_score, high_daa_score))
+            .await?
+            .chain_blocks)
+    }
+    async fn get_chain_blocks_by_daa_score_range_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetChainBlocksByDaaScoreRangeRequest,
+    ) -> RpcResult<GetChainBlocksByDaaScoreRangeResponse>;
+
     /// Shuts down this node.
     async fn shutdown(&self) -> RpcResult<()> {
         self.shutdown_call(None, ShutdownRequest {}).await?;
@@ -419,6 +584,29 @@ pub trait RpcApi: Sync + Send + AnySync {
         request: GetMempoolEntriesByAddressesRequest,
     ) -> RpcResult<GetMempoolEntriesByAddressesResponse>;
 
+    /// Sets the priority delta of a transaction, honored when selecting the transactions of subsequent block templates.
+    ///
+    /// A zero fee delta with no exclusion clears any previously set delta. Deltas are persisted by the node.
+    async fn prioritize_transaction(&self, transaction_id: RpcTransactionId, fee_delta: i64, excluded: bool) -> RpcResult<()> {
+        self.prioritize_transaction_call(None, PrioritizeTransactionRequest::new(transaction_id, fee_delta, excluded)).await?;
+        Ok(())
+    }
+    async fn prioritize_transaction_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: PrioritizeTransactionRequest,
+    ) -> RpcResult<PrioritizeTransactionResponse>;
+
+    /// Requests the priority deltas of all prioritized transactions.
+    async fn get_prioritized_transactions(&self) -> RpcResult<Vec<RpcPrioritizedTransaction>> {
+        Ok(self.get_prioritized_transactions_call(None, GetPrioritizedTransactionsRequest {}).await?.prioritized_transactions)
+    }
+    async fn get_prioritized_transactions_call(
+        &self,
+        connection: Option<&DynRpcConnection>,
+        request: GetPrioritizedTransactionsRequest,
+    ) -> RpcResult<GetPrioritizedTransactionsResponse>;
+
     ///
     async fn get_coin_supply(&self) -> RpcResult<GetCoinSupplyResponse> {
         self.get_coin_supply_call(None, GetCoinSupplyRequest {}).await
@@ -1,9 +1,10 @@
 //! Conversion of Notification related types
 
 use crate::{
-    convert::utxo::utxo_set_into_rpc, BlockAddedNotification, FinalityConflictNotification, FinalityConflictResolvedNotification,
-    NewBlockTemplateNotification, Notification, PruningPointUtxoSetOverrideNotification, RpcAcceptedTransactionIds,
-    SinkBlueScoreChangedNotification, UtxosChangedNotification, VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
+    convert::utxo::utxo_set_into_rpc, AcceptedTransactionsChangedNotification, BlockAddedNotification, FinalityConflictNotification,
+    FinalityConflictResolvedNotification, NewBlockTemplateNotification, Notification, PruningPointUtxoSetOverrideNotification,
+    RpcAcceptedTransactionIds, SinkBlueScoreChangedNotification, UtxosChangedNotification, VirtualChainChangedNotification,
+    VirtualDaaScoreChangedNotification,
 };
 use spectre_consensus_notify::notification as consensus_notify;
 use spectre_index_core::notification as index_notify;
@@ -31,6 +32,7 @@ impl From<&consensus_notify::Notification> for Notification {
             consensus_notify::Notification::VirtualDaaScoreChanged(msg) => Notification::VirtualDaaScoreChanged(msg.into()),
             consensus_notify::Notification::PruningPointUtxoSetOverride(msg) => Notification::PruningPointUtxoSetOverride(msg.into()),
             consensus_notify::Notification::NewBlockTemplate(msg) => Notification::NewBlockTemplate(msg.into()),
+            consensus_notify::Notification::AcceptedTransactionsChanged(msg) => Notification::AcceptedTransactionsChanged(msg.into()),
         }
     }
 }
@@ -112,6 +114,14 @@ impl From<&consensus_notify::NewBlockTemplateNotification> for NewBlockTemplateN
     }
 }
 
+impl From<&consensus_notify::AcceptedTransactionsChangedNotification> for AcceptedTransactionsChangedNotification {
+    // This is not intended to be ever called because the accepted transactions must be fetched from consensus.
+    // Use spectre_rpc_service::converter::consensus::ConsensusConverter instead.
+    fn from(_: &consensus_notify::AcceptedTransactionsChangedNotification) -> Self {
+        AcceptedTransactionsChangedNotification::default()
+    }
+}
+
 // ----------------------------------------------------------------------------
 // index to rpc_core
 // ----------------------------------------------------------------------------
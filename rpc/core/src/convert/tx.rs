@@ -1,6 +1,7 @@
 //! Conversion of Transaction related types
 
-use crate::{RpcError, RpcResult, RpcTransaction, RpcTransactionInput, RpcTransactionOutput};
+use crate::{RpcError, RpcResult, RpcSerializedTransaction, RpcTransaction, RpcTransactionInput, RpcTransactionOutput};
+use borsh::BorshDeserialize;
 use spectre_consensus_core::tx::{Transaction, TransactionInput, TransactionOutput};
 
 // ----------------------------------------------------------------------------
@@ -88,3 +89,73 @@ impl TryFrom<RpcTransactionInput> for TransactionInput {
         Ok(Self::new(item.previous_outpoint.into(), item.signature_script, item.sequence, item.sig_op_count))
     }
 }
+
+impl TryFrom<&RpcSerializedTransaction> for Transaction {
+    type Error = RpcError;
+    fn try_from(item: &RpcSerializedTransaction) -> RpcResult<Self> {
+        match item {
+            RpcSerializedTransaction::Hex(hex) => {
+                let mut bytes = vec![0u8; hex.len() / 2];
+                faster_hex::hex_decode(hex.as_bytes(), bytes.as_mut_slice())?;
+                Transaction::try_from(&RpcSerializedTransaction::Borsh(bytes))
+            }
+            RpcSerializedTransaction::Borsh(bytes) => {
+                let mut transaction =
+                    Transaction::try_from_slice(bytes).map_err(|err| RpcError::TransactionDecodingError(err.to_string()))?;
+                // The Borsh encoding carries the cached id, which must not be trusted
+                let encoded_id = transaction.id();
+                transaction.finalize();
+                if transaction.id() != encoded_id {
+                    return Err(RpcError::TransactionDecodingError(format!(
+                        "encoded transaction id {} does not match the transaction id {}",
+                        encoded_id,
+                        transaction.id()
+                    )));
+                }
+                Ok(transaction)
+            }
+            RpcSerializedTransaction::Json(json) => serde_json::from_str::<RpcTransaction>(json)
+                .map_err(|err| RpcError::TransactionDecodingError(err.to_string()))?
+                .try_into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spectre_consensus_core::{
+        subnets::SUBNETWORK_ID_NATIVE,
+        tx::{ScriptPublicKey, TransactionOutpoint},
+    };
+
+    fn transaction() -> Transaction {
+        Transaction::new(
+            0,
+            vec![TransactionInput::new(TransactionOutpoint::new(Default::default(), 1), vec![1, 2, 3], 0, 1)],
+            vec![TransactionOutput::new(1000, ScriptPublicKey::from_vec(0, vec![0xac]))],
+            0,
+            SUBNETWORK_ID_NATIVE,
+            0,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_serialized_transaction_id() {
+        let tx = transaction();
+        let bytes = borsh::to_vec(&tx).unwrap();
+        let decoded = Transaction::try_from(&RpcSerializedTransaction::Borsh(bytes.clone())).unwrap();
+        assert_eq!(decoded.id(), tx.id());
+        let decoded = Transaction::try_from(&RpcSerializedTransaction::Hex(faster_hex::hex_string(&bytes))).unwrap();
+        assert_eq!(decoded.id(), tx.id());
+
+        // The id is the last encoded field
+        let mut tampered = bytes;
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            Transaction::try_from(&RpcSerializedTransaction::Borsh(tampered)),
+            Err(RpcError::TransactionDecodingError(_))
+        ));
+    }
+}
@@ -1,10 +1,12 @@
 //! Conversion functions for UTXO related types.
 
 use crate::RpcUtxoEntry;
-use crate::RpcUtxosByAddressesEntry;
+use crate::{RpcAddress, RpcUtxoOrder, RpcUtxosByAddressesCursor, RpcUtxosByAddressesEntry};
 use spectre_addresses::Prefix;
-use spectre_index_core::indexed_utxos::UtxoSetByScriptPublicKey;
+use spectre_consensus_core::tx::ScriptPublicKey;
+use spectre_index_core::indexed_utxos::{UtxoOrder, UtxoPage, UtxoSetByScriptPublicKey};
 use spectre_txscript::extract_script_pub_key_address;
+use std::collections::HashMap;
 
 // ----------------------------------------------------------------------------
 // index to rpc_core
@@ -25,3 +27,45 @@ pub fn utxo_set_into_rpc(item: &UtxoSetByScriptPublicKey, prefix: Option<Prefix>
         })
         .collect::<Vec<_>>()
 }
+
+/// Converts a page of indexed utxos into the entries and the next cursor of a `GetUtxosByAddresses` response,
+/// `addresses` mapping the script public keys of the page back to the requested addresses
+pub fn utxo_page_into_rpc(
+    page: UtxoPage,
+    addresses: &HashMap<ScriptPublicKey, RpcAddress>,
+) -> (Vec<RpcUtxosByAddressesEntry>, Option<RpcUtxosByAddressesCursor>) {
+    let entries = page
+        .entries
+        .into_iter()
+        .map(|utxo| RpcUtxosByAddressesEntry {
+            address: addresses.get(&utxo.script_public_key).cloned(),
+            outpoint: utxo.outpoint.into(),
+            utxo_entry: RpcUtxoEntry::new(
+                utxo.entry.amount,
+                utxo.script_public_key,
+                utxo.entry.block_daa_score,
+                utxo.entry.is_coinbase,
+            ),
+        })
+        .collect();
+    let next_cursor = page.next_cursor.map(|cursor| RpcUtxosByAddressesCursor {
+        address: addresses[&cursor.script_public_key].clone(),
+        outpoint: cursor.outpoint.into(),
+        block_daa_score: cursor.block_daa_score,
+    });
+    (entries, next_cursor)
+}
+
+// ----------------------------------------------------------------------------
+// rpc_core to index
+// ----------------------------------------------------------------------------
+
+impl From<RpcUtxoOrder> for UtxoOrder {
+    fn from(item: RpcUtxoOrder) -> Self {
+        match item {
+            RpcUtxoOrder::Outpoint => UtxoOrder::Outpoint,
+            RpcUtxoOrder::DaaScoreAscending => UtxoOrder::DaaScoreAscending,
+            RpcUtxoOrder::DaaScoreDescending => UtxoOrder::DaaScoreDescending,
+        }
+    }
+}
@@ -48,6 +48,9 @@ pub enum RpcError {
     #[error("Coinbase payload is above max length ({0}). Try to shorten the extra data.")]
     CoinbasePayloadLengthAboveMax(usize),
 
+    #[error("Invalid coinbase payload tag {0:?}: tags must be non-empty and must not contain '/'")]
+    InvalidPayloadTag(String),
+
     #[error("Rejected transaction {0}: {1}")]
     RejectedTransaction(RpcTransactionId, String),
 
@@ -84,6 +87,21 @@ pub enum RpcError {
     #[error("Block {0} doesn't have any merger block.")]
     MergerNotFound(RpcHash),
 
+    #[error("No chain block has reached {0} {1} yet.")]
+    ChainBlockNotReached(String, u64),
+
+    #[error("Requested {0} transactions, more than the max {1} allowed in a single mempool acceptance test.")]
+    TestMempoolAcceptTooManyTransactions(usize, usize),
+
+    #[error("Requested {0} blocks, more than the max {1} allowed to be generated in a single call.")]
+    GenerateBlocksTooManyBlocks(u32, u32),
+
+    #[error("Transaction could not be decoded: {0}")]
+    TransactionDecodingError(String),
+
+    #[error("{0} UTXO entries were supplied for a transaction with {1} inputs.")]
+    UtxoEntriesCountMismatch(usize, usize),
+
     #[error("Block was not submitted: {0}")]
     SubmitBlockError(SubmitBlockRejectReason),
 
@@ -1,4 +1,5 @@
 use crate::{RpcTransactionOutpoint, RpcUtxoEntry};
+use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use workflow_serializer::prelude::*;
 
@@ -32,6 +33,49 @@ impl Deserializer for RpcUtxosByAddressesEntry {
     }
 }
 
+/// Order of the entries returned by the `GetUtxosByAddresses` RPC.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+#[borsh(use_discriminant = true)]
+pub enum RpcUtxoOrder {
+    /// Grouped by address in request order, and ordered by outpoint within each address
+    #[default]
+    Outpoint = 0,
+    /// Ordered by block DAA score, then as in [`RpcUtxoOrder::Outpoint`]. Rejected if the requested addresses hold
+    /// more than 100,000 UTXOs
+    DaaScoreAscending = 1,
+    /// The reverse of [`RpcUtxoOrder::DaaScoreAscending`]
+    DaaScoreDescending = 2,
+}
+
+/// Position of an entry in the results of the `GetUtxosByAddresses` RPC, used to request the following page.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcUtxosByAddressesCursor {
+    pub address: RpcAddress,
+    pub outpoint: RpcTransactionOutpoint,
+    pub block_daa_score: u64,
+}
+
+impl Serializer for RpcUtxosByAddressesCursor {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u8, &1, writer)?; // version
+        store!(RpcAddress, &self.address, writer)?;
+        serialize!(RpcTransactionOutpoint, &self.outpoint, writer)?;
+        store!(u64, &self.block_daa_score, writer)
+    }
+}
+
+impl Deserializer for RpcUtxosByAddressesCursor {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version: u8 = load!(u8, reader)?;
+        let address = load!(RpcAddress, reader)?;
+        let outpoint = deserialize!(RpcTransactionOutpoint, reader)?;
+        let block_daa_score = load!(u64, reader)?;
+        Ok(Self { address, outpoint, block_daa_score })
+    }
+}
+
 /// Represents a balance of an address returned by the `GetBalancesByAddresses` RPC.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
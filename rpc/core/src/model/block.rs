@@ -176,3 +176,42 @@ cfg_if::cfg_if! {
         "#;
     }
 }
+
+/// Represents a block of the selected parent chain
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcChainBlock {
+    pub hash: RpcHash,
+    /// Position of the block in the selected parent chain stored by the node, which is only meaningful to this node
+    /// since counted from the first pruning point it synced from
+    pub chain_index: u64,
+    pub blue_score: u64,
+    pub daa_score: u64,
+    pub timestamp: u64,
+}
+
+impl Serializer for RpcChainBlock {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u8, &1, writer)?;
+        store!(RpcHash, &self.hash, writer)?;
+        store!(u64, &self.chain_index, writer)?;
+        store!(u64, &self.blue_score, writer)?;
+        store!(u64, &self.daa_score, writer)?;
+        store!(u64, &self.timestamp, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcChainBlock {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u8, reader)?;
+        let hash = load!(RpcHash, reader)?;
+        let chain_index = load!(u64, reader)?;
+        let blue_score = load!(u64, reader)?;
+        let daa_score = load!(u64, reader)?;
+        let timestamp = load!(u64, reader)?;
+
+        Ok(Self { hash, chain_index, blue_score, daa_score, timestamp })
+    }
+}
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use workflow_serializer::prelude::*;
+
+/// Approximate on-disk usage of the stores sharing a database prefix
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcStoreStats {
+    pub prefix: u8,
+    pub name: String,
+    pub approximate_key_count: u64,
+    pub approximate_size: u64,
+}
+
+impl Serializer for RpcStoreStats {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u8, &self.prefix, writer)?;
+        store!(String, &self.name, writer)?;
+        store!(u64, &self.approximate_key_count, writer)?;
+        store!(u64, &self.approximate_size, writer)
+    }
+}
+
+impl Deserializer for RpcStoreStats {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let prefix = load!(u8, reader)?;
+        let name = load!(String, reader)?;
+        let approximate_key_count = load!(u64, reader)?;
+        let approximate_size = load!(u64, reader)?;
+        Ok(Self { prefix, name, approximate_key_count, approximate_size })
+    }
+}
+
+/// RocksDB statistics of a node database
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcDatabaseStats {
+    /// Directory name of the database
+    pub name: String,
+    pub estimated_key_count: u64,
+    pub total_sst_files_size: u64,
+    pub live_sst_files_size: u64,
+    pub memtables_size: u64,
+    pub pending_compaction_bytes: u64,
+    pub running_compactions: u64,
+    pub is_compaction_pending: bool,
+    /// Number of table files at each level of the LSM tree
+    pub files_per_level: Vec<u64>,
+    /// Usage of the stores holding flushed data, approximated from the key ranges of the table files
+    pub stores: Vec<RpcStoreStats>,
+}
+
+impl Serializer for RpcDatabaseStats {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.name, writer)?;
+        store!(u64, &self.estimated_key_count, writer)?;
+        store!(u64, &self.total_sst_files_size, writer)?;
+        store!(u64, &self.live_sst_files_size, writer)?;
+        store!(u64, &self.memtables_size, writer)?;
+        store!(u64, &self.pending_compaction_bytes, writer)?;
+        store!(u64, &self.running_compactions, writer)?;
+        store!(bool, &self.is_compaction_pending, writer)?;
+        store!(Vec<u64>, &self.files_per_level, writer)?;
+        serialize!(Vec<RpcStoreStats>, &self.stores, writer)
+    }
+}
+
+impl Deserializer for RpcDatabaseStats {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let name = load!(String, reader)?;
+        let estimated_key_count = load!(u64, reader)?;
+        let total_sst_files_size = load!(u64, reader)?;
+        let live_sst_files_size = load!(u64, reader)?;
+        let memtables_size = load!(u64, reader)?;
+        let pending_compaction_bytes = load!(u64, reader)?;
+        let running_compactions = load!(u64, reader)?;
+        let is_compaction_pending = load!(bool, reader)?;
+        let files_per_level = load!(Vec<u64>, reader)?;
+        let stores = deserialize!(Vec<RpcStoreStats>, reader)?;
+        Ok(Self {
+            name,
+            estimated_key_count,
+            total_sst_files_size,
+            live_sst_files_size,
+            memtables_size,
+            pending_compaction_bytes,
+            running_compactions,
+            is_compaction_pending,
+            files_per_level,
+            stores,
+        })
+    }
+}
+
+/// Cache access counters of the stores sharing a database prefix, accumulated since the node started
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcStoreCacheStats {
+    pub prefix: u8,
+    pub name: String,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl RpcStoreCacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        self.hits as f64 / (self.hits + self.misses).max(1) as f64
+    }
+}
+
+impl Serializer for RpcStoreCacheStats {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u8, &self.prefix, writer)?;
+        store!(String, &self.name, writer)?;
+        store!(u64, &self.hits, writer)?;
+        store!(u64, &self.misses, writer)
+    }
+}
+
+impl Deserializer for RpcStoreCacheStats {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let prefix = load!(u8, reader)?;
+        let name = load!(String, reader)?;
+        let hits = load!(u64, reader)?;
+        let misses = load!(u64, reader)?;
+        Ok(Self { prefix, name, hits, misses })
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "wasm32-sdk")] {
+        use wasm_bindgen::prelude::*;
+
+        #[wasm_bindgen(typescript_custom_section)]
+        const TS_DATABASE_STATS: &'static str = r#"
+            /**
+             * Approximate on-disk usage of the stores sharing a database prefix.
+             *
+             * @category Node RPC
+             */
+            export interface IStoreStats {
+                prefix : number;
+                name : string;
+                approximateKeyCount : bigint;
+                approximateSize : bigint;
+            }
+
+            /**
+             * RocksDB statistics of a node database.
+             *
+             * @category Node RPC
+             */
+            export interface IDatabaseStats {
+                name : string;
+                estimatedKeyCount : bigint;
+                totalSstFilesSize : bigint;
+                liveSstFilesSize : bigint;
+                memtablesSize : bigint;
+                pendingCompactionBytes : bigint;
+                runningCompactions : bigint;
+                isCompactionPending : boolean;
+                filesPerLevel : bigint[];
+                stores : IStoreStats[];
+            }
+
+            /**
+             * Cache access counters of the stores sharing a database prefix.
+             *
+             * @category Node RPC
+             */
+            export interface IStoreCacheStats {
+                prefix : number;
+                name : string;
+                hits : bigint;
+                misses : bigint;
+            }
+        "#;
+    }
+}
@@ -63,6 +63,19 @@ impl Deserializer for RpcFeeEstimate {
     }
 }
 
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcHistoricalFeerateEstimate {
+    /// The inclusion time target in seconds
+    pub target_seconds: f64,
+
+    /// The fraction of the historically observed transactions paying at least `feerate` which were included within `target_seconds`
+    pub confidence: f64,
+
+    /// The lowest fee/mass ratio meeting the target with the given confidence
+    pub feerate: f64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RpcFeeEstimateVerboseExperimentalData {
@@ -73,30 +86,41 @@ pub struct RpcFeeEstimateVerboseExperimentalData {
     pub next_block_template_feerate_min: f64,
     pub next_block_template_feerate_median: f64,
     pub next_block_template_feerate_max: f64,
+
+    /// Decayed count of the included, expired and evicted transactions the historical estimates are built from
+    pub historical_sample_count: f64,
+
+    /// Feerates historically required for given inclusion time targets at several confidence levels.
+    /// Targets and confidence levels lacking enough observations are omitted.
+    pub historical_estimates: Vec<RpcHistoricalFeerateEstimate>,
 }
 
 impl Serializer for RpcFeeEstimateVerboseExperimentalData {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(u64, &self.mempool_ready_transactions_count, writer)?;
         store!(u64, &self.mempool_ready_transactions_total_mass, writer)?;
         store!(u64, &self.network_mass_per_second, writer)?;
         store!(f64, &self.next_block_template_feerate_min, writer)?;
         store!(f64, &self.next_block_template_feerate_median, writer)?;
         store!(f64, &self.next_block_template_feerate_max, writer)?;
+        store!(f64, &self.historical_sample_count, writer)?;
+        store!(Vec<RpcHistoricalFeerateEstimate>, &self.historical_estimates, writer)?;
         Ok(())
     }
 }
 
 impl Deserializer for RpcFeeEstimateVerboseExperimentalData {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let mempool_ready_transactions_count = load!(u64, reader)?;
         let mempool_ready_transactions_total_mass = load!(u64, reader)?;
         let network_mass_per_second = load!(u64, reader)?;
         let next_block_template_feerate_min = load!(f64, reader)?;
         let next_block_template_feerate_median = load!(f64, reader)?;
         let next_block_template_feerate_max = load!(f64, reader)?;
+        let (historical_sample_count, historical_estimates) =
+            if version > 1 { (load!(f64, reader)?, load!(Vec<RpcHistoricalFeerateEstimate>, reader)?) } else { Default::default() };
         Ok(Self {
             mempool_ready_transactions_count,
             mempool_ready_transactions_total_mass,
@@ -104,6 +128,8 @@ impl Deserializer for RpcFeeEstimateVerboseExperimentalData {
             next_block_template_feerate_min,
             next_block_template_feerate_median,
             next_block_template_feerate_max,
+            historical_sample_count,
+            historical_estimates,
         })
     }
 }
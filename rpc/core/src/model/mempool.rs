@@ -1,5 +1,6 @@
 use super::RpcAddress;
 use super::RpcTransaction;
+use super::RpcTransactionId;
 use serde::{Deserialize, Serialize};
 use workflow_serializer::prelude::*;
 
@@ -63,6 +64,102 @@ impl Deserializer for RpcMempoolEntryByAddress {
     }
 }
 
+/// An operator-defined adjustment of a mempool transaction, honored when selecting block template transactions
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPrioritizedTransaction {
+    pub transaction_id: RpcTransactionId,
+    pub fee_delta: i64,
+    pub excluded: bool,
+}
+
+impl RpcPrioritizedTransaction {
+    pub fn new(transaction_id: RpcTransactionId, fee_delta: i64, excluded: bool) -> Self {
+        Self { transaction_id, fee_delta, excluded }
+    }
+}
+
+impl Serializer for RpcPrioritizedTransaction {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcTransactionId, &self.transaction_id, writer)?;
+        store!(i64, &self.fee_delta, writer)?;
+        store!(bool, &self.excluded, writer)
+    }
+}
+
+impl Deserializer for RpcPrioritizedTransaction {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let transaction_id = load!(RpcTransactionId, reader)?;
+        let fee_delta = load!(i64, reader)?;
+        let excluded = load!(bool, reader)?;
+        Ok(Self { transaction_id, fee_delta, excluded })
+    }
+}
+
+/// Outcome of testing whether the mempool would accept a transaction
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMempoolAcceptResult {
+    pub transaction_id: RpcTransactionId,
+    /// Whether the transaction would be accepted, either by the mempool or by the orphan pool
+    pub allowed: bool,
+    pub is_orphan: bool,
+    /// The rule error the transaction would be rejected with
+    pub reject_reason: Option<String>,
+    pub compute_mass: u64,
+    /// Only known once the transaction was validated against the UTXO set
+    pub storage_mass: Option<u64>,
+    /// Only known once the transaction was validated against the UTXO set
+    pub fee: Option<u64>,
+    /// Fee per gram of the full contextual (compute & storage) mass
+    pub feerate: Option<f64>,
+    /// The mempool transaction that would be replaced by fee
+    pub replaced_transaction_id: Option<RpcTransactionId>,
+}
+
+impl Serializer for RpcMempoolAcceptResult {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcTransactionId, &self.transaction_id, writer)?;
+        store!(bool, &self.allowed, writer)?;
+        store!(bool, &self.is_orphan, writer)?;
+        store!(Option<String>, &self.reject_reason, writer)?;
+        store!(u64, &self.compute_mass, writer)?;
+        store!(Option<u64>, &self.storage_mass, writer)?;
+        store!(Option<u64>, &self.fee, writer)?;
+        store!(Option<f64>, &self.feerate, writer)?;
+        store!(Option<RpcTransactionId>, &self.replaced_transaction_id, writer)
+    }
+}
+
+impl Deserializer for RpcMempoolAcceptResult {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let transaction_id = load!(RpcTransactionId, reader)?;
+        let allowed = load!(bool, reader)?;
+        let is_orphan = load!(bool, reader)?;
+        let reject_reason = load!(Option<String>, reader)?;
+        let compute_mass = load!(u64, reader)?;
+        let storage_mass = load!(Option<u64>, reader)?;
+        let fee = load!(Option<u64>, reader)?;
+        let feerate = load!(Option<f64>, reader)?;
+        let replaced_transaction_id = load!(Option<RpcTransactionId>, reader)?;
+        Ok(Self {
+            transaction_id,
+            allowed,
+            is_orphan,
+            reject_reason,
+            compute_mass,
+            storage_mass,
+            fee,
+            feerate,
+            replaced_transaction_id,
+        })
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "wasm32-sdk")] {
         use wasm_bindgen::prelude::*;
@@ -80,5 +177,39 @@ cfg_if::cfg_if! {
                 isOrphan : boolean;
             }
         "#;
+
+        #[wasm_bindgen(typescript_custom_section)]
+        const TS_PRIORITIZED_TRANSACTION: &'static str = r#"
+            /**
+             * Priority delta of a transaction, honored when selecting block template transactions.
+             * 
+             * @category Node RPC
+             */
+            export interface IPrioritizedTransaction {
+                transactionId : HexString;
+                feeDelta : bigint;
+                excluded : boolean;
+            }
+        "#;
+
+        #[wasm_bindgen(typescript_custom_section)]
+        const TS_MEMPOOL_ACCEPT_RESULT: &'static str = r#"
+            /**
+             * Outcome of testing whether the mempool would accept a transaction.
+             * 
+             * @category Node RPC
+             */
+            export interface IMempoolAcceptResult {
+                transactionId : HexString;
+                allowed : boolean;
+                isOrphan : boolean;
+                rejectReason? : string;
+                computeMass : bigint;
+                storageMass? : bigint;
+                fee? : bigint;
+                feerate? : number;
+                replacedTransactionId? : HexString;
+            }
+        "#;
     }
 }
@@ -3,7 +3,11 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use spectre_consensus_core::api::stats::BlockCount;
 use spectre_core::debug;
-use spectre_notify::subscription::{context::SubscriptionContext, single::UtxosChangedSubscription, Command};
+use spectre_notify::subscription::{
+    context::SubscriptionContext,
+    single::{AcceptedTransactionsChangedSubscription, UtxosChangedSubscription},
+    Command,
+};
 use spectre_utils::hex::ToHex;
 use std::collections::HashMap;
 use std::{
@@ -123,18 +127,27 @@ pub struct GetBlockTemplateRequest {
     pub pay_address: RpcAddress,
     // TODO: replace with hex serialization
     pub extra_data: RpcExtraData,
+    /// Tags appended to the coinbase payload of this template, after the extra data and each one preceded by a `/`,
+    /// e.g. to identify the pool and the worker the template is built for. Tags must be non-empty and must not contain `/`
+    #[serde(default)]
+    pub payload_tags: Vec<String>,
 }
 impl GetBlockTemplateRequest {
     pub fn new(pay_address: RpcAddress, extra_data: RpcExtraData) -> Self {
-        Self { pay_address, extra_data }
+        Self { pay_address, extra_data, payload_tags: vec![] }
+    }
+
+    pub fn with_payload_tags(self, payload_tags: Vec<String>) -> Self {
+        Self { payload_tags, ..self }
     }
 }
 
 impl Serializer for GetBlockTemplateRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(RpcAddress, &self.pay_address, writer)?;
         store!(RpcExtraData, &self.extra_data, writer)?;
+        store!(Vec<String>, &self.payload_tags, writer)?;
 
         Ok(())
     }
@@ -142,11 +155,12 @@ impl Serializer for GetBlockTemplateRequest {
 
 impl Deserializer for GetBlockTemplateRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let pay_address = load!(RpcAddress, reader)?;
         let extra_data = load!(RpcExtraData, reader)?;
+        let payload_tags = if version > 1 { load!(Vec<String>, reader)? } else { vec![] };
 
-        Ok(Self { pay_address, extra_data })
+        Ok(Self { pay_address, extra_data, payload_tags })
     }
 }
 
@@ -859,19 +873,35 @@ impl Deserializer for GetSubnetworkResponse {
 pub struct GetVirtualChainFromBlockRequest {
     pub start_hash: RpcHash,
     pub include_accepted_transaction_ids: bool,
+    /// Maximal number of added chain blocks returned, further bounded by the node
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// Includes the full accepted transactions along with the UTXO entries spent by their inputs
+    #[serde(default)]
+    pub include_accepted_transactions: bool,
 }
 
 impl GetVirtualChainFromBlockRequest {
     pub fn new(start_hash: RpcHash, include_accepted_transaction_ids: bool) -> Self {
-        Self { start_hash, include_accepted_transaction_ids }
+        Self { start_hash, include_accepted_transaction_ids, limit: None, include_accepted_transactions: false }
+    }
+
+    pub fn with_limit(self, limit: u32) -> Self {
+        Self { limit: Some(limit), ..self }
+    }
+
+    pub fn with_accepted_transactions(self) -> Self {
+        Self { include_accepted_transactions: true, ..self }
     }
 }
 
 impl Serializer for GetVirtualChainFromBlockRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(RpcHash, &self.start_hash, writer)?;
         store!(bool, &self.include_accepted_transaction_ids, writer)?;
+        store!(Option<u32>, &self.limit, writer)?;
+        store!(bool, &self.include_accepted_transactions, writer)?;
 
         Ok(())
     }
@@ -879,11 +909,13 @@ impl Serializer for GetVirtualChainFromBlockRequest {
 
 impl Deserializer for GetVirtualChainFromBlockRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let start_hash = load!(RpcHash, reader)?;
         let include_accepted_transaction_ids = load!(bool, reader)?;
+        let (limit, include_accepted_transactions) =
+            if version > 1 { (load!(Option<u32>, reader)?, load!(bool, reader)?) } else { (None, false) };
 
-        Ok(Self { start_hash, include_accepted_transaction_ids })
+        Ok(Self { start_hash, include_accepted_transaction_ids, limit, include_accepted_transactions })
     }
 }
 
@@ -893,6 +925,13 @@ pub struct GetVirtualChainFromBlockResponse {
     pub removed_chain_block_hashes: Vec<RpcHash>,
     pub added_chain_block_hashes: Vec<RpcHash>,
     pub accepted_transaction_ids: Vec<RpcAcceptedTransactionIds>,
+    /// The transactions accepted by each added chain block, filled only if `include_accepted_transactions` is set
+    #[serde(default)]
+    pub accepted_transactions: Vec<RpcAcceptedTransactions>,
+    /// Set if the added chain blocks do not reach the sink, the remaining ones being obtained by a request
+    /// with this hash as `start_hash`
+    #[serde(default)]
+    pub next_start_hash: Option<RpcHash>,
 }
 
 impl GetVirtualChainFromBlockResponse {
@@ -900,17 +939,21 @@ impl GetVirtualChainFromBlockResponse {
         removed_chain_block_hashes: Vec<RpcHash>,
         added_chain_block_hashes: Vec<RpcHash>,
         accepted_transaction_ids: Vec<RpcAcceptedTransactionIds>,
+        accepted_transactions: Vec<RpcAcceptedTransactions>,
+        next_start_hash: Option<RpcHash>,
     ) -> Self {
-        Self { removed_chain_block_hashes, added_chain_block_hashes, accepted_transaction_ids }
+        Self { removed_chain_block_hashes, added_chain_block_hashes, accepted_transaction_ids, accepted_transactions, next_start_hash }
     }
 }
 
 impl Serializer for GetVirtualChainFromBlockResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(Vec<RpcHash>, &self.removed_chain_block_hashes, writer)?;
         store!(Vec<RpcHash>, &self.added_chain_block_hashes, writer)?;
         store!(Vec<RpcAcceptedTransactionIds>, &self.accepted_transaction_ids, writer)?;
+        serialize!(Vec<RpcAcceptedTransactions>, &self.accepted_transactions, writer)?;
+        store!(Option<RpcHash>, &self.next_start_hash, writer)?;
 
         Ok(())
     }
@@ -918,12 +961,23 @@ impl Serializer for GetVirtualChainFromBlockResponse {
 
 impl Deserializer for GetVirtualChainFromBlockResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let removed_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
         let added_chain_block_hashes = load!(Vec<RpcHash>, reader)?;
         let accepted_transaction_ids = load!(Vec<RpcAcceptedTransactionIds>, reader)?;
+        let (accepted_transactions, next_start_hash) = if version > 1 {
+            (deserialize!(Vec<RpcAcceptedTransactions>, reader)?, load!(Option<RpcHash>, reader)?)
+        } else {
+            (vec![], None)
+        };
 
-        Ok(Self { removed_chain_block_hashes, added_chain_block_hashes, accepted_transaction_ids })
+        Ok(Self {
+            removed_chain_block_hashes,
+            added_chain_block_hashes,
+            accepted_transaction_ids,
+            accepted_transactions,
+            next_start_hash,
+        })
     }
 }
 
@@ -1109,66 +1163,766 @@ impl Deserializer for GetBlockDagInfoResponse {
         let virtual_daa_score = load!(u64, reader)?;
         let sink = load!(RpcHash, reader)?;
 
-        Ok(Self {
-            network,
-            block_count,
-            header_count,
-            tip_hashes,
-            difficulty,
-            past_median_time,
-            virtual_parent_hashes,
-            pruning_point_hash,
-            virtual_daa_score,
-            sink,
-        })
+        Ok(Self {
+            network,
+            block_count,
+            header_count,
+            tip_hashes,
+            difficulty,
+            past_median_time,
+            virtual_parent_hashes,
+            pruning_point_hash,
+            virtual_daa_score,
+            sink,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveFinalityConflictRequest {
+    pub finality_block_hash: RpcHash,
+}
+
+impl ResolveFinalityConflictRequest {
+    pub fn new(finality_block_hash: RpcHash) -> Self {
+        Self { finality_block_hash }
+    }
+}
+
+impl Serializer for ResolveFinalityConflictRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.finality_block_hash, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for ResolveFinalityConflictRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let finality_block_hash = load!(RpcHash, reader)?;
+
+        Ok(Self { finality_block_hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveFinalityConflictResponse {}
+
+impl Serializer for ResolveFinalityConflictResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for ResolveFinalityConflictResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+/// InvalidateBlockRequest disqualifies a block from the virtual chain, so that the virtual chain
+/// moves to the best chain not containing it. Chain blocks in its future get disqualified as well.
+/// The block must be in the future of the virtual finality point.
+///
+/// The resulting virtual chain change is notified as usual.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidateBlockRequest {
+    pub hash: RpcHash,
+}
+
+impl InvalidateBlockRequest {
+    pub fn new(hash: RpcHash) -> Self {
+        Self { hash }
+    }
+}
+
+impl Serializer for InvalidateBlockRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.hash, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for InvalidateBlockRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let hash = load!(RpcHash, reader)?;
+
+        Ok(Self { hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidateBlockResponse {}
+
+impl Serializer for InvalidateBlockResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for InvalidateBlockResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+/// ReconsiderBlockRequest reverts the disqualification of a block and of its future from the
+/// virtual chain, undoing any previous invalidation, and lets the virtual chain re-resolve.
+/// The block must be in the future of the virtual finality point.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconsiderBlockRequest {
+    pub hash: RpcHash,
+}
+
+impl ReconsiderBlockRequest {
+    pub fn new(hash: RpcHash) -> Self {
+        Self { hash }
+    }
+}
+
+impl Serializer for ReconsiderBlockRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcHash, &self.hash, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for ReconsiderBlockRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let hash = load!(RpcHash, reader)?;
+
+        Ok(Self { hash })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconsiderBlockResponse {}
+
+impl Serializer for ReconsiderBlockResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for ReconsiderBlockResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+/// GenerateBlocksRequest mines blocks within the node, building block templates, solving their
+/// proof of work and submitting them, so that tests can produce blocks on demand without an
+/// external miner. Blocks are mined one after the other, each one over the previous.
+///
+/// Only available on simnet and devnet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateBlocksRequest {
+    pub count: u32,
+    /// Which spectre address should the coinbase block reward transactions pay into
+    pub pay_address: RpcAddress,
+    /// Mempool transactions the first block must hold, in this order, instead of the regular
+    /// transaction selection. The call fails if any of them is missing or invalid.
+    pub transaction_ids: Vec<RpcTransactionId>,
+}
+
+impl GenerateBlocksRequest {
+    pub fn new(count: u32, pay_address: RpcAddress, transaction_ids: Vec<RpcTransactionId>) -> Self {
+        Self { count, pay_address, transaction_ids }
+    }
+}
+
+impl Serializer for GenerateBlocksRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u32, &self.count, writer)?;
+        store!(RpcAddress, &self.pay_address, writer)?;
+        store!(Vec<RpcTransactionId>, &self.transaction_ids, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GenerateBlocksRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let count = load!(u32, reader)?;
+        let pay_address = load!(RpcAddress, reader)?;
+        let transaction_ids = load!(Vec<RpcTransactionId>, reader)?;
+
+        Ok(Self { count, pay_address, transaction_ids })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateBlocksResponse {
+    /// Hashes of the generated blocks, in mining order
+    pub block_hashes: Vec<RpcHash>,
+}
+
+impl GenerateBlocksResponse {
+    pub fn new(block_hashes: Vec<RpcHash>) -> Self {
+        Self { block_hashes }
+    }
+}
+
+impl Serializer for GenerateBlocksResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<RpcHash>, &self.block_hashes, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GenerateBlocksResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let block_hashes = load!(Vec<RpcHash>, reader)?;
+
+        Ok(Self { block_hashes })
+    }
+}
+
+/// CreateBackupRequest creates a consistent point-in-time backup of the consensus, meta and utxoindex
+/// databases of the node, using RocksDB checkpoints, within the backup directory of the node.
+///
+/// The backup has the layout of the node data directory, so that it can be restored with
+/// `spectred backup restore <DIR>`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBackupRequest {}
+
+impl Serializer for CreateBackupRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for CreateBackupRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBackupResponse {
+    /// Path of the backup directory on the node host
+    pub path: String,
+}
+
+impl CreateBackupResponse {
+    pub fn new(path: String) -> Self {
+        Self { path }
+    }
+}
+
+impl Serializer for CreateBackupResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.path, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for CreateBackupResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let path = load!(String, reader)?;
+
+        Ok(Self { path })
+    }
+}
+
+/// GetDatabaseStatsRequest requests the RocksDB statistics of the consensus, meta and utxoindex databases,
+/// including the approximate on-disk usage of every store, and the cache statistics of the stores.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDatabaseStatsRequest {}
+
+impl Serializer for GetDatabaseStatsRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetDatabaseStatsRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDatabaseStatsResponse {
+    pub databases: Vec<RpcDatabaseStats>,
+    /// Cache counters of the stores of all the `databases`, summed by store prefix
+    pub cache_stats: Vec<RpcStoreCacheStats>,
+}
+
+impl GetDatabaseStatsResponse {
+    pub fn new(databases: Vec<RpcDatabaseStats>, cache_stats: Vec<RpcStoreCacheStats>) -> Self {
+        Self { databases, cache_stats }
+    }
+}
+
+impl Serializer for GetDatabaseStatsResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcDatabaseStats>, &self.databases, writer)?;
+        serialize!(Vec<RpcStoreCacheStats>, &self.cache_stats, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetDatabaseStatsResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let databases = deserialize!(Vec<RpcDatabaseStats>, reader)?;
+        let cache_stats = deserialize!(Vec<RpcStoreCacheStats>, reader)?;
+
+        Ok(Self { databases, cache_stats })
+    }
+}
+
+/// GetChainBlockByBlueScoreRequest requests the first selected chain block whose blue score reaches `blue_score`, i.e. the chain block
+/// covering this blue score, since the blue scores of consecutive chain blocks are not contiguous.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlockByBlueScoreRequest {
+    pub blue_score: u64,
+}
+
+impl GetChainBlockByBlueScoreRequest {
+    pub fn new(blue_score: u64) -> Self {
+        Self { blue_score }
+    }
+}
+
+impl Serializer for GetChainBlockByBlueScoreRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.blue_score, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlockByBlueScoreRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let blue_score = load!(u64, reader)?;
+
+        Ok(Self { blue_score })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlockByBlueScoreResponse {
+    pub chain_block: RpcChainBlock,
+}
+
+impl GetChainBlockByBlueScoreResponse {
+    pub fn new(chain_block: RpcChainBlock) -> Self {
+        Self { chain_block }
+    }
+}
+
+impl Serializer for GetChainBlockByBlueScoreResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(RpcChainBlock, &self.chain_block, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlockByBlueScoreResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let chain_block = deserialize!(RpcChainBlock, reader)?;
+
+        Ok(Self { chain_block })
+    }
+}
+
+/// GetChainBlockByDaaScoreRequest requests the first selected chain block whose DAA score reaches `daa_score`, i.e. the chain block
+/// covering this DAA score, since the DAA scores of consecutive chain blocks are not contiguous.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlockByDaaScoreRequest {
+    pub daa_score: u64,
+}
+
+impl GetChainBlockByDaaScoreRequest {
+    pub fn new(daa_score: u64) -> Self {
+        Self { daa_score }
+    }
+}
+
+impl Serializer for GetChainBlockByDaaScoreRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.daa_score, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlockByDaaScoreRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let daa_score = load!(u64, reader)?;
+
+        Ok(Self { daa_score })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlockByDaaScoreResponse {
+    pub chain_block: RpcChainBlock,
+}
+
+impl GetChainBlockByDaaScoreResponse {
+    pub fn new(chain_block: RpcChainBlock) -> Self {
+        Self { chain_block }
+    }
+}
+
+impl Serializer for GetChainBlockByDaaScoreResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(RpcChainBlock, &self.chain_block, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlockByDaaScoreResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let chain_block = deserialize!(RpcChainBlock, reader)?;
+
+        Ok(Self { chain_block })
+    }
+}
+
+/// GetChainBlocksByBlueScoreRangeRequest requests the selected chain blocks with a blue score within `[low_blue_score, high_blue_score]`, in ascending order.
+///
+/// At most [`MAX_CHAIN_BLOCKS_RANGE_SIZE`](crate::api::rpc::MAX_CHAIN_BLOCKS_RANGE_SIZE) blocks are returned, the
+/// following ones being requested from the blue score following the one of the last returned block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlocksByBlueScoreRangeRequest {
+    pub low_blue_score: u64,
+    pub high_blue_score: u64,
+}
+
+impl GetChainBlocksByBlueScoreRangeRequest {
+    pub fn new(low_blue_score: u64, high_blue_score: u64) -> Self {
+        Self { low_blue_score, high_blue_score }
+    }
+}
+
+impl Serializer for GetChainBlocksByBlueScoreRangeRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.low_blue_score, writer)?;
+        store!(u64, &self.high_blue_score, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlocksByBlueScoreRangeRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let low_blue_score = load!(u64, reader)?;
+        let high_blue_score = load!(u64, reader)?;
+
+        Ok(Self { low_blue_score, high_blue_score })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlocksByBlueScoreRangeResponse {
+    pub chain_blocks: Vec<RpcChainBlock>,
+}
+
+impl GetChainBlocksByBlueScoreRangeResponse {
+    pub fn new(chain_blocks: Vec<RpcChainBlock>) -> Self {
+        Self { chain_blocks }
+    }
+}
+
+impl Serializer for GetChainBlocksByBlueScoreRangeResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcChainBlock>, &self.chain_blocks, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlocksByBlueScoreRangeResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let chain_blocks = deserialize!(Vec<RpcChainBlock>, reader)?;
+
+        Ok(Self { chain_blocks })
+    }
+}
+
+/// GetChainBlocksByDaaScoreRangeRequest requests the selected chain blocks with a DAA score within `[low_daa_score, high_daa_score]`, in ascending order.
+///
+/// At most [`MAX_CHAIN_BLOCKS_RANGE_SIZE`](crate::api::rpc::MAX_CHAIN_BLOCKS_RANGE_SIZE) blocks are returned, the
+/// following ones being requested from the DAA score following the one of the last returned block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlocksByDaaScoreRangeRequest {
+    pub low_daa_score: u64,
+    pub high_daa_score: u64,
+}
+
+impl GetChainBlocksByDaaScoreRangeRequest {
+    pub fn new(low_daa_score: u64, high_daa_score: u64) -> Self {
+        Self { low_daa_score, high_daa_score }
+    }
+}
+
+impl Serializer for GetChainBlocksByDaaScoreRangeRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.low_daa_score, writer)?;
+        store!(u64, &self.high_daa_score, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlocksByDaaScoreRangeRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let low_daa_score = load!(u64, reader)?;
+        let high_daa_score = load!(u64, reader)?;
+
+        Ok(Self { low_daa_score, high_daa_score })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChainBlocksByDaaScoreRangeResponse {
+    pub chain_blocks: Vec<RpcChainBlock>,
+}
+
+impl GetChainBlocksByDaaScoreRangeResponse {
+    pub fn new(chain_blocks: Vec<RpcChainBlock>) -> Self {
+        Self { chain_blocks }
+    }
+}
+
+impl Serializer for GetChainBlocksByDaaScoreRangeResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcChainBlock>, &self.chain_blocks, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetChainBlocksByDaaScoreRangeResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let chain_blocks = deserialize!(Vec<RpcChainBlock>, reader)?;
+
+        Ok(Self { chain_blocks })
+    }
+}
+
+/// TestMempoolAcceptRequest tests whether the mempool would accept a batch of transactions,
+/// submitted in order, without inserting any of them.
+///
+/// A transaction may spend the outputs of preceding transactions of the batch. When `replace_by_fee`
+/// is set, every transaction is tested as a replacement, as submitted via SubmitTransactionReplacement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestMempoolAcceptRequest {
+    pub transactions: Vec<RpcTransaction>,
+    pub allow_orphan: bool,
+    pub replace_by_fee: bool,
+}
+
+impl TestMempoolAcceptRequest {
+    pub fn new(transactions: Vec<RpcTransaction>, allow_orphan: bool, replace_by_fee: bool) -> Self {
+        Self { transactions, allow_orphan, replace_by_fee }
+    }
+}
+
+impl Serializer for TestMempoolAcceptRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcTransaction>, &self.transactions, writer)?;
+        store!(bool, &self.allow_orphan, writer)?;
+        store!(bool, &self.replace_by_fee, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for TestMempoolAcceptRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let transactions = deserialize!(Vec<RpcTransaction>, reader)?;
+        let allow_orphan = load!(bool, reader)?;
+        let replace_by_fee = load!(bool, reader)?;
+
+        Ok(Self { transactions, allow_orphan, replace_by_fee })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestMempoolAcceptResponse {
+    /// One result for every requested transaction, in the same order
+    pub results: Vec<RpcMempoolAcceptResult>,
+}
+
+impl TestMempoolAcceptResponse {
+    pub fn new(results: Vec<RpcMempoolAcceptResult>) -> Self {
+        Self { results }
+    }
+}
+
+impl Serializer for TestMempoolAcceptResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcMempoolAcceptResult>, &self.results, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for TestMempoolAcceptResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let results = deserialize!(Vec<RpcMempoolAcceptResult>, reader)?;
+
+        Ok(Self { results })
     }
 }
 
+/// DecodeTransactionRequest decodes a serialized transaction.
+///
+/// When `verify_scripts` is set, the script of every input is executed against `utxo_entries`, the UTXO
+/// entries spent by the inputs in input order, or against the entries found by the node in its UTXO set
+/// and mempool if `utxo_entries` is empty.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ResolveFinalityConflictRequest {
-    pub finality_block_hash: RpcHash,
+pub struct DecodeTransactionRequest {
+    pub transaction: RpcSerializedTransaction,
+    #[serde(default)]
+    pub verify_scripts: bool,
+    #[serde(default)]
+    pub utxo_entries: Vec<RpcUtxoEntry>,
 }
 
-impl ResolveFinalityConflictRequest {
-    pub fn new(finality_block_hash: RpcHash) -> Self {
-        Self { finality_block_hash }
+impl DecodeTransactionRequest {
+    pub fn new(transaction: RpcSerializedTransaction, verify_scripts: bool, utxo_entries: Vec<RpcUtxoEntry>) -> Self {
+        Self { transaction, verify_scripts, utxo_entries }
     }
 }
 
-impl Serializer for ResolveFinalityConflictRequest {
+impl Serializer for DecodeTransactionRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
-        store!(RpcHash, &self.finality_block_hash, writer)?;
+        serialize!(RpcSerializedTransaction, &self.transaction, writer)?;
+        store!(bool, &self.verify_scripts, writer)?;
+        serialize!(Vec<RpcUtxoEntry>, &self.utxo_entries, writer)?;
 
         Ok(())
     }
 }
 
-impl Deserializer for ResolveFinalityConflictRequest {
+impl Deserializer for DecodeTransactionRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        let finality_block_hash = load!(RpcHash, reader)?;
+        let transaction = deserialize!(RpcSerializedTransaction, reader)?;
+        let verify_scripts = load!(bool, reader)?;
+        let utxo_entries = deserialize!(Vec<RpcUtxoEntry>, reader)?;
 
-        Ok(Self { finality_block_hash })
+        Ok(Self { transaction, verify_scripts, utxo_entries })
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ResolveFinalityConflictResponse {}
+pub struct DecodeTransactionResponse {
+    /// The decoded transaction, its verbose data holding the transaction id, the compute mass
+    /// and the class and disassembly of every standard output script
+    pub transaction: RpcTransaction,
+    /// Only known if the UTXO entries spent by all inputs are known
+    pub storage_mass: Option<u64>,
+    /// One verification for every input, in input order, if scripts verification was requested
+    pub script_verifications: Vec<RpcInputScriptVerification>,
+}
 
-impl Serializer for ResolveFinalityConflictResponse {
+impl DecodeTransactionResponse {
+    pub fn new(transaction: RpcTransaction, storage_mass: Option<u64>, script_verifications: Vec<RpcInputScriptVerification>) -> Self {
+        Self { transaction, storage_mass, script_verifications }
+    }
+}
+
+impl Serializer for DecodeTransactionResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         store!(u16, &1, writer)?;
+        serialize!(RpcTransaction, &self.transaction, writer)?;
+        store!(Option<u64>, &self.storage_mass, writer)?;
+        serialize!(Vec<RpcInputScriptVerification>, &self.script_verifications, writer)?;
+
         Ok(())
     }
 }
 
-impl Deserializer for ResolveFinalityConflictResponse {
+impl Deserializer for DecodeTransactionResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let _version = load!(u16, reader)?;
-        Ok(Self {})
+        let transaction = deserialize!(RpcTransaction, reader)?;
+        let storage_mass = load!(Option<u64>, reader)?;
+        let script_verifications = deserialize!(Vec<RpcInputScriptVerification>, reader)?;
+
+        Ok(Self { transaction, storage_mass, script_verifications })
     }
 }
 
@@ -1442,22 +2196,59 @@ impl Deserializer for GetSinkBlueScoreResponse {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetUtxosByAddressesRequest {
     pub addresses: Vec<RpcAddress>,
+    /// Maximal number of entries returned, all matching entries being returned if `None`
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// The returned entries start right after this position, usually the `next_cursor` of the previous response
+    #[serde(default)]
+    pub cursor: Option<RpcUtxosByAddressesCursor>,
+    #[serde(default)]
+    pub min_amount: Option<u64>,
+    #[serde(default)]
+    pub max_amount: Option<u64>,
+    /// Excludes the coinbase entries which cannot be spent yet by a transaction entering the mempool
+    #[serde(default)]
+    pub exclude_immature_coinbase: bool,
+    #[serde(default)]
+    pub order: RpcUtxoOrder,
 }
 
 impl GetUtxosByAddressesRequest {
     pub fn new(addresses: Vec<RpcAddress>) -> Self {
-        Self { addresses }
+        Self { addresses, ..Default::default() }
+    }
+
+    pub fn with_page(self, limit: u32, cursor: Option<RpcUtxosByAddressesCursor>) -> Self {
+        Self { limit: Some(limit), cursor, ..self }
+    }
+
+    pub fn with_amount_range(self, min_amount: Option<u64>, max_amount: Option<u64>) -> Self {
+        Self { min_amount, max_amount, ..self }
+    }
+
+    pub fn with_mature_coinbase_only(self) -> Self {
+        Self { exclude_immature_coinbase: true, ..self }
+    }
+
+    pub fn with_order(self, order: RpcUtxoOrder) -> Self {
+        Self { order, ..self }
     }
 }
 
 impl Serializer for GetUtxosByAddressesRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(Vec<RpcAddress>, &self.addresses, writer)?;
+        store!(Option<u32>, &self.limit, writer)?;
+        serialize!(Option<RpcUtxosByAddressesCursor>, &self.cursor, writer)?;
+        store!(Option<u64>, &self.min_amount, writer)?;
+        store!(Option<u64>, &self.max_amount, writer)?;
+        store!(bool, &self.exclude_immature_coinbase, writer)?;
+        store!(RpcUtxoOrder, &self.order, writer)?;
 
         Ok(())
     }
@@ -1465,10 +2256,19 @@ impl Serializer for GetUtxosByAddressesRequest {
 
 impl Deserializer for GetUtxosByAddressesRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let addresses = load!(Vec<RpcAddress>, reader)?;
+        if version < 2 {
+            return Ok(Self::new(addresses));
+        }
+        let limit = load!(Option<u32>, reader)?;
+        let cursor = deserialize!(Option<RpcUtxosByAddressesCursor>, reader)?;
+        let min_amount = load!(Option<u64>, reader)?;
+        let max_amount = load!(Option<u64>, reader)?;
+        let exclude_immature_coinbase = load!(bool, reader)?;
+        let order = load!(RpcUtxoOrder, reader)?;
 
-        Ok(Self { addresses })
+        Ok(Self { addresses, limit, cursor, min_amount, max_amount, exclude_immature_coinbase, order })
     }
 }
 
@@ -1476,18 +2276,22 @@ impl Deserializer for GetUtxosByAddressesRequest {
 #[serde(rename_all = "camelCase")]
 pub struct GetUtxosByAddressesResponse {
     pub entries: Vec<RpcUtxosByAddressesEntry>,
+    /// Position of the last returned entry if more entries match the request, `None` if this is the last page
+    #[serde(default)]
+    pub next_cursor: Option<RpcUtxosByAddressesCursor>,
 }
 
 impl GetUtxosByAddressesResponse {
-    pub fn new(entries: Vec<RpcUtxosByAddressesEntry>) -> Self {
-        Self { entries }
+    pub fn new(entries: Vec<RpcUtxosByAddressesEntry>, next_cursor: Option<RpcUtxosByAddressesCursor>) -> Self {
+        Self { entries, next_cursor }
     }
 }
 
 impl Serializer for GetUtxosByAddressesResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         serialize!(Vec<RpcUtxosByAddressesEntry>, &self.entries, writer)?;
+        serialize!(Option<RpcUtxosByAddressesCursor>, &self.next_cursor, writer)?;
 
         Ok(())
     }
@@ -1495,10 +2299,11 @@ impl Serializer for GetUtxosByAddressesResponse {
 
 impl Deserializer for GetUtxosByAddressesResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let entries = deserialize!(Vec<RpcUtxosByAddressesEntry>, reader)?;
+        let next_cursor = if version > 1 { deserialize!(Option<RpcUtxosByAddressesCursor>, reader)? } else { None };
 
-        Ok(Self { entries })
+        Ok(Self { entries, next_cursor })
     }
 }
 
@@ -1956,17 +2761,21 @@ pub struct GetMetricsRequest {
     pub consensus_metrics: bool,
     pub storage_metrics: bool,
     pub custom_metrics: bool,
+    /// Requests the per-worker share accounting of the built-in Stratum server
+    #[serde(default)]
+    pub stratum_metrics: bool,
 }
 
 impl Serializer for GetMetricsRequest {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(bool, &self.process_metrics, writer)?;
         store!(bool, &self.connection_metrics, writer)?;
         store!(bool, &self.bandwidth_metrics, writer)?;
         store!(bool, &self.consensus_metrics, writer)?;
         store!(bool, &self.storage_metrics, writer)?;
         store!(bool, &self.custom_metrics, writer)?;
+        store!(bool, &self.stratum_metrics, writer)?;
 
         Ok(())
     }
@@ -1974,15 +2783,24 @@ impl Serializer for GetMetricsRequest {
 
 impl Deserializer for GetMetricsRequest {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let process_metrics = load!(bool, reader)?;
         let connection_metrics = load!(bool, reader)?;
         let bandwidth_metrics = load!(bool, reader)?;
         let consensus_metrics = load!(bool, reader)?;
         let storage_metrics = load!(bool, reader)?;
         let custom_metrics = load!(bool, reader)?;
+        let stratum_metrics = if version > 1 { load!(bool, reader)? } else { false };
 
-        Ok(Self { process_metrics, connection_metrics, bandwidth_metrics, consensus_metrics, storage_metrics, custom_metrics })
+        Ok(Self {
+            process_metrics,
+            connection_metrics,
+            bandwidth_metrics,
+            consensus_metrics,
+            storage_metrics,
+            custom_metrics,
+            stratum_metrics,
+        })
     }
 }
 
@@ -2257,6 +3075,83 @@ impl Deserializer for StorageMetrics {
     }
 }
 
+/// Share accounting of a worker of the built-in Stratum server
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StratumWorkerMetrics {
+    /// Worker login, i.e. `<address>.<worker name>`
+    pub worker: String,
+    pub valid_shares: u64,
+    pub stale_shares: u64,
+    pub invalid_shares: u64,
+    pub duplicate_shares: u64,
+    pub blocks_found: u64,
+    pub blocks_rejected: u64,
+    /// Sum of the difficulties of the valid shares
+    pub work: f64,
+}
+
+impl Serializer for StratumWorkerMetrics {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(String, &self.worker, writer)?;
+        store!(u64, &self.valid_shares, writer)?;
+        store!(u64, &self.stale_shares, writer)?;
+        store!(u64, &self.invalid_shares, writer)?;
+        store!(u64, &self.duplicate_shares, writer)?;
+        store!(u64, &self.blocks_found, writer)?;
+        store!(u64, &self.blocks_rejected, writer)?;
+        store!(f64, &self.work, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for StratumWorkerMetrics {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let worker = load!(String, reader)?;
+        let valid_shares = load!(u64, reader)?;
+        let stale_shares = load!(u64, reader)?;
+        let invalid_shares = load!(u64, reader)?;
+        let duplicate_shares = load!(u64, reader)?;
+        let blocks_found = load!(u64, reader)?;
+        let blocks_rejected = load!(u64, reader)?;
+        let work = load!(f64, reader)?;
+
+        Ok(Self { worker, valid_shares, stale_shares, invalid_shares, duplicate_shares, blocks_found, blocks_rejected, work })
+    }
+}
+
+/// Accounting of the built-in Stratum server, covering all the workers which ever connected to it
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StratumMetrics {
+    /// Number of requests which could not be parsed
+    pub malformed_requests: u64,
+    pub workers: Vec<StratumWorkerMetrics>,
+}
+
+impl Serializer for StratumMetrics {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(u64, &self.malformed_requests, writer)?;
+        serialize!(Vec<StratumWorkerMetrics>, &self.workers, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for StratumMetrics {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let malformed_requests = load!(u64, reader)?;
+        let workers = deserialize!(Vec<StratumWorkerMetrics>, reader)?;
+
+        Ok(Self { malformed_requests, workers })
+    }
+}
+
 // TODO: Custom metrics dictionary
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum CustomMetricValue {
@@ -2290,6 +3185,9 @@ pub struct GetMetricsResponse {
     pub storage_metrics: Option<StorageMetrics>,
     // TODO: this is currently a placeholder
     pub custom_metrics: Option<HashMap<String, CustomMetricValue>>,
+    /// `None` if not requested or if the node does not run the Stratum server
+    #[serde(default)]
+    pub stratum_metrics: Option<StratumMetrics>,
 }
 
 impl GetMetricsResponse {
@@ -2310,13 +3208,14 @@ impl GetMetricsResponse {
             storage_metrics,
             server_time,
             custom_metrics,
+            stratum_metrics: None,
         }
     }
 }
 
 impl Serializer for GetMetricsResponse {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u16, &1, writer)?;
+        store!(u16, &2, writer)?;
         store!(u64, &self.server_time, writer)?;
         serialize!(Option<ProcessMetrics>, &self.process_metrics, writer)?;
         serialize!(Option<ConnectionMetrics>, &self.connection_metrics, writer)?;
@@ -2324,6 +3223,7 @@ impl Serializer for GetMetricsResponse {
         serialize!(Option<ConsensusMetrics>, &self.consensus_metrics, writer)?;
         serialize!(Option<StorageMetrics>, &self.storage_metrics, writer)?;
         serialize!(Option<HashMap<String, CustomMetricValue>>, &self.custom_metrics, writer)?;
+        serialize!(Option<StratumMetrics>, &self.stratum_metrics, writer)?;
 
         Ok(())
     }
@@ -2331,7 +3231,7 @@ impl Serializer for GetMetricsResponse {
 
 impl Deserializer for GetMetricsResponse {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u16, reader)?;
+        let version = load!(u16, reader)?;
         let server_time = load!(u64, reader)?;
         let process_metrics = deserialize!(Option<ProcessMetrics>, reader)?;
         let connection_metrics = deserialize!(Option<ConnectionMetrics>, reader)?;
@@ -2339,6 +3239,7 @@ impl Deserializer for GetMetricsResponse {
         let consensus_metrics = deserialize!(Option<ConsensusMetrics>, reader)?;
         let storage_metrics = deserialize!(Option<StorageMetrics>, reader)?;
         let custom_metrics = deserialize!(Option<HashMap<String, CustomMetricValue>>, reader)?;
+        let stratum_metrics = if version > 1 { deserialize!(Option<StratumMetrics>, reader)? } else { None };
 
         Ok(Self {
             server_time,
@@ -2348,6 +3249,7 @@ impl Deserializer for GetMetricsResponse {
             consensus_metrics,
             storage_metrics,
             custom_metrics,
+            stratum_metrics,
         })
     }
 }
@@ -2593,7 +3495,7 @@ impl Deserializer for GetFeeEstimateExperimentalRequest {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFeeEstimateExperimentalResponse {
-    /// The usual feerate estimate response
+    /// The feerate estimate, blended with the historically observed inclusion times
     pub estimate: RpcFeeEstimate,
 
     /// Experimental verbose data
@@ -2729,6 +3631,116 @@ impl Deserializer for GetUtxoReturnAddressResponse {
     }
 }
 
+/// PrioritizeTransactionRequest sets the priority delta of a transaction, honored when selecting
+/// the transactions of subsequent block templates. A zero fee delta with no exclusion clears any
+/// previously set delta. The delta is kept until the transaction gets accepted in a block and
+/// survives a node restart.
+///
+/// The transaction does not need to be in the mempool.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrioritizeTransactionRequest {
+    pub transaction_id: RpcTransactionId,
+    /// Amount in sompi added to the transaction fee when weighting it for selection (may be negative)
+    pub fee_delta: i64,
+    /// Never select the transaction into a block template
+    pub excluded: bool,
+}
+
+impl PrioritizeTransactionRequest {
+    pub fn new(transaction_id: RpcTransactionId, fee_delta: i64, excluded: bool) -> Self {
+        Self { transaction_id, fee_delta, excluded }
+    }
+}
+
+impl Serializer for PrioritizeTransactionRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(RpcTransactionId, &self.transaction_id, writer)?;
+        store!(i64, &self.fee_delta, writer)?;
+        store!(bool, &self.excluded, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for PrioritizeTransactionRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let transaction_id = load!(RpcTransactionId, reader)?;
+        let fee_delta = load!(i64, reader)?;
+        let excluded = load!(bool, reader)?;
+
+        Ok(Self { transaction_id, fee_delta, excluded })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrioritizeTransactionResponse {}
+
+impl Serializer for PrioritizeTransactionResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for PrioritizeTransactionResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPrioritizedTransactionsRequest {}
+
+impl Serializer for GetPrioritizedTransactionsRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for GetPrioritizedTransactionsRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPrioritizedTransactionsResponse {
+    pub prioritized_transactions: Vec<RpcPrioritizedTransaction>,
+}
+
+impl GetPrioritizedTransactionsResponse {
+    pub fn new(prioritized_transactions: Vec<RpcPrioritizedTransaction>) -> Self {
+        Self { prioritized_transactions }
+    }
+}
+
+impl Serializer for GetPrioritizedTransactionsResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcPrioritizedTransaction>, &self.prioritized_transactions, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for GetPrioritizedTransactionsResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let prioritized_transactions = deserialize!(Vec<RpcPrioritizedTransaction>, reader)?;
+
+        Ok(Self { prioritized_transactions })
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Subscriptions & notifications
 // ----------------------------------------------------------------------------
@@ -3163,6 +4175,156 @@ impl Deserializer for UtxosChangedNotification {
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// AcceptedTransactionsChangedNotification
+
+// NotifyAcceptedTransactionsChangedRequest registers this connection for acceptedTransactionsChanged
+// notifications for the given addresses. Depending on the provided `command`, notifications will
+// start or stop for the provided `addresses`.
+//
+// If `addresses` is empty, the notifications will start or stop for all addresses.
+//
+// See: AcceptedTransactionsChangedNotification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyAcceptedTransactionsChangedRequest {
+    pub addresses: Vec<RpcAddress>,
+    pub command: Command,
+}
+
+impl NotifyAcceptedTransactionsChangedRequest {
+    pub fn new(addresses: Vec<RpcAddress>, command: Command) -> Self {
+        Self { addresses, command }
+    }
+}
+
+impl Serializer for NotifyAcceptedTransactionsChangedRequest {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        store!(Vec<RpcAddress>, &self.addresses, writer)?;
+        store!(Command, &self.command, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyAcceptedTransactionsChangedRequest {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let addresses = load!(Vec<RpcAddress>, reader)?;
+        let command = load!(Command, reader)?;
+        Ok(Self { addresses, command })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyAcceptedTransactionsChangedResponse {}
+
+impl Serializer for NotifyAcceptedTransactionsChangedResponse {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for NotifyAcceptedTransactionsChangedResponse {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        Ok(Self {})
+    }
+}
+
+// AcceptedTransactionsChangedNotification is sent whenever the selected parent chain changed,
+// carrying the transactions paying to or spending from the subscribed addresses.
+//
+// `accepted` holds the transactions accepted by the chain blocks added to the selected chain
+// and `unaccepted` the transactions formerly accepted by the chain blocks removed from it by a
+// reorg, both grouped by accepting chain block.
+//
+// `incomplete` is set when the transactions of some chain block could not be resolved by the node,
+// in which case the notification is sent to every subscriber, which should reconcile its state
+// through GetVirtualChainFromBlock.
+//
+// See: NotifyAcceptedTransactionsChangedRequest
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptedTransactionsChangedNotification {
+    pub accepted: Arc<Vec<RpcAcceptedTransactions>>,
+    pub unaccepted: Arc<Vec<RpcAcceptedTransactions>>,
+    pub incomplete: bool,
+}
+
+impl AcceptedTransactionsChangedNotification {
+    pub(crate) fn apply_accepted_transactions_changed_subscription(
+        &self,
+        subscription: &AcceptedTransactionsChangedSubscription,
+        context: &SubscriptionContext,
+    ) -> Option<Self> {
+        if subscription.to_all() {
+            (!self.accepted.is_empty() || !self.unaccepted.is_empty() || self.incomplete).then(|| self.clone())
+        } else {
+            let accepted = Self::filter_transactions(&self.accepted, subscription, context);
+            let unaccepted = Self::filter_transactions(&self.unaccepted, subscription, context);
+            if accepted.is_empty() && unaccepted.is_empty() && !self.incomplete {
+                None
+            } else {
+                debug!(
+                    "CRPC, Creating AcceptedTransactionsChanged notifications with {} accepting and {} unaccepting blocks",
+                    accepted.len(),
+                    unaccepted.len()
+                );
+                Some(Self { accepted: Arc::new(accepted), unaccepted: Arc::new(unaccepted), incomplete: self.incomplete })
+            }
+        }
+    }
+
+    /// Keeps the transactions having an output or a spent UTXO entry matching an address of the subscription,
+    /// dropping the accepting blocks left without any transaction
+    fn filter_transactions(
+        blocks: &[RpcAcceptedTransactions],
+        subscription: &AcceptedTransactionsChangedSubscription,
+        context: &SubscriptionContext,
+    ) -> Vec<RpcAcceptedTransactions> {
+        let subscription_data = subscription.data();
+        blocks
+            .iter()
+            .filter_map(|block| {
+                let accepted_transactions = block
+                    .accepted_transactions
+                    .iter()
+                    .filter(|x| {
+                        x.transaction.outputs.iter().any(|output| subscription_data.contains(&output.script_public_key, context))
+                            || x.input_utxo_entries.iter().any(|entry| subscription_data.contains(&entry.script_public_key, context))
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (!accepted_transactions.is_empty())
+                    .then(|| RpcAcceptedTransactions { accepting_block_hash: block.accepting_block_hash, accepted_transactions })
+            })
+            .collect()
+    }
+}
+
+impl Serializer for AcceptedTransactionsChangedNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u16, &1, writer)?;
+        serialize!(Vec<RpcAcceptedTransactions>, &self.accepted, writer)?;
+        serialize!(Vec<RpcAcceptedTransactions>, &self.unaccepted, writer)?;
+        store!(bool, &self.incomplete, writer)?;
+        Ok(())
+    }
+}
+
+impl Deserializer for AcceptedTransactionsChangedNotification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u16, reader)?;
+        let accepted = deserialize!(Vec<RpcAcceptedTransactions>, reader)?;
+        let unaccepted = deserialize!(Vec<RpcAcceptedTransactions>, reader)?;
+        let incomplete = load!(bool, reader)?;
+        Ok(Self { accepted: accepted.into(), unaccepted: unaccepted.into(), incomplete })
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // SinkBlueScoreChangedNotification
 
@@ -4,6 +4,7 @@
 pub mod address;
 pub mod block;
 pub mod blue_work;
+pub mod database;
 pub mod feerate_estimate;
 pub mod hash;
 pub mod header;
@@ -20,6 +21,7 @@ pub mod tx;
 pub use address::*;
 pub use block::*;
 pub use blue_work::*;
+pub use database::*;
 pub use feerate_estimate::*;
 pub use hash::*;
 pub use header::*;
@@ -223,7 +223,11 @@ mod mockery {
 
     impl Mock for RpcTransactionOutputVerboseData {
         fn mock() -> Self {
-            RpcTransactionOutputVerboseData { script_public_key_type: RpcScriptClass::PubKey, script_public_key_address: mock() }
+            RpcTransactionOutputVerboseData {
+                script_public_key_type: RpcScriptClass::PubKey,
+                script_public_key_address: mock(),
+                script_public_key_asm: "OpCheckSig".to_string(),
+            }
         }
     }
 
@@ -313,6 +317,12 @@ mod mockery {
         }
     }
 
+    impl Mock for RpcPrioritizedTransaction {
+        fn mock() -> Self {
+            RpcPrioritizedTransaction { transaction_id: mock(), fee_delta: mock(), excluded: mock() }
+        }
+    }
+
     impl Mock for RpcMempoolEntryByAddress {
         fn mock() -> Self {
             RpcMempoolEntryByAddress { address: mock(), sending: mock(), receiving: mock() }
@@ -345,6 +355,12 @@ mod mockery {
         }
     }
 
+    impl Mock for RpcUtxosByAddressesCursor {
+        fn mock() -> Self {
+            RpcUtxosByAddressesCursor { address: mock(), outpoint: mock(), block_daa_score: mock() }
+        }
+    }
+
     impl Mock for ProcessMetrics {
         fn mock() -> Self {
             ProcessMetrics {
@@ -418,6 +434,27 @@ mod mockery {
         }
     }
 
+    impl Mock for StratumWorkerMetrics {
+        fn mock() -> Self {
+            StratumWorkerMetrics {
+                worker: "miner.1".to_string(),
+                valid_shares: mock(),
+                stale_shares: mock(),
+                invalid_shares: mock(),
+                duplicate_shares: mock(),
+                blocks_found: mock(),
+                blocks_rejected: mock(),
+                work: mock(),
+            }
+        }
+    }
+
+    impl Mock for StratumMetrics {
+        fn mock() -> Self {
+            StratumMetrics { malformed_requests: mock(), workers: mock() }
+        }
+    }
+
     // --------------------------------------------
     // implementations for all the rpc request
     // and response data structures.
@@ -440,7 +477,11 @@ mod mockery {
 
     impl Mock for GetBlockTemplateRequest {
         fn mock() -> Self {
-            GetBlockTemplateRequest { pay_address: mock(), extra_data: vec![4, 2] }
+            GetBlockTemplateRequest {
+                pay_address: mock(),
+                extra_data: vec![4, 2],
+                payload_tags: vec!["pool".to_owned(), "rig".to_owned()],
+            }
         }
     }
 
@@ -640,7 +681,12 @@ mod mockery {
 
     impl Mock for GetVirtualChainFromBlockRequest {
         fn mock() -> Self {
-            GetVirtualChainFromBlockRequest { start_hash: mock(), include_accepted_transaction_ids: mock() }
+            GetVirtualChainFromBlockRequest {
+                start_hash: mock(),
+                include_accepted_transaction_ids: mock(),
+                limit: mock(),
+                include_accepted_transactions: mock(),
+            }
         }
     }
 
@@ -652,12 +698,26 @@ mod mockery {
         }
     }
 
+    impl Mock for RpcAcceptedTransaction {
+        fn mock() -> Self {
+            RpcAcceptedTransaction { transaction: mock(), input_utxo_entries: mock() }
+        }
+    }
+
+    impl Mock for RpcAcceptedTransactions {
+        fn mock() -> Self {
+            RpcAcceptedTransactions { accepting_block_hash: mock(), accepted_transactions: mock() }
+        }
+    }
+
     impl Mock for GetVirtualChainFromBlockResponse {
         fn mock() -> Self {
             GetVirtualChainFromBlockResponse {
                 removed_chain_block_hashes: mock(),
                 added_chain_block_hashes: mock(),
                 accepted_transaction_ids: mock(),
+                accepted_transactions: mock(),
+                next_start_hash: mock(),
             }
         }
     }
@@ -739,6 +799,245 @@ mod mockery {
 
     test!(ResolveFinalityConflictResponse);
 
+    impl Mock for InvalidateBlockRequest {
+        fn mock() -> Self {
+            InvalidateBlockRequest { hash: mock() }
+        }
+    }
+
+    test!(InvalidateBlockRequest);
+
+    impl Mock for InvalidateBlockResponse {
+        fn mock() -> Self {
+            InvalidateBlockResponse {}
+        }
+    }
+
+    test!(InvalidateBlockResponse);
+
+    impl Mock for ReconsiderBlockRequest {
+        fn mock() -> Self {
+            ReconsiderBlockRequest { hash: mock() }
+        }
+    }
+
+    test!(ReconsiderBlockRequest);
+
+    impl Mock for ReconsiderBlockResponse {
+        fn mock() -> Self {
+            ReconsiderBlockResponse {}
+        }
+    }
+
+    test!(ReconsiderBlockResponse);
+
+    impl Mock for GenerateBlocksRequest {
+        fn mock() -> Self {
+            GenerateBlocksRequest { count: mock(), pay_address: mock(), transaction_ids: mock() }
+        }
+    }
+
+    test!(GenerateBlocksRequest);
+
+    impl Mock for GenerateBlocksResponse {
+        fn mock() -> Self {
+            GenerateBlocksResponse { block_hashes: mock() }
+        }
+    }
+
+    test!(GenerateBlocksResponse);
+
+    impl Mock for CreateBackupRequest {
+        fn mock() -> Self {
+            CreateBackupRequest {}
+        }
+    }
+
+    test!(CreateBackupRequest);
+
+    impl Mock for CreateBackupResponse {
+        fn mock() -> Self {
+            CreateBackupResponse { path: "/tmp/backup".to_string() }
+        }
+    }
+
+    test!(CreateBackupResponse);
+
+    impl Mock for RpcStoreStats {
+        fn mock() -> Self {
+            RpcStoreStats { prefix: mock(), name: "Headers".to_string(), approximate_key_count: mock(), approximate_size: mock() }
+        }
+    }
+
+    impl Mock for RpcDatabaseStats {
+        fn mock() -> Self {
+            RpcDatabaseStats {
+                name: "utxoindex".to_string(),
+                estimated_key_count: mock(),
+                total_sst_files_size: mock(),
+                live_sst_files_size: mock(),
+                memtables_size: mock(),
+                pending_compaction_bytes: mock(),
+                running_compactions: mock(),
+                is_compaction_pending: mock(),
+                files_per_level: mock(),
+                stores: mock(),
+            }
+        }
+    }
+
+    impl Mock for RpcStoreCacheStats {
+        fn mock() -> Self {
+            RpcStoreCacheStats { prefix: mock(), name: "Headers".to_string(), hits: mock(), misses: mock() }
+        }
+    }
+
+    impl Mock for GetDatabaseStatsRequest {
+        fn mock() -> Self {
+            GetDatabaseStatsRequest {}
+        }
+    }
+
+    test!(GetDatabaseStatsRequest);
+
+    impl Mock for GetDatabaseStatsResponse {
+        fn mock() -> Self {
+            GetDatabaseStatsResponse { databases: mock(), cache_stats: mock() }
+        }
+    }
+
+    test!(GetDatabaseStatsResponse);
+
+    impl Mock for RpcChainBlock {
+        fn mock() -> Self {
+            RpcChainBlock { hash: mock(), chain_index: mock(), blue_score: mock(), daa_score: mock(), timestamp: mock() }
+        }
+    }
+
+    impl Mock for GetChainBlockByBlueScoreRequest {
+        fn mock() -> Self {
+            GetChainBlockByBlueScoreRequest { blue_score: mock() }
+        }
+    }
+
+    test!(GetChainBlockByBlueScoreRequest);
+
+    impl Mock for GetChainBlockByBlueScoreResponse {
+        fn mock() -> Self {
+            GetChainBlockByBlueScoreResponse { chain_block: mock() }
+        }
+    }
+
+    test!(GetChainBlockByBlueScoreResponse);
+
+    impl Mock for GetChainBlockByDaaScoreRequest {
+        fn mock() -> Self {
+            GetChainBlockByDaaScoreRequest { daa_score: mock() }
+        }
+    }
+
+    test!(GetChainBlockByDaaScoreRequest);
+
+    impl Mock for GetChainBlockByDaaScoreResponse {
+        fn mock() -> Self {
+            GetChainBlockByDaaScoreResponse { chain_block: mock() }
+        }
+    }
+
+    test!(GetChainBlockByDaaScoreResponse);
+
+    impl Mock for GetChainBlocksByBlueScoreRangeRequest {
+        fn mock() -> Self {
+            GetChainBlocksByBlueScoreRangeRequest { low_blue_score: mock(), high_blue_score: mock() }
+        }
+    }
+
+    test!(GetChainBlocksByBlueScoreRangeRequest);
+
+    impl Mock for GetChainBlocksByBlueScoreRangeResponse {
+        fn mock() -> Self {
+            GetChainBlocksByBlueScoreRangeResponse { chain_blocks: mock() }
+        }
+    }
+
+    test!(GetChainBlocksByBlueScoreRangeResponse);
+
+    impl Mock for GetChainBlocksByDaaScoreRangeRequest {
+        fn mock() -> Self {
+            GetChainBlocksByDaaScoreRangeRequest { low_daa_score: mock(), high_daa_score: mock() }
+        }
+    }
+
+    test!(GetChainBlocksByDaaScoreRangeRequest);
+
+    impl Mock for GetChainBlocksByDaaScoreRangeResponse {
+        fn mock() -> Self {
+            GetChainBlocksByDaaScoreRangeResponse { chain_blocks: mock() }
+        }
+    }
+
+    test!(GetChainBlocksByDaaScoreRangeResponse);
+
+    impl Mock for RpcMempoolAcceptResult {
+        fn mock() -> Self {
+            RpcMempoolAcceptResult {
+                transaction_id: mock(),
+                allowed: mock(),
+                is_orphan: mock(),
+                reject_reason: Some("mock".to_string()),
+                compute_mass: mock(),
+                storage_mass: mock(),
+                fee: mock(),
+                feerate: mock(),
+                replaced_transaction_id: mock(),
+            }
+        }
+    }
+
+    impl Mock for TestMempoolAcceptRequest {
+        fn mock() -> Self {
+            TestMempoolAcceptRequest { transactions: mock(), allow_orphan: mock(), replace_by_fee: mock() }
+        }
+    }
+
+    test!(TestMempoolAcceptRequest);
+
+    impl Mock for TestMempoolAcceptResponse {
+        fn mock() -> Self {
+            TestMempoolAcceptResponse { results: mock() }
+        }
+    }
+
+    test!(TestMempoolAcceptResponse);
+
+    impl Mock for RpcSerializedTransaction {
+        fn mock() -> Self {
+            RpcSerializedTransaction::Borsh(mock())
+        }
+    }
+
+    impl Mock for RpcInputScriptVerification {
+        fn mock() -> Self {
+            RpcInputScriptVerification { input_index: mock(), utxo_entry: mock(), verified: mock(), error: Some("mock".to_string()) }
+        }
+    }
+
+    impl Mock for DecodeTransactionRequest {
+        fn mock() -> Self {
+            DecodeTransactionRequest { transaction: mock(), verify_scripts: mock(), utxo_entries: mock() }
+        }
+    }
+
+    test!(DecodeTransactionRequest);
+
+    impl Mock for DecodeTransactionResponse {
+        fn mock() -> Self {
+            DecodeTransactionResponse { transaction: mock(), storage_mass: mock(), script_verifications: mock() }
+        }
+    }
+
+    test!(DecodeTransactionResponse);
+
     impl Mock for ShutdownRequest {
         fn mock() -> Self {
             ShutdownRequest {}
@@ -827,7 +1126,15 @@ mod mockery {
 
     impl Mock for GetUtxosByAddressesRequest {
         fn mock() -> Self {
-            GetUtxosByAddressesRequest { addresses: mock() }
+            GetUtxosByAddressesRequest {
+                addresses: mock(),
+                limit: mock(),
+                cursor: mock(),
+                min_amount: mock(),
+                max_amount: mock(),
+                exclude_immature_coinbase: mock(),
+                order: RpcUtxoOrder::DaaScoreDescending,
+            }
         }
     }
 
@@ -835,7 +1142,7 @@ mod mockery {
 
     impl Mock for GetUtxosByAddressesResponse {
         fn mock() -> Self {
-            GetUtxosByAddressesResponse { entries: mock() }
+            GetUtxosByAddressesResponse { entries: mock(), next_cursor: mock() }
         }
     }
 
@@ -921,6 +1228,38 @@ mod mockery {
 
     test!(GetCoinSupplyResponse);
 
+    impl Mock for PrioritizeTransactionRequest {
+        fn mock() -> Self {
+            PrioritizeTransactionRequest { transaction_id: mock(), fee_delta: mock(), excluded: mock() }
+        }
+    }
+
+    test!(PrioritizeTransactionRequest);
+
+    impl Mock for PrioritizeTransactionResponse {
+        fn mock() -> Self {
+            PrioritizeTransactionResponse {}
+        }
+    }
+
+    test!(PrioritizeTransactionResponse);
+
+    impl Mock for GetPrioritizedTransactionsRequest {
+        fn mock() -> Self {
+            GetPrioritizedTransactionsRequest {}
+        }
+    }
+
+    test!(GetPrioritizedTransactionsRequest);
+
+    impl Mock for GetPrioritizedTransactionsResponse {
+        fn mock() -> Self {
+            GetPrioritizedTransactionsResponse { prioritized_transactions: mock() }
+        }
+    }
+
+    test!(GetPrioritizedTransactionsResponse);
+
     impl Mock for PingRequest {
         fn mock() -> Self {
             PingRequest {}
@@ -986,6 +1325,7 @@ mod mockery {
                 consensus_metrics: true,
                 storage_metrics: true,
                 custom_metrics: false,
+                stratum_metrics: true,
             }
         }
     }
@@ -1002,6 +1342,7 @@ mod mockery {
                 consensus_metrics: mock(),
                 storage_metrics: mock(),
                 custom_metrics: None,
+                stratum_metrics: mock(),
             }
         }
     }
@@ -1188,6 +1529,30 @@ mod mockery {
 
     test!(UtxosChangedNotification);
 
+    impl Mock for NotifyAcceptedTransactionsChangedRequest {
+        fn mock() -> Self {
+            NotifyAcceptedTransactionsChangedRequest { addresses: mock(), command: Command::Start }
+        }
+    }
+
+    test!(NotifyAcceptedTransactionsChangedRequest);
+
+    impl Mock for NotifyAcceptedTransactionsChangedResponse {
+        fn mock() -> Self {
+            NotifyAcceptedTransactionsChangedResponse {}
+        }
+    }
+
+    test!(NotifyAcceptedTransactionsChangedResponse);
+
+    impl Mock for AcceptedTransactionsChangedNotification {
+        fn mock() -> Self {
+            AcceptedTransactionsChangedNotification { accepted: mock(), unaccepted: mock(), incomplete: true }
+        }
+    }
+
+    test!(AcceptedTransactionsChangedNotification);
+
     impl Mock for NotifySinkBlueScoreChangedRequest {
         fn mock() -> Self {
             NotifySinkBlueScoreChangedRequest { command: Command::Start }
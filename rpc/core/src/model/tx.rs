@@ -265,14 +265,19 @@ impl Deserializer for RpcTransactionOutput {
 #[serde(rename_all = "camelCase")]
 pub struct RpcTransactionOutputVerboseData {
     pub script_public_key_type: RpcScriptClass,
-    pub script_public_key_address: Address,
+    /// The address paying to the script public key, absent for scripts which do not map to an address
+    pub script_public_key_address: Option<Address>,
+    /// Human-readable disassembly of the script public key
+    #[serde(default)]
+    pub script_public_key_asm: String,
 }
 
 impl Serializer for RpcTransactionOutputVerboseData {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        store!(u8, &1, writer)?;
+        store!(u8, &3, writer)?;
         store!(RpcScriptClass, &self.script_public_key_type, writer)?;
-        store!(Address, &self.script_public_key_address, writer)?;
+        store!(Option<Address>, &self.script_public_key_address, writer)?;
+        store!(String, &self.script_public_key_asm, writer)?;
 
         Ok(())
     }
@@ -280,11 +285,12 @@ impl Serializer for RpcTransactionOutputVerboseData {
 
 impl Deserializer for RpcTransactionOutputVerboseData {
     fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let _version = load!(u8, reader)?;
+        let version = load!(u8, reader)?;
         let script_public_key_type = load!(RpcScriptClass, reader)?;
-        let script_public_key_address = load!(Address, reader)?;
+        let script_public_key_address = if version > 2 { load!(Option<Address>, reader)? } else { Some(load!(Address, reader)?) };
+        let script_public_key_asm = if version > 1 { load!(String, reader)? } else { String::new() };
 
-        Ok(Self { script_public_key_type, script_public_key_address })
+        Ok(Self { script_public_key_type, script_public_key_address, script_public_key_asm })
     }
 }
 
@@ -398,3 +404,143 @@ pub struct RpcAcceptedTransactionIds {
     pub accepting_block_hash: RpcHash,
     pub accepted_transaction_ids: Vec<RpcTransactionId>,
 }
+
+/// Represents a transaction accepted by a chain block, along with the UTXO entries spent by its inputs
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAcceptedTransaction {
+    /// The transaction, its verbose data referring to its containing block
+    pub transaction: RpcTransaction,
+    /// The UTXO entries spent by the transaction, in input order
+    pub input_utxo_entries: Vec<RpcUtxoEntry>,
+}
+
+impl Serializer for RpcAcceptedTransaction {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u8, &1, writer)?;
+        serialize!(RpcTransaction, &self.transaction, writer)?;
+        serialize!(Vec<RpcUtxoEntry>, &self.input_utxo_entries, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcAcceptedTransaction {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u8, reader)?;
+        let transaction = deserialize!(RpcTransaction, reader)?;
+        let input_utxo_entries = deserialize!(Vec<RpcUtxoEntry>, reader)?;
+
+        Ok(Self { transaction, input_utxo_entries })
+    }
+}
+
+/// Represents the transactions accepted by a chain block, in acceptance order
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAcceptedTransactions {
+    pub accepting_block_hash: RpcHash,
+    pub accepted_transactions: Vec<RpcAcceptedTransaction>,
+}
+
+impl Serializer for RpcAcceptedTransactions {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u8, &1, writer)?;
+        store!(RpcHash, &self.accepting_block_hash, writer)?;
+        serialize!(Vec<RpcAcceptedTransaction>, &self.accepted_transactions, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcAcceptedTransactions {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u8, reader)?;
+        let accepting_block_hash = load!(RpcHash, reader)?;
+        let accepted_transactions = deserialize!(Vec<RpcAcceptedTransaction>, reader)?;
+
+        Ok(Self { accepting_block_hash, accepted_transactions })
+    }
+}
+
+/// A serialized transaction
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcSerializedTransaction {
+    /// Hex encoding of the Borsh serialization of a transaction
+    Hex(String),
+    /// Borsh serialization of a transaction
+    Borsh(Vec<u8>),
+    /// JSON serialization of an [`RpcTransaction`]
+    Json(String),
+}
+
+impl Serializer for RpcSerializedTransaction {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u8, &1, writer)?;
+        match self {
+            RpcSerializedTransaction::Hex(hex) => {
+                store!(u8, &0, writer)?;
+                store!(String, hex, writer)?;
+            }
+            RpcSerializedTransaction::Borsh(bytes) => {
+                store!(u8, &1, writer)?;
+                store!(Vec<u8>, bytes, writer)?;
+            }
+            RpcSerializedTransaction::Json(json) => {
+                store!(u8, &2, writer)?;
+                store!(String, json, writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcSerializedTransaction {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u8, reader)?;
+        match load!(u8, reader)? {
+            0 => Ok(RpcSerializedTransaction::Hex(load!(String, reader)?)),
+            1 => Ok(RpcSerializedTransaction::Borsh(load!(Vec<u8>, reader)?)),
+            2 => Ok(RpcSerializedTransaction::Json(load!(String, reader)?)),
+            _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid serialized transaction encoding")),
+        }
+    }
+}
+
+/// Outcome of executing the script of a transaction input
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcInputScriptVerification {
+    pub input_index: u32,
+    /// The UTXO entry spent by the input, either supplied or resolved by the node
+    pub utxo_entry: Option<RpcUtxoEntry>,
+    pub verified: bool,
+    /// The reason why the input could not be verified
+    pub error: Option<String>,
+}
+
+impl Serializer for RpcInputScriptVerification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        store!(u8, &1, writer)?;
+        store!(u32, &self.input_index, writer)?;
+        serialize!(Option<RpcUtxoEntry>, &self.utxo_entry, writer)?;
+        store!(bool, &self.verified, writer)?;
+        store!(Option<String>, &self.error, writer)?;
+
+        Ok(())
+    }
+}
+
+impl Deserializer for RpcInputScriptVerification {
+    fn deserialize<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let _version = load!(u8, reader)?;
+        let input_index = load!(u32, reader)?;
+        let utxo_entry = deserialize!(Option<RpcUtxoEntry>, reader)?;
+        let verified = load!(bool, reader)?;
+        let error = load!(Option<String>, reader)?;
+
+        Ok(Self { input_index, utxo_entry, verified, error })
+    }
+}
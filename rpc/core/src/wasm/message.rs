@@ -42,6 +42,50 @@ const TS_ACCEPTED_TRANSACTION_IDS: &'static str = r#"
         acceptingBlockHash : HexString;
         acceptedTransactionIds : HexString[];
     }
+
+    /**
+     * Transaction accepted by a chain block, along with the UTXO entries spent by its inputs.
+     * 
+     * @category Node RPC
+     */
+    export interface IAcceptedTransaction {
+        /**
+         * The transaction, its verbose data referring to its containing block.
+         */
+        transaction : ITransaction;
+        inputUtxoEntries : {
+            amount : bigint;
+            scriptPublicKey : IScriptPublicKey;
+            blockDaaScore : bigint;
+            isCoinbase : boolean;
+        }[];
+    }
+
+    /**
+     * Transactions accepted by a chain block, in acceptance order.
+     * 
+     * @category Node RPC
+     */
+    export interface IAcceptedTransactions {
+        acceptingBlockHash : HexString;
+        acceptedTransactions : IAcceptedTransaction[];
+    }
+
+    /**
+     * Block of the selected parent chain.
+     * 
+     * @category Node RPC
+     */
+    export interface IChainBlock {
+        hash : HexString;
+        /**
+         * Position of the block in the selected parent chain stored by the node, only meaningful to this node.
+         */
+        chainIndex : bigint;
+        blueScore : bigint;
+        daaScore : bigint;
+        timestamp : bigint;
+    }
 "#;
 
 // ---
@@ -819,6 +863,11 @@ declare! {
          * `extraData` can contain a user-supplied plain text or a byte array represented by `Uint8array`.
          */
         extraData? : string | Uint8Array;
+        /**
+         * Tags appended to the coinbase payload, after the extra data and each one preceded by a `/`.
+         * Tags must be non-empty and must not contain `/`.
+         */
+        payloadTags? : string[];
     }
     "#,
 }
@@ -834,9 +883,17 @@ try_from! ( args: IGetBlockTemplateRequest, GetBlockTemplateRequest, {
     } else {
         Default::default()
     };
+    let payload_tags = match args.try_get_value("payloadTags")? {
+        Some(payload_tags) => js_sys::Array::from(&payload_tags)
+            .iter()
+            .map(|tag| tag.as_string().ok_or_else(|| Error::General("payloadTags must be an array of strings".to_string())))
+            .collect::<Result<Vec<_>>>()?,
+        None => vec![],
+    };
     Ok(GetBlockTemplateRequest {
         pay_address,
         extra_data,
+        payload_tags,
     })
 });
 
@@ -1091,6 +1148,42 @@ try_from! ( args: GetMempoolEntriesByAddressesResponse, IGetMempoolEntriesByAddr
 
 // ---
 
+declare! {
+    IGetPrioritizedTransactionsRequest,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetPrioritizedTransactionsRequest { }
+    "#,
+}
+
+try_from! ( args: IGetPrioritizedTransactionsRequest, GetPrioritizedTransactionsRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetPrioritizedTransactionsResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetPrioritizedTransactionsResponse {
+        prioritizedTransactions : IPrioritizedTransaction[];
+    }
+    "#,
+}
+
+try_from! ( args: GetPrioritizedTransactionsResponse, IGetPrioritizedTransactionsResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IGetMempoolEntryRequest,
     r#"
@@ -1131,6 +1224,44 @@ try_from! ( args: GetMempoolEntryResponse, IGetMempoolEntryResponse, {
 
 // ---
 
+declare! {
+    IPrioritizeTransactionRequest,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IPrioritizeTransactionRequest {
+        transactionId : HexString;
+        feeDelta : bigint;
+        excluded : boolean;
+    }
+    "#,
+}
+
+try_from! ( args: IPrioritizeTransactionRequest, PrioritizeTransactionRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IPrioritizeTransactionResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IPrioritizeTransactionResponse { }
+    "#,
+}
+
+try_from! ( args: PrioritizeTransactionResponse, IPrioritizeTransactionResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IGetSubnetworkRequest,
     r#"
@@ -1179,7 +1310,33 @@ declare! {
      * @category Node RPC
      */
     export interface IGetUtxosByAddressesRequest { 
-        addresses : Address[] | string[]
+        addresses : Address[] | string[];
+        /**
+         * Maximal number of returned entries, all matching entries being returned if omitted.
+         */
+        limit? : number;
+        /**
+         * The returned entries start right after this position, usually the `nextCursor` of the previous response.
+         */
+        cursor? : IUtxosByAddressesCursor;
+        minAmount? : bigint;
+        maxAmount? : bigint;
+        /**
+         * Excludes the coinbase entries which cannot be spent yet by a transaction entering the mempool.
+         */
+        excludeImmatureCoinbase? : boolean;
+        order? : "outpoint" | "daaScoreAscending" | "daaScoreDescending";
+    }
+
+    /**
+     * Position of an entry returned by {@link RpcClient.getUtxosByAddresses}.
+     * 
+     * @category Node RPC
+     */
+    export interface IUtxosByAddressesCursor {
+        address : Address | string;
+        outpoint : ITransactionOutpoint;
+        blockDaaScore : bigint;
     }
     "#,
 }
@@ -1187,7 +1344,7 @@ declare! {
 try_from! ( args: IGetUtxosByAddressesRequest, GetUtxosByAddressesRequest, {
     let js_value = JsValue::from(args);
     let request = if let Ok(addresses) = Vec::<Address>::try_from(AddressOrStringArrayT::from(js_value.clone())) {
-        GetUtxosByAddressesRequest { addresses }
+        GetUtxosByAddressesRequest::new(addresses)
     } else {
         from_value::<GetUtxosByAddressesRequest>(js_value)?
     };
@@ -1204,16 +1361,23 @@ declare! {
      */
     export interface IGetUtxosByAddressesResponse {
         entries : UtxoEntryReference[];
+        /**
+         * Set if more entries match the request.
+         */
+        nextCursor? : IUtxosByAddressesCursor;
     }
     "#,
 }
 
 try_from! ( args: GetUtxosByAddressesResponse, IGetUtxosByAddressesResponse, {
-    let GetUtxosByAddressesResponse { entries } = args;
+    let GetUtxosByAddressesResponse { entries, next_cursor } = args;
     let entries = entries.into_iter().map(UtxoEntryReference::from).collect::<Vec<UtxoEntryReference>>();
     let entries = js_sys::Array::from_iter(entries.into_iter().map(JsValue::from));
     let response = IGetUtxosByAddressesResponse::default();
     response.set("entries", entries.as_ref())?;
+    if let Some(next_cursor) = next_cursor {
+        response.set("nextCursor", &to_value(&next_cursor)?)?;
+    }
     Ok(response)
 });
 
@@ -1230,6 +1394,14 @@ declare! {
     export interface IGetVirtualChainFromBlockRequest {
         startHash : HexString;
         includeAcceptedTransactionIds: boolean;
+        /**
+         * Maximal number of added chain blocks returned, further bounded by the node.
+         */
+        limit? : number;
+        /**
+         * Includes the full accepted transactions along with the UTXO entries spent by their inputs.
+         */
+        includeAcceptedTransactions? : boolean;
     }
     "#,
 }
@@ -1250,6 +1422,11 @@ declare! {
         removedChainBlockHashes : HexString[];
         addedChainBlockHashes : HexString[];
         acceptedTransactionIds : IAcceptedTransactionIds[];
+        acceptedTransactions : IAcceptedTransactions[];
+        /**
+         * Set if more chain blocks were added, to be requested with this hash as `startHash`.
+         */
+        nextStartHash? : HexString;
     }
     "#,
 }
@@ -1296,6 +1473,355 @@ try_from! ( args: ResolveFinalityConflictResponse, IResolveFinalityConflictRespo
 
 // ---
 
+declare! {
+    IInvalidateBlockRequest,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IInvalidateBlockRequest {
+        hash : HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IInvalidateBlockRequest, InvalidateBlockRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IInvalidateBlockResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IInvalidateBlockResponse { }
+    "#,
+}
+
+try_from! ( args: InvalidateBlockResponse, IInvalidateBlockResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IReconsiderBlockRequest,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IReconsiderBlockRequest {
+        hash : HexString;
+    }
+    "#,
+}
+
+try_from! ( args: IReconsiderBlockRequest, ReconsiderBlockRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IReconsiderBlockResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IReconsiderBlockResponse { }
+    "#,
+}
+
+try_from! ( args: ReconsiderBlockResponse, IReconsiderBlockResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGenerateBlocksRequest,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGenerateBlocksRequest {
+        count : number;
+        payAddress : Address | string;
+        /**
+         * Mempool transactions the first block must hold, in this order.
+         */
+        transactionIds? : HexString[];
+    }
+    "#,
+}
+
+try_from! ( args: IGenerateBlocksRequest, GenerateBlocksRequest, {
+    let count = args.get_u32("count")?;
+    let pay_address = args.cast_into::<Address>("payAddress")?;
+    let transaction_ids = if let Some(transaction_ids) = args.try_get_value("transactionIds")? {
+        from_value(transaction_ids)?
+    } else {
+        Default::default()
+    };
+    Ok(GenerateBlocksRequest { count, pay_address, transaction_ids })
+});
+
+declare! {
+    IGenerateBlocksResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGenerateBlocksResponse {
+        blockHashes : HexString[];
+    }
+    "#,
+}
+
+try_from! ( args: GenerateBlocksResponse, IGenerateBlocksResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    ICreateBackupRequest,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface ICreateBackupRequest { }
+    "#,
+}
+
+try_from! ( args: ICreateBackupRequest, CreateBackupRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    ICreateBackupResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface ICreateBackupResponse {
+        path : string;
+    }
+    "#,
+}
+
+try_from! ( args: CreateBackupResponse, ICreateBackupResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetDatabaseStatsRequest,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetDatabaseStatsRequest { }
+    "#,
+}
+
+try_from! ( args: IGetDatabaseStatsRequest, GetDatabaseStatsRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetDatabaseStatsResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetDatabaseStatsResponse {
+        databases : IDatabaseStats[];
+        cacheStats : IStoreCacheStats[];
+    }
+    "#,
+}
+
+try_from! ( args: GetDatabaseStatsResponse, IGetDatabaseStatsResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetChainBlockByBlueScoreRequest,
+    r#"
+    /**
+     * Requests the first chain block whose blue score reaches `blueScore`.
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlockByBlueScoreRequest {
+        blueScore : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IGetChainBlockByBlueScoreRequest, GetChainBlockByBlueScoreRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetChainBlockByBlueScoreResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlockByBlueScoreResponse {
+        chainBlock : IChainBlock;
+    }
+    "#,
+}
+
+try_from! ( args: GetChainBlockByBlueScoreResponse, IGetChainBlockByBlueScoreResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetChainBlockByDaaScoreRequest,
+    r#"
+    /**
+     * Requests the first chain block whose DAA score reaches `daaScore`.
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlockByDaaScoreRequest {
+        daaScore : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IGetChainBlockByDaaScoreRequest, GetChainBlockByDaaScoreRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetChainBlockByDaaScoreResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlockByDaaScoreResponse {
+        chainBlock : IChainBlock;
+    }
+    "#,
+}
+
+try_from! ( args: GetChainBlockByDaaScoreResponse, IGetChainBlockByDaaScoreResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetChainBlocksByBlueScoreRangeRequest,
+    r#"
+    /**
+     * Requests the chain blocks with a blue score within `[lowBlueScore, highBlueScore]`, at most 1000 of them.
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlocksByBlueScoreRangeRequest {
+        lowBlueScore : bigint;
+        highBlueScore : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IGetChainBlocksByBlueScoreRangeRequest, GetChainBlocksByBlueScoreRangeRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetChainBlocksByBlueScoreRangeResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlocksByBlueScoreRangeResponse {
+        chainBlocks : IChainBlock[];
+    }
+    "#,
+}
+
+try_from! ( args: GetChainBlocksByBlueScoreRangeResponse, IGetChainBlocksByBlueScoreRangeResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IGetChainBlocksByDaaScoreRangeRequest,
+    r#"
+    /**
+     * Requests the chain blocks with a DAA score within `[lowDaaScore, highDaaScore]`, at most 1000 of them.
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlocksByDaaScoreRangeRequest {
+        lowDaaScore : bigint;
+        highDaaScore : bigint;
+    }
+    "#,
+}
+
+try_from! ( args: IGetChainBlocksByDaaScoreRangeRequest, GetChainBlocksByDaaScoreRangeRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IGetChainBlocksByDaaScoreRangeResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IGetChainBlocksByDaaScoreRangeResponse {
+        chainBlocks : IChainBlock[];
+    }
+    "#,
+}
+
+try_from! ( args: GetChainBlocksByDaaScoreRangeResponse, IGetChainBlocksByDaaScoreRangeResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     ISubmitBlockRequest,
     r#"
@@ -1485,6 +2011,116 @@ try_from! ( args: SubmitTransactionResponse, ISubmitTransactionResponse, {
 
 // ---
 
+declare! {
+    ITestMempoolAcceptRequest,
+    r#"
+    /**
+     * Tests whether the mempool would accept a batch of transactions, submitted in order,
+     * without inserting any of them. A transaction may spend the outputs of preceding
+     * transactions of the batch.
+     * 
+     * @category Node RPC
+     */
+    export interface ITestMempoolAcceptRequest {
+        transactions : Transaction[],
+        allowOrphan? : boolean,
+        /**
+         * Test the transactions as replacements, as submitted via `submitTransactionReplacement`.
+         */
+        replaceByFee? : boolean
+    }
+    "#,
+}
+
+try_from! ( args: ITestMempoolAcceptRequest, TestMempoolAcceptRequest, {
+    let transactions = args.try_get_value("transactions")?.ok_or_else(|| Error::MissingRpcFieldError("ITestMempoolAcceptRequest".to_string(), "transactions".to_string()))?;
+    let transactions = js_sys::Array::from(&transactions).iter().map(|transaction| {
+        if let Ok(transaction) = Transaction::try_owned_from(&transaction) {
+            Ok(transaction.into())
+        } else {
+            Ok(Transaction::try_cast_from(&transaction)?.as_ref().into())
+        }
+    }).collect::<Result<Vec<_>>>()?;
+    let allow_orphan = args.try_get_bool("allowOrphan")?.unwrap_or(false);
+    let replace_by_fee = args.try_get_bool("replaceByFee")?.unwrap_or(false);
+    Ok(TestMempoolAcceptRequest { transactions, allow_orphan, replace_by_fee })
+});
+
+declare! {
+    ITestMempoolAcceptResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface ITestMempoolAcceptResponse {
+        results : IMempoolAcceptResult[];
+    }
+    "#,
+}
+
+try_from! ( args: TestMempoolAcceptResponse, ITestMempoolAcceptResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
+declare! {
+    IDecodeTransactionRequest,
+    r#"
+    /**
+     * Decodes a serialized transaction and optionally verifies the scripts of its inputs.
+     * 
+     * @category Node RPC
+     */
+    export interface IDecodeTransactionRequest {
+        /**
+         * The Borsh serialization of the transaction, either hex encoded or as bytes,
+         * or its JSON serialization.
+         */
+        transaction : { hex : HexString } | { borsh : Uint8Array | number[] } | { json : string },
+        verifyScripts? : boolean,
+        /**
+         * The UTXO entries spent by the inputs, in input order. If omitted, the node
+         * looks the entries up in its UTXO set and mempool.
+         */
+        utxoEntries? : IUtxoEntry[]
+    }
+    "#,
+}
+
+try_from! ( args: IDecodeTransactionRequest, DecodeTransactionRequest, {
+    Ok(from_value(args.into())?)
+});
+
+declare! {
+    IDecodeTransactionResponse,
+    r#"
+    /**
+     * 
+     * 
+     * @category Node RPC
+     */
+    export interface IDecodeTransactionResponse {
+        transaction : ITransaction;
+        storageMass? : bigint;
+        scriptVerifications : {
+            inputIndex : number;
+            utxoEntry? : IUtxoEntry;
+            verified : boolean;
+            error? : string;
+        }[];
+    }
+    "#,
+}
+
+try_from! ( args: DecodeTransactionResponse, IDecodeTransactionResponse, {
+    Ok(to_value(&args)?.into())
+});
+
+// ---
+
 declare! {
     IUnbanRequest,
     r#"
@@ -1665,6 +2301,20 @@ declare! {
         nextBlockTemplateFeerateMin : number;
         nextBlockTemplateFeerateMedian : number;
         nextBlockTemplateFeerateMax : number;
+        historicalSampleCount : number;
+        historicalEstimates : IHistoricalFeerateEstimate[];
+    }
+
+    /**
+     * The lowest feerate at which at least a `confidence` fraction of the
+     * historically observed transactions were included within `targetSeconds`.
+     *
+     * @category Node RPC
+     */
+    export interface IHistoricalFeerateEstimate {
+        targetSeconds : number;
+        confidence : number;
+        feerate : number;
     }
     "#,
 }
@@ -1678,6 +2328,8 @@ try_from!( data: RpcFeeEstimateVerboseExperimentalData, IFeeEstimateVerboseExper
     target.set("nextBlockTemplateFeerateMin", &data.next_block_template_feerate_min.into())?;
     target.set("nextBlockTemplateFeerateMedian", &data.next_block_template_feerate_median.into())?;
     target.set("nextBlockTemplateFeerateMax", &data.next_block_template_feerate_max.into())?;
+    target.set("historicalSampleCount", &data.historical_sample_count.into())?;
+    target.set("historicalEstimates", &to_value(&data.historical_estimates)?)?;
 
     Ok(target)
 });
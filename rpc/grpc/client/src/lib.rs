@@ -5,7 +5,7 @@ use self::{
 use async_channel::{Receiver, Sender};
 use async_trait::async_trait;
 pub use client_pool::ClientPool;
-use connection_event::ConnectionEvent;
+pub use connection_event::ConnectionEvent;
 use futures::{future::FutureExt, pin_mut, select};
 use regex::Regex;
 use spectre_core::{debug, error, trace};
@@ -277,6 +277,19 @@ impl RpcApi for GrpcClient {
     route!(get_fee_estimate_experimental_call, GetFeeEstimateExperimental);
     route!(get_current_block_color_call, GetCurrentBlockColor);
     route!(get_utxo_return_address_call, GetUtxoReturnAddress);
+    route!(prioritize_transaction_call, PrioritizeTransaction);
+    route!(get_prioritized_transactions_call, GetPrioritizedTransactions);
+    route!(invalidate_block_call, InvalidateBlock);
+    route!(reconsider_block_call, ReconsiderBlock);
+    route!(generate_blocks_call, GenerateBlocks);
+    route!(create_backup_call, CreateBackup);
+    route!(get_database_stats_call, GetDatabaseStats);
+    route!(get_chain_block_by_blue_score_call, GetChainBlockByBlueScore);
+    route!(get_chain_block_by_daa_score_call, GetChainBlockByDaaScore);
+    route!(get_chain_blocks_by_blue_score_range_call, GetChainBlocksByBlueScoreRange);
+    route!(get_chain_blocks_by_daa_score_range_call, GetChainBlocksByDaaScoreRange);
+    route!(test_mempool_accept_call, TestMempoolAccept);
+    route!(decode_transaction_call, DecodeTransaction);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
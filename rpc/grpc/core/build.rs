@@ -1,10 +1,16 @@
+use std::{env, path::PathBuf};
+
 fn main() {
     let protowire_files = &["./proto/messages.proto", "./proto/rpc.proto"];
     let dirs = &["./proto"];
 
+    // The encoded descriptors of the protowire files, served by the gRPC reflection service
+    let descriptor_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("protowire_descriptor.bin");
+
     tonic_build::configure()
         .build_server(true)
         .build_client(true)
+        .file_descriptor_set_path(descriptor_path)
 
         // In case we want protowire.rs to be explicitly integrated in the crate code,
         // uncomment this line and reflect the change in src/lib.rs
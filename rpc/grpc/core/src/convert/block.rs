@@ -38,6 +38,16 @@ from!(item: &spectre_rpc_core::RpcBlockVerboseData, protowire::RpcBlockVerboseDa
     }
 });
 
+from!(item: &spectre_rpc_core::RpcChainBlock, protowire::RpcChainBlock, {
+    Self {
+        hash: item.hash.to_string(),
+        chain_index: item.chain_index,
+        blue_score: item.blue_score,
+        daa_score: item.daa_score,
+        timestamp: item.timestamp,
+    }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -95,3 +105,13 @@ try_from!(item: &protowire::RpcBlockVerboseData, spectre_rpc_core::RpcBlockVerbo
         is_chain_block: item.is_chain_block,
     }
 });
+
+try_from!(item: &protowire::RpcChainBlock, spectre_rpc_core::RpcChainBlock, {
+    Self {
+        hash: RpcHash::from_str(&item.hash)?,
+        chain_index: item.chain_index,
+        blue_score: item.blue_score,
+        daa_score: item.daa_score,
+        timestamp: item.timestamp,
+    }
+});
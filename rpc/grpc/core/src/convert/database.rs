@@ -0,0 +1,72 @@
+use crate::protowire;
+use crate::{from, try_from};
+use spectre_rpc_core::RpcError;
+
+// ----------------------------------------------------------------------------
+// rpc_core to protowire
+// ----------------------------------------------------------------------------
+
+from!(item: &spectre_rpc_core::RpcStoreStats, protowire::RpcStoreStats, {
+    Self {
+        prefix: item.prefix as u32,
+        name: item.name.clone(),
+        approximate_key_count: item.approximate_key_count,
+        approximate_size: item.approximate_size,
+    }
+});
+
+from!(item: &spectre_rpc_core::RpcDatabaseStats, protowire::RpcDatabaseStats, {
+    Self {
+        name: item.name.clone(),
+        estimated_key_count: item.estimated_key_count,
+        total_sst_files_size: item.total_sst_files_size,
+        live_sst_files_size: item.live_sst_files_size,
+        memtables_size: item.memtables_size,
+        pending_compaction_bytes: item.pending_compaction_bytes,
+        running_compactions: item.running_compactions,
+        is_compaction_pending: item.is_compaction_pending,
+        files_per_level: item.files_per_level.clone(),
+        stores: item.stores.iter().map(|x| x.into()).collect(),
+    }
+});
+
+from!(item: &spectre_rpc_core::RpcStoreCacheStats, protowire::RpcStoreCacheStats, {
+    Self { prefix: item.prefix as u32, name: item.name.clone(), hits: item.hits, misses: item.misses }
+});
+
+// ----------------------------------------------------------------------------
+// protowire to rpc_core
+// ----------------------------------------------------------------------------
+
+try_from!(item: &protowire::RpcStoreStats, spectre_rpc_core::RpcStoreStats, {
+    Self {
+        prefix: u8::try_from(item.prefix)?,
+        name: item.name.clone(),
+        approximate_key_count: item.approximate_key_count,
+        approximate_size: item.approximate_size,
+    }
+});
+
+try_from!(item: &protowire::RpcDatabaseStats, spectre_rpc_core::RpcDatabaseStats, {
+    Self {
+        name: item.name.clone(),
+        estimated_key_count: item.estimated_key_count,
+        total_sst_files_size: item.total_sst_files_size,
+        live_sst_files_size: item.live_sst_files_size,
+        memtables_size: item.memtables_size,
+        pending_compaction_bytes: item.pending_compaction_bytes,
+        running_compactions: item.running_compactions,
+        is_compaction_pending: item.is_compaction_pending,
+        files_per_level: item.files_per_level.clone(),
+        stores: item.stores.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::RpcStoreCacheStats, spectre_rpc_core::RpcStoreCacheStats, {
+    Self {
+        prefix: u8::try_from(item.prefix)?,
+        name: item.name.clone(),
+        hits: item.hits,
+        misses: item.misses,
+    }
+});
@@ -1,6 +1,7 @@
 use crate::protowire;
 use crate::{from, try_from};
 use spectre_rpc_core::RpcError;
+use std::str::FromStr;
 
 // ----------------------------------------------------------------------------
 // rpc_core to protowire
@@ -18,6 +19,24 @@ from!(item: &spectre_rpc_core::RpcMempoolEntryByAddress, protowire::RpcMempoolEn
     }
 });
 
+from!(item: &spectre_rpc_core::RpcPrioritizedTransaction, protowire::RpcPrioritizedTransaction, {
+    Self { transaction_id: item.transaction_id.to_string(), fee_delta: item.fee_delta, excluded: item.excluded }
+});
+
+from!(item: &spectre_rpc_core::RpcMempoolAcceptResult, protowire::RpcMempoolAcceptResult, {
+    Self {
+        transaction_id: item.transaction_id.to_string(),
+        allowed: item.allowed,
+        is_orphan: item.is_orphan,
+        reject_reason: item.reject_reason.clone().unwrap_or_default(),
+        compute_mass: item.compute_mass,
+        storage_mass: item.storage_mass.unwrap_or_default(),
+        fee: item.fee.unwrap_or_default(),
+        feerate: item.feerate.unwrap_or_default(),
+        replaced_transaction_id: item.replaced_transaction_id.map(|x| x.to_string()).unwrap_or_default(),
+    }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -40,3 +59,25 @@ try_from!(item: &protowire::RpcMempoolEntryByAddress, spectre_rpc_core::RpcMempo
         item.receiving.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
     )
 });
+
+try_from!(item: &protowire::RpcPrioritizedTransaction, spectre_rpc_core::RpcPrioritizedTransaction, {
+    Self::new(spectre_rpc_core::RpcTransactionId::from_str(&item.transaction_id)?, item.fee_delta, item.excluded)
+});
+
+try_from!(item: &protowire::RpcMempoolAcceptResult, spectre_rpc_core::RpcMempoolAcceptResult, {
+    Self {
+        transaction_id: spectre_rpc_core::RpcTransactionId::from_str(&item.transaction_id)?,
+        allowed: item.allowed,
+        is_orphan: item.is_orphan,
+        reject_reason: (!item.reject_reason.is_empty()).then(|| item.reject_reason.clone()),
+        compute_mass: item.compute_mass,
+        storage_mass: (item.storage_mass != 0).then_some(item.storage_mass),
+        fee: (item.fee != 0).then_some(item.fee),
+        feerate: (item.feerate != 0.0).then_some(item.feerate),
+        replaced_transaction_id: if item.replaced_transaction_id.is_empty() {
+            None
+        } else {
+            Some(spectre_rpc_core::RpcTransactionId::from_str(&item.replaced_transaction_id)?)
+        },
+    }
+});
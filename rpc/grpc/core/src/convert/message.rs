@@ -157,6 +157,7 @@ from!(item: &spectre_rpc_core::GetBlockTemplateRequest, protowire::GetBlockTempl
     Self {
         pay_address: (&item.pay_address).into(),
         extra_data: String::from_utf8(item.extra_data.clone()).expect("extra data has to be valid UTF-8"),
+        payload_tags: item.payload_tags.clone(),
     }
 });
 from!(item: RpcResult<&spectre_rpc_core::GetBlockTemplateResponse>, protowire::GetBlockTemplateResponseMessage, {
@@ -267,13 +268,20 @@ from!(item: RpcResult<&spectre_rpc_core::GetSubnetworkResponse>, protowire::GetS
 // ~~~
 
 from!(item: &spectre_rpc_core::GetVirtualChainFromBlockRequest, protowire::GetVirtualChainFromBlockRequestMessage, {
-    Self { start_hash: item.start_hash.to_string(), include_accepted_transaction_ids: item.include_accepted_transaction_ids }
+    Self {
+        start_hash: item.start_hash.to_string(),
+        include_accepted_transaction_ids: item.include_accepted_transaction_ids,
+        limit: item.limit.unwrap_or_default(),
+        include_accepted_transactions: item.include_accepted_transactions,
+    }
 });
 from!(item: RpcResult<&spectre_rpc_core::GetVirtualChainFromBlockResponse>, protowire::GetVirtualChainFromBlockResponseMessage, {
     Self {
         removed_chain_block_hashes: item.removed_chain_block_hashes.iter().map(|x| x.to_string()).collect(),
         added_chain_block_hashes: item.added_chain_block_hashes.iter().map(|x| x.to_string()).collect(),
         accepted_transaction_ids: item.accepted_transaction_ids.iter().map(|x| x.into()).collect(),
+        accepted_transactions: item.accepted_transactions.iter().map(|x| x.into()).collect(),
+        next_start_hash: item.next_start_hash.map(|x| x.to_string()).unwrap_or_default(),
         error: None,
     }
 });
@@ -322,6 +330,91 @@ from!(_item: RpcResult<&spectre_rpc_core::ResolveFinalityConflictResponse>, prot
     Self { error: None }
 });
 
+from!(item: &spectre_rpc_core::InvalidateBlockRequest, protowire::InvalidateBlockRequestMessage, { Self { hash: item.hash.to_string() } });
+from!(RpcResult<&spectre_rpc_core::InvalidateBlockResponse>, protowire::InvalidateBlockResponseMessage);
+
+from!(item: &spectre_rpc_core::ReconsiderBlockRequest, protowire::ReconsiderBlockRequestMessage, { Self { hash: item.hash.to_string() } });
+from!(RpcResult<&spectre_rpc_core::ReconsiderBlockResponse>, protowire::ReconsiderBlockResponseMessage);
+
+from!(item: &spectre_rpc_core::GenerateBlocksRequest, protowire::GenerateBlocksRequestMessage, {
+    Self {
+        count: item.count,
+        pay_address: (&item.pay_address).into(),
+        transaction_ids: item.transaction_ids.iter().map(|x| x.to_string()).collect(),
+    }
+});
+from!(item: RpcResult<&spectre_rpc_core::GenerateBlocksResponse>, protowire::GenerateBlocksResponseMessage, {
+    Self { block_hashes: item.block_hashes.iter().map(|x| x.to_string()).collect(), error: None }
+});
+
+from!(&spectre_rpc_core::CreateBackupRequest, protowire::CreateBackupRequestMessage);
+from!(&spectre_rpc_core::GetDatabaseStatsRequest, protowire::GetDatabaseStatsRequestMessage);
+from!(item: RpcResult<&spectre_rpc_core::GetDatabaseStatsResponse>, protowire::GetDatabaseStatsResponseMessage, {
+    Self {
+        databases: item.databases.iter().map(|x| x.into()).collect(),
+        cache_stats: item.cache_stats.iter().map(|x| x.into()).collect(),
+        error: None,
+    }
+});
+from!(item: RpcResult<&spectre_rpc_core::CreateBackupResponse>, protowire::CreateBackupResponseMessage, {
+    Self { path: item.path.clone(), error: None }
+});
+
+from!(item: &spectre_rpc_core::GetChainBlockByBlueScoreRequest, protowire::GetChainBlockByBlueScoreRequestMessage, {
+    Self { blue_score: item.blue_score }
+});
+from!(item: RpcResult<&spectre_rpc_core::GetChainBlockByBlueScoreResponse>, protowire::GetChainBlockByBlueScoreResponseMessage, {
+    Self { chain_block: Some((&item.chain_block).into()), error: None }
+});
+
+from!(item: &spectre_rpc_core::GetChainBlockByDaaScoreRequest, protowire::GetChainBlockByDaaScoreRequestMessage, {
+    Self { daa_score: item.daa_score }
+});
+from!(item: RpcResult<&spectre_rpc_core::GetChainBlockByDaaScoreResponse>, protowire::GetChainBlockByDaaScoreResponseMessage, {
+    Self { chain_block: Some((&item.chain_block).into()), error: None }
+});
+
+from!(item: &spectre_rpc_core::GetChainBlocksByBlueScoreRangeRequest, protowire::GetChainBlocksByBlueScoreRangeRequestMessage, {
+    Self { low_blue_score: item.low_blue_score, high_blue_score: item.high_blue_score }
+});
+from!(item: RpcResult<&spectre_rpc_core::GetChainBlocksByBlueScoreRangeResponse>, protowire::GetChainBlocksByBlueScoreRangeResponseMessage, {
+    Self { chain_blocks: item.chain_blocks.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(item: &spectre_rpc_core::GetChainBlocksByDaaScoreRangeRequest, protowire::GetChainBlocksByDaaScoreRangeRequestMessage, {
+    Self { low_daa_score: item.low_daa_score, high_daa_score: item.high_daa_score }
+});
+from!(item: RpcResult<&spectre_rpc_core::GetChainBlocksByDaaScoreRangeResponse>, protowire::GetChainBlocksByDaaScoreRangeResponseMessage, {
+    Self { chain_blocks: item.chain_blocks.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(item: &spectre_rpc_core::TestMempoolAcceptRequest, protowire::TestMempoolAcceptRequestMessage, {
+    Self {
+        transactions: item.transactions.iter().map(|x| x.into()).collect(),
+        allow_orphan: item.allow_orphan,
+        replace_by_fee: item.replace_by_fee,
+    }
+});
+from!(item: RpcResult<&spectre_rpc_core::TestMempoolAcceptResponse>, protowire::TestMempoolAcceptResponseMessage, {
+    Self { results: item.results.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(item: &spectre_rpc_core::DecodeTransactionRequest, protowire::DecodeTransactionRequestMessage, {
+    Self {
+        transaction: Some((&item.transaction).into()),
+        verify_scripts: item.verify_scripts,
+        utxo_entries: item.utxo_entries.iter().map(|x| x.into()).collect(),
+    }
+});
+from!(item: RpcResult<&spectre_rpc_core::DecodeTransactionResponse>, protowire::DecodeTransactionResponseMessage, {
+    Self {
+        transaction: Some((&item.transaction).into()),
+        storage_mass: item.storage_mass.unwrap_or_default(),
+        script_verifications: item.script_verifications.iter().map(|x| x.into()).collect(),
+        error: None,
+    }
+});
+
 from!(&spectre_rpc_core::ShutdownRequest, protowire::ShutdownRequestMessage);
 from!(RpcResult<&spectre_rpc_core::ShutdownResponse>, protowire::ShutdownResponseMessage);
 
@@ -332,12 +425,30 @@ from!(item: RpcResult<&spectre_rpc_core::GetHeadersResponse>, protowire::GetHead
     Self { headers: item.headers.iter().map(|x| x.hash.to_string()).collect(), error: None }
 });
 
+from!(item: spectre_rpc_core::RpcUtxoOrder, protowire::RpcUtxoOrder, {
+    match item {
+        spectre_rpc_core::RpcUtxoOrder::Outpoint => protowire::RpcUtxoOrder::OrderByOutpoint,
+        spectre_rpc_core::RpcUtxoOrder::DaaScoreAscending => protowire::RpcUtxoOrder::OrderByDaaScoreAscending,
+        spectre_rpc_core::RpcUtxoOrder::DaaScoreDescending => protowire::RpcUtxoOrder::OrderByDaaScoreDescending,
+    }
+});
+from!(item: &spectre_rpc_core::RpcUtxosByAddressesCursor, protowire::RpcUtxosByAddressesCursor, {
+    Self { address: (&item.address).into(), outpoint: Some((&item.outpoint).into()), block_daa_score: item.block_daa_score }
+});
 from!(item: &spectre_rpc_core::GetUtxosByAddressesRequest, protowire::GetUtxosByAddressesRequestMessage, {
-    Self { addresses: item.addresses.iter().map(|x| x.into()).collect() }
+    Self {
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+        limit: item.limit.unwrap_or_default(),
+        cursor: item.cursor.as_ref().map(|x| x.into()),
+        min_amount: item.min_amount.unwrap_or_default(),
+        max_amount: item.max_amount.unwrap_or_default(),
+        exclude_immature_coinbase: item.exclude_immature_coinbase,
+        order: protowire::RpcUtxoOrder::from(item.order) as i32,
+    }
 });
 from!(item: RpcResult<&spectre_rpc_core::GetUtxosByAddressesResponse>, protowire::GetUtxosByAddressesResponseMessage, {
     debug!("GRPC, Creating GetUtxosByAddresses message with {} entries", item.entries.len());
-    Self { entries: item.entries.iter().map(|x| x.into()).collect(), error: None }
+    Self { entries: item.entries.iter().map(|x| x.into()).collect(), next_cursor: item.next_cursor.as_ref().map(|x| x.into()), error: None }
 });
 
 from!(item: &spectre_rpc_core::GetBalanceByAddressRequest, protowire::GetBalanceByAddressRequestMessage, {
@@ -431,6 +542,16 @@ from!(item: RpcResult<&spectre_rpc_core::GetCurrentBlockColorResponse>, protowir
     Self { blue: item.blue, error: None }
 });
 
+from!(item: &spectre_rpc_core::PrioritizeTransactionRequest, protowire::PrioritizeTransactionRequestMessage, {
+    Self { transaction_id: item.transaction_id.to_string(), fee_delta: item.fee_delta, excluded: item.excluded }
+});
+from!(RpcResult<&spectre_rpc_core::PrioritizeTransactionResponse>, protowire::PrioritizeTransactionResponseMessage);
+
+from!(&spectre_rpc_core::GetPrioritizedTransactionsRequest, protowire::GetPrioritizedTransactionsRequestMessage);
+from!(item: RpcResult<&spectre_rpc_core::GetPrioritizedTransactionsResponse>, protowire::GetPrioritizedTransactionsResponseMessage, {
+    Self { prioritized_transactions: item.prioritized_transactions.iter().map(|x| x.into()).collect(), error: None }
+});
+
 from!(item: &spectre_rpc_core::GetUtxoReturnAddressRequest, protowire::GetUtxoReturnAddressRequestMessage, {
     Self {
         txid: item.txid.to_string(),
@@ -452,6 +573,7 @@ from!(item: &spectre_rpc_core::GetMetricsRequest, protowire::GetMetricsRequestMe
         consensus_metrics: item.consensus_metrics,
         storage_metrics: item.storage_metrics,
         custom_metrics: item.custom_metrics,
+        stratum_metrics: item.stratum_metrics,
     }
 });
 from!(item: RpcResult<&spectre_rpc_core::GetMetricsResponse>, protowire::GetMetricsResponseMessage, {
@@ -462,6 +584,7 @@ from!(item: RpcResult<&spectre_rpc_core::GetMetricsResponse>, protowire::GetMetr
         bandwidth_metrics: item.bandwidth_metrics.as_ref().map(|x| x.into()),
         consensus_metrics: item.consensus_metrics.as_ref().map(|x| x.into()),
         storage_metrics: item.storage_metrics.as_ref().map(|x| x.into()),
+        stratum_metrics: item.stratum_metrics.as_ref().map(|x| x.into()),
         // TODO
         // custom_metrics : None,
         error: None,
@@ -527,6 +650,14 @@ from!(item: &spectre_rpc_core::NotifyUtxosChangedRequest, protowire::StopNotifyi
 from!(RpcResult<&spectre_rpc_core::NotifyUtxosChangedResponse>, protowire::NotifyUtxosChangedResponseMessage);
 from!(RpcResult<&spectre_rpc_core::NotifyUtxosChangedResponse>, protowire::StopNotifyingUtxosChangedResponseMessage);
 
+from!(item: &spectre_rpc_core::NotifyAcceptedTransactionsChangedRequest, protowire::NotifyAcceptedTransactionsChangedRequestMessage, {
+    Self { addresses: item.addresses.iter().map(|x| x.into()).collect(), command: item.command.into() }
+});
+from!(
+    RpcResult<&spectre_rpc_core::NotifyAcceptedTransactionsChangedResponse>,
+    protowire::NotifyAcceptedTransactionsChangedResponseMessage
+);
+
 from!(item: &spectre_rpc_core::NotifyPruningPointUtxoSetOverrideRequest, protowire::NotifyPruningPointUtxoSetOverrideRequestMessage, {
     Self { command: item.command.into() }
 });
@@ -609,7 +740,11 @@ impl TryFrom<&protowire::SubmitBlockResponseMessage> for spectre_rpc_core::Submi
 }
 
 try_from!(item: &protowire::GetBlockTemplateRequestMessage, spectre_rpc_core::GetBlockTemplateRequest, {
-    Self { pay_address: item.pay_address.clone().try_into()?, extra_data: RpcExtraData::from_iter(item.extra_data.bytes()) }
+    Self {
+        pay_address: item.pay_address.clone().try_into()?,
+        extra_data: RpcExtraData::from_iter(item.extra_data.bytes()),
+        payload_tags: item.payload_tags.clone(),
+    }
 });
 try_from!(item: &protowire::GetBlockTemplateResponseMessage, RpcResult<spectre_rpc_core::GetBlockTemplateResponse>, {
     Self {
@@ -757,7 +892,12 @@ try_from!(item: &protowire::GetSubnetworkResponseMessage, RpcResult<spectre_rpc_
 });
 
 try_from!(item: &protowire::GetVirtualChainFromBlockRequestMessage, spectre_rpc_core::GetVirtualChainFromBlockRequest, {
-    Self { start_hash: RpcHash::from_str(&item.start_hash)?, include_accepted_transaction_ids: item.include_accepted_transaction_ids }
+    Self {
+        start_hash: RpcHash::from_str(&item.start_hash)?,
+        include_accepted_transaction_ids: item.include_accepted_transaction_ids,
+        limit: (item.limit > 0).then_some(item.limit),
+        include_accepted_transactions: item.include_accepted_transactions,
+    }
 });
 try_from!(item: &protowire::GetVirtualChainFromBlockResponseMessage, RpcResult<spectre_rpc_core::GetVirtualChainFromBlockResponse>, {
     Self {
@@ -768,6 +908,8 @@ try_from!(item: &protowire::GetVirtualChainFromBlockResponseMessage, RpcResult<s
             .collect::<Result<Vec<_>, _>>()?,
         added_chain_block_hashes: item.added_chain_block_hashes.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
         accepted_transaction_ids: item.accepted_transaction_ids.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        accepted_transactions: item.accepted_transactions.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        next_start_hash: if item.next_start_hash.is_empty() { None } else { Some(RpcHash::from_str(&item.next_start_hash)?) },
     }
 });
 
@@ -811,6 +953,117 @@ try_from!(item: &protowire::ResolveFinalityConflictRequestMessage, spectre_rpc_c
 });
 try_from!(&protowire::ResolveFinalityConflictResponseMessage, RpcResult<spectre_rpc_core::ResolveFinalityConflictResponse>);
 
+try_from!(item: &protowire::InvalidateBlockRequestMessage, spectre_rpc_core::InvalidateBlockRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(&protowire::InvalidateBlockResponseMessage, RpcResult<spectre_rpc_core::InvalidateBlockResponse>);
+
+try_from!(item: &protowire::ReconsiderBlockRequestMessage, spectre_rpc_core::ReconsiderBlockRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(&protowire::ReconsiderBlockResponseMessage, RpcResult<spectre_rpc_core::ReconsiderBlockResponse>);
+
+try_from!(item: &protowire::GenerateBlocksRequestMessage, spectre_rpc_core::GenerateBlocksRequest, {
+    Self {
+        count: item.count,
+        pay_address: item.pay_address.clone().try_into()?,
+        transaction_ids: item
+            .transaction_ids
+            .iter()
+            .map(|x| spectre_rpc_core::RpcTransactionId::from_str(x))
+            .collect::<Result<Vec<_>, _>>()?,
+    }
+});
+try_from!(item: &protowire::GenerateBlocksResponseMessage, RpcResult<spectre_rpc_core::GenerateBlocksResponse>, {
+    Self { block_hashes: item.block_hashes.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(&protowire::CreateBackupRequestMessage, spectre_rpc_core::CreateBackupRequest);
+try_from!(&protowire::GetDatabaseStatsRequestMessage, spectre_rpc_core::GetDatabaseStatsRequest);
+try_from!(item: &protowire::GetDatabaseStatsResponseMessage, RpcResult<spectre_rpc_core::GetDatabaseStatsResponse>, {
+    Self {
+        databases: item.databases.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        cache_stats: item.cache_stats.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+try_from!(item: &protowire::CreateBackupResponseMessage, RpcResult<spectre_rpc_core::CreateBackupResponse>, {
+    Self { path: item.path.clone() }
+});
+
+try_from!(item: &protowire::GetChainBlockByBlueScoreRequestMessage, spectre_rpc_core::GetChainBlockByBlueScoreRequest, {
+    Self { blue_score: item.blue_score }
+});
+try_from!(item: &protowire::GetChainBlockByBlueScoreResponseMessage, RpcResult<spectre_rpc_core::GetChainBlockByBlueScoreResponse>, {
+    Self {
+        chain_block: item
+            .chain_block
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetChainBlockByBlueScoreResponseMessage".to_string(), "chain_block".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::GetChainBlockByDaaScoreRequestMessage, spectre_rpc_core::GetChainBlockByDaaScoreRequest, {
+    Self { daa_score: item.daa_score }
+});
+try_from!(item: &protowire::GetChainBlockByDaaScoreResponseMessage, RpcResult<spectre_rpc_core::GetChainBlockByDaaScoreResponse>, {
+    Self {
+        chain_block: item
+            .chain_block
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetChainBlockByDaaScoreResponseMessage".to_string(), "chain_block".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::GetChainBlocksByBlueScoreRangeRequestMessage, spectre_rpc_core::GetChainBlocksByBlueScoreRangeRequest, {
+    Self { low_blue_score: item.low_blue_score, high_blue_score: item.high_blue_score }
+});
+try_from!(item: &protowire::GetChainBlocksByBlueScoreRangeResponseMessage, RpcResult<spectre_rpc_core::GetChainBlocksByBlueScoreRangeResponse>, {
+    Self { chain_blocks: item.chain_blocks.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(item: &protowire::GetChainBlocksByDaaScoreRangeRequestMessage, spectre_rpc_core::GetChainBlocksByDaaScoreRangeRequest, {
+    Self { low_daa_score: item.low_daa_score, high_daa_score: item.high_daa_score }
+});
+try_from!(item: &protowire::GetChainBlocksByDaaScoreRangeResponseMessage, RpcResult<spectre_rpc_core::GetChainBlocksByDaaScoreRangeResponse>, {
+    Self { chain_blocks: item.chain_blocks.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(item: &protowire::TestMempoolAcceptRequestMessage, spectre_rpc_core::TestMempoolAcceptRequest, {
+    Self {
+        transactions: item.transactions.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        allow_orphan: item.allow_orphan,
+        replace_by_fee: item.replace_by_fee,
+    }
+});
+try_from!(item: &protowire::TestMempoolAcceptResponseMessage, RpcResult<spectre_rpc_core::TestMempoolAcceptResponse>, {
+    Self { results: item.results.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(item: &protowire::DecodeTransactionRequestMessage, spectre_rpc_core::DecodeTransactionRequest, {
+    Self {
+        transaction: item
+            .transaction
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("DecodeTransactionRequestMessage".to_string(), "transaction".to_string()))?
+            .try_into()?,
+        verify_scripts: item.verify_scripts,
+        utxo_entries: item.utxo_entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+try_from!(item: &protowire::DecodeTransactionResponseMessage, RpcResult<spectre_rpc_core::DecodeTransactionResponse>, {
+    Self {
+        transaction: item
+            .transaction
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("DecodeTransactionResponseMessage".to_string(), "transaction".to_string()))?
+            .try_into()?,
+        storage_mass: if item.storage_mass == 0 { None } else { Some(item.storage_mass) },
+        script_verifications: item.script_verifications.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
 try_from!(&protowire::ShutdownRequestMessage, spectre_rpc_core::ShutdownRequest);
 try_from!(&protowire::ShutdownResponseMessage, RpcResult<spectre_rpc_core::ShutdownResponse>);
 
@@ -822,11 +1075,40 @@ try_from!(item: &protowire::GetHeadersResponseMessage, RpcResult<spectre_rpc_cor
     Self { headers: vec![] }
 });
 
+from!(item: protowire::RpcUtxoOrder, spectre_rpc_core::RpcUtxoOrder, {
+    match item {
+        protowire::RpcUtxoOrder::OrderByOutpoint => spectre_rpc_core::RpcUtxoOrder::Outpoint,
+        protowire::RpcUtxoOrder::OrderByDaaScoreAscending => spectre_rpc_core::RpcUtxoOrder::DaaScoreAscending,
+        protowire::RpcUtxoOrder::OrderByDaaScoreDescending => spectre_rpc_core::RpcUtxoOrder::DaaScoreDescending,
+    }
+});
+try_from!(item: &protowire::RpcUtxosByAddressesCursor, spectre_rpc_core::RpcUtxosByAddressesCursor, {
+    Self {
+        address: item.address.as_str().try_into()?,
+        outpoint: item
+            .outpoint
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcUtxosByAddressesCursor".to_string(), "outpoint".to_string()))?
+            .try_into()?,
+        block_daa_score: item.block_daa_score,
+    }
+});
 try_from!(item: &protowire::GetUtxosByAddressesRequestMessage, spectre_rpc_core::GetUtxosByAddressesRequest, {
-    Self { addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()? }
+    Self {
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        limit: (item.limit > 0).then_some(item.limit),
+        cursor: item.cursor.as_ref().map(|x| x.try_into()).transpose()?,
+        min_amount: (item.min_amount > 0).then_some(item.min_amount),
+        max_amount: (item.max_amount > 0).then_some(item.max_amount),
+        exclude_immature_coinbase: item.exclude_immature_coinbase,
+        order: protowire::RpcUtxoOrder::try_from(item.order).map_err(|_| RpcError::PrimitiveToEnumConversionError)?.into(),
+    }
 });
 try_from!(item: &protowire::GetUtxosByAddressesResponseMessage, RpcResult<spectre_rpc_core::GetUtxosByAddressesResponse>, {
-    Self { entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
+    Self {
+        entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        next_cursor: item.next_cursor.as_ref().map(|x| x.try_into()).transpose()?,
+    }
 });
 
 try_from!(item: &protowire::GetBalanceByAddressRequestMessage, spectre_rpc_core::GetBalanceByAddressRequest, {
@@ -927,6 +1209,26 @@ try_from!(item: &protowire::GetCurrentBlockColorResponseMessage, RpcResult<spect
         blue: item.blue
     }
 });
+try_from!(item: &protowire::PrioritizeTransactionRequestMessage, spectre_rpc_core::PrioritizeTransactionRequest, {
+    Self {
+        transaction_id: spectre_rpc_core::RpcTransactionId::from_str(&item.transaction_id)?,
+        fee_delta: item.fee_delta,
+        excluded: item.excluded,
+    }
+});
+try_from!(&protowire::PrioritizeTransactionResponseMessage, RpcResult<spectre_rpc_core::PrioritizeTransactionResponse>);
+
+try_from!(&protowire::GetPrioritizedTransactionsRequestMessage, spectre_rpc_core::GetPrioritizedTransactionsRequest);
+try_from!(item: &protowire::GetPrioritizedTransactionsResponseMessage, RpcResult<spectre_rpc_core::GetPrioritizedTransactionsResponse>, {
+    Self {
+        prioritized_transactions: item
+            .prioritized_transactions
+            .iter()
+            .map(spectre_rpc_core::RpcPrioritizedTransaction::try_from)
+            .collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
 try_from!(item: &protowire::GetUtxoReturnAddressRequestMessage, spectre_rpc_core::GetUtxoReturnAddressRequest , {
     Self {
         txid: Hash::from_str(&item.txid).unwrap_or_default(),
@@ -948,6 +1250,7 @@ try_from!(item: &protowire::GetMetricsRequestMessage, spectre_rpc_core::GetMetri
         consensus_metrics: item.consensus_metrics,
         storage_metrics: item.storage_metrics,
         custom_metrics : item.custom_metrics,
+        stratum_metrics: item.stratum_metrics,
     }
 });
 try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<spectre_rpc_core::GetMetricsResponse>, {
@@ -960,6 +1263,7 @@ try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<spectre_rpc_cor
         storage_metrics: item.storage_metrics.as_ref().map(|x| x.try_into()).transpose()?,
         // TODO
         custom_metrics: None,
+        stratum_metrics: item.stratum_metrics.as_ref().map(|x| x.try_into()).transpose()?,
     }
 });
 
@@ -1022,6 +1326,17 @@ try_from!(item: &protowire::StopNotifyingUtxosChangedRequestMessage, spectre_rpc
 try_from!(&protowire::NotifyUtxosChangedResponseMessage, RpcResult<spectre_rpc_core::NotifyUtxosChangedResponse>);
 try_from!(&protowire::StopNotifyingUtxosChangedResponseMessage, RpcResult<spectre_rpc_core::NotifyUtxosChangedResponse>);
 
+try_from!(item: &protowire::NotifyAcceptedTransactionsChangedRequestMessage, spectre_rpc_core::NotifyAcceptedTransactionsChangedRequest, {
+    Self {
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        command: item.command.into(),
+    }
+});
+try_from!(
+    &protowire::NotifyAcceptedTransactionsChangedResponseMessage,
+    RpcResult<spectre_rpc_core::NotifyAcceptedTransactionsChangedResponse>
+);
+
 try_from!(
     item: &protowire::NotifyPruningPointUtxoSetOverrideRequestMessage,
     spectre_rpc_core::NotifyPruningPointUtxoSetOverrideRequest,
@@ -80,6 +80,26 @@ from!(item: &spectre_rpc_core::StorageMetrics, protowire::StorageMetrics, {
     }
 });
 
+from!(item: &spectre_rpc_core::StratumWorkerMetrics, protowire::StratumWorkerMetrics, {
+    Self {
+        worker: item.worker.clone(),
+        valid_shares: item.valid_shares,
+        stale_shares: item.stale_shares,
+        invalid_shares: item.invalid_shares,
+        duplicate_shares: item.duplicate_shares,
+        blocks_found: item.blocks_found,
+        blocks_rejected: item.blocks_rejected,
+        work: item.work,
+    }
+});
+
+from!(item: &spectre_rpc_core::StratumMetrics, protowire::StratumMetrics, {
+    Self {
+        malformed_requests: item.malformed_requests,
+        workers: item.workers.iter().map(|x| x.into()).collect(),
+    }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -153,3 +173,23 @@ try_from!(item: &protowire::StorageMetrics, spectre_rpc_core::StorageMetrics, {
         storage_size_bytes: item.storage_size_bytes,
     }
 });
+
+try_from!(item: &protowire::StratumWorkerMetrics, spectre_rpc_core::StratumWorkerMetrics, {
+    Self {
+        worker: item.worker.clone(),
+        valid_shares: item.valid_shares,
+        stale_shares: item.stale_shares,
+        invalid_shares: item.invalid_shares,
+        duplicate_shares: item.duplicate_shares,
+        blocks_found: item.blocks_found,
+        blocks_rejected: item.blocks_rejected,
+        work: item.work,
+    }
+});
+
+try_from!(item: &protowire::StratumMetrics, spectre_rpc_core::StratumMetrics, {
+    Self {
+        malformed_requests: item.malformed_requests,
+        workers: item.workers.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod block;
+pub mod database;
 pub mod error;
 pub mod feerate_estimate;
 pub mod header;
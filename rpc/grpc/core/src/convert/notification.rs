@@ -2,12 +2,13 @@ use crate::protowire::{
     spectred_response::Payload, BlockAddedNotificationMessage, NewBlockTemplateNotificationMessage, RpcNotifyCommand, SpectredResponse,
 };
 use crate::protowire::{
-    FinalityConflictNotificationMessage, FinalityConflictResolvedNotificationMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
-    NotifyPruningPointUtxoSetOverrideResponseMessage, NotifyUtxosChangedRequestMessage, NotifyUtxosChangedResponseMessage,
-    PruningPointUtxoSetOverrideNotificationMessage, SinkBlueScoreChangedNotificationMessage,
-    StopNotifyingPruningPointUtxoSetOverrideRequestMessage, StopNotifyingPruningPointUtxoSetOverrideResponseMessage,
-    StopNotifyingUtxosChangedRequestMessage, StopNotifyingUtxosChangedResponseMessage, UtxosChangedNotificationMessage,
-    VirtualChainChangedNotificationMessage, VirtualDaaScoreChangedNotificationMessage,
+    AcceptedTransactionsChangedNotificationMessage, FinalityConflictNotificationMessage, FinalityConflictResolvedNotificationMessage,
+    NotifyPruningPointUtxoSetOverrideRequestMessage, NotifyPruningPointUtxoSetOverrideResponseMessage,
+    NotifyUtxosChangedRequestMessage, NotifyUtxosChangedResponseMessage, PruningPointUtxoSetOverrideNotificationMessage,
+    SinkBlueScoreChangedNotificationMessage, StopNotifyingPruningPointUtxoSetOverrideRequestMessage,
+    StopNotifyingPruningPointUtxoSetOverrideResponseMessage, StopNotifyingUtxosChangedRequestMessage,
+    StopNotifyingUtxosChangedResponseMessage, UtxosChangedNotificationMessage, VirtualChainChangedNotificationMessage,
+    VirtualDaaScoreChangedNotificationMessage,
 };
 use crate::{from, try_from};
 use spectre_notify::subscription::Command;
@@ -34,6 +35,9 @@ from!(item: &spectre_rpc_core::Notification, Payload, {
         Notification::PruningPointUtxoSetOverride(ref notification) => {
             Payload::PruningPointUtxoSetOverrideNotification(notification.into())
         }
+        Notification::AcceptedTransactionsChanged(ref notification) => {
+            Payload::AcceptedTransactionsChangedNotification(notification.into())
+        }
     }
 });
 
@@ -74,6 +78,14 @@ from!(item: &spectre_rpc_core::VirtualDaaScoreChangedNotification, VirtualDaaSco
 
 from!(&spectre_rpc_core::PruningPointUtxoSetOverrideNotification, PruningPointUtxoSetOverrideNotificationMessage);
 
+from!(item: &spectre_rpc_core::AcceptedTransactionsChangedNotification, AcceptedTransactionsChangedNotificationMessage, {
+    Self {
+        accepted: item.accepted.iter().map(|x| x.into()).collect::<Vec<_>>(),
+        unaccepted: item.unaccepted.iter().map(|x| x.into()).collect::<Vec<_>>(),
+        incomplete: item.incomplete,
+    }
+});
+
 from!(item: Command, RpcNotifyCommand, {
     match item {
         Command::Start => RpcNotifyCommand::NotifyStart,
@@ -117,6 +129,9 @@ try_from!(item: &Payload, spectre_rpc_core::Notification, {
         Payload::PruningPointUtxoSetOverrideNotification(ref notification) => {
             Notification::PruningPointUtxoSetOverride(notification.try_into()?)
         }
+        Payload::AcceptedTransactionsChangedNotification(ref notification) => {
+            Notification::AcceptedTransactionsChanged(notification.try_into()?)
+        }
         _ => Err(RpcError::UnsupportedFeature)?,
     }
 });
@@ -171,6 +186,14 @@ try_from!(item: &VirtualDaaScoreChangedNotificationMessage, spectre_rpc_core::Vi
 
 try_from!(&PruningPointUtxoSetOverrideNotificationMessage, spectre_rpc_core::PruningPointUtxoSetOverrideNotification);
 
+try_from!(item: &AcceptedTransactionsChangedNotificationMessage, spectre_rpc_core::AcceptedTransactionsChangedNotification, {
+    Self {
+        accepted: Arc::new(item.accepted.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?),
+        unaccepted: Arc::new(item.unaccepted.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?),
+        incomplete: item.incomplete,
+    }
+});
+
 from!(item: RpcNotifyCommand, Command, {
     match item {
         RpcNotifyCommand::NotifyStart => Command::Start,
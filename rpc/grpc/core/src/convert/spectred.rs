@@ -64,6 +64,19 @@ pub mod spectred_request_convert {
     impl_into_spectred_request!(GetFeeEstimateExperimental);
     impl_into_spectred_request!(GetCurrentBlockColor);
     impl_into_spectred_request!(GetUtxoReturnAddress);
+    impl_into_spectred_request!(PrioritizeTransaction);
+    impl_into_spectred_request!(GetPrioritizedTransactions);
+    impl_into_spectred_request!(InvalidateBlock);
+    impl_into_spectred_request!(ReconsiderBlock);
+    impl_into_spectred_request!(GenerateBlocks);
+    impl_into_spectred_request!(CreateBackup);
+    impl_into_spectred_request!(GetDatabaseStats);
+    impl_into_spectred_request!(GetChainBlockByBlueScore);
+    impl_into_spectred_request!(GetChainBlockByDaaScore);
+    impl_into_spectred_request!(GetChainBlocksByBlueScoreRange);
+    impl_into_spectred_request!(GetChainBlocksByDaaScoreRange);
+    impl_into_spectred_request!(TestMempoolAccept);
+    impl_into_spectred_request!(DecodeTransaction);
 
     impl_into_spectred_request!(NotifyBlockAdded);
     impl_into_spectred_request!(NotifyNewBlockTemplate);
@@ -73,6 +86,7 @@ pub mod spectred_request_convert {
     impl_into_spectred_request!(NotifyVirtualDaaScoreChanged);
     impl_into_spectred_request!(NotifyVirtualChainChanged);
     impl_into_spectred_request!(NotifySinkBlueScoreChanged);
+    impl_into_spectred_request!(NotifyAcceptedTransactionsChanged);
 
     macro_rules! impl_into_spectred_request {
         ($name:tt) => {
@@ -202,6 +216,19 @@ pub mod spectred_response_convert {
     impl_into_spectred_response!(GetFeeEstimateExperimental);
     impl_into_spectred_response!(GetCurrentBlockColor);
     impl_into_spectred_response!(GetUtxoReturnAddress);
+    impl_into_spectred_response!(PrioritizeTransaction);
+    impl_into_spectred_response!(GetPrioritizedTransactions);
+    impl_into_spectred_response!(InvalidateBlock);
+    impl_into_spectred_response!(ReconsiderBlock);
+    impl_into_spectred_response!(GenerateBlocks);
+    impl_into_spectred_response!(CreateBackup);
+    impl_into_spectred_response!(GetDatabaseStats);
+    impl_into_spectred_response!(GetChainBlockByBlueScore);
+    impl_into_spectred_response!(GetChainBlockByDaaScore);
+    impl_into_spectred_response!(GetChainBlocksByBlueScoreRange);
+    impl_into_spectred_response!(GetChainBlocksByDaaScoreRange);
+    impl_into_spectred_response!(TestMempoolAccept);
+    impl_into_spectred_response!(DecodeTransaction);
 
     impl_into_spectred_notify_response!(NotifyBlockAdded);
     impl_into_spectred_notify_response!(NotifyNewBlockTemplate);
@@ -211,6 +238,7 @@ pub mod spectred_response_convert {
     impl_into_spectred_notify_response!(NotifyVirtualDaaScoreChanged);
     impl_into_spectred_notify_response!(NotifyVirtualChainChanged);
     impl_into_spectred_notify_response!(NotifySinkBlueScoreChanged);
+    impl_into_spectred_notify_response!(NotifyAcceptedTransactionsChanged);
 
     impl_into_spectred_notify_response!(NotifyUtxosChanged, StopNotifyingUtxosChanged);
     impl_into_spectred_notify_response!(NotifyPruningPointUtxoSetOverride, StopNotifyingPruningPointUtxoSetOverride);
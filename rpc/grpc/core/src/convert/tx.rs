@@ -71,7 +71,8 @@ from!(&spectre_rpc_core::RpcTransactionInputVerboseData, protowire::RpcTransacti
 from!(item: &spectre_rpc_core::RpcTransactionOutputVerboseData, protowire::RpcTransactionOutputVerboseData, {
     Self {
         script_public_key_type: item.script_public_key_type.to_string(),
-        script_public_key_address: (&item.script_public_key_address).into(),
+        script_public_key_address: item.script_public_key_address.as_ref().map(String::from).unwrap_or_default(),
+        script_public_key_asm: item.script_public_key_asm.clone(),
     }
 });
 
@@ -82,6 +83,20 @@ from!(item: &spectre_rpc_core::RpcAcceptedTransactionIds, protowire::RpcAccepted
     }
 });
 
+from!(item: &spectre_rpc_core::RpcAcceptedTransaction, protowire::RpcAcceptedTransaction, {
+    Self {
+        transaction: Some((&item.transaction).into()),
+        input_utxo_entries: item.input_utxo_entries.iter().map(|x| x.into()).collect(),
+    }
+});
+
+from!(item: &spectre_rpc_core::RpcAcceptedTransactions, protowire::RpcAcceptedTransactions, {
+    Self {
+        accepting_block_hash: item.accepting_block_hash.to_string(),
+        accepted_transactions: item.accepted_transactions.iter().map(|x| x.into()).collect(),
+    }
+});
+
 from!(item: &spectre_rpc_core::RpcUtxosByAddressesEntry, protowire::RpcUtxosByAddressesEntry, {
     Self {
         address: item.address.as_ref().map_or("".to_string(), |x| x.into()),
@@ -90,6 +105,24 @@ from!(item: &spectre_rpc_core::RpcUtxosByAddressesEntry, protowire::RpcUtxosByAd
     }
 });
 
+from!(item: &spectre_rpc_core::RpcSerializedTransaction, protowire::RpcSerializedTransaction, {
+    let encoding = match item {
+        spectre_rpc_core::RpcSerializedTransaction::Hex(hex) => protowire::rpc_serialized_transaction::Encoding::Hex(hex.clone()),
+        spectre_rpc_core::RpcSerializedTransaction::Borsh(bytes) => protowire::rpc_serialized_transaction::Encoding::Borsh(bytes.clone()),
+        spectre_rpc_core::RpcSerializedTransaction::Json(json) => protowire::rpc_serialized_transaction::Encoding::Json(json.clone()),
+    };
+    Self { encoding: Some(encoding) }
+});
+
+from!(item: &spectre_rpc_core::RpcInputScriptVerification, protowire::RpcInputScriptVerification, {
+    Self {
+        input_index: item.input_index,
+        utxo_entry: item.utxo_entry.as_ref().map(|x| x.into()),
+        verified: item.verified,
+        error: item.error.clone().unwrap_or_default(),
+    }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -178,7 +211,11 @@ try_from!(&protowire::RpcTransactionInputVerboseData, spectre_rpc_core::RpcTrans
 try_from!(item: &protowire::RpcTransactionOutputVerboseData, spectre_rpc_core::RpcTransactionOutputVerboseData, {
     Self {
         script_public_key_type: item.script_public_key_type.as_str().try_into()?,
-        script_public_key_address: item.script_public_key_address.as_str().try_into()?,
+        script_public_key_address: match item.script_public_key_address.as_str() {
+            "" => None,
+            address => Some(address.try_into()?),
+        },
+        script_public_key_asm: item.script_public_key_asm.clone(),
     }
 });
 
@@ -189,6 +226,24 @@ try_from!(item: &protowire::RpcAcceptedTransactionIds, spectre_rpc_core::RpcAcce
     }
 });
 
+try_from!(item: &protowire::RpcAcceptedTransaction, spectre_rpc_core::RpcAcceptedTransaction, {
+    Self {
+        transaction: item
+            .transaction
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("RpcAcceptedTransaction".to_string(), "transaction".to_string()))?
+            .try_into()?,
+        input_utxo_entries: item.input_utxo_entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::RpcAcceptedTransactions, spectre_rpc_core::RpcAcceptedTransactions, {
+    Self {
+        accepting_block_hash: RpcHash::from_str(&item.accepting_block_hash)?,
+        accepted_transactions: item.accepted_transactions.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
 try_from!(item: &protowire::RpcUtxosByAddressesEntry, spectre_rpc_core::RpcUtxosByAddressesEntry, {
     let address = if item.address.is_empty() { None } else { Some(item.address.as_str().try_into()?) };
     Self {
@@ -205,3 +260,24 @@ try_from!(item: &protowire::RpcUtxosByAddressesEntry, spectre_rpc_core::RpcUtxos
             .try_into()?,
     }
 });
+
+try_from!(item: &protowire::RpcSerializedTransaction, spectre_rpc_core::RpcSerializedTransaction, {
+    match item
+        .encoding
+        .as_ref()
+        .ok_or_else(|| RpcError::MissingRpcFieldError("RpcSerializedTransaction".to_string(), "encoding".to_string()))?
+    {
+        protowire::rpc_serialized_transaction::Encoding::Hex(hex) => Self::Hex(hex.clone()),
+        protowire::rpc_serialized_transaction::Encoding::Borsh(bytes) => Self::Borsh(bytes.clone()),
+        protowire::rpc_serialized_transaction::Encoding::Json(json) => Self::Json(json.clone()),
+    }
+});
+
+try_from!(item: &protowire::RpcInputScriptVerification, spectre_rpc_core::RpcInputScriptVerification, {
+    Self {
+        input_index: item.input_index,
+        utxo_entry: item.utxo_entry.as_ref().map(spectre_rpc_core::RpcUtxoEntry::try_from).transpose()?,
+        verified: item.verified,
+        error: if item.error.is_empty() { None } else { Some(item.error.clone()) },
+    }
+});
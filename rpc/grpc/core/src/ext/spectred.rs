@@ -1,10 +1,10 @@
 use spectre_notify::{scope::Scope, subscription::Command};
 
 use crate::protowire::{
-    spectred_request, spectred_response, NotifyBlockAddedRequestMessage, NotifyFinalityConflictRequestMessage,
-    NotifyNewBlockTemplateRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage, NotifySinkBlueScoreChangedRequestMessage,
-    NotifyUtxosChangedRequestMessage, NotifyVirtualChainChangedRequestMessage, NotifyVirtualDaaScoreChangedRequestMessage,
-    SpectredRequest, SpectredResponse,
+    spectred_request, spectred_response, NotifyAcceptedTransactionsChangedRequestMessage, NotifyBlockAddedRequestMessage,
+    NotifyFinalityConflictRequestMessage, NotifyNewBlockTemplateRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
+    NotifySinkBlueScoreChangedRequestMessage, NotifyUtxosChangedRequestMessage, NotifyVirtualChainChangedRequestMessage,
+    NotifyVirtualDaaScoreChangedRequestMessage, SpectredRequest, SpectredResponse,
 };
 
 impl SpectredRequest {
@@ -64,6 +64,12 @@ impl spectred_request::Payload {
                     command: command.into(),
                 })
             }
+            Scope::AcceptedTransactionsChanged(ref scope) => {
+                spectred_request::Payload::NotifyAcceptedTransactionsChangedRequest(NotifyAcceptedTransactionsChangedRequestMessage {
+                    addresses: scope.addresses.iter().map(|x| x.into()).collect::<Vec<String>>(),
+                    command: command.into(),
+                })
+            }
         }
     }
 
@@ -79,6 +85,7 @@ impl spectred_request::Payload {
                 | Payload::NotifyVirtualDaaScoreChangedRequest(_)
                 | Payload::NotifyPruningPointUtxoSetOverrideRequest(_)
                 | Payload::NotifyNewBlockTemplateRequest(_)
+                | Payload::NotifyAcceptedTransactionsChangedRequest(_)
                 | Payload::StopNotifyingUtxosChangedRequest(_)
                 | Payload::StopNotifyingPruningPointUtxoSetOverrideRequest(_)
         )
@@ -108,6 +115,7 @@ impl spectred_response::Payload {
             Payload::VirtualDaaScoreChangedNotification(_) => true,
             Payload::PruningPointUtxoSetOverrideNotification(_) => true,
             Payload::NewBlockTemplateNotification(_) => true,
+            Payload::AcceptedTransactionsChangedNotification(_) => true,
             _ => false,
         }
     }
@@ -10,3 +10,6 @@ pub const RPC_MAX_MESSAGE_SIZE: usize = 1024 * 1024 * 1024; // 1GB
 pub mod protowire {
     tonic::include_proto!("protowire");
 }
+
+/// Encoded `FileDescriptorSet` of the protowire protobuf files
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("protowire_descriptor");
@@ -88,6 +88,19 @@ pub enum SpectredPayloadOps {
     GetFeeEstimateExperimental,
     GetCurrentBlockColor,
     GetUtxoReturnAddress,
+    PrioritizeTransaction,
+    GetPrioritizedTransactions,
+    InvalidateBlock,
+    ReconsiderBlock,
+    GenerateBlocks,
+    CreateBackup,
+    GetDatabaseStats,
+    GetChainBlockByBlueScore,
+    GetChainBlockByDaaScore,
+    GetChainBlocksByBlueScoreRange,
+    GetChainBlocksByDaaScoreRange,
+    TestMempoolAccept,
+    DecodeTransaction,
 
     // Subscription commands for starting/stopping notifications
     NotifyBlockAdded,
@@ -98,6 +111,7 @@ pub enum SpectredPayloadOps {
     NotifyPruningPointUtxoSetOverride,
     NotifyVirtualDaaScoreChanged,
     NotifyVirtualChainChanged,
+    NotifyAcceptedTransactionsChanged,
 
     // Legacy stop subscription commands
     StopNotifyingUtxosChanged,
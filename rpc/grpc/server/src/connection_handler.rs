@@ -1,6 +1,7 @@
 use crate::{
     collector::{GrpcServiceCollector, GrpcServiceConverter},
     connection::Connection,
+    health::health_service,
     manager::{ManagerEvent, RegistrationRequest},
     request_handler::{factory::Factory, interface::Interface},
 };
@@ -11,7 +12,7 @@ use spectre_grpc_core::{
         rpc_server::{Rpc, RpcServer},
         SpectredRequest, SpectredResponse,
     },
-    RPC_MAX_MESSAGE_SIZE,
+    FILE_DESCRIPTOR_SET, RPC_MAX_MESSAGE_SIZE,
 };
 use spectre_notify::{
     connection::ChannelType,
@@ -76,6 +77,7 @@ pub struct ConnectionHandler {
     interface: Arc<Interface>,
     running: Arc<AtomicBool>,
     counters: Arc<TowerConnectionCounters>,
+    reflection: bool,
 }
 
 const GRPC_SERVER: &str = "grpc-server";
@@ -89,6 +91,7 @@ impl ConnectionHandler {
         subscription_context: SubscriptionContext,
         broadcasters: usize,
         counters: Arc<TowerConnectionCounters>,
+        reflection: bool,
     ) -> Self {
         // This notifier UTXOs subscription granularity to rpc-core notifier
         let policies = MutationPolicies::new(UtxosChangedMutationPolicy::AddressSet);
@@ -118,7 +121,7 @@ impl ConnectionHandler {
         let interface = Arc::new(Factory::new_interface(server_context.clone(), network_bps));
         let running = Default::default();
 
-        Self { manager_sender, server_context, interface, running, counters }
+        Self { manager_sender, server_context, interface, running, counters, reflection }
     }
 
     /// Launches a gRPC server listener loop
@@ -133,6 +136,22 @@ impl ConnectionHandler {
 
         // Spawn server task
         let server_handle = tokio::spawn(async move {
+            let (health_server, health_reporting) =
+                health_service(connection_handler.server_context.core_service.clone(), connection_handler.running.clone()).await;
+            let (reflection_server, reflection_server_v1alpha) = match connection_handler.reflection {
+                true => {
+                    let builder = || {
+                        tonic_reflection::server::Builder::configure()
+                            .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+                            .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+                    };
+                    (
+                        Some(builder().build_v1().expect("valid file descriptor sets")),
+                        Some(builder().build_v1alpha().expect("valid file descriptor sets")),
+                    )
+                }
+                false => (None, None),
+            };
             let protowire_server = RpcServer::new(connection_handler)
                 .accept_compressed(CompressionEncoding::Gzip)
                 .send_compressed(CompressionEncoding::Gzip)
@@ -147,6 +166,9 @@ impl ConnectionHandler {
                 .layer(MapRequestBodyLayer::new(move |body| CountBytesBody::new(body, bytes_rx.clone()).boxed_unsync()))
                 .layer(MapResponseBodyLayer::new(move |body| CountBytesBody::new(body, bytes_tx.clone())))
                 .add_service(protowire_server)
+                .add_service(health_server)
+                .add_optional_service(reflection_server)
+                .add_optional_service(reflection_server_v1alpha)
                 .serve_with_shutdown(
                     serve_address.into(),
                     signal_receiver.map(|_| {
@@ -154,6 +176,7 @@ impl ConnectionHandler {
                     }),
                 )
                 .await;
+            health_reporting.abort();
 
             match serve_result {
                 Ok(_) => info!("GRPC Server stopped on: {}", serve_address),
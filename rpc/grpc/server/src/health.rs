@@ -0,0 +1,59 @@
+use crate::connection_handler::ConnectionHandler;
+use spectre_grpc_core::protowire::rpc_server::RpcServer;
+use spectre_rpc_core::api::rpc::DynRpcService;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+use tonic::server::NamedService;
+use tonic_health::{
+    pb::health_server::{Health, HealthServer},
+    server::HealthReporter,
+    ServingStatus,
+};
+
+/// Fully qualified name of the protowire RPC service, as exposed to health checking clients
+pub const RPC_SERVICE_NAME: &str = <RpcServer<ConnectionHandler> as NamedService>::NAME;
+
+/// Interval at which the sync status of the node is polled
+const SYNC_STATUS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Builds the standard `grpc.health.v1.Health` service of the gRPC server, along with the task reporting the node health to it.
+///
+/// Both the server as a whole (empty service name) and the protowire RPC service are reported
+/// as serving when the gRPC server is running and the node is synced, meaning it has sufficient
+/// peer connectivity and its sink is recent. The reporting task runs until aborted.
+pub(crate) async fn health_service(
+    core_service: DynRpcService,
+    running: Arc<AtomicBool>,
+) -> (HealthServer<impl Health>, JoinHandle<()>) {
+    let (mut reporter, server) = tonic_health::server::health_reporter();
+    // Nothing is serving until the first sync status is known
+    set_status(&mut reporter, ServingStatus::NotServing).await;
+    (server, tokio::spawn(report_sync_status(reporter, core_service, running)))
+}
+
+async fn set_status(reporter: &mut HealthReporter, status: ServingStatus) {
+    for service in ["", RPC_SERVICE_NAME] {
+        reporter.set_service_status(service, status).await;
+    }
+}
+
+async fn report_sync_status(mut reporter: HealthReporter, core_service: DynRpcService, running: Arc<AtomicBool>) {
+    let mut last_status = ServingStatus::NotServing;
+    loop {
+        let status = match running.load(Ordering::SeqCst) && core_service.get_sync_status().await.unwrap_or(false) {
+            true => ServingStatus::Serving,
+            false => ServingStatus::NotServing,
+        };
+        if status != last_status {
+            last_status = status;
+            set_status(&mut reporter, status).await;
+        }
+        tokio::time::sleep(SYNC_STATUS_INTERVAL).await;
+    }
+}
@@ -3,6 +3,7 @@ pub mod collector;
 pub mod connection;
 pub mod connection_handler;
 pub mod error;
+pub mod health;
 pub mod manager;
 pub mod request_handler;
 pub mod service;
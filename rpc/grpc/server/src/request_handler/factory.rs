@@ -82,6 +82,19 @@ impl Factory {
                 GetFeeEstimateExperimental,
                 GetCurrentBlockColor,
                 GetUtxoReturnAddress,
+                PrioritizeTransaction,
+                GetPrioritizedTransactions,
+                InvalidateBlock,
+                ReconsiderBlock,
+                GenerateBlocks,
+                CreateBackup,
+                GetDatabaseStats,
+                GetChainBlockByBlueScore,
+                GetChainBlockByDaaScore,
+                GetChainBlocksByBlueScoreRange,
+                GetChainBlocksByDaaScoreRange,
+                TestMempoolAccept,
+                DecodeTransaction,
                 NotifyBlockAdded,
                 NotifyNewBlockTemplate,
                 NotifyFinalityConflict,
@@ -90,6 +103,7 @@ impl Factory {
                 NotifyPruningPointUtxoSetOverride,
                 NotifyVirtualDaaScoreChanged,
                 NotifyVirtualChainChanged,
+                NotifyAcceptedTransactionsChanged,
                 StopNotifyingUtxosChanged,
                 StopNotifyingPruningPointUtxoSetOverride,
             ]
@@ -20,6 +20,7 @@ pub struct GrpcService {
     started: SingleTrigger,
     shutdown: SingleTrigger,
     counters: Arc<TowerConnectionCounters>,
+    reflection: bool,
 }
 
 impl GrpcService {
@@ -32,6 +33,7 @@ impl GrpcService {
         rpc_max_clients: usize,
         broadcasters: usize,
         counters: Arc<TowerConnectionCounters>,
+        reflection: bool,
     ) -> Self {
         Self {
             net_address: address,
@@ -42,6 +44,7 @@ impl GrpcService {
             started: Default::default(),
             shutdown: Default::default(),
             counters,
+            reflection,
         }
     }
 
@@ -71,6 +74,7 @@ impl AsyncService for GrpcService {
             self.core_service.subscription_context(),
             self.broadcasters,
             self.counters.clone(),
+            self.reflection,
         );
 
         // Signal the server was started
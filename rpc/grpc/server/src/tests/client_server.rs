@@ -1,11 +1,16 @@
-use super::rpc_core_mock::RpcCoreMock;
 use crate::{adaptor::Adaptor, manager::Manager};
 use spectre_core::info;
 use spectre_grpc_client::GrpcClient;
 use spectre_notify::scope::{NewBlockTemplateScope, Scope};
+use spectre_rpc_core::api::mock::RpcCoreMock;
 use spectre_rpc_core::api::rpc::RpcApi;
 use spectre_utils::networking::{ContextualNetAddress, NetAddress};
 use std::sync::Arc;
+use tonic_health::pb::{health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest};
+use tonic_reflection::pb::v1::{
+    server_reflection_client::ServerReflectionClient, server_reflection_request::MessageRequest,
+    server_reflection_response::MessageResponse, ServerReflectionRequest,
+};
 
 #[tokio::test]
 async fn test_client_server_sanity_check() {
@@ -36,6 +41,50 @@ async fn test_client_server_sanity_check() {
     tokio::time::sleep(std::time::Duration::from_millis(50)).await;
 }
 
+#[tokio::test]
+async fn test_client_server_health_and_reflection() {
+    spectre_core::log::try_init_logger("info, spectre_grpc_core=trace, spectre_grpc_server=trace, spectre_grpc_client=trace");
+
+    // Create and start a fake core service, which does not report being synced
+    let rpc_core_service = Arc::new(RpcCoreMock::new());
+    rpc_core_service.start();
+
+    // Create and start the server
+    let server = create_server(rpc_core_service.clone());
+    let server_url = format!("grpc://localhost:{}", server.serve_address().port);
+
+    let channel = tonic::transport::Endpoint::from_shared(server_url).unwrap().connect().await.unwrap();
+    let mut health_client = HealthClient::new(channel.clone());
+    for service in ["", crate::health::RPC_SERVICE_NAME] {
+        let response = health_client.check(HealthCheckRequest { service: service.to_owned() }).await.unwrap().into_inner();
+        assert_eq!(response.status(), ServingStatus::NotServing, "service \"{service}\" should not be serving");
+    }
+    let status = health_client.check(HealthCheckRequest { service: "unknown".to_owned() }).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    let mut watch =
+        health_client.watch(HealthCheckRequest { service: crate::health::RPC_SERVICE_NAME.to_owned() }).await.unwrap().into_inner();
+    assert_eq!(watch.message().await.unwrap().unwrap().status(), ServingStatus::NotServing);
+
+    let mut reflection_client = ServerReflectionClient::new(channel);
+    let request = ServerReflectionRequest { host: String::new(), message_request: Some(MessageRequest::ListServices(String::new())) };
+    let mut responses = reflection_client.server_reflection_info(tokio_stream::iter([request])).await.unwrap().into_inner();
+    let Some(MessageResponse::ListServicesResponse(services)) = responses.message().await.unwrap().unwrap().message_response else {
+        panic!("expected a list of services");
+    };
+    for name in [crate::health::RPC_SERVICE_NAME, "grpc.health.v1.Health"] {
+        assert!(services.service.iter().any(|service| service.name == name), "service {name} should be listed");
+    }
+
+    // Stop the fake service
+    rpc_core_service.join().await;
+
+    // Stop the server
+    assert!(server.stop().await.is_ok(), "error stopping the server");
+    drop(server);
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+}
+
 #[tokio::test]
 async fn test_client_server_connections() {
     enum ClosingEnd {
@@ -199,6 +248,7 @@ fn create_server(core_service: Arc<RpcCoreMock>) -> Arc<Adaptor> {
         core_service.subscription_context(),
         3,
         Default::default(),
+        true,
     )
 }
 
@@ -0,0 +1,44 @@
+use crate::jsonrpc::{INTERNAL_ERROR, INVALID_PARAMS, INVALID_REQUEST, METHOD_NOT_FOUND, PARSE_ERROR, SERVER_ERROR};
+use spectre_rpc_core::RpcError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Parse error: {0}")]
+    Parse(serde_json::Error),
+
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    #[error("Method not found: {0}")]
+    MethodNotFound(String),
+
+    #[error("Invalid params: {0}")]
+    InvalidParams(serde_json::Error),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Rpc(#[from] RpcError),
+}
+
+impl Error {
+    /// The JSON-RPC 2.0 error code matching the error
+    pub fn code(&self) -> i64 {
+        match self {
+            Error::Parse(_) => PARSE_ERROR,
+            Error::InvalidRequest(_) => INVALID_REQUEST,
+            Error::MethodNotFound(_) => METHOD_NOT_FOUND,
+            Error::InvalidParams(_) | Error::InvalidArgument(_) => INVALID_PARAMS,
+            Error::Io(_) | Error::Json(_) => INTERNAL_ERROR,
+            Error::Rpc(_) => SERVER_ERROR,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//!
+//! JSON-RPC 2.0 envelope types, see <https://www.jsonrpc.org/specification>
+//!
+
+use crate::error::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+/// Code of the errors returned by the RPC service itself
+pub const SERVER_ERROR: i64 = -32000;
+
+#[derive(Debug, Deserialize)]
+pub struct JsonRpcRequest {
+    pub jsonrpc: String,
+    /// `None` when the request is a notification, which must not be answered
+    #[serde(default, deserialize_with = "deserialize_id")]
+    pub id: Option<Value>,
+    pub method: String,
+    /// Either the named (object) or the positional (array) fields of the request type, may be omitted if it has no fields
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// Distinguishes a `null` id, which is answered, from a missing one
+fn deserialize_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl From<Error> for JsonRpcError {
+    fn from(err: Error) -> Self {
+        Self { code: err.code(), message: err.to_string() }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonRpcResponse {
+    pub jsonrpc: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+    pub id: Value,
+}
+
+impl JsonRpcResponse {
+    pub fn new(id: Value, result: Result<Value, Error>) -> Self {
+        match result {
+            Ok(result) => Self { jsonrpc: JSONRPC_VERSION, result: Some(result), error: None, id },
+            Err(err) => Self { jsonrpc: JSONRPC_VERSION, result: None, error: Some(err.into()), id },
+        }
+    }
+}
+
+/// The body answering a single call or a batch of calls
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum JsonRpcReply {
+    Single(JsonRpcResponse),
+    Batch(Vec<JsonRpcResponse>),
+}
@@ -0,0 +1,25 @@
+//!
+//! Spectre HTTP server exposing the node RPC to plain HTTP clients.
+//!
+//! Every [`RpcApiOps`](spectre_rpc_core::api::ops::RpcApiOps) method is available as a JSON-RPC 2.0
+//! call posted to `/`, where the method name is the camelCase name of the operation (ie. `getInfo`)
+//! and the params are the JSON encoding of the matching request type. A small set of REST routes
+//! covers the most common queries:
+//!
+//! - `GET /info`
+//! - `GET /blocks/{hash}?includeTransactions=<bool>`
+//! - `GET /addresses/{address}/utxos?limit=<u32>&cursor=<transactionId>:<index>:<blockDaaScore>`
+//!
+//! The number of requests processed concurrently is bounded, the excess ones waiting for a slot.
+//!
+//! Notifications are not available over HTTP, clients needing them should use gRPC or wRPC.
+//!
+
+pub mod error;
+pub mod jsonrpc;
+pub mod rest;
+pub mod result;
+pub mod router;
+pub mod service;
+
+pub use service::HttpService;
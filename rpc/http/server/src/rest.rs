@@ -0,0 +1,111 @@
+use crate::{error::Error, result::Result, router::Router};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use spectre_rpc_core::prelude::*;
+use std::{str::FromStr, sync::Arc};
+
+/// Query string of `GET /blocks/{hash}`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockQuery {
+    #[serde(default = "default_include_transactions")]
+    pub include_transactions: bool,
+}
+
+fn default_include_transactions() -> bool {
+    true
+}
+
+/// Query string of `GET /addresses/{address}/utxos`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressUtxosQuery {
+    /// Maximal number of entries of the page, all entries being returned if absent
+    pub limit: Option<u32>,
+    /// Position of the last entry of the previous page, as `<transactionId>:<index>:<blockDaaScore>`
+    /// built from the `nextCursor` of the previous response
+    pub cursor: Option<String>,
+}
+
+impl AddressUtxosQuery {
+    fn cursor(&self, address: &RpcAddress) -> Result<Option<RpcUtxosByAddressesCursor>> {
+        let Some(cursor) = self.cursor.as_deref() else {
+            return Ok(None);
+        };
+        let invalid = || Error::InvalidArgument(format!("invalid cursor {cursor}, expecting <transactionId>:<index>:<blockDaaScore>"));
+        let mut parts = cursor.split(':');
+        let (Some(transaction_id), Some(index), Some(block_daa_score), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        Ok(Some(RpcUtxosByAddressesCursor {
+            address: address.clone(),
+            outpoint: RpcTransactionOutpoint {
+                transaction_id: RpcHash::from_str(transaction_id).map_err(|_| invalid())?,
+                index: index.parse().map_err(|_| invalid())?,
+            },
+            block_daa_score: block_daa_score.parse().map_err(|_| invalid())?,
+        }))
+    }
+}
+
+/// `GET /info`
+pub async fn info(State(router): State<Arc<Router>>) -> Result<Response> {
+    let response = router.rpc().get_info_call(None, GetInfoRequest {}).await?;
+    Ok(json_response(StatusCode::OK, &response))
+}
+
+/// `GET /blocks/{hash}`
+pub async fn block(State(router): State<Arc<Router>>, Path(hash): Path<String>, Query(query): Query<BlockQuery>) -> Result<Response> {
+    let hash = RpcHash::from_str(&hash).map_err(|err| Error::InvalidArgument(format!("invalid block hash {hash}: {err}")))?;
+    let response = router.rpc().get_block_call(None, GetBlockRequest::new(hash, query.include_transactions)).await?;
+    Ok(json_response(StatusCode::OK, &response))
+}
+
+/// `GET /addresses/{address}/utxos`
+pub async fn address_utxos(
+    State(router): State<Arc<Router>>,
+    Path(address): Path<String>,
+    Query(query): Query<AddressUtxosQuery>,
+) -> Result<Response> {
+    let address =
+        RpcAddress::try_from(address.as_str()).map_err(|err| Error::InvalidArgument(format!("invalid address {address}: {err}")))?;
+    let cursor = query.cursor(&address)?;
+    let request = match (query.limit, cursor) {
+        (Some(limit), cursor) => GetUtxosByAddressesRequest::new(vec![address]).with_page(limit, cursor),
+        (None, None) => GetUtxosByAddressesRequest::new(vec![address]),
+        (None, Some(_)) => return Err(Error::InvalidArgument("a cursor requires a limit".to_string())),
+    };
+    let response = router.rpc().get_utxos_by_addresses_call(None, request).await?;
+    Ok(json_response(StatusCode::OK, &response))
+}
+
+pub fn json_response<T: Serialize>(status: StatusCode, value: &T) -> Response {
+    match serde_json::to_vec(value) {
+        Ok(body) => (status, [(header::CONTENT_TYPE, "application/json")], body).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Error::InvalidArgument(_) | Error::InvalidParams(_) | Error::InvalidRequest(_) | Error::Parse(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            Error::MethodNotFound(_) => StatusCode::NOT_FOUND,
+            Error::Io(_) | Error::Json(_) | Error::Rpc(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        json_response(status, &ErrorBody { error: self.to_string() })
+    }
+}
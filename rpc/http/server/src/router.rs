@@ -0,0 +1,134 @@
+use crate::{error::Error, jsonrpc::*, result::Result};
+use serde_json::Value;
+use spectre_core::trace;
+use spectre_rpc_core::{
+    api::{ops::RpcApiOps, rpc::DynRpcService},
+    prelude::*,
+};
+use spectre_rpc_macros::build_http_server_interface;
+
+/// Maps JSON-RPC 2.0 calls to the [`RpcApiOps`] methods of the RPC service
+pub struct Router {
+    rpc: DynRpcService,
+}
+
+impl Router {
+    pub fn new(rpc: DynRpcService) -> Self {
+        Self { rpc }
+    }
+
+    pub fn rpc(&self) -> &DynRpcService {
+        &self.rpc
+    }
+
+    /// Calls the RPC method named `method`, the camelCase name of an [`RpcApiOps`] variant
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let op = serde_json::from_value::<RpcApiOps>(Value::String(method.to_owned()))
+            .map_err(|_| Error::MethodNotFound(method.to_owned()))?;
+        // Methods whose request has no fields may be called without params
+        let params = if params.is_null() { Value::Object(Default::default()) } else { params };
+        let rpc = &self.rpc;
+
+        // The following macro maps each listed variant to a call of the matching RPC method. For example,
+        // `GetInfo` deserializes `params` into a `GetInfoRequest`, calls get_info_call() and serializes
+        // the resulting `GetInfoResponse`. Subscriptions are left out since HTTP cannot carry notifications.
+        build_http_server_interface!(
+            rpc,
+            op,
+            params,
+            RpcApiOps,
+            [
+                Ping,
+                AddPeer,
+                Ban,
+                CreateBackup,
+                EstimateNetworkHashesPerSecond,
+                GenerateBlocks,
+                GetBalanceByAddress,
+                GetBalancesByAddresses,
+                GetBlock,
+                GetBlockCount,
+                GetBlockDagInfo,
+                GetBlocks,
+                GetBlockTemplate,
+                GetChainBlockByBlueScore,
+                GetChainBlockByDaaScore,
+                GetChainBlocksByBlueScoreRange,
+                GetChainBlocksByDaaScoreRange,
+                TestMempoolAccept,
+                DecodeTransaction,
+                GetCurrentBlockColor,
+                GetDatabaseStats,
+                GetCoinSupply,
+                GetConnectedPeerInfo,
+                GetDaaScoreTimestampEstimate,
+                GetUtxoReturnAddress,
+                GetCurrentNetwork,
+                GetFeeEstimate,
+                GetFeeEstimateExperimental,
+                GetHeaders,
+                GetInfo,
+                GetMempoolEntries,
+                GetMempoolEntriesByAddresses,
+                GetMempoolEntry,
+                GetMetrics,
+                GetConnections,
+                GetPeerAddresses,
+                GetPrioritizedTransactions,
+                GetServerInfo,
+                GetSink,
+                GetSinkBlueScore,
+                GetSubnetwork,
+                GetSyncStatus,
+                GetSystemInfo,
+                GetUtxosByAddresses,
+                GetVirtualChainFromBlock,
+                InvalidateBlock,
+                PrioritizeTransaction,
+                ReconsiderBlock,
+                ResolveFinalityConflict,
+                Shutdown,
+                SubmitBlock,
+                SubmitTransaction,
+                SubmitTransactionReplacement,
+                Unban,
+            ]
+        )
+    }
+
+    /// Handles the body of a JSON-RPC 2.0 request, either a single call or a batch of calls.
+    /// Returns `None` if the body only holds notifications, which are not answered
+    pub async fn handle_json_rpc(&self, body: &[u8]) -> Option<JsonRpcReply> {
+        let body: Value = match serde_json::from_slice(body) {
+            Ok(body) => body,
+            Err(err) => return Some(JsonRpcReply::Single(JsonRpcResponse::new(Value::Null, Err(Error::Parse(err))))),
+        };
+        match body {
+            Value::Array(calls) if calls.is_empty() => {
+                Some(JsonRpcReply::Single(JsonRpcResponse::new(Value::Null, Err(Error::InvalidRequest("empty batch".to_owned())))))
+            }
+            Value::Array(calls) => {
+                let mut responses = Vec::with_capacity(calls.len());
+                for call in calls {
+                    responses.extend(self.handle_call(call).await);
+                }
+                (!responses.is_empty()).then_some(JsonRpcReply::Batch(responses))
+            }
+            call => self.handle_call(call).await.map(JsonRpcReply::Single),
+        }
+    }
+
+    async fn handle_call(&self, call: Value) -> Option<JsonRpcResponse> {
+        let request: JsonRpcRequest = match serde_json::from_value(call) {
+            Ok(request) => request,
+            Err(err) => return Some(JsonRpcResponse::new(Value::Null, Err(Error::InvalidRequest(err.to_string())))),
+        };
+        if request.jsonrpc != JSONRPC_VERSION {
+            let err = Error::InvalidRequest(format!("unsupported JSON-RPC version {}", request.jsonrpc));
+            return Some(JsonRpcResponse::new(request.id.unwrap_or_default(), Err(err)));
+        }
+        trace!("HTTP RPC call: {}", request.method);
+        let result = self.call(&request.method, request.params).await;
+        request.id.map(|id| JsonRpcResponse::new(id, result))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{rest, result::Result, router::Router};
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use spectre_core::{
+    info,
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
+    trace,
+};
+use spectre_rpc_core::api::rpc::DynRpcService;
+use spectre_utils::{networking::NetAddress, triggers::SingleTrigger};
+use std::{net::SocketAddr, sync::Arc};
+use tokio::net::TcpListener;
+use tower::limit::ConcurrencyLimitLayer;
+
+/// Maximal size of a request body, matching the maximal wRPC message size
+const MAX_HTTP_REQUEST_SIZE: usize = 1024 * 1024 * 128; // 128MB
+
+/// HTTP server answering JSON-RPC 2.0 calls and REST queries with the RPC service
+pub struct HttpService {
+    net_address: NetAddress,
+    router: Arc<Router>,
+    /// Maximal number of requests processed concurrently
+    max_concurrent_requests: usize,
+    shutdown: SingleTrigger,
+}
+
+impl HttpService {
+    pub const IDENT: &'static str = "http-rpc-service";
+
+    pub fn new(net_address: NetAddress, rpc: DynRpcService, max_concurrent_requests: usize) -> Self {
+        Self { net_address, router: Arc::new(Router::new(rpc)), max_concurrent_requests, shutdown: Default::default() }
+    }
+
+    /// Builds the routes served by the HTTP server, processing at most `max_concurrent_requests` requests concurrently
+    pub fn routes(router: Arc<Router>, max_concurrent_requests: usize) -> axum::Router {
+        axum::Router::new()
+            .route("/", post(json_rpc))
+            .route("/info", get(rest::info))
+            .route("/blocks/:hash", get(rest::block))
+            .route("/addresses/:address/utxos", get(rest::address_utxos))
+            .layer(DefaultBodyLimit::max(MAX_HTTP_REQUEST_SIZE))
+            .layer(ConcurrencyLimitLayer::new(max_concurrent_requests))
+            .with_state(router)
+    }
+
+    async fn serve(self: Arc<Self>) -> Result<()> {
+        let listener = TcpListener::bind(SocketAddr::from(self.net_address)).await?;
+        info!("HTTP RPC server listening on {}", self.net_address);
+        axum::serve(listener, Self::routes(self.router.clone(), self.max_concurrent_requests))
+            .with_graceful_shutdown(self.shutdown.listener.clone())
+            .await?;
+        info!("HTTP RPC server stopped on {}", self.net_address);
+        Ok(())
+    }
+}
+
+async fn json_rpc(State(router): State<Arc<Router>>, body: Bytes) -> Response {
+    match router.handle_json_rpc(&body).await {
+        Some(reply) => rest::json_response(StatusCode::OK, &reply),
+        None => StatusCode::NO_CONTENT.into_response(),
+    }
+}
+
+impl AsyncService for HttpService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        Self::IDENT
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", Self::IDENT);
+        Box::pin(async move { self.serve().await.map_err(|err| AsyncServiceError::Service(err.to_string())) })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", Self::IDENT);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", Self::IDENT);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::handler::*;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::convert::Into;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Error, Expr, ExprArray, Result, Token,
+};
+
+#[derive(Debug)]
+struct RpcTable {
+    rpc_service: Expr,
+    op: Expr,
+    params: Expr,
+    rpc_api_ops: Expr,
+    handlers: ExprArray,
+}
+
+impl Parse for RpcTable {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parsed = Punctuated::<Expr, Token![,]>::parse_terminated(input).unwrap();
+        if parsed.len() != 5 {
+            return Err(Error::new_spanned(
+                parsed,
+                "usage: build_http_server_interface!(rpc_service,op,params,RpcApiOps,[getInfo, ..])".to_string(),
+            ));
+        }
+
+        let mut iter = parsed.iter();
+        let rpc_service = iter.next().unwrap().clone();
+        let op = iter.next().unwrap().clone();
+        let params = iter.next().unwrap().clone();
+        let rpc_api_ops = iter.next().unwrap().clone();
+        let handlers = get_handlers(iter.next().unwrap().clone())?;
+
+        Ok(RpcTable { rpc_service, op, params, rpc_api_ops, handlers })
+    }
+}
+
+impl ToTokens for RpcTable {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut targets = Vec::new();
+        let rpc_service = &self.rpc_service;
+        let op = &self.op;
+        let params = &self.params;
+        let rpc_api_ops = &self.rpc_api_ops;
+
+        for handler in self.handlers.elems.iter() {
+            let Handler { fn_call, request_type, response_type, .. } = Handler::new(handler);
+
+            targets.push(quote! {
+                #rpc_api_ops::#handler => {
+                    let request: #request_type = serde_json::from_value(#params).map_err(Error::InvalidParams)?;
+                    let response: #response_type = #rpc_service.#fn_call(None, request).await?;
+                    Ok(serde_json::to_value(response)?)
+                }
+            });
+        }
+
+        quote! {
+            match #op {
+                #(#targets)*
+                _ => Err(Error::MethodNotFound(format!("{:?}", #op))),
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub fn build_http_server_interface(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let rpc_table = parse_macro_input!(input as RpcTable);
+    rpc_table.to_token_stream().into()
+}
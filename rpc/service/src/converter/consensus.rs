@@ -1,35 +1,43 @@
 use async_trait::async_trait;
 use spectre_addresses::Address;
 use spectre_consensus_core::{
+    acceptance_data::AcceptanceData,
     block::Block,
     config::Config,
     hashing::tx::hash,
     header::Header,
-    tx::{MutableTransaction, Transaction, TransactionId, TransactionInput, TransactionOutput},
-    ChainPath,
+    tx::{MutableTransaction, SignableTransaction, Transaction, TransactionId, TransactionInput, TransactionOutput},
+};
+use spectre_consensus_notify::{
+    notification::{self as consensus_notify, Notification as ConsensusNotification},
+    notifier::ConsensusNotifier,
 };
-use spectre_consensus_notify::notification::{self as consensus_notify, Notification as ConsensusNotification};
 use spectre_consensusmanager::{ConsensusManager, ConsensusProxy};
+use spectre_core::error;
+use spectre_hashes::Hash;
 use spectre_math::Uint256;
 use spectre_mining::model::{owner_txs::OwnerTransactions, TransactionIdSet};
-use spectre_notify::converter::Converter;
+use spectre_notify::{converter::Converter, scope::AcceptedTransactionsChangedScope};
 use spectre_rpc_core::{
-    BlockAddedNotification, Notification, RpcAcceptedTransactionIds, RpcBlock, RpcBlockVerboseData, RpcHash, RpcMempoolEntry,
+    AcceptedTransactionsChangedNotification, BlockAddedNotification, Notification, RpcAcceptedTransaction, RpcAcceptedTransactionIds,
+    RpcAcceptedTransactions, RpcBlock, RpcBlockVerboseData, RpcChainBlock, RpcError, RpcHash, RpcMempoolEntry,
     RpcMempoolEntryByAddress, RpcResult, RpcTransaction, RpcTransactionInput, RpcTransactionOutput, RpcTransactionOutputVerboseData,
-    RpcTransactionVerboseData,
+    RpcTransactionVerboseData, RpcUtxoEntry,
 };
-use spectre_txscript::{extract_script_pub_key_address, script_class::ScriptClass};
+use spectre_txscript::{extract_script_pub_key_address, script_asm::disassemble, script_class::ScriptClass};
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 /// Conversion of consensus_core to rpc_core structures
 pub struct ConsensusConverter {
     consensus_manager: Arc<ConsensusManager>,
     config: Arc<Config>,
+    /// The notifier of the consensus notifications, whose compounded subscriptions cover all the RPC subscriptions
+    consensus_notifier: Arc<ConsensusNotifier>,
 }
 
 impl ConsensusConverter {
-    pub fn new(consensus_manager: Arc<ConsensusManager>, config: Arc<Config>) -> Self {
-        Self { consensus_manager, config }
+    pub fn new(consensus_manager: Arc<ConsensusManager>, config: Arc<Config>, consensus_notifier: Arc<ConsensusNotifier>) -> Self {
+        Self { consensus_manager, config, consensus_notifier }
     }
 
     /// Returns the proof-of-work difficulty as a multiple of the minimum difficulty using
@@ -151,22 +159,42 @@ impl ConsensusConverter {
     }
 
     fn get_transaction_output(&self, output: &TransactionOutput) -> RpcTransactionOutput {
-        let script_public_key_type = ScriptClass::from_script(&output.script_public_key);
-        let address = extract_script_pub_key_address(&output.script_public_key, self.config.prefix()).ok();
-        let verbose_data =
-            address.map(|address| RpcTransactionOutputVerboseData { script_public_key_type, script_public_key_address: address });
-        RpcTransactionOutput { value: output.value, script_public_key: output.script_public_key.clone(), verbose_data }
+        let verbose_data = RpcTransactionOutputVerboseData {
+            script_public_key_type: ScriptClass::from_script(&output.script_public_key),
+            script_public_key_address: extract_script_pub_key_address(&output.script_public_key, self.config.prefix()).ok(),
+            script_public_key_asm: disassemble(output.script_public_key.script()),
+        };
+        RpcTransactionOutput {
+            value: output.value,
+            script_public_key: output.script_public_key.clone(),
+            verbose_data: Some(verbose_data),
+        }
+    }
+
+    /// Converts selected chain blocks, each preceded by its selected chain index, into [`RpcChainBlock`]s.
+    pub async fn get_chain_blocks(&self, consensus: &ConsensusProxy, chain_blocks: Vec<(u64, Hash)>) -> RpcResult<Vec<RpcChainBlock>> {
+        let mut rpc_chain_blocks = Vec::with_capacity(chain_blocks.len());
+        for (chain_index, hash) in chain_blocks {
+            let header = consensus.async_get_header(hash).await?;
+            rpc_chain_blocks.push(RpcChainBlock {
+                hash,
+                chain_index,
+                blue_score: header.blue_score,
+                daa_score: header.daa_score,
+                timestamp: header.timestamp,
+            });
+        }
+        Ok(rpc_chain_blocks)
     }
 
-    pub async fn get_virtual_chain_accepted_transaction_ids(
+    /// Returns the ids of the transactions accepted by each of the `accepting_blocks` chain blocks,
+    /// `acceptance_data` holding the acceptance data of its prefix to be considered
+    pub fn get_accepted_transaction_ids(
         &self,
-        consensus: &ConsensusProxy,
-        chain_path: &ChainPath,
-        merged_blocks_limit: Option<usize>,
-    ) -> RpcResult<Vec<RpcAcceptedTransactionIds>> {
-        let acceptance_data = consensus.async_get_blocks_acceptance_data(chain_path.added.clone(), merged_blocks_limit).await.unwrap();
-        Ok(chain_path
-            .added
+        accepting_blocks: &[Hash],
+        acceptance_data: &[Arc<AcceptanceData>],
+    ) -> Vec<RpcAcceptedTransactionIds> {
+        accepting_blocks
             .iter()
             .zip(acceptance_data.iter())
             .map(|(hash, block_data)| RpcAcceptedTransactionIds {
@@ -176,7 +204,104 @@ impl ConsensusConverter {
                     .flat_map(|x| x.accepted_transactions.iter().map(|tx| tx.transaction_id))
                     .collect(),
             })
-            .collect())
+            .collect()
+    }
+
+    /// Returns the transactions accepted by each of the `accepting_blocks` chain blocks, with verbose data
+    /// and the UTXO entries spent by their inputs
+    pub async fn get_accepted_transactions(
+        &self,
+        consensus: &ConsensusProxy,
+        accepting_blocks: &[Hash],
+    ) -> RpcResult<Vec<RpcAcceptedTransactions>> {
+        let mut headers = HashMap::new();
+        let mut accepted_transactions = Vec::with_capacity(accepting_blocks.len());
+        for &accepting_block_hash in accepting_blocks {
+            accepted_transactions.push(
+                self.get_block_accepted_transactions(consensus, accepting_block_hash, &mut headers, |transactions| {
+                    vec![true; transactions.len()]
+                })
+                .await?,
+            );
+        }
+        Ok(accepted_transactions)
+    }
+
+    /// Returns the transactions accepted by chain block `accepting_block_hash` and satisfying `filter`, with verbose
+    /// data and the UTXO entries spent by their inputs.
+    ///
+    /// The filter is applied before any conversion so that only the retained transactions bear its cost.
+    async fn get_block_accepted_transactions(
+        &self,
+        consensus: &ConsensusProxy,
+        accepting_block_hash: Hash,
+        headers: &mut HashMap<Hash, Arc<Header>>,
+        filter: impl Fn(&[(Hash, SignableTransaction)]) -> Vec<bool>,
+    ) -> RpcResult<RpcAcceptedTransactions> {
+        let populated_transactions = consensus
+            .async_get_populated_accepted_transactions(accepting_block_hash)
+            .await
+            .map_err(|err| RpcError::General(err.to_string()))?;
+        let retained = filter(&populated_transactions);
+        let mut transactions = Vec::new();
+        for ((block_hash, populated_transaction), _) in
+            populated_transactions.into_iter().zip(retained).filter(|(_, retained)| *retained)
+        {
+            let header = match headers.get(&block_hash) {
+                Some(header) => header.clone(),
+                None => {
+                    let header = consensus.async_get_header(block_hash).await?;
+                    headers.insert(block_hash, header.clone());
+                    header
+                }
+            };
+            transactions.push(RpcAcceptedTransaction {
+                transaction: self.get_transaction(consensus, &populated_transaction.tx, Some(&header), true),
+                input_utxo_entries: populated_transaction.entries.into_iter().flatten().map(RpcUtxoEntry::from).collect(),
+            });
+        }
+        Ok(RpcAcceptedTransactions { accepting_block_hash, accepted_transactions: transactions })
+    }
+
+    /// Returns the transactions accepted by each of the `accepting_blocks` chain blocks that involve an address subscribed
+    /// to by some [`AcceptedTransactionsChangedNotification`] listener, the accepting blocks left without any such
+    /// transaction being omitted.
+    ///
+    /// The failure to resolve the transactions of a block is logged and reported by returning `false` as second item.
+    async fn get_subscribed_accepted_transactions(
+        &self,
+        consensus: &ConsensusProxy,
+        accepting_blocks: &[Hash],
+    ) -> (Vec<RpcAcceptedTransactions>, bool) {
+        let filter = |transactions: &[(Hash, SignableTransaction)]| {
+            self.consensus_notifier.with_addresses_subscription::<AcceptedTransactionsChangedScope, _>(|subscription, context| {
+                transactions
+                    .iter()
+                    .map(|(_, transaction)| {
+                        transaction.tx.outputs.iter().any(|output| subscription.contains(&output.script_public_key, context))
+                            || transaction
+                                .entries
+                                .iter()
+                                .flatten()
+                                .any(|entry| subscription.contains(&entry.script_public_key, context))
+                    })
+                    .collect()
+            })
+        };
+        let mut headers = HashMap::new();
+        let mut accepted_transactions = Vec::new();
+        let mut complete = true;
+        for &accepting_block_hash in accepting_blocks {
+            match self.get_block_accepted_transactions(consensus, accepting_block_hash, &mut headers, filter).await {
+                Ok(block_transactions) if block_transactions.accepted_transactions.is_empty() => {}
+                Ok(block_transactions) => accepted_transactions.push(block_transactions),
+                Err(err) => {
+                    error!("Failed to get the transactions accepted by chain block {}: {}", accepting_block_hash, err);
+                    complete = false;
+                }
+            }
+        }
+        (accepted_transactions, complete)
     }
 }
 
@@ -193,6 +318,20 @@ impl Converter for ConsensusConverter {
                 let block = Arc::new(self.get_block(&session, &msg.block, true, true).await.unwrap_or_else(|_| (&msg.block).into()));
                 Notification::BlockAdded(BlockAddedNotification { block })
             }
+            consensus_notify::Notification::AcceptedTransactionsChanged(msg) => {
+                let session = self.consensus_manager.consensus().unguarded_session();
+                // A chain block whose transactions cannot be resolved flags the notification as incomplete,
+                // letting the subscribers know they have to reconcile their state
+                let (accepted, accepted_complete) =
+                    self.get_subscribed_accepted_transactions(&session, &msg.added_chain_block_hashes).await;
+                let (unaccepted, unaccepted_complete) =
+                    self.get_subscribed_accepted_transactions(&session, &msg.removed_chain_block_hashes).await;
+                Notification::AcceptedTransactionsChanged(AcceptedTransactionsChangedNotification {
+                    accepted: Arc::new(accepted),
+                    unaccepted: Arc::new(unaccepted),
+                    incomplete: !accepted_complete || !unaccepted_complete,
+                })
+            }
             _ => (&incoming).into(),
         }
     }
@@ -0,0 +1,48 @@
+use spectre_database::stats::{prefix_name, DbStats, StoreCacheStats, StoreStats};
+use spectre_rpc_core::{RpcDatabaseStats, RpcStoreCacheStats, RpcStoreStats};
+
+pub trait StoreStatsConverter {
+    fn into_rpc(self) -> RpcStoreStats;
+}
+
+impl StoreStatsConverter for StoreStats {
+    fn into_rpc(self) -> RpcStoreStats {
+        RpcStoreStats {
+            prefix: self.prefix,
+            name: prefix_name(self.prefix),
+            approximate_key_count: self.approximate_key_count,
+            approximate_size: self.approximate_size,
+        }
+    }
+}
+
+pub trait DbStatsConverter {
+    fn into_rpc(self) -> RpcDatabaseStats;
+}
+
+impl DbStatsConverter for DbStats {
+    fn into_rpc(self) -> RpcDatabaseStats {
+        RpcDatabaseStats {
+            name: self.name,
+            estimated_key_count: self.estimated_key_count,
+            total_sst_files_size: self.total_sst_files_size,
+            live_sst_files_size: self.live_sst_files_size,
+            memtables_size: self.memtables_size,
+            pending_compaction_bytes: self.pending_compaction_bytes,
+            running_compactions: self.running_compactions,
+            is_compaction_pending: self.is_compaction_pending,
+            files_per_level: self.files_per_level,
+            stores: self.stores.into_iter().map(StoreStatsConverter::into_rpc).collect(),
+        }
+    }
+}
+
+pub trait StoreCacheStatsConverter {
+    fn into_rpc(self) -> RpcStoreCacheStats;
+}
+
+impl StoreCacheStatsConverter for StoreCacheStats {
+    fn into_rpc(self) -> RpcStoreCacheStats {
+        RpcStoreCacheStats { prefix: self.prefix, name: prefix_name(self.prefix), hits: self.hits, misses: self.misses }
+    }
+}
@@ -1,7 +1,7 @@
-use spectre_mining::feerate::{FeeEstimateVerbose, FeerateBucket, FeerateEstimations};
+use spectre_mining::feerate::{FeeEstimateVerbose, FeerateBucket, FeerateEstimations, HistoricalFeerateEstimate};
 use spectre_rpc_core::{
     message::GetFeeEstimateExperimentalResponse as RpcFeeEstimateVerboseResponse, RpcFeeEstimate,
-    RpcFeeEstimateVerboseExperimentalData as RpcFeeEstimateVerbose, RpcFeerateBucket,
+    RpcFeeEstimateVerboseExperimentalData as RpcFeeEstimateVerbose, RpcFeerateBucket, RpcHistoricalFeerateEstimate,
 };
 
 pub trait FeerateBucketConverter {
@@ -14,6 +14,16 @@ impl FeerateBucketConverter for FeerateBucket {
     }
 }
 
+pub trait HistoricalFeerateEstimateConverter {
+    fn into_rpc(self) -> RpcHistoricalFeerateEstimate;
+}
+
+impl HistoricalFeerateEstimateConverter for HistoricalFeerateEstimate {
+    fn into_rpc(self) -> RpcHistoricalFeerateEstimate {
+        RpcHistoricalFeerateEstimate { target_seconds: self.target_seconds, confidence: self.confidence, feerate: self.feerate }
+    }
+}
+
 pub trait FeeEstimateConverter {
     fn into_rpc(self) -> RpcFeeEstimate;
 }
@@ -43,6 +53,12 @@ impl FeeEstimateVerboseConverter for FeeEstimateVerbose {
                 next_block_template_feerate_min: self.next_block_template_feerate_min,
                 next_block_template_feerate_median: self.next_block_template_feerate_median,
                 next_block_template_feerate_max: self.next_block_template_feerate_max,
+                historical_sample_count: self.historical_sample_count,
+                historical_estimates: self
+                    .historical_estimates
+                    .into_iter()
+                    .map(HistoricalFeerateEstimateConverter::into_rpc)
+                    .collect(),
             }),
         }
     }
@@ -0,0 +1,22 @@
+use spectre_mining::model::tx_dry_run::TransactionDryRun;
+use spectre_rpc_core::RpcMempoolAcceptResult;
+
+pub trait TransactionDryRunConverter {
+    fn into_rpc(self) -> RpcMempoolAcceptResult;
+}
+
+impl TransactionDryRunConverter for TransactionDryRun {
+    fn into_rpc(self) -> RpcMempoolAcceptResult {
+        RpcMempoolAcceptResult {
+            transaction_id: self.transaction_id,
+            allowed: self.result.is_ok(),
+            is_orphan: self.is_orphan,
+            reject_reason: self.result.err().map(|err| err.to_string()),
+            compute_mass: self.compute_mass,
+            storage_mass: self.storage_mass,
+            fee: self.fee,
+            feerate: self.feerate,
+            replaced_transaction_id: self.replaced_transaction_id,
+        }
+    }
+}
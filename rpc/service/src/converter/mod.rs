@@ -1,4 +1,6 @@
 pub mod consensus;
+pub mod database;
 pub mod feerate_estimate;
 pub mod index;
+pub mod mempool;
 pub mod protocol;
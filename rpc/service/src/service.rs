@@ -1,7 +1,9 @@
 //! Core server implementation for ClientAPI
 
 use super::collector::{CollectorFromConsensus, CollectorFromIndex};
+use crate::converter::database::{DbStatsConverter, StoreCacheStatsConverter};
 use crate::converter::feerate_estimate::{FeeEstimateConverter, FeeEstimateVerboseConverter};
+use crate::converter::mempool::TransactionDryRunConverter;
 use crate::converter::{consensus::ConsensusConverter, index::IndexConverter, protocol::ProtocolConverter};
 use crate::service::NetworkType::{Mainnet, Testnet};
 use async_trait::async_trait;
@@ -14,31 +16,33 @@ use spectre_consensus_core::{
     config::Config,
     constants::MAX_SOMPI,
     network::NetworkType,
-    tx::{Transaction, COINBASE_TRANSACTION_INDEX},
+    tx::{MutableTransaction, Transaction, COINBASE_TRANSACTION_INDEX},
 };
 use spectre_consensus_notify::{
     notifier::ConsensusNotifier,
     {connection::ConsensusChannelConnection, notification::Notification as ConsensusNotification},
 };
-use spectre_consensusmanager::ConsensusManager;
+use spectre_consensusmanager::{spawn_blocking, ConsensusManager};
 use spectre_core::time::unix_now;
 use spectre_core::{
     core::Core,
-    debug,
+    debug, info,
     signals::Shutdown,
     spectred_env::version,
     task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
     task::tick::TickService,
     trace, warn,
 };
-use spectre_index_core::indexed_utxos::BalanceByScriptPublicKey;
+use spectre_index_core::indexed_utxos::{BalanceByScriptPublicKey, UtxoCursor, UtxoFilter, UtxoPageQuery};
 use spectre_index_core::{
-    connection::IndexChannelConnection, indexed_utxos::UtxoSetByScriptPublicKey, notification::Notification as IndexNotification,
-    notifier::IndexNotifier,
+    connection::IndexChannelConnection, notification::Notification as IndexNotification, notifier::IndexNotifier,
 };
 use spectre_mining::feerate::FeeEstimateVerbose;
-use spectre_mining::model::tx_query::TransactionQuery;
-use spectre_mining::{manager::MiningManagerProxy, mempool::tx::Orphan};
+use spectre_mining::model::{tx_priority::TransactionPriorityDelta, tx_query::TransactionQuery};
+use spectre_mining::{
+    manager::MiningManagerProxy,
+    mempool::tx::{Orphan, RbfPolicy},
+};
 use spectre_notify::listener::ListenerLifespan;
 use spectre_notify::subscription::context::SubscriptionContext;
 use spectre_notify::subscription::{MutationPolicies, UtxosChangedMutationPolicy};
@@ -58,12 +62,15 @@ use spectre_rpc_core::{
     api::{
         connection::DynRpcConnection,
         ops::{RPC_API_REVISION, RPC_API_VERSION},
-        rpc::{RpcApi, MAX_SAFE_WINDOW_SIZE},
+        rpc::{
+            RpcApi, MAX_CHAIN_BLOCKS_RANGE_SIZE, MAX_GENERATE_BLOCKS_COUNT, MAX_SAFE_WINDOW_SIZE, MAX_TEST_MEMPOOL_ACCEPT_TRANSACTIONS,
+        },
     },
     model::*,
     notify::connection::ChannelConnection,
-    Notification, RpcError, RpcResult,
+    utxo_page_into_rpc, Notification, RpcError, RpcResult,
 };
+use spectre_stratum::stats::StratumStats;
 use spectre_txscript::{extract_script_pub_key_address, pay_to_address_script};
 use spectre_utils::expiring_cache::ExpiringCache;
 use spectre_utils::sysinfo::SystemInfo;
@@ -73,7 +80,9 @@ use spectre_utxoindex::api::UtxoIndexProxy;
 use std::time::Duration;
 use std::{
     collections::HashMap,
+    fs,
     iter::once,
+    path::PathBuf,
     sync::{atomic::Ordering, Arc},
     vec,
 };
@@ -105,7 +114,6 @@ pub struct RpcCoreService {
     utxoindex: Option<UtxoIndexProxy>,
     config: Arc<Config>,
     consensus_converter: Arc<ConsensusConverter>,
-    index_converter: Arc<IndexConverter>,
     protocol_converter: Arc<ProtocolConverter>,
     core: Arc<Core>,
     processing_counters: Arc<ProcessingCounters>,
@@ -117,7 +125,11 @@ pub struct RpcCoreService {
     p2p_tower_counters: Arc<TowerConnectionCounters>,
     grpc_tower_counters: Arc<TowerConnectionCounters>,
     system_info: SystemInfo,
+    backup_dir: PathBuf,
+    /// Share accounting of the built-in Stratum server, if running
+    stratum_stats: Option<Arc<StratumStats>>,
     fee_estimate_cache: ExpiringCache<RpcFeeEstimate>,
+    fee_estimate_experimental_cache: ExpiringCache<RpcFeeEstimate>,
     fee_estimate_verbose_cache: ExpiringCache<spectre_mining::errors::MiningManagerResult<GetFeeEstimateExperimentalResponse>>,
 }
 
@@ -144,12 +156,17 @@ impl RpcCoreService {
         p2p_tower_counters: Arc<TowerConnectionCounters>,
         grpc_tower_counters: Arc<TowerConnectionCounters>,
         system_info: SystemInfo,
+        backup_dir: PathBuf,
+        stratum_stats: Option<Arc<StratumStats>>,
     ) -> Self {
         // This notifier UTXOs subscription granularity to index-processor or consensus notifier
         let policies = match index_notifier {
             Some(_) => MutationPolicies::new(UtxosChangedMutationPolicy::AddressSet),
             None => MutationPolicies::new(UtxosChangedMutationPolicy::Wildcard),
         };
+        // The consensus notifier keeps the exact addresses of the accepted transactions subscriptions,
+        // letting the converter skip the transactions no listener is interested in
+        let policies = policies.with_accepted_transactions_changed(UtxosChangedMutationPolicy::AddressSet);
 
         // Prepare consensus-notify objects
         let consensus_notify_channel = Channel::<ConsensusNotification>::default();
@@ -162,7 +179,8 @@ impl RpcCoreService {
         let mut consensus_events: EventSwitches = EVENT_TYPE_ARRAY[..].into();
         consensus_events[EventType::UtxosChanged] = false;
         consensus_events[EventType::PruningPointUtxoSetOverride] = index_notifier.is_none();
-        let consensus_converter = Arc::new(ConsensusConverter::new(consensus_manager.clone(), config.clone()));
+        let consensus_converter =
+            Arc::new(ConsensusConverter::new(consensus_manager.clone(), config.clone(), consensus_notifier.clone()));
         let consensus_collector = Arc::new(CollectorFromConsensus::new(
             "rpc-core <= consensus",
             consensus_notify_channel.receiver(),
@@ -208,7 +226,6 @@ impl RpcCoreService {
             utxoindex,
             config,
             consensus_converter,
-            index_converter,
             protocol_converter,
             core,
             processing_counters,
@@ -220,7 +237,10 @@ impl RpcCoreService {
             p2p_tower_counters,
             grpc_tower_counters,
             system_info,
+            backup_dir,
+            stratum_stats,
             fee_estimate_cache: ExpiringCache::new(Duration::from_millis(500), Duration::from_millis(1000)),
+            fee_estimate_experimental_cache: ExpiringCache::new(Duration::from_millis(500), Duration::from_millis(1000)),
             fee_estimate_verbose_cache: ExpiringCache::new(Duration::from_millis(500), Duration::from_millis(1000)),
         }
     }
@@ -249,18 +269,6 @@ impl RpcCoreService {
         self.core_shutdown_request.listener.clone()
     }
 
-    async fn get_utxo_set_by_script_public_key<'a>(
-        &self,
-        addresses: impl Iterator<Item = &'a RpcAddress>,
-    ) -> UtxoSetByScriptPublicKey {
-        self.utxoindex
-            .clone()
-            .unwrap()
-            .get_utxos_by_script_public_keys(addresses.map(pay_to_address_script).collect())
-            .await
-            .unwrap_or_default()
-    }
-
     async fn get_balance_by_script_public_key<'a>(&self, addresses: impl Iterator<Item = &'a RpcAddress>) -> BalanceByScriptPublicKey {
         self.utxoindex
             .clone()
@@ -367,9 +375,20 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             return Err(spectre_addresses::AddressError::InvalidPrefix(request.pay_address.prefix.to_string()))?;
         }
 
+        if let Some(tag) = request.payload_tags.iter().find(|tag| tag.is_empty() || tag.contains('/')) {
+            return Err(RpcError::InvalidPayloadTag(tag.clone()));
+        }
+
         // Build block template
         let script_public_key = spectre_txscript::pay_to_address_script(&request.pay_address);
-        let extra_data = version().as_bytes().iter().chain(once(&(b'/'))).chain(&request.extra_data).cloned().collect::<Vec<_>>();
+        let extra_data = version()
+            .as_bytes()
+            .iter()
+            .chain(once(&(b'/')))
+            .chain(&request.extra_data)
+            .chain(request.payload_tags.iter().flat_map(|tag| once(&b'/').chain(tag.as_bytes())))
+            .cloned()
+            .collect::<Vec<_>>();
         let miner_data: MinerData = MinerData::new(script_public_key, extra_data);
         let session = self.consensus_manager.consensus().unguarded_session();
         let block_template = self.mining_manager.clone().get_block_template(&session, miner_data).await?;
@@ -533,6 +552,36 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(GetMempoolEntriesByAddressesResponse::new(mempool_entries))
     }
 
+    async fn prioritize_transaction_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: PrioritizeTransactionRequest,
+    ) -> RpcResult<PrioritizeTransactionResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("PrioritizeTransaction RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        let delta = TransactionPriorityDelta::new(request.fee_delta, request.excluded);
+        self.mining_manager.clone().set_transaction_priority_delta(request.transaction_id, delta).await;
+        Ok(PrioritizeTransactionResponse {})
+    }
+
+    async fn get_prioritized_transactions_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetPrioritizedTransactionsRequest,
+    ) -> RpcResult<GetPrioritizedTransactionsResponse> {
+        let prioritized_transactions = self
+            .mining_manager
+            .clone()
+            .get_transaction_priority_deltas()
+            .await
+            .into_iter()
+            .map(|(transaction_id, delta)| RpcPrioritizedTransaction::new(transaction_id, delta.fee_delta, delta.excluded))
+            .collect();
+        Ok(GetPrioritizedTransactionsResponse::new(prioritized_transactions))
+    }
+
     async fn submit_transaction_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -617,19 +666,41 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         // else it returns the batch_size amount on pure chain blocks.
         // Note: batch_size does not bound removed chain blocks, only added chain blocks.
         let batch_size = (self.config.mergeset_size_limit * 10) as usize;
-        let mut virtual_chain_batch = session.async_get_virtual_chain_from_block(request.start_hash, Some(batch_size)).await?;
-        let accepted_transaction_ids = if request.include_accepted_transaction_ids {
-            let accepted_transaction_ids = self
-                .consensus_converter
-                .get_virtual_chain_accepted_transaction_ids(&session, &virtual_chain_batch, Some(batch_size))
-                .await?;
-            // bound added to the length of the accepted transaction ids, which is bounded by merged blocks
-            virtual_chain_batch.added.truncate(accepted_transaction_ids.len());
-            accepted_transaction_ids
-        } else {
-            vec![]
-        };
-        Ok(GetVirtualChainFromBlockResponse::new(virtual_chain_batch.removed, virtual_chain_batch.added, accepted_transaction_ids))
+        let added_limit = request.limit.filter(|&limit| limit > 0).map_or(batch_size, |limit| batch_size.min(limit as usize));
+        let mut virtual_chain_batch = session.async_get_virtual_chain_from_block(request.start_hash, Some(added_limit)).await?;
+        let (accepted_transaction_ids, accepted_transactions) =
+            if request.include_accepted_transaction_ids || request.include_accepted_transactions {
+                // Full transactions are much larger, so they are bounded by a single mergeset_size_limit of merged blocks,
+                // which still always covers at least one chain block.
+                let merged_blocks_limit =
+                    if request.include_accepted_transactions { self.config.mergeset_size_limit as usize } else { batch_size };
+                let acceptance_data =
+                    session.async_get_blocks_acceptance_data(virtual_chain_batch.added.clone(), Some(merged_blocks_limit)).await?;
+                // bound added to the length of the acceptance data, which is bounded by merged blocks
+                virtual_chain_batch.added.truncate(acceptance_data.len());
+                let accepted_transaction_ids = if request.include_accepted_transaction_ids {
+                    self.consensus_converter.get_accepted_transaction_ids(&virtual_chain_batch.added, &acceptance_data)
+                } else {
+                    vec![]
+                };
+                let accepted_transactions = if request.include_accepted_transactions {
+                    self.consensus_converter.get_accepted_transactions(&session, &virtual_chain_batch.added).await?
+                } else {
+                    vec![]
+                };
+                (accepted_transaction_ids, accepted_transactions)
+            } else {
+                (vec![], vec![])
+            };
+        let sink = session.async_get_sink().await;
+        let next_start_hash = virtual_chain_batch.added.last().copied().filter(|&hash| hash != sink);
+        Ok(GetVirtualChainFromBlockResponse::new(
+            virtual_chain_batch.removed,
+            virtual_chain_batch.added,
+            accepted_transaction_ids,
+            accepted_transactions,
+            next_start_hash,
+        ))
     }
 
     async fn get_block_count_call(
@@ -648,10 +719,34 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         if !self.config.utxoindex {
             return Err(RpcError::NoUtxoIndex);
         }
-        // TODO: discuss if the entry order is part of the method requirements
-        //       (the current impl does not retain an entry order matching the request addresses order)
-        let entry_map = self.get_utxo_set_by_script_public_key(request.addresses.iter()).await;
-        Ok(GetUtxosByAddressesResponse::new(self.index_converter.get_utxos_by_addresses_entries(&entry_map)))
+        let script_public_keys = request.addresses.iter().map(pay_to_address_script).collect::<Vec<_>>();
+        let cursor = match request.cursor {
+            Some(cursor) => match request.addresses.iter().position(|address| *address == cursor.address) {
+                Some(index) => Some(UtxoCursor {
+                    script_public_key: script_public_keys[index].clone(),
+                    outpoint: cursor.outpoint.into(),
+                    block_daa_score: cursor.block_daa_score,
+                }),
+                None => return Err(RpcError::General(format!("cursor address {} is not a requested address", cursor.address))),
+            },
+            None => None,
+        };
+        // A coinbase entry can be spent by a transaction entering the mempool once the virtual DAA score reaches its own plus the maturity
+        let min_immature_coinbase_daa_score = request.exclude_immature_coinbase.then(|| {
+            let virtual_daa_score = self.consensus_manager.consensus().unguarded_session().get_virtual_daa_score();
+            (virtual_daa_score + 1).saturating_sub(self.config.params.coinbase_maturity)
+        });
+        let addresses = script_public_keys.iter().cloned().zip(request.addresses).collect::<HashMap<_, _>>();
+        let query = UtxoPageQuery {
+            script_public_keys,
+            filter: UtxoFilter { min_amount: request.min_amount, max_amount: request.max_amount, min_immature_coinbase_daa_score },
+            order: request.order.into(),
+            cursor,
+            limit: request.limit.filter(|&limit| limit > 0).map(|limit| limit as usize),
+        };
+        let page = self.utxoindex.clone().unwrap().get_utxo_page(query).await.map_err(|err| RpcError::General(err.to_string()))?;
+        let (entries, next_cursor) = utxo_page_into_rpc(page, &addresses);
+        Ok(GetUtxosByAddressesResponse::new(entries, next_cursor))
     }
 
     async fn get_balance_by_address_call(
@@ -773,7 +868,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
 
     async fn get_fee_estimate_experimental_call(
         &self,
-        connection: Option<&DynRpcConnection>,
+        _connection: Option<&DynRpcConnection>,
         request: GetFeeEstimateExperimentalRequest,
     ) -> RpcResult<GetFeeEstimateExperimentalResponse> {
         if request.verbose {
@@ -790,7 +885,11 @@ NOTE: This error usually indicates an RPC conversion error between the node and
                 .await?;
             Ok(response)
         } else {
-            let estimate = self.get_fee_estimate_call(connection, GetFeeEstimateRequest {}).await?.estimate;
+            let mining_manager = self.mining_manager.clone();
+            let estimate = self
+                .fee_estimate_experimental_cache
+                .get(async move { mining_manager.get_blended_feerate_estimations().await.into_rpc() })
+                .await;
             Ok(GetFeeEstimateExperimentalResponse { estimate, verbose: None })
         }
     }
@@ -985,6 +1084,288 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Err(RpcError::NotImplemented)
     }
 
+    async fn invalidate_block_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: InvalidateBlockRequest,
+    ) -> RpcResult<InvalidateBlockResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("InvalidateBlock RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        warn!("InvalidateBlock RPC command called, disqualifying block {} from the virtual chain", request.hash);
+        self.consensus_manager.consensus().session().await.async_invalidate_block(request.hash).await?;
+        Ok(InvalidateBlockResponse {})
+    }
+
+    async fn reconsider_block_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: ReconsiderBlockRequest,
+    ) -> RpcResult<ReconsiderBlockResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("ReconsiderBlock RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        warn!("ReconsiderBlock RPC command called, requalifying block {} for the virtual chain", request.hash);
+        self.consensus_manager.consensus().session().await.async_reconsider_block(request.hash).await?;
+        Ok(ReconsiderBlockResponse {})
+    }
+
+    async fn generate_blocks_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GenerateBlocksRequest,
+    ) -> RpcResult<GenerateBlocksResponse> {
+        if !matches!(*self.config.net, NetworkType::Simnet | NetworkType::Devnet) {
+            return Err(RpcError::General(format!("Block generation is not supported on {}", self.config.net)));
+        }
+        if request.count > MAX_GENERATE_BLOCKS_COUNT {
+            return Err(RpcError::GenerateBlocksTooManyBlocks(request.count, MAX_GENERATE_BLOCKS_COUNT));
+        }
+
+        // Make sure the pay address prefix matches the config network type
+        if request.pay_address.prefix != self.config.prefix() {
+            return Err(spectre_addresses::AddressError::InvalidPrefix(request.pay_address.prefix.to_string()))?;
+        }
+
+        let extra_data = version().as_bytes().to_vec();
+        let miner_data = MinerData::new(pay_to_address_script(&request.pay_address), extra_data);
+        let session = self.consensus_manager.consensus().unguarded_session();
+        let mut block_hashes = vec![];
+        let mut transaction_ids = request.transaction_ids;
+        for _ in 0..request.count {
+            let block_template = if transaction_ids.is_empty() {
+                self.mining_manager.clone().get_block_template(&session, miner_data.clone()).await?
+            } else {
+                let transaction_ids = std::mem::take(&mut transaction_ids);
+                self.mining_manager.clone().get_block_template_with_transactions(&session, miner_data.clone(), transaction_ids).await?
+            };
+
+            // Solve the proof of work, which is trivial on the networks where block generation is allowed
+            let mut header = block_template.block.header;
+            header.nonce = tokio::task::spawn_blocking({
+                let state = spectre_pow::State::new(&header);
+                move || (0..=u64::MAX).find(|&nonce| state.check_pow(nonce).0).unwrap()
+            })
+            .await
+            .map_err(|err| RpcError::General(err.to_string()))?;
+            header.finalize();
+
+            let block = Block::new(header, block_template.block.transactions);
+            let hash = block.hash();
+            self.flow_context
+                .submit_rpc_block(&session, block)
+                .await
+                .map_err(|err| RpcError::General(format!("Generated block {} was rejected: {}", hash, err)))?;
+            block_hashes.push(hash);
+        }
+        Ok(GenerateBlocksResponse { block_hashes })
+    }
+
+    async fn create_backup_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: CreateBackupRequest,
+    ) -> RpcResult<CreateBackupResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("CreateBackup RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+
+        let backup_dir = self.backup_dir.join(format!("backup-{}", unix_now()));
+        if backup_dir.exists() {
+            return Err(RpcError::General(format!("Backup directory {} already exists", backup_dir.display())));
+        }
+        info!("Creating a database backup at {}", backup_dir.display());
+
+        // The utxoindex is checkpointed after consensus, so it may hold a few more updates. A restored node
+        // detects such a gap and resyncs the utxoindex on startup.
+        let consensus_manager = self.consensus_manager.clone();
+        let checkpoint_dir = backup_dir.clone();
+        let mut result = spawn_blocking(move || consensus_manager.create_checkpoint(&checkpoint_dir))
+            .await
+            .unwrap()
+            .map_err(|err| RpcError::General(err.to_string()));
+        if let (Ok(_), Some(utxoindex)) = (&result, self.utxoindex.clone()) {
+            result = utxoindex.create_checkpoint(backup_dir.clone()).await.map_err(|err| RpcError::General(err.to_string()));
+        }
+        if let Err(err) = result {
+            warn!("Failed creating a database backup at {}: {}", backup_dir.display(), err);
+            let _ = fs::remove_dir_all(&backup_dir);
+            return Err(err);
+        }
+
+        info!("Created a database backup at {}", backup_dir.display());
+        Ok(CreateBackupResponse { path: backup_dir.display().to_string() })
+    }
+
+    async fn get_database_stats_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        _request: GetDatabaseStatsRequest,
+    ) -> RpcResult<GetDatabaseStatsResponse> {
+        let consensus_manager = self.consensus_manager.clone();
+        let mut databases = spawn_blocking(move || consensus_manager.database_stats())
+            .await
+            .unwrap()
+            .map_err(|err| RpcError::General(err.to_string()))?;
+        if let Some(utxoindex) = self.utxoindex.clone() {
+            databases.push(utxoindex.database_stats().await.map_err(|err| RpcError::General(err.to_string()))?);
+        }
+        let cache_stats =
+            spectre_database::stats::aggregate_cache_stats(&databases).into_iter().map(StoreCacheStatsConverter::into_rpc).collect();
+        Ok(GetDatabaseStatsResponse::new(databases.into_iter().map(DbStatsConverter::into_rpc).collect(), cache_stats))
+    }
+
+    async fn get_chain_block_by_blue_score_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetChainBlockByBlueScoreRequest,
+    ) -> RpcResult<GetChainBlockByBlueScoreResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let chain_blocks = session.async_get_chain_blocks_by_blue_score(request.blue_score, u64::MAX, 1).await?;
+        let chain_block = self
+            .consensus_converter
+            .get_chain_blocks(&session, chain_blocks)
+            .await?
+            .pop()
+            .ok_or_else(|| RpcError::ChainBlockNotReached("blue score".to_string(), request.blue_score))?;
+        Ok(GetChainBlockByBlueScoreResponse::new(chain_block))
+    }
+
+    async fn get_chain_block_by_daa_score_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetChainBlockByDaaScoreRequest,
+    ) -> RpcResult<GetChainBlockByDaaScoreResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let chain_blocks = session.async_get_chain_blocks_by_daa_score(request.daa_score, u64::MAX, 1).await?;
+        let chain_block = self
+            .consensus_converter
+            .get_chain_blocks(&session, chain_blocks)
+            .await?
+            .pop()
+            .ok_or_else(|| RpcError::ChainBlockNotReached("DAA score".to_string(), request.daa_score))?;
+        Ok(GetChainBlockByDaaScoreResponse::new(chain_block))
+    }
+
+    async fn get_chain_blocks_by_blue_score_range_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetChainBlocksByBlueScoreRangeRequest,
+    ) -> RpcResult<GetChainBlocksByBlueScoreRangeResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let chain_blocks = session
+            .async_get_chain_blocks_by_blue_score(request.low_blue_score, request.high_blue_score, MAX_CHAIN_BLOCKS_RANGE_SIZE)
+            .await?;
+        Ok(GetChainBlocksByBlueScoreRangeResponse::new(self.consensus_converter.get_chain_blocks(&session, chain_blocks).await?))
+    }
+
+    async fn get_chain_blocks_by_daa_score_range_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: GetChainBlocksByDaaScoreRangeRequest,
+    ) -> RpcResult<GetChainBlocksByDaaScoreRangeResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let chain_blocks = session
+            .async_get_chain_blocks_by_daa_score(request.low_daa_score, request.high_daa_score, MAX_CHAIN_BLOCKS_RANGE_SIZE)
+            .await?;
+        Ok(GetChainBlocksByDaaScoreRangeResponse::new(self.consensus_converter.get_chain_blocks(&session, chain_blocks).await?))
+    }
+
+    async fn test_mempool_accept_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: TestMempoolAcceptRequest,
+    ) -> RpcResult<TestMempoolAcceptResponse> {
+        if request.transactions.len() > MAX_TEST_MEMPOOL_ACCEPT_TRANSACTIONS {
+            return Err(RpcError::TestMempoolAcceptTooManyTransactions(
+                request.transactions.len(),
+                MAX_TEST_MEMPOOL_ACCEPT_TRANSACTIONS,
+            ));
+        }
+        let allow_orphan = self.config.unsafe_rpc && request.allow_orphan;
+        if !self.config.unsafe_rpc && request.allow_orphan {
+            debug!("TestMempoolAccept RPC command called with AllowOrphan enabled while node in safe RPC mode -- switching to ForbidOrphan.");
+        }
+
+        // Mirror the policies applied to transactions submitted via SubmitTransaction and SubmitTransactionReplacement
+        let (orphan, rbf_policy) = match (request.replace_by_fee, allow_orphan) {
+            (true, _) => (Orphan::Forbidden, RbfPolicy::Mandatory),
+            (false, true) => (Orphan::Allowed, RbfPolicy::Forbidden),
+            (false, false) => (Orphan::Forbidden, RbfPolicy::Forbidden),
+        };
+        let transactions = request.transactions.into_iter().map(Transaction::try_from).collect::<Result<Vec<_>, _>>()?;
+        let session = self.consensus_manager.consensus().unguarded_session();
+        let dry_runs = self.mining_manager.clone().dry_run_transaction_batch(&session, transactions, orphan, rbf_policy).await;
+        Ok(TestMempoolAcceptResponse::new(dry_runs.into_iter().map(TransactionDryRunConverter::into_rpc).collect()))
+    }
+
+    async fn decode_transaction_call(
+        &self,
+        _connection: Option<&DynRpcConnection>,
+        request: DecodeTransactionRequest,
+    ) -> RpcResult<DecodeTransactionResponse> {
+        let transaction = Transaction::try_from(&request.transaction)?;
+        if !request.utxo_entries.is_empty() && request.utxo_entries.len() != transaction.inputs.len() {
+            return Err(RpcError::UtxoEntriesCountMismatch(request.utxo_entries.len(), transaction.inputs.len()));
+        }
+        let session = self.consensus_manager.consensus().unguarded_session();
+        let rpc_transaction = self.consensus_converter.get_transaction(&session, &transaction, None, true);
+
+        let mut transaction = MutableTransaction::from_tx(transaction);
+        if request.utxo_entries.is_empty() {
+            transaction = self.mining_manager.clone().populate_transaction_entries(&session, transaction).await;
+        } else {
+            transaction.entries = request.utxo_entries.into_iter().map(|entry| Some(entry.into())).collect();
+        }
+
+        // The storage mass formula is undefined for transactions without inputs or spending or creating zero values
+        let storage_mass = (transaction.is_verifiable()
+            && !transaction.tx.inputs.is_empty()
+            && transaction.entries.iter().flatten().all(|entry| entry.amount > 0)
+            && transaction.tx.outputs.iter().all(|output| output.value > 0))
+        .then(|| session.calculate_transaction_storage_mass(&transaction))
+        .flatten();
+
+        let script_verifications = if !request.verify_scripts {
+            vec![]
+        } else if transaction.is_verifiable() {
+            let entries = transaction.entries.clone();
+            let results = session.async_verify_transaction_scripts(transaction).await;
+            results
+                .into_iter()
+                .zip(entries)
+                .enumerate()
+                .map(|(i, (result, entry))| RpcInputScriptVerification {
+                    input_index: i as u32,
+                    utxo_entry: entry.map(RpcUtxoEntry::from),
+                    verified: result.is_ok(),
+                    error: result.err().map(|err| err.to_string()),
+                })
+                .collect()
+        } else {
+            // Scripts may only be executed once the entries spent by all inputs are known
+            transaction
+                .entries
+                .into_iter()
+                .enumerate()
+                .map(|(i, entry)| RpcInputScriptVerification {
+                    input_index: i as u32,
+                    error: Some(match entry {
+                        Some(_) => "not verified since the UTXO entries of other inputs are missing".to_string(),
+                        None => "missing UTXO entry".to_string(),
+                    }),
+                    utxo_entry: entry.map(RpcUtxoEntry::from),
+                    verified: false,
+                })
+                .collect()
+        };
+
+        Ok(DecodeTransactionResponse::new(rpc_transaction, storage_mass, script_verifications))
+    }
+
     async fn get_connections_call(
         &self,
         _connection: Option<&DynRpcConnection>,
@@ -1081,6 +1462,8 @@ NOTE: This error usually indicates an RPC conversion error between the node and
 
         let custom_metrics: Option<HashMap<String, CustomMetricValue>> = None;
 
+        let stratum_metrics = self.stratum_stats.as_ref().filter(|_| req.stratum_metrics).map(|stats| stats.metrics());
+
         let server_time = unix_now();
 
         let response = GetMetricsResponse {
@@ -1091,6 +1474,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             consensus_metrics,
             storage_metrics,
             custom_metrics,
+            stratum_metrics,
         };
 
         Ok(response)
@@ -1173,6 +1557,14 @@ NOTE: This error usually indicates an RPC conversion error between the node and
                 warn!("RPC subscription to blanket UtxosChanged called while node in safe RPC mode -- ignoring.");
                 Err(RpcError::UnavailableInSafeMode)
             }
+            Scope::AcceptedTransactionsChanged(ref accepted_transactions_changed_scope)
+                if !self.config.unsafe_rpc && accepted_transactions_changed_scope.addresses.is_empty() =>
+            {
+                // Same restriction as above, blanket AcceptedTransactionsChanged notifications carrying
+                // all the transactions accepted by the selected chain.
+                warn!("RPC subscription to blanket AcceptedTransactionsChanged called while node in safe RPC mode -- ignoring.");
+                Err(RpcError::UnavailableInSafeMode)
+            }
             _ => {
                 self.notifier.clone().start_notify(id, scope).await?;
                 Ok(())
@@ -76,6 +76,7 @@ impl Inner {
             RpcApiOps::VirtualDaaScoreChangedNotification,
             RpcApiOps::PruningPointUtxoSetOverrideNotification,
             RpcApiOps::NewBlockTemplateNotification,
+            RpcApiOps::AcceptedTransactionsChangedNotification,
         ]
         .into_iter()
         .for_each(|notification_op| {
@@ -615,7 +616,9 @@ impl RpcApi for SpectreRpcClient {
             Ping,
             AddPeer,
             Ban,
+            CreateBackup,
             EstimateNetworkHashesPerSecond,
+            GenerateBlocks,
             GetBalanceByAddress,
             GetBalancesByAddresses,
             GetBlock,
@@ -623,7 +626,14 @@ impl RpcApi for SpectreRpcClient {
             GetBlockDagInfo,
             GetBlocks,
             GetBlockTemplate,
+            GetChainBlockByBlueScore,
+            GetChainBlockByDaaScore,
+            GetChainBlocksByBlueScoreRange,
+            GetChainBlocksByDaaScoreRange,
+            TestMempoolAccept,
+            DecodeTransaction,
             GetCurrentBlockColor,
+            GetDatabaseStats,
             GetCoinSupply,
             GetConnectedPeerInfo,
             GetConnections,
@@ -638,6 +648,7 @@ impl RpcApi for SpectreRpcClient {
             GetMempoolEntry,
             GetMetrics,
             GetPeerAddresses,
+            GetPrioritizedTransactions,
             GetServerInfo,
             GetSink,
             GetSinkBlueScore,
@@ -647,6 +658,9 @@ impl RpcApi for SpectreRpcClient {
             GetUtxoReturnAddress,
             GetUtxosByAddresses,
             GetVirtualChainFromBlock,
+            InvalidateBlock,
+            PrioritizeTransaction,
+            ReconsiderBlock,
             ResolveFinalityConflict,
             Shutdown,
             SubmitBlock,
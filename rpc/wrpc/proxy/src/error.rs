@@ -17,6 +17,9 @@ pub enum Error {
 
     #[error(transparent)]
     WorkflowRpc(#[from] workflow_rpc::error::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
 }
 
 impl From<String> for Error {
@@ -1,4 +1,5 @@
 mod error;
+mod metrics;
 mod result;
 
 use clap::Parser;
@@ -6,12 +7,13 @@ use result::Result;
 use spectre_consensus_core::network::NetworkType;
 use spectre_rpc_core::api::ops::RpcApiOps;
 use spectre_wrpc_server::{
+    balancer::{Balancer, BalancingStrategy},
     connection::Connection,
     router::Router,
     server::Server,
     service::{Options, SpectreRpcHandler},
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use workflow_log::*;
 use workflow_rpc::server::prelude::*;
 use workflow_rpc::server::WebSocketCounters;
@@ -30,9 +32,18 @@ struct Args {
     #[clap(long)]
     devnet: bool,
 
-    /// proxy:port for gRPC server (grpc://127.0.0.1:18110)
+    /// proxy:port for gRPC servers (grpc://127.0.0.1:18110), connections being balanced over all of them
     #[clap(name = "grpc")]
-    grpc_proxy_address: Option<String>,
+    grpc_proxy_addresses: Vec<String>,
+    /// Backend balancing strategy: `round-robin` or `least-connections`
+    #[clap(long)]
+    balancing: Option<String>,
+    /// Interval in seconds between two health checks of the backends
+    #[clap(long, default_value_t = 5)]
+    health_check_interval: u64,
+    /// interface:port serving the backend metrics over HTTP (127.0.0.1:19120)
+    #[clap(long)]
+    metrics: Option<String>,
 
     // /// wRPC port
     /// interface:port for wRPC server (wrpc://127.0.0.1:19110)
@@ -51,7 +62,19 @@ struct Args {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let Args { testnet, simnet, devnet, grpc_proxy_address, interface, verbose, threads, encoding } = Args::parse();
+    let Args {
+        testnet,
+        simnet,
+        devnet,
+        grpc_proxy_addresses,
+        balancing,
+        health_check_interval,
+        metrics,
+        interface,
+        verbose,
+        threads,
+        encoding,
+    } = Args::parse();
 
     let network_type = if testnet {
         NetworkType::Testnet
@@ -71,18 +94,37 @@ async fn main() -> Result<()> {
         Encoding::SerdeJson => network_type.default_json_rpc_port(),
     };
 
+    let grpc_proxy_addresses =
+        if grpc_proxy_addresses.is_empty() { vec![format!("grpc://127.0.0.1:{spectred_port}")] } else { grpc_proxy_addresses };
+    let strategy: BalancingStrategy = balancing.map(|x| x.parse()).transpose()?.unwrap_or_default();
+    let balancer = Arc::new(Balancer::new(grpc_proxy_addresses, strategy, Duration::from_secs(health_check_interval.max(1))));
+
     let options = Arc::new(Options {
         listen_address: interface.unwrap_or_else(|| format!("wrpc://127.0.0.1:{proxy_port}")),
-        grpc_proxy_address: Some(grpc_proxy_address.unwrap_or_else(|| format!("grpc://127.0.0.1:{spectred_port}"))),
+        balancer: Some(balancer.clone()),
         verbose,
         // ..Options::default()
     });
     log_info!("");
-    log_info!("Proxy routing to `{}` on {}", network_type, options.grpc_proxy_address.as_ref().unwrap());
+    for backend in balancer.backends() {
+        log_info!("Proxy routing to `{}` on {}", network_type, backend.address());
+    }
+    log_info!("Using `{strategy}` balancing");
+    balancer.check_health().await;
+
+    if let Some(metrics) = metrics {
+        let balancer = balancer.clone();
+        tokio::spawn(async move {
+            if let Err(err) = metrics::serve(metrics, balancer).await {
+                log_error!("Metrics server error: {err}");
+            }
+        });
+    }
 
     let counters = Arc::new(WebSocketCounters::default());
     let tasks = threads.unwrap_or_else(num_cpus::get);
     let rpc_handler = Arc::new(SpectreRpcHandler::new(tasks, encoding, None, options.clone()));
+    rpc_handler.server.start();
 
     let router = Arc::new(Router::new(rpc_handler.server.clone()));
     let server = RpcServer::new_with_encoding::<Server, Connection, RpcApiOps, Id64>(
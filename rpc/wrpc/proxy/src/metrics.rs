@@ -0,0 +1,26 @@
+use crate::result::Result;
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use spectre_wrpc_server::balancer::Balancer;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use workflow_log::*;
+
+/// Serves the metrics of every backend as JSON on `GET /metrics`
+pub async fn serve(listen_address: String, balancer: Arc<Balancer>) -> Result<()> {
+    let listener = TcpListener::bind(&listen_address).await?;
+    log_info!("Backend metrics are served on http://{listen_address}/metrics");
+    let router = axum::Router::new().route("/metrics", get(move || metrics(balancer.clone())));
+    axum::serve(listener, router).await?;
+    Ok(())
+}
+
+async fn metrics(balancer: Arc<Balancer>) -> Response {
+    match serde_json::to_vec(&balancer.metrics()) {
+        Ok(body) => (StatusCode::OK, [(header::CONTENT_TYPE, "application/json")], body).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
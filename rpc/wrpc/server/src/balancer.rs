@@ -0,0 +1,324 @@
+//! Distribution of the connections of a wRPC proxy over several gRPC node backends.
+
+use crate::error::Error;
+use futures::future::join_all;
+use serde::Serialize;
+use spectre_grpc_client::{ConnectionEvent, GrpcClient};
+use spectre_notify::subscription::context::SubscriptionContext;
+use spectre_rpc_core::{api::rpc::RpcApi, notify::mode::NotificationMode, GetServerInfoResponse};
+use spectre_utils::channel::Channel;
+use std::{
+    fmt::Display,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use workflow_log::*;
+
+/// Strategy assigning a backend to each new wRPC connection
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BalancingStrategy {
+    /// Cycle through the healthy backends
+    #[default]
+    RoundRobin,
+    /// Pick the healthy backend relaying the fewest connections
+    LeastConnections,
+}
+
+impl FromStr for BalancingStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "round-robin" => Ok(BalancingStrategy::RoundRobin),
+            "least-connections" => Ok(BalancingStrategy::LeastConnections),
+            _ => Err(Error::UnknownBalancingStrategy(s.to_string())),
+        }
+    }
+}
+
+impl Display for BalancingStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BalancingStrategy::RoundRobin => write!(f, "round-robin"),
+            BalancingStrategy::LeastConnections => write!(f, "least-connections"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct BackendCounters {
+    active_connections: AtomicU64,
+    total_connections: AtomicU64,
+    requests: AtomicU64,
+    failovers: AtomicU64,
+    failed_health_checks: AtomicU64,
+}
+
+/// Snapshot of the state and counters of a backend
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackendMetrics {
+    pub address: String,
+    pub is_healthy: bool,
+    pub is_synced: bool,
+    pub server_version: Option<String>,
+    pub virtual_daa_score: u64,
+    /// Connections currently relayed to the backend
+    pub active_connections: u64,
+    /// Connections relayed to the backend since the proxy started, including failovers to it
+    pub total_connections: u64,
+    pub requests: u64,
+    /// Connections moved away from the backend after it failed
+    pub failovers: u64,
+    pub failed_health_checks: u64,
+}
+
+/// A gRPC node the proxy relays connections to
+#[derive(Debug)]
+pub struct Backend {
+    address: String,
+    is_healthy: AtomicBool,
+    server_info: Mutex<Option<GetServerInfoResponse>>,
+    /// Client dedicated to health checks
+    monitor: tokio::sync::Mutex<Option<GrpcClient>>,
+    counters: BackendCounters,
+}
+
+impl Backend {
+    fn new(address: String) -> Self {
+        Self {
+            address,
+            is_healthy: AtomicBool::new(false),
+            server_info: Mutex::new(None),
+            monitor: tokio::sync::Mutex::new(None),
+            counters: BackendCounters::default(),
+        }
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// A backend is healthy if it answered the last health check and reported being synced
+    pub fn is_healthy(&self) -> bool {
+        self.is_healthy.load(Ordering::SeqCst)
+    }
+
+    pub fn metrics(&self) -> BackendMetrics {
+        let server_info = self.server_info.lock().unwrap();
+        BackendMetrics {
+            address: self.address.clone(),
+            is_healthy: self.is_healthy(),
+            is_synced: server_info.as_ref().is_some_and(|x| x.is_synced),
+            server_version: server_info.as_ref().map(|x| x.server_version.clone()),
+            virtual_daa_score: server_info.as_ref().map_or(0, |x| x.virtual_daa_score),
+            active_connections: self.counters.active_connections.load(Ordering::Relaxed),
+            total_connections: self.counters.total_connections.load(Ordering::Relaxed),
+            requests: self.counters.requests.load(Ordering::Relaxed),
+            failovers: self.counters.failovers.load(Ordering::Relaxed),
+            failed_health_checks: self.counters.failed_health_checks.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Connects a client relaying the requests and notifications of a wRPC connection
+    pub(crate) async fn connect(
+        &self,
+        subscription_context: SubscriptionContext,
+        connection_event_sender: async_channel::Sender<ConnectionEvent>,
+    ) -> crate::result::Result<GrpcClient> {
+        let grpc_client = GrpcClient::connect_with_args(
+            NotificationMode::Direct,
+            self.address.clone(),
+            Some(subscription_context),
+            false,
+            Some(connection_event_sender),
+            true,
+            None,
+            Default::default(),
+        )
+        .await
+        .map_err(|err| Error::BackendConnection(self.address.clone(), err.to_string()))?;
+        Ok(grpc_client)
+    }
+
+    pub(crate) fn register_connection(&self) {
+        self.counters.active_connections.fetch_add(1, Ordering::Relaxed);
+        self.counters.total_connections.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn unregister_connection(&self, failover: bool) {
+        self.counters.active_connections.fetch_sub(1, Ordering::Relaxed);
+        if failover {
+            self.counters.failovers.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn register_request(&self) {
+        self.counters.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn mark_unhealthy(&self) {
+        self.is_healthy.store(false, Ordering::SeqCst);
+    }
+
+    /// Queries the server info of the backend and updates its health, returning whether the backend is healthy
+    async fn check_health(&self) -> bool {
+        let mut monitor = self.monitor.lock().await;
+        if !monitor.as_ref().is_some_and(|x| x.is_connected()) {
+            *monitor = GrpcClient::connect(self.address.clone()).await.ok();
+        }
+        let server_info = match monitor.as_ref() {
+            Some(grpc_client) => grpc_client.get_server_info().await.ok(),
+            None => None,
+        };
+        let is_healthy = server_info.as_ref().is_some_and(|x| x.is_synced);
+        if server_info.is_none() {
+            self.counters.failed_health_checks.fetch_add(1, Ordering::Relaxed);
+            if let Some(grpc_client) = monitor.take() {
+                let _ = grpc_client.disconnect().await;
+            }
+        }
+        *self.server_info.lock().unwrap() = server_info;
+        self.is_healthy.store(is_healthy, Ordering::SeqCst);
+        is_healthy
+    }
+}
+
+/// Assigns backends to wRPC connections and keeps track of the backends health
+#[derive(Debug)]
+pub struct Balancer {
+    backends: Vec<Arc<Backend>>,
+    strategy: BalancingStrategy,
+    health_check_interval: Duration,
+    next: AtomicUsize,
+    /// Backends turning unhealthy, whose connections must fail over
+    failed_backends: Channel<Arc<Backend>>,
+}
+
+impl Balancer {
+    pub fn new(addresses: Vec<String>, strategy: BalancingStrategy, health_check_interval: Duration) -> Self {
+        assert!(!addresses.is_empty(), "a balancer requires at least one backend");
+        Self {
+            backends: addresses.into_iter().map(|address| Arc::new(Backend::new(address))).collect(),
+            strategy,
+            health_check_interval,
+            next: AtomicUsize::new(0),
+            failed_backends: Channel::default(),
+        }
+    }
+
+    pub fn backends(&self) -> &[Arc<Backend>] {
+        &self.backends
+    }
+
+    pub fn strategy(&self) -> BalancingStrategy {
+        self.strategy
+    }
+
+    pub fn metrics(&self) -> Vec<BackendMetrics> {
+        self.backends.iter().map(|backend| backend.metrics()).collect()
+    }
+
+    /// Checks the health of all backends, reporting the ones turning unhealthy
+    pub async fn check_health(&self) {
+        let was_healthy = self.backends.iter().map(|backend| backend.is_healthy()).collect::<Vec<_>>();
+        let is_healthy = join_all(self.backends.iter().map(|backend| backend.check_health())).await;
+        for ((backend, was_healthy), is_healthy) in self.backends.iter().zip(was_healthy).zip(is_healthy) {
+            match (was_healthy, is_healthy) {
+                (false, true) => log_info!("Backend {} is healthy", backend.address()),
+                (true, false) => {
+                    log_warn!("Backend {} is unhealthy", backend.address());
+                    let _ = self.failed_backends.try_send(backend.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Spawns the periodic health check of the backends
+    pub fn start(self: &Arc<Self>) {
+        let balancer = self.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(balancer.health_check_interval).await;
+                balancer.check_health().await;
+            }
+        });
+    }
+
+    /// Receives the backends turning unhealthy
+    pub(crate) fn failed_backends(&self) -> async_channel::Receiver<Arc<Backend>> {
+        self.failed_backends.receiver()
+    }
+
+    /// Selects a healthy backend according to the balancing strategy.
+    ///
+    /// The `exclude` backend is only selected if no other backend is healthy.
+    pub(crate) fn select(&self, exclude: Option<&Arc<Backend>>) -> Option<Arc<Backend>> {
+        let is_excluded = |backend: &Arc<Backend>| exclude.is_some_and(|x| Arc::ptr_eq(x, backend));
+        let candidates = self.backends.iter().filter(|backend| backend.is_healthy() && !is_excluded(backend)).collect::<Vec<_>>();
+        let selected = match self.strategy {
+            BalancingStrategy::RoundRobin if !candidates.is_empty() => {
+                Some(candidates[self.next.fetch_add(1, Ordering::Relaxed) % candidates.len()])
+            }
+            BalancingStrategy::RoundRobin => None,
+            BalancingStrategy::LeastConnections => {
+                candidates.into_iter().min_by_key(|backend| backend.counters.active_connections.load(Ordering::Relaxed))
+            }
+        };
+        selected.cloned().or_else(|| exclude.filter(|backend| backend.is_healthy()).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_balancer(strategy: BalancingStrategy) -> Balancer {
+        let addresses = (0..3).map(|i| format!("grpc://127.0.0.1:{}", 16110 + i)).collect();
+        Balancer::new(addresses, strategy, Duration::from_secs(5))
+    }
+
+    #[test]
+    fn test_balancer_select() {
+        // Round robin cycles over the healthy backends only
+        let balancer = new_balancer(BalancingStrategy::RoundRobin);
+        assert!(balancer.select(None).is_none());
+        balancer.backends[0].is_healthy.store(true, Ordering::SeqCst);
+        balancer.backends[2].is_healthy.store(true, Ordering::SeqCst);
+        let selected = (0..4).map(|_| balancer.select(None).unwrap().address().to_string()).collect::<Vec<_>>();
+        assert_eq!(selected, [balancer.backends[0].address(), balancer.backends[2].address()].repeat(2));
+
+        // The excluded backend is only selected as a last resort
+        let excluded = balancer.backends[0].clone();
+        assert!((0..4).all(|_| Arc::ptr_eq(&balancer.select(Some(&excluded)).unwrap(), &balancer.backends[2])));
+        balancer.backends[2].mark_unhealthy();
+        assert!(Arc::ptr_eq(&balancer.select(Some(&excluded)).unwrap(), &excluded));
+        excluded.mark_unhealthy();
+        assert!(balancer.select(Some(&excluded)).is_none());
+
+        // Least connections picks the healthy backend with the fewest active connections
+        let balancer = new_balancer(BalancingStrategy::LeastConnections);
+        balancer.backends.iter().for_each(|backend| backend.is_healthy.store(true, Ordering::SeqCst));
+        balancer.backends[0].register_connection();
+        balancer.backends[1].register_connection();
+        assert!(Arc::ptr_eq(&balancer.select(None).unwrap(), &balancer.backends[2]));
+        balancer.backends[2].register_connection();
+        balancer.backends[2].register_connection();
+        balancer.backends[0].unregister_connection(true);
+        assert!(Arc::ptr_eq(&balancer.select(None).unwrap(), &balancer.backends[0]));
+        assert_eq!(balancer.backends[0].metrics().failovers, 1);
+        assert_eq!(balancer.backends[0].metrics().total_connections, 1);
+    }
+
+    #[test]
+    fn test_balancing_strategy_from_str() {
+        assert_eq!("round-robin".parse::<BalancingStrategy>().unwrap(), BalancingStrategy::RoundRobin);
+        assert_eq!("Least-Connections".parse::<BalancingStrategy>().unwrap(), BalancingStrategy::LeastConnections);
+        assert!("random".parse::<BalancingStrategy>().is_err());
+    }
+}
@@ -1,10 +1,13 @@
-use spectre_grpc_client::{GrpcClient, GrpcClientNotify};
+use crate::balancer::Backend;
+use spectre_grpc_client::{ConnectionEvent, GrpcClient, GrpcClientNotify};
 use spectre_notify::{
     connection::Connection as ConnectionT,
     error::{Error as NotifyError, Result as NotifyResult},
+    events::EventArray,
     listener::ListenerId,
     notification::Notification as NotificationT,
     notifier::Notify,
+    subscription::{array::ArrayBuilder, DynSubscription},
 };
 use spectre_rpc_core::{api::ops::RpcApiOps, notify::mode::NotificationMode, Notification};
 use std::{
@@ -43,12 +46,28 @@ impl From<NotifyEncoding> for Encoding {
     }
 }
 
+/// The backend a proxied connection is relayed to and the client doing so
+#[derive(Debug, Clone)]
+pub struct Relay {
+    pub backend: Arc<Backend>,
+    pub grpc_client: Arc<GrpcClient>,
+}
+
+#[derive(Debug)]
+struct ProxyState {
+    relay: Mutex<Relay>,
+    /// Subscriptions of the connection, renewed on the new backend after a failover.
+    /// The lock also serializes failovers with subscription changes.
+    subscriptions: tokio::sync::Mutex<EventArray<DynSubscription>>,
+    connection_events: async_channel::Sender<ConnectionEvent>,
+}
+
 #[derive(Debug)]
 struct ConnectionInner {
     pub id: u64,
     pub peer: SocketAddr,
     pub messenger: Arc<Messenger>,
-    pub grpc_client: Option<Arc<GrpcClient>>,
+    proxy: Option<ProxyState>,
     // not using an atomic in case an Id will change type in the future...
     pub listener_id: Mutex<Option<ListenerId>>,
 }
@@ -83,12 +102,29 @@ pub struct Connection {
 }
 
 impl Connection {
-    pub fn new(id: u64, peer: &SocketAddr, messenger: Arc<Messenger>, grpc_client: Option<Arc<GrpcClient>>) -> Connection {
-        // If a GrpcClient is provided, it has to come configured in direct mode
-        assert!(grpc_client.is_none() || grpc_client.as_ref().unwrap().notification_mode() == NotificationMode::Direct);
-        // Should a gRPC client be provided, no listener_id is required for subscriptions so the listener id is set to default
-        let listener_id = Mutex::new(grpc_client.clone().map(|_| ListenerId::default()));
-        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, grpc_client, listener_id }) }
+    pub fn new(id: u64, peer: &SocketAddr, messenger: Arc<Messenger>) -> Connection {
+        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, proxy: None, listener_id: Mutex::new(None) }) }
+    }
+
+    /// Creates a connection relayed to a gRPC backend, the relay client reporting its connection events
+    /// to `connection_events`
+    pub fn new_relayed(
+        id: u64,
+        peer: &SocketAddr,
+        messenger: Arc<Messenger>,
+        relay: Relay,
+        connection_events: async_channel::Sender<ConnectionEvent>,
+    ) -> Connection {
+        // The relay client has to come configured in direct mode
+        assert_eq!(relay.grpc_client.notification_mode(), NotificationMode::Direct);
+        // No listener_id is required for subscriptions so the listener id is set to default
+        let listener_id = Mutex::new(Some(ListenerId::default()));
+        let proxy = Some(ProxyState {
+            relay: Mutex::new(relay),
+            subscriptions: tokio::sync::Mutex::new(ArrayBuilder::single(ListenerId::default(), None)),
+            connection_events,
+        });
+        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, proxy, listener_id }) }
     }
 
     /// Obtain the connection id
@@ -101,12 +137,29 @@ impl Connection {
         &self.inner.messenger
     }
 
+    fn proxy(&self) -> &ProxyState {
+        self.inner.proxy.as_ref().unwrap_or_else(|| panic!("Incorrect use: `server::Connection` does not carry RpcApi references"))
+    }
+
     pub fn grpc_client(&self) -> Arc<GrpcClient> {
-        self.inner
-            .grpc_client
-            .as_ref()
-            .cloned()
-            .unwrap_or_else(|| panic!("Incorrect use: `server::Connection` does not carry RpcApi references"))
+        self.relay().grpc_client
+    }
+
+    pub fn relay(&self) -> Relay {
+        self.proxy().relay.lock().unwrap().clone()
+    }
+
+    /// Replaces the relay of the connection, returning the previous one
+    pub(crate) fn replace_relay(&self, relay: Relay) -> Relay {
+        std::mem::replace(&mut *self.proxy().relay.lock().unwrap(), relay)
+    }
+
+    pub(crate) fn subscriptions(&self) -> &tokio::sync::Mutex<EventArray<DynSubscription>> {
+        &self.proxy().subscriptions
+    }
+
+    pub(crate) fn connection_events(&self) -> &async_channel::Sender<ConnectionEvent> {
+        &self.proxy().connection_events
     }
 
     pub fn grpc_client_notify_target(&self) -> GrpcClientNotify {
@@ -20,6 +20,15 @@ pub enum Error {
 
     #[error("Notify error: {0}")]
     NotifyError(#[from] NotifyError),
+
+    #[error("Unknown balancing strategy `{0}`, expected `round-robin` or `least-connections`")]
+    UnknownBalancingStrategy(String),
+
+    #[error("Unable to connect to backend {0}: {1}")]
+    BackendConnection(String, String),
+
+    #[error("No healthy backend available")]
+    NoHealthyBackend,
 }
 
 impl<T> From<PoisonError<T>> for Error {
@@ -1,5 +1,6 @@
 //! Spectre wRPC Server (AsyncService) module
 pub mod address;
+pub mod balancer;
 pub mod collector;
 pub mod connection;
 pub mod error;
@@ -36,7 +36,9 @@ impl Router {
                 Ping,
                 AddPeer,
                 Ban,
+                CreateBackup,
                 EstimateNetworkHashesPerSecond,
+                GenerateBlocks,
                 GetBalanceByAddress,
                 GetBalancesByAddresses,
                 GetBlock,
@@ -44,7 +46,14 @@ impl Router {
                 GetBlockDagInfo,
                 GetBlocks,
                 GetBlockTemplate,
+                GetChainBlockByBlueScore,
+                GetChainBlockByDaaScore,
+                GetChainBlocksByBlueScoreRange,
+                GetChainBlocksByDaaScoreRange,
+                TestMempoolAccept,
+                DecodeTransaction,
                 GetCurrentBlockColor,
+                GetDatabaseStats,
                 GetCoinSupply,
                 GetConnectedPeerInfo,
                 GetDaaScoreTimestampEstimate,
@@ -61,6 +70,7 @@ impl Router {
                 GetMetrics,
                 GetConnections,
                 GetPeerAddresses,
+                GetPrioritizedTransactions,
                 GetServerInfo,
                 GetSink,
                 GetSinkBlueScore,
@@ -69,6 +79,9 @@ impl Router {
                 GetSystemInfo,
                 GetUtxosByAddresses,
                 GetVirtualChainFromBlock,
+                InvalidateBlock,
+                PrioritizeTransaction,
+                ReconsiderBlock,
                 ResolveFinalityConflict,
                 Shutdown,
                 SubmitBlock,
@@ -1,22 +1,24 @@
 use crate::{
+    balancer::{Backend, Balancer},
     collector::{WrpcServiceCollector, WrpcServiceConverter},
-    connection::Connection,
+    connection::{Connection, Relay},
+    error::Error,
     result::Result,
     service::Options,
 };
-use spectre_grpc_client::GrpcClient;
+use spectre_grpc_client::{ConnectionEvent, GrpcClient};
 use spectre_notify::{
-    connection::ChannelType,
+    connection::{ChannelType, Connection as ConnectionT},
     events::EVENT_TYPE_ARRAY,
     listener::ListenerLifespan,
     notifier::Notifier,
     scope::Scope,
     subscriber::Subscriber,
-    subscription::{MutationPolicies, UtxosChangedMutationPolicy},
+    subscription::{context::SubscriptionContext, Command, MutateSingle, Mutation, MutationPolicies, UtxosChangedMutationPolicy},
 };
 use spectre_rpc_core::{
     api::rpc::{DynRpcService, RpcApi},
-    notify::{channel::NotificationChannel, connection::ChannelConnection, mode::NotificationMode},
+    notify::{channel::NotificationChannel, connection::ChannelConnection},
     Notification, RpcResult,
 };
 use spectre_rpc_service::service::RpcCoreService;
@@ -43,6 +45,8 @@ struct ServerInner {
     pub sockets: Mutex<HashMap<u64, Connection>>,
     pub rpc_core: Option<RpcCore>,
     pub options: Arc<Options>,
+    pub policies: MutationPolicies,
+    pub subscription_context: SubscriptionContext,
 }
 
 #[derive(Clone)]
@@ -60,8 +64,8 @@ impl Server {
         // Either get a core service or be called from the proxy and rely each connection having its own gRPC client
         assert_eq!(
             core_service.is_none(),
-            options.grpc_proxy_address.is_some(),
-            "invalid setup: Server must exclusively get either a core service or a gRPC server address"
+            options.balancer.is_some(),
+            "invalid setup: Server must exclusively get either a core service or gRPC backends"
         );
 
         let rpc_core = if let Some(service) = core_service {
@@ -98,6 +102,8 @@ impl Server {
                 sockets: Mutex::new(HashMap::new()),
                 rpc_core,
                 options,
+                policies,
+                subscription_context: SubscriptionContext::default(),
             }),
         }
     }
@@ -107,39 +113,127 @@ impl Server {
             // Start the internal notifier
             rpc_core.wrpc_notifier.clone().start();
         }
+        if let Some(balancer) = self.balancer() {
+            balancer.start();
+
+            // Move the connections of the backends turning unhealthy
+            let failed_backends = balancer.failed_backends();
+            let server = self.clone();
+            tokio::spawn(async move {
+                while let Ok(backend) = failed_backends.recv().await {
+                    let connections = server.inner.sockets.lock().unwrap().values().cloned().collect::<Vec<_>>();
+                    for connection in connections.into_iter().filter(|x| Arc::ptr_eq(&x.relay().backend, &backend)) {
+                        let server = server.clone();
+                        tokio::spawn(async move { server.failover(&connection).await });
+                    }
+                }
+            });
+        }
+    }
+
+    #[inline(always)]
+    fn balancer(&self) -> Option<&Arc<Balancer>> {
+        self.inner.options.balancer.as_ref()
+    }
+
+    /// Connects a relay client to a healthy backend, preferably not to the `exclude` one
+    async fn connect_relay(
+        &self,
+        exclude: Option<&Arc<Backend>>,
+        connection_events: &async_channel::Sender<ConnectionEvent>,
+    ) -> Result<Relay> {
+        let balancer = self.balancer().expect("the server is a proxy");
+        for _ in 0..balancer.backends().len() {
+            let Some(backend) = balancer.select(exclude) else {
+                break;
+            };
+            match backend.connect(self.inner.subscription_context.clone(), connection_events.clone()).await {
+                Ok(grpc_client) => {
+                    backend.register_connection();
+                    return Ok(Relay { backend, grpc_client: Arc::new(grpc_client) });
+                }
+                Err(err) => {
+                    // Skip the backend until the next health check
+                    log_warn!("{err}");
+                    backend.mark_unhealthy();
+                }
+            }
+        }
+        Err(Error::NoHealthyBackend)
+    }
+
+    /// Moves a proxied connection to another backend if its relay client is disconnected or its backend
+    /// is unhealthy, renewing all its subscriptions on the new backend.
+    ///
+    /// The connection is closed if its relay client is disconnected and no other backend is available.
+    async fn failover(&self, connection: &Connection) {
+        let subscriptions = connection.subscriptions().lock().await;
+        let relay = connection.relay();
+        // A closed events channel means the connection was disconnected
+        if connection.connection_events().is_closed() || (relay.grpc_client.is_connected() && relay.backend.is_healthy()) {
+            return;
+        }
+        let new_relay = match self.connect_relay(Some(&relay.backend), connection.connection_events()).await {
+            Ok(new_relay) if Arc::ptr_eq(&new_relay.backend, &relay.backend) && relay.grpc_client.is_connected() => {
+                // Nothing better than the current backend
+                new_relay.backend.unregister_connection(false);
+                let _ = new_relay.grpc_client.disconnect().await;
+                return;
+            }
+            Ok(new_relay) => new_relay,
+            Err(err) => {
+                if !relay.grpc_client.is_connected() {
+                    log_warn!("Closing wrpc://{}, its backend {} is lost: {err}", connection.peer(), relay.backend.address());
+                    connection.close();
+                }
+                return;
+            }
+        };
+
+        new_relay.grpc_client.start(Some(connection.grpc_client_notify_target())).await;
+        for event in EVENT_TYPE_ARRAY.into_iter().filter(|event| subscriptions[*event].active()) {
+            let scope = subscriptions[event].scope(&self.inner.subscription_context);
+            if let Err(err) = new_relay.grpc_client.start_notify(GrpcClient::DIRECT_MODE_LISTENER_ID, scope).await {
+                log_warn!(
+                    "wrpc://{} failed to renew its {event:?} subscription on {}: {err}",
+                    connection.peer(),
+                    new_relay.backend.address()
+                );
+            }
+        }
+        log_info!("Failover of wrpc://{} from {} to {}", connection.peer(), relay.backend.address(), new_relay.backend.address());
+        let relay = connection.replace_relay(new_relay);
+        relay.backend.unregister_connection(!Arc::ptr_eq(&relay.backend, &connection.relay().backend));
+        let _ = relay.grpc_client.disconnect().await;
+        let _ = relay.grpc_client.join().await;
     }
 
     pub async fn connect(&self, peer: &SocketAddr, messenger: Arc<Messenger>) -> Result<Connection> {
         // log_trace!("WebSocket connected: {}", peer);
         let id = self.inner.next_connection_id.fetch_add(1, Ordering::SeqCst);
 
-        let grpc_client = if let Some(grpc_proxy_address) = &self.inner.options.grpc_proxy_address {
+        let connection = if self.balancer().is_some() {
             // Provider::GrpcClient
+            let connection_events = async_channel::unbounded();
+            let relay = self.connect_relay(None, &connection_events.0).await.map_err(|e| WebSocketError::Other(e.to_string()))?;
+            log_info!("Routing wrpc://{peer} -> {}", relay.backend.address());
+            let connection = Connection::new_relayed(id, peer, messenger, relay, connection_events.0);
+            connection.grpc_client().start(Some(connection.grpc_client_notify_target())).await;
 
-            log_info!("Routing wrpc://{peer} -> {grpc_proxy_address}");
-            let grpc_client = GrpcClient::connect_with_args(
-                NotificationMode::Direct,
-                grpc_proxy_address.to_owned(),
-                None,
-                false,
-                None,
-                true,
-                None,
-                Default::default(),
-            )
-            .await
-            .map_err(|e| WebSocketError::Other(e.to_string()))?;
-            // log_trace!("Creating proxy relay...");
-            Some(Arc::new(grpc_client))
+            // Fail over as soon as the relay client gets disconnected, the loop ending when the connection is closed
+            let server = self.clone();
+            let watched = connection.clone();
+            tokio::spawn(async move {
+                while let Ok(event) = connection_events.1.recv().await {
+                    if let ConnectionEvent::Disconnected = event {
+                        server.failover(&watched).await;
+                    }
+                }
+            });
+            connection
         } else {
-            None
+            Connection::new(id, peer, messenger)
         };
-        let connection = Connection::new(id, peer, messenger, grpc_client);
-        if self.inner.options.grpc_proxy_address.is_some() {
-            // log_trace!("starting gRPC");
-            connection.grpc_client().start(Some(connection.grpc_client_notify_target())).await;
-            // log_trace!("gRPC started...");
-        }
         self.inner.sockets.lock()?.insert(id, connection.clone());
         Ok(connection)
     }
@@ -153,8 +247,13 @@ impl Server {
                 });
             }
         } else {
-            let _ = connection.grpc_client().disconnect().await;
-            let _ = connection.grpc_client().join().await;
+            // Hold the subscriptions lock so that no failover is ongoing
+            let _subscriptions = connection.subscriptions().lock().await;
+            connection.connection_events().close();
+            let relay = connection.relay();
+            relay.backend.unregister_connection(false);
+            let _ = relay.grpc_client.disconnect().await;
+            let _ = relay.grpc_client.join().await;
         }
 
         self.inner.sockets.lock().unwrap().remove(&connection.id());
@@ -172,7 +271,9 @@ impl Server {
         if let Some(rpc_core) = &self.inner.rpc_core {
             rpc_core.service.clone()
         } else {
-            connection.grpc_client()
+            let relay = connection.relay();
+            relay.backend.register_request();
+            relay.grpc_client
         }
     }
 
@@ -193,6 +294,13 @@ impl Server {
         if let Some(rpc_core) = &self.inner.rpc_core {
             rpc_core.wrpc_notifier.clone().try_start_notify(listener_id, scope)?;
         } else {
+            // Keep track of the subscription so it can be renewed on failover
+            let mut subscriptions = connection.subscriptions().lock().await;
+            subscriptions[scope.event_type()].mutate(
+                Mutation::new(Command::Start, scope.clone()),
+                self.inner.policies,
+                &self.inner.subscription_context,
+            )?;
             connection.grpc_client().start_notify(listener_id, scope).await?;
         }
         Ok(())
@@ -204,6 +312,12 @@ impl Server {
             if let Some(rpc_core) = &self.inner.rpc_core {
                 rpc_core.wrpc_notifier.clone().try_stop_notify(listener_id, scope)?;
             } else {
+                let mut subscriptions = connection.subscriptions().lock().await;
+                subscriptions[scope.event_type()].mutate(
+                    Mutation::new(Command::Stop, scope.clone()),
+                    self.inner.policies,
+                    &self.inner.subscription_context,
+                )?;
                 connection.grpc_client().stop_notify(listener_id, scope).await?;
             }
         } else {
@@ -1,4 +1,4 @@
-use crate::{connection::*, router::*, server::*};
+use crate::{balancer::Balancer, connection::*, router::*, server::*};
 use async_trait::async_trait;
 use spectre_core::{
     info,
@@ -18,13 +18,14 @@ static MAX_WRPC_MESSAGE_SIZE: usize = 1024 * 1024 * 128; // 128MB
 /// Options for configuring the wRPC server
 pub struct Options {
     pub listen_address: String,
-    pub grpc_proxy_address: Option<String>,
+    /// Backends the connections get relayed to when running as a proxy
+    pub balancer: Option<Arc<Balancer>>,
     pub verbose: bool,
 }
 
 impl Default for Options {
     fn default() -> Self {
-        Options { listen_address: "127.0.0.1:19110".to_owned(), verbose: false, grpc_proxy_address: None }
+        Options { listen_address: "127.0.0.1:19110".to_owned(), verbose: false, balancer: None }
     }
 }
 
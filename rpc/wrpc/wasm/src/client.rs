@@ -841,6 +841,42 @@ impl RpcClient {
         Ok(())
     }
 
+    /// Subscribe for an accepted transactions changed notification event.
+    /// Accepted transactions changed notification event is produced when the
+    /// virtual chain changes in the Spectre BlockDAG, carrying the transactions
+    /// accepted or unaccepted by the chain blocks that pay to or spend from the
+    /// provided list of addresses.
+    #[wasm_bindgen(js_name = subscribeAcceptedTransactionsChanged)]
+    pub async fn subscribe_accepted_transactions_changed(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let addresses: Vec<Address> = addresses.try_into()?;
+            self.inner
+                .client
+                .start_notify(listener_id, Scope::AcceptedTransactionsChanged(AcceptedTransactionsChangedScope { addresses }))
+                .await?;
+        } else {
+            log_error!("RPC subscribe on a closed connection");
+        }
+
+        Ok(())
+    }
+
+    /// Unsubscribe from accepted transactions changed notification event
+    /// for a specific set of addresses.
+    #[wasm_bindgen(js_name = unsubscribeAcceptedTransactionsChanged)]
+    pub async fn unsubscribe_accepted_transactions_changed(&self, addresses: AddressOrStringArrayT) -> Result<()> {
+        if let Some(listener_id) = self.listener_id() {
+            let addresses: Vec<Address> = addresses.try_into()?;
+            self.inner
+                .client
+                .stop_notify(listener_id, Scope::AcceptedTransactionsChanged(AcceptedTransactionsChangedScope { addresses }))
+                .await?;
+        } else {
+            log_error!("RPC unsubscribe on a closed connection");
+        }
+        Ok(())
+    }
+
     // TODO: scope variant with field functions
 
     /// Manage subscription for a virtual chain changed notification event.
@@ -881,6 +917,7 @@ build_wrpc_wasm_bindgen_subscriptions!([
     // Manually implemented subscriptions (above)
     // - VirtualChainChanged, // can't used this here due to non-C-style enum variant
     // - UtxosChanged, // can't used this here due to non-C-style enum variant
+    // - AcceptedTransactionsChanged, // can't used this here due to non-C-style enum variant
     // - VirtualDaaScoreChanged,
     /// Manage subscription for a block added notification event.
     /// Block added notification event is produced when a new
@@ -978,6 +1015,15 @@ build_wrpc_wasm_bindgen_interface!(
         GetSyncStatus,
         /// Feerate estimates
         GetFeeEstimate,
+        /// Retrieves the priority deltas of all prioritized transactions.
+        /// Returned information: List of prioritized transactions.
+        GetPrioritizedTransactions,
+        /// Creates a backup of the node databases within the node backup directory (requires `--unsaferpc`).
+        /// Returned information: Path of the backup.
+        CreateBackup,
+        /// Retrieves the RocksDB statistics of the node databases and the cache statistics of their stores.
+        /// Returned information: Per-store on-disk usage, compaction status, cache hits and misses.
+        GetDatabaseStats,
         /// Retrieves the current network configuration.
         /// Returned information: Current network configuration.
         GetCurrentNetwork,
@@ -1008,6 +1054,18 @@ build_wrpc_wasm_bindgen_interface!(
         /// Generates a new block template for mining.
         /// Returned information: Block template information.
         GetBlockTemplate,
+        /// Retrieves the first chain block whose blue score reaches the given blue score.
+        /// Returned information: Chain block hash, index, scores and timestamp.
+        GetChainBlockByBlueScore,
+        /// Retrieves the first chain block whose DAA score reaches the given DAA score.
+        /// Returned information: Chain block hash, index, scores and timestamp.
+        GetChainBlockByDaaScore,
+        /// Retrieves the chain blocks within a blue score range.
+        /// Returned information: List of chain blocks.
+        GetChainBlocksByBlueScoreRange,
+        /// Retrieves the chain blocks within a DAA score range.
+        /// Returned information: List of chain blocks.
+        GetChainBlocksByDaaScoreRange,
         /// Checks if block is blue or not.
         /// Returned information: Block blueness.
         GetCurrentBlockColor,
@@ -1039,6 +1097,21 @@ build_wrpc_wasm_bindgen_interface!(
         /// Retrieves the virtual chain corresponding to a specified block hash.
         /// Returned information: Virtual chain information.
         GetVirtualChainFromBlock,
+        /// Mines blocks within the node (simnet and devnet only).
+        /// Returned information: Hashes of the generated blocks.
+        GenerateBlocks,
+        /// Disqualifies a block from the virtual chain, moving
+        /// the sink to the best chain not containing it.
+        /// Returned information: None.
+        InvalidateBlock,
+        /// Sets the priority delta of a transaction, honored when
+        /// selecting the transactions of subsequent block templates.
+        /// Returned information: None.
+        PrioritizeTransaction,
+        /// Reverts the disqualification of a block and of its
+        /// future from the virtual chain.
+        /// Returned information: None.
+        ReconsiderBlock,
         /// Resolves a finality conflict in the Spectre BlockDAG.
         /// Returned information: None.
         ResolveFinalityConflict,
@@ -1051,6 +1124,13 @@ build_wrpc_wasm_bindgen_interface!(
         /// Submits an RBF transaction to the Spectre network.
         /// Returned information: Submitted Transaction Id, Transaction that was replaced.
         SubmitTransactionReplacement,
+        /// Tests whether the mempool would accept a batch of transactions, without inserting them.
+        /// Returned information: Acceptance, rejection reason, masses and fee of every transaction.
+        TestMempoolAccept,
+        /// Decodes a serialized transaction and optionally verifies the scripts of its inputs.
+        /// Returned information: The decoded transaction with its masses, output script classes
+        /// and disassembly, and the outcome of every input script.
+        DecodeTransaction,
         /// Unbans a previously banned peer, allowing it to connect
         /// to the Spectre node again.
         /// Returned information: None.
@@ -25,6 +25,7 @@ export enum RpcEventType {
     VirtualDaaScoreChanged = "virtual-daa-score-changed",
     PruningPointUtxoSetOverride = "pruning-point-utxo-set-override",
     NewBlockTemplate = "new-block-template",
+    AcceptedTransactionsChanged = "accepted-transactions-changed",
 }
 
 /**
@@ -40,7 +41,8 @@ export type RpcEventData = IBlockAdded
     | ISinkBlueScoreChanged 
     | IVirtualDaaScoreChanged 
     | IPruningPointUtxoSetOverride 
-    | INewBlockTemplate;
+    | INewBlockTemplate 
+    | IAcceptedTransactionsChanged;
 
 /**
  * RPC notification event data map.
@@ -59,6 +61,7 @@ export type RpcEventMap = {
     "virtual-daa-score-changed" : IVirtualDaaScoreChanged,
     "pruning-point-utxo-set-override" : IPruningPointUtxoSetOverride,
     "new-block-template" : INewBlockTemplate,
+    "accepted-transactions-changed" : IAcceptedTransactionsChanged,
 }
 
 /**
@@ -84,6 +87,7 @@ export type RpcEvent = {
  * {@link RpcClient.subscribeSinkBlueScoreChanged},
  * {@link RpcClient.subscribePruningPointUtxoSetOverride},
  * {@link RpcClient.subscribeNewBlockTemplate},
+ * {@link RpcClient.subscribeAcceptedTransactionsChanged},
  * 
  * @category Node RPC
  */
@@ -241,3 +245,22 @@ declare! {
     }
     "#,
 }
+
+declare! {
+    IAcceptedTransactionsChanged,
+    r#"
+    /**
+     * Accepted transactions changed notification event is produced when the
+     * virtual chain changes in the Spectre BlockDAG. The event notification
+     * carries the transactions accepted by the added chain blocks and those
+     * unaccepted by the removed chain blocks, scoped to the monitored list of
+     * addresses specified during the subscription. The `incomplete` flag is
+     * set when the node failed to resolve the transactions of some chain block.
+     * 
+     * @category Node RPC
+     */
+    export interface IAcceptedTransactionsChanged {
+        [key: string]: any;
+    }
+    "#,
+}
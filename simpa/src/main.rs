@@ -28,7 +28,7 @@ use spectre_core::{
     trace, warn,
 };
 use spectre_database::prelude::ConnBuilder;
-use spectre_database::{create_temp_db, load_existing_db};
+use spectre_database::{create_memory_db, create_temp_db, load_existing_db};
 use spectre_hashes::Hash;
 use spectre_perf_monitor::{builder::Builder, counters::CountersSnapshot};
 use spectre_utils::fd_budget;
@@ -122,6 +122,10 @@ struct Args {
     rocksdb_files_limit: Option<i32>,
     #[arg(long)]
     rocksdb_mem_budget: Option<usize>,
+    /// Hold the consensus databases in memory rather than in temp directories. Databases persisted to the output dir
+    /// are not affected
+    #[arg(long, default_value_t = false)]
+    in_memory_db: bool,
     #[arg(long, default_value_t = false)]
     long_payload: bool,
 }
@@ -248,6 +252,7 @@ fn main_impl(mut args: Args) {
                 args.rocksdb_stats_period_sec,
                 args.rocksdb_files_limit,
                 args.rocksdb_mem_budget,
+                args.in_memory_db,
                 args.long_payload,
             )
             .run(until);
@@ -261,7 +266,8 @@ fn main_impl(mut args: Args) {
     }
 
     // Benchmark the DAG validation time
-    let (_lifetime2, db2) = create_temp_db!(ConnBuilder::default().with_parallelism(num_cpus::get()).with_files_limit(default_fd));
+    let conn_builder = ConnBuilder::default().with_parallelism(num_cpus::get()).with_files_limit(default_fd);
+    let (_lifetime2, db2) = if args.in_memory_db { create_memory_db!(conn_builder) } else { create_temp_db!(conn_builder) };
     let (dummy_notification_sender, _) = unbounded();
     let notification_root = Arc::new(ConsensusNotificationRoot::new(dummy_notification_sender));
     let consensus2 = Arc::new(Consensus::new(
@@ -11,7 +11,7 @@ use spectre_consensus::consensus::Consensus;
 use spectre_consensus_core::block::Block;
 use spectre_database::prelude::ConnBuilder;
 use spectre_database::utils::DbLifetime;
-use spectre_database::{create_permanent_db, create_temp_db};
+use spectre_database::{create_memory_db, create_permanent_db, create_temp_db};
 use spectre_utils::fd_budget;
 use spectre_utils::sim::Simulation;
 
@@ -50,6 +50,7 @@ impl SpectreNetworkSimulator {
         rocksdb_stats_period_sec: Option<u32>,
         rocksdb_files_limit: Option<i32>,
         rocksdb_mem_budget: Option<usize>,
+        in_memory_db: bool,
         long_payload: bool,
     ) -> &mut Self {
         let secp = secp256k1::Secp256k1::new();
@@ -69,6 +70,8 @@ impl SpectreNetworkSimulator {
                 (true, Some(dir), true, None) => create_permanent_db!(dir, builder.enable_stats()),
                 (true, Some(dir), false, _) => create_permanent_db!(dir, builder),
 
+                _ if in_memory_db => create_memory_db!(builder),
+
                 (_, _, true, Some(rocksdb_stats_period_sec)) => {
                     create_temp_db!(builder.enable_stats().with_stats_period(rocksdb_stats_period_sec))
                 }
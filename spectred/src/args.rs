@@ -6,10 +6,11 @@ use spectre_consensus_core::{
     network::{NetworkId, NetworkType},
 };
 use spectre_core::spectred_env::version;
+use spectre_hashes::Hash;
 use spectre_notify::address::tracker::Tracker;
 use spectre_utils::networking::ContextualNetAddress;
 use spectre_wrpc_server::address::WrpcNetAddress;
-use std::{ffi::OsString, fs};
+use std::{ffi::OsString, fs, path::PathBuf};
 use toml::from_str;
 
 #[cfg(feature = "devnet-prealloc")]
@@ -28,6 +29,7 @@ pub struct Args {
     // NOTE: it is best if property names match config file fields
     pub appdir: Option<String>,
     pub logdir: Option<String>,
+    pub backupdir: Option<String>,
     #[serde(rename = "nologfiles")]
     pub no_log_files: bool,
     #[serde_as(as = "Option<DisplayFromStr>")]
@@ -36,6 +38,8 @@ pub struct Args {
     pub rpclisten_borsh: Option<WrpcNetAddress>,
     #[serde_as(as = "Option<DisplayFromStr>")]
     pub rpclisten_json: Option<WrpcNetAddress>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub rpclisten_http: Option<ContextualNetAddress>,
     #[serde(rename = "unsaferpc")]
     pub unsafe_rpc: bool,
     pub wrpc_verbose: bool,
@@ -89,7 +93,35 @@ pub struct Args {
     pub disable_dns_seeding: bool,
     #[serde(rename = "nogrpc")]
     pub disable_grpc: bool,
+    pub grpc_reflection: bool,
     pub ram_scale: f64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub stratum_listen: Option<ContextualNetAddress>,
+    pub stratum_difficulty: f64,
+    pub stratum_shares_per_minute: u32,
+    #[serde(rename = "assumevalid")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub assume_valid: Option<Hash>,
+
+    #[serde(skip)]
+    pub command: Option<NodeCommand>,
+}
+
+/// Offline commands running over the node databases instead of starting the node
+#[derive(Debug, Clone)]
+pub enum NodeCommand {
+    /// Export the pruning point state to a snapshot file
+    ExportSnapshot(PathBuf),
+    /// Bootstrap the node from a snapshot file
+    ImportSnapshot(PathBuf),
+    /// Export the blocks in the future of the pruning point to a block file
+    ExportBlocks(PathBuf),
+    /// Feed the blocks of a block file to consensus
+    ImportBlocks(PathBuf),
+    /// Replace the node databases with a backup created through RPC
+    RestoreBackup(PathBuf),
+    /// Check the consistency of the node databases, repairing the recoverable inconsistencies if set
+    VerifyDb { repair: bool },
 }
 
 impl Default for Args {
@@ -99,6 +131,7 @@ impl Default for Args {
             no_log_files: false,
             rpclisten_borsh: None,
             rpclisten_json: None,
+            rpclisten_http: None,
             unsafe_rpc: false,
             async_threads: num_cpus::get(),
             utxoindex: false,
@@ -116,6 +149,7 @@ impl Default for Args {
             archival: false,
             sanity: false,
             logdir: None,
+            backupdir: None,
             rpclisten: None,
             wrpc_verbose: false,
             log_level: "INFO".into(),
@@ -139,7 +173,13 @@ impl Default for Args {
             disable_upnp: false,
             disable_dns_seeding: false,
             disable_grpc: false,
+            grpc_reflection: false,
             ram_scale: 1.0,
+            stratum_listen: None,
+            stratum_difficulty: 1.0,
+            stratum_shares_per_minute: 20,
+            assume_valid: None,
+            command: None,
         }
     }
 }
@@ -159,6 +199,10 @@ impl Args {
         config.p2p_listen_address = self.listen.unwrap_or(ContextualNetAddress::unspecified());
         config.externalip = self.externalip.map(|v| v.normalize(config.default_p2p_port()));
         config.ram_scale = self.ram_scale;
+        if let Some(assume_valid) = self.assume_valid {
+            // The zero hash explicitly disables assume-valid, overriding any network parameter
+            config.params.assume_valid = (assume_valid != Hash::default()).then_some(assume_valid);
+        }
 
         #[cfg(feature = "devnet-prealloc")]
         if let Some(num_prealloc_utxos) = self.num_prealloc_utxos {
@@ -202,6 +246,7 @@ pub fn cli() -> Command {
         .arg(arg!(-b --appdir <DATA_DIR> "Directory to store data."))
         .arg(arg!(--logdir <LOG_DIR> "Directory to log output."))
         .arg(arg!(--nologfiles "Disable logging to files."))
+        .arg(arg!(--backupdir <BACKUP_DIR> "Directory to store the database backups created through RPC."))
         .arg(
             Arg::new("async_threads")
                 .short('t')
@@ -250,6 +295,16 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(WrpcNetAddress))
                 .help("Interface:port to listen for wRPC JSON connections (default port: 20110, testnet: 20210)."),
         )
+        .arg(
+            Arg::new("rpclisten-http")
+                .long("rpclisten-http")
+                .value_name("IP[:PORT]")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("127.0.0.1")
+                .value_parser(clap::value_parser!(ContextualNetAddress))
+                .help("Interface:port to listen for HTTP JSON-RPC and REST connections (default port: 21110, testnet: 21210)."),
+        )
         .arg(arg!(--unsaferpc "Enable RPC commands which affect the state of the node"))
         .arg(
             Arg::new("connect-peers")
@@ -299,7 +354,7 @@ pub fn cli() -> Command {
                 .value_name("rpcmaxclients")
                 .require_equals(true)
                 .value_parser(clap::value_parser!(usize))
-                .help("Max number of RPC clients for standard connections (default: 128)."),
+                .help("Max number of RPC clients for standard connections, also bounding the HTTP requests processed concurrently (default: 128)."),
         )
         .arg(arg!(--"reset-db" "Reset database before starting node. It's needed when switching between subnetworks."))
         .arg(arg!(--"enable-unsynced-mining" "Allow the node to accept blocks from RPC while not synced (this flag is mainly used for testing)"))
@@ -361,6 +416,7 @@ Setting to 0 prevents the preallocation and sets the maximum to {}, leading to 0
         .arg(arg!(--"disable-upnp" "Disable upnp"))
         .arg(arg!(--"nodnsseed" "Disable DNS seeding for peers"))
         .arg(arg!(--"nogrpc" "Disable gRPC server"))
+        .arg(arg!(--"grpc-reflection" "Enable the gRPC server reflection service, allowing tools such as grpcurl to introspect the RPC protocol"))
         .arg(
             Arg::new("ram-scale")
                 .long("ram-scale")
@@ -369,6 +425,81 @@ Setting to 0 prevents the preallocation and sets the maximum to {}, leading to 0
                 .help("Apply a scale factor to memory allocation bounds. Nodes with limited RAM (~4-8GB) should set this to ~0.3-0.5 respectively. Nodes with
 a large RAM (~64GB) can set this value to ~3.0-4.0 and gain superior performance especially for syncing peers faster"),
         )
+        .arg(
+            Arg::new("stratum-listen")
+                .long("stratum-listen")
+                .value_name("IP[:PORT]")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(ContextualNetAddress))
+                .help("Interface:port to listen for Stratum miner connections (default port: 5555). The Stratum server is disabled if not set."),
+        )
+        .arg(
+            Arg::new("stratum-difficulty")
+                .long("stratum-difficulty")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(f64))
+                .help("Initial share difficulty assigned to Stratum connections (default: 1.0)."),
+        )
+        .arg(
+            Arg::new("stratum-shares-per-minute")
+                .long("stratum-shares-per-minute")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u32))
+                .help("Target share rate of a Stratum connection used to adjust its difficulty, 0 disabling variable difficulty (default: 20)."),
+        )
+        .arg(
+            Arg::new("assumevalid")
+                .long("assumevalid")
+                .value_name("BLOCK_HASH")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(Hash))
+                .help("Skip script verification for the transactions in the selected chain past of this block, while still checking all other consensus rules (default: disabled, all scripts being verified unless --assumevalid is given; the zero hash also disables it)."),
+        )
+        .subcommand(
+            Command::new("snapshot")
+                .about("Export or import a snapshot of the pruning point state (the node must not be running)")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("export")
+                        .about("Export the pruning point proof, trusted data, headers and UTXO set to a snapshot file")
+                        .arg(Arg::new("file").required(true).value_name("FILE").value_parser(clap::value_parser!(PathBuf))),
+                )
+                .subcommand(
+                    Command::new("import")
+                        .about("Bootstrap the node database from a snapshot file, validating it against the header chain")
+                        .arg(Arg::new("file").required(true).value_name("FILE").value_parser(clap::value_parser!(PathBuf))),
+                ),
+        )
+        .subcommand(
+            Command::new("blocks")
+                .about("Export or import the DAG blocks without any P2P connection (the node must not be running)")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("export")
+                        .about("Export the headers and bodies in the future of the pruning point, in topological order, to a block file")
+                        .arg(Arg::new("file").required(true).value_name("FILE").value_parser(clap::value_parser!(PathBuf))),
+                )
+                .subcommand(
+                    Command::new("import")
+                        .about("Validate and insert the blocks of a block file, along with its pruning point data if any")
+                        .arg(Arg::new("file").required(true).value_name("FILE").value_parser(clap::value_parser!(PathBuf))),
+                ),
+        )
+        .subcommand(
+            Command::new("backup")
+                .about("Manage the database backups created through RPC (the node must not be running)")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("restore")
+                        .about("Replace the node databases with the content of a backup directory")
+                        .arg(Arg::new("dir").required(true).value_name("DIR").value_parser(clap::value_parser!(PathBuf))),
+                ),
+        )
+        .subcommand(
+            Command::new("verify-db")
+                .about("Check the consistency of the consensus and utxoindex databases (the node must not be running)")
+                .arg(arg!(--repair "Repair the recoverable inconsistencies, such as missing children entries or a utxoindex drift")),
+        )
         ;
 
     #[cfg(feature = "devnet-prealloc")]
@@ -413,9 +544,11 @@ impl Args {
             appdir: m.get_one::<String>("appdir").cloned().or(defaults.appdir),
             logdir: m.get_one::<String>("logdir").cloned().or(defaults.logdir),
             no_log_files: arg_match_unwrap_or::<bool>(&m, "nologfiles", defaults.no_log_files),
+            backupdir: m.get_one::<String>("backupdir").cloned().or(defaults.backupdir),
             rpclisten: m.get_one::<ContextualNetAddress>("rpclisten").cloned().or(defaults.rpclisten),
             rpclisten_borsh: m.get_one::<WrpcNetAddress>("rpclisten-borsh").cloned().or(defaults.rpclisten_borsh),
             rpclisten_json: m.get_one::<WrpcNetAddress>("rpclisten-json").cloned().or(defaults.rpclisten_json),
+            rpclisten_http: m.get_one::<ContextualNetAddress>("rpclisten-http").cloned().or(defaults.rpclisten_http),
             unsafe_rpc: arg_match_unwrap_or::<bool>(&m, "unsaferpc", defaults.unsafe_rpc),
             wrpc_verbose: false,
             log_level: arg_match_unwrap_or::<String>(&m, "log_level", defaults.log_level),
@@ -447,7 +580,13 @@ impl Args {
             disable_upnp: arg_match_unwrap_or::<bool>(&m, "disable-upnp", defaults.disable_upnp),
             disable_dns_seeding: arg_match_unwrap_or::<bool>(&m, "nodnsseed", defaults.disable_dns_seeding),
             disable_grpc: arg_match_unwrap_or::<bool>(&m, "nogrpc", defaults.disable_grpc),
+            grpc_reflection: arg_match_unwrap_or::<bool>(&m, "grpc-reflection", defaults.grpc_reflection),
             ram_scale: arg_match_unwrap_or::<f64>(&m, "ram-scale", defaults.ram_scale),
+            stratum_listen: m.get_one::<ContextualNetAddress>("stratum-listen").cloned(),
+            stratum_difficulty: arg_match_unwrap_or::<f64>(&m, "stratum-difficulty", defaults.stratum_difficulty),
+            stratum_shares_per_minute: arg_match_unwrap_or::<u32>(&m, "stratum-shares-per-minute", defaults.stratum_shares_per_minute),
+            assume_valid: m.get_one::<Hash>("assumevalid").cloned().or(defaults.assume_valid),
+            command: parse_command(&m),
 
             #[cfg(feature = "devnet-prealloc")]
             num_prealloc_utxos: m.get_one::<u64>("num-prealloc-utxos").cloned(),
@@ -465,6 +604,27 @@ impl Args {
     }
 }
 
+fn parse_command(m: &clap::ArgMatches) -> Option<NodeCommand> {
+    match m.subcommand()? {
+        ("snapshot", m) => match m.subcommand()? {
+            ("export", m) => Some(NodeCommand::ExportSnapshot(m.get_one::<PathBuf>("file").cloned().unwrap())),
+            ("import", m) => Some(NodeCommand::ImportSnapshot(m.get_one::<PathBuf>("file").cloned().unwrap())),
+            _ => None,
+        },
+        ("blocks", m) => match m.subcommand()? {
+            ("export", m) => Some(NodeCommand::ExportBlocks(m.get_one::<PathBuf>("file").cloned().unwrap())),
+            ("import", m) => Some(NodeCommand::ImportBlocks(m.get_one::<PathBuf>("file").cloned().unwrap())),
+            _ => None,
+        },
+        ("backup", m) => match m.subcommand()? {
+            ("restore", m) => Some(NodeCommand::RestoreBackup(m.get_one::<PathBuf>("dir").cloned().unwrap())),
+            _ => None,
+        },
+        ("verify-db", m) => Some(NodeCommand::VerifyDb { repair: m.get_flag("repair") }),
+        _ => None,
+    }
+}
+
 use clap::parser::ValueSource::DefaultValue;
 use std::marker::{Send, Sync};
 fn arg_match_unwrap_or<T: Clone + Send + Sync + 'static>(m: &clap::ArgMatches, arg_id: &str, default: T) -> T {
@@ -0,0 +1,86 @@
+//!
+//! Restore of the database backups created through the `CreateBackup` RPC.
+//!
+//! A backup mirrors the layout of the node data directory, holding RocksDB checkpoints of the meta, consensus and
+//! utxoindex databases, so that restoring it amounts to replacing the data directory with a copy of the backup.
+//! The utxoindex is resynced on startup if it is missing from the backup or does not match the consensus tips.
+//!
+
+use spectre_core::info;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Replaces the data directory `db_dir` with a copy of the backup at `backup_dir`.
+///
+/// The backup is first copied next to the data directory, so that the current data is only removed once the
+/// backup was fully copied.
+pub fn restore_backup(backup_dir: &Path, db_dir: &Path) -> io::Result<()> {
+    let staging_dir = staging_dir(db_dir);
+    if staging_dir.exists() {
+        fs::remove_dir_all(&staging_dir)?;
+    }
+    info!("Copying backup {} to {}", backup_dir.display(), staging_dir.display());
+    if let Err(err) = copy_dir(backup_dir, &staging_dir) {
+        let _ = fs::remove_dir_all(&staging_dir);
+        return Err(err);
+    }
+
+    if db_dir.exists() {
+        info!("Deleting data directory {}", db_dir.display());
+        fs::remove_dir_all(db_dir)?;
+    }
+    fs::rename(&staging_dir, db_dir)?;
+    info!("Restored backup {} to {}", backup_dir.display(), db_dir.display());
+    Ok(())
+}
+
+fn staging_dir(db_dir: &Path) -> PathBuf {
+    let mut name = db_dir.file_name().unwrap_or_default().to_owned();
+    name.push("-restore");
+    db_dir.with_file_name(name)
+}
+
+/// Recursively copies the content of `from` to the new directory `to`
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_backup() {
+        let root = tempfile::tempdir().unwrap();
+        let backup_dir = root.path().join("backup");
+        let db_dir = root.path().join("datadir");
+
+        fs::create_dir_all(backup_dir.join("consensus").join("consensus-001")).unwrap();
+        fs::write(backup_dir.join("consensus").join("consensus-001").join("CURRENT"), b"backup").unwrap();
+        fs::create_dir_all(backup_dir.join("meta")).unwrap();
+        fs::create_dir_all(db_dir.join("utxoindex")).unwrap();
+        fs::write(db_dir.join("utxoindex").join("CURRENT"), b"stale").unwrap();
+
+        restore_backup(&backup_dir, &db_dir).unwrap();
+
+        assert_eq!(fs::read(db_dir.join("consensus").join("consensus-001").join("CURRENT")).unwrap(), b"backup");
+        assert!(db_dir.join("meta").is_dir());
+        // Data missing from the backup does not survive the restore
+        assert!(!db_dir.join("utxoindex").exists());
+        assert!(!staging_dir(&db_dir).exists());
+        // The backup itself is left untouched
+        assert!(backup_dir.join("meta").is_dir());
+    }
+}
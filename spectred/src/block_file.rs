@@ -0,0 +1,199 @@
+//!
+//! Offline export and import of the DAG blocks, used to replay real data without any P2P connection.
+//!
+//! A block file holds the blocks in the future of the pruning point up to the sink of the exporting node, in
+//! topological order. Unless the pruning point is genesis, the file also holds the pruning point data and UTXO
+//! set, written the same way they are in a [snapshot](crate::snapshot), so that blocks in its future can be
+//! fully validated.
+//!
+//! The file opens with [`BLOCK_FILE_MAGIC`], the format version and a [`SnapshotInfo`]. Each block follows
+//! as a record prefixed by its little-endian `u32` length, the list terminated by a zero length.
+//!
+
+use crate::snapshot::{
+    create_file, finalized, for_each_hashes_chunk, import_utxo_set, read_file_info, with_staging_consensus, write_file_info,
+    write_pruning_point_data, write_utxo_set, PruningPointData, SnapshotError, SnapshotInfo, SnapshotResult,
+};
+use futures::{executor::block_on, future::try_join_all};
+use spectre_consensus_core::{api::ConsensusApi, block::Block, config::Config, header::Header, tx::Transaction};
+use spectre_consensusmanager::ConsensusManager;
+use spectre_core::info;
+use std::{
+    fs::File,
+    io::{BufReader, Read, Write},
+    path::Path,
+    sync::Arc,
+    time::Instant,
+};
+
+/// Magic bytes opening a block file
+pub const BLOCK_FILE_MAGIC: &[u8; 8] = b"SPRBLKS\0";
+
+/// Current version of the block file format
+pub const BLOCK_FILE_VERSION: u16 = 1;
+
+/// Upper bound of the size of a single block record, protecting the import from allocating on corrupted lengths
+const MAX_BLOCK_RECORD_SIZE: usize = 1 << 26;
+
+/// Number of blocks submitted to consensus before awaiting their processing
+const IMPORT_BATCH_SIZE: usize = 1 << 10;
+
+/// Reads the magic bytes, the format version and the summary opening a block file
+pub fn read_block_file_info(reader: &mut impl Read) -> SnapshotResult<SnapshotInfo> {
+    read_file_info(reader, "block file", BLOCK_FILE_MAGIC, BLOCK_FILE_VERSION)
+}
+
+fn write_block_record(writer: &mut impl Write, block: &Block) -> SnapshotResult<()> {
+    let bytes = bincode::serialize(&(&block.header, &block.transactions))?;
+    writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Reads the next block record, returning `None` once the terminating zero length is reached
+fn read_block_record(reader: &mut impl Read) -> SnapshotResult<Option<Block>> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    if len == 0 {
+        return Ok(None);
+    }
+    if len > MAX_BLOCK_RECORD_SIZE {
+        return Err(SnapshotError::Invalid("the block record exceeds the maximum size"));
+    }
+    let mut bytes = vec![0u8; len];
+    reader.read_exact(&mut bytes)?;
+    let (header, mut transactions): (Header, Vec<Transaction>) = bincode::deserialize(&bytes)?;
+    transactions.iter_mut().for_each(Transaction::finalize);
+    Ok(Some(Block::from_arcs(finalized(header), Arc::new(transactions))))
+}
+
+/// Exports the blocks of `consensus` in the future of its pruning point to a block file at `path`. Blocks whose body
+/// is missing are exported as headers only.
+///
+/// The file is removed if the export fails.
+pub fn export_blocks(consensus: &dyn ConsensusApi, config: &Config, path: &Path) -> SnapshotResult<SnapshotInfo> {
+    let info = SnapshotInfo::new(consensus, config)?;
+    info!("Exporting the blocks in the future of pruning point {} up to sink {}", info.pruning_point, info.sink);
+    create_file(path, |writer| {
+        write_file_info(writer, BLOCK_FILE_MAGIC, BLOCK_FILE_VERSION, &info)?;
+
+        if info.pruning_point != config.genesis.hash {
+            let anticone_len = write_pruning_point_data(consensus, writer)?;
+            let utxo_count = write_utxo_set(consensus, &info, writer)?;
+            info!("Exported the pruning point data with {} anticone blocks and {} UTXOs", anticone_len, utxo_count);
+        }
+
+        let mut block_count = 0;
+        for_each_hashes_chunk(consensus, config, info.pruning_point, info.sink, |hashes| {
+            for hash in hashes {
+                write_block_record(writer, &consensus.get_block_even_if_header_only(hash)?)?;
+                block_count += 1;
+            }
+            info!("Exported {} blocks", block_count);
+            Ok(())
+        })?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        Ok(())
+    })?;
+    Ok(info)
+}
+
+/// Submits the block records of `reader` to `consensus` in batches, awaiting the processing of each batch.
+/// Returns the number of blocks.
+fn import_block_records(consensus: &dyn ConsensusApi, reader: &mut impl Read) -> SnapshotResult<usize> {
+    let start = Instant::now();
+    let mut block_count = 0;
+    let mut done = false;
+    while !done {
+        let mut jobs = Vec::with_capacity(IMPORT_BATCH_SIZE);
+        while jobs.len() < IMPORT_BATCH_SIZE {
+            let Some(block) = read_block_record(reader)? else {
+                done = true;
+                break;
+            };
+            jobs.push(consensus.validate_and_insert_block(block).virtual_state_task);
+        }
+        if jobs.is_empty() {
+            break;
+        }
+        block_count += jobs.len();
+        block_on(try_join_all(jobs))?;
+        info!("Processed {} blocks", block_count);
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    info!("Processed {} blocks in {:.2}s ({:.2} blocks/s)", block_count, elapsed, block_count as f64 / elapsed.max(f64::EPSILON));
+    Ok(block_count)
+}
+
+/// Imports the block file at `path` by feeding its blocks to consensus, without any P2P connection.
+///
+/// Files exported from a genesis pruning point are imported into the current consensus, so that blocks it already
+/// knows are skipped. Otherwise, the pruning point data is validated like a snapshot and the blocks are imported
+/// into a staging consensus, which replaces the current consensus once all blocks were processed.
+pub fn import_blocks(consensus_manager: &Arc<ConsensusManager>, config: &Config, path: &Path) -> SnapshotResult<SnapshotInfo> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let info = read_block_file_info(&mut reader)?;
+    if info.network_id != config.net {
+        return Err(SnapshotError::NetworkMismatch(info.network_id, config.net));
+    }
+    info!("Importing the blocks in the future of pruning point {} up to sink {}", info.pruning_point, info.sink);
+
+    if info.pruning_point == config.genesis.hash {
+        import_block_records(&*consensus_manager.consensus().unguarded_session_blocking(), &mut reader)?;
+        return Ok(info);
+    }
+
+    let pruning_point_data =
+        PruningPointData::read(&mut reader, &*consensus_manager.consensus().unguarded_session_blocking(), config, &info)?;
+
+    with_staging_consensus(consensus_manager, |consensus| {
+        pruning_point_data.apply(consensus)?;
+        let utxo_count = import_utxo_set(consensus, &info, &mut reader)?;
+        info!("Imported {} UTXOs with commitment {}", utxo_count, info.utxo_commitment);
+
+        import_block_records(consensus, &mut reader)?;
+        if consensus.get_block_status(info.sink).is_none() {
+            return Err(SnapshotError::Invalid("the file blocks do not reach the file sink"));
+        }
+        consensus.validate_pruning_points()?;
+        Ok(())
+    })?;
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spectre_consensus_core::subnets::SUBNETWORK_ID_COINBASE;
+    use spectre_hashes::Hash;
+
+    #[test]
+    fn test_block_record_round_trip() {
+        let header =
+            Header::new_finalized(1, vec![vec![Hash::from(1)]], 2.into(), 3.into(), 4.into(), 5, 6, 7, 8, 9.into(), 10, 11.into());
+        let coinbase = Transaction::new(0, vec![], vec![], 0, SUBNETWORK_ID_COINBASE, 0, vec![1, 2, 3]);
+        let blocks = [Block::new(header.clone(), vec![coinbase]), Block::from_header(header)];
+
+        let mut bytes = Vec::new();
+        blocks.iter().for_each(|block| write_block_record(&mut bytes, block).unwrap());
+        bytes.extend(0u32.to_le_bytes());
+
+        let mut reader = bytes.as_slice();
+        for block in blocks.iter() {
+            let decoded = read_block_record(&mut reader).unwrap().unwrap();
+            assert_eq!(decoded.hash(), block.hash());
+            assert_eq!(*decoded.transactions, *block.transactions);
+        }
+        assert!(read_block_record(&mut reader).unwrap().is_none());
+
+        // A corrupted header no longer matches its cached hash
+        let mut corrupted = bytes.clone();
+        corrupted[4 + 32] ^= 1;
+        assert_ne!(read_block_record(&mut corrupted.as_slice()).unwrap().unwrap().hash(), blocks[0].hash());
+
+        let mut oversized = Vec::new();
+        oversized.extend((MAX_BLOCK_RECORD_SIZE as u32 + 1).to_le_bytes());
+        assert!(matches!(read_block_record(&mut oversized.as_slice()), Err(SnapshotError::Invalid(_))));
+    }
+}
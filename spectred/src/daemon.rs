@@ -6,15 +6,17 @@ use spectre_consensus_core::{
     errors::config::{ConfigError, ConfigResult},
 };
 use spectre_consensus_notify::{root::ConsensusNotificationRoot, service::NotifyService};
-use spectre_core::{core::Core, debug, info, trace};
+use spectre_core::{core::Core, debug, info, service::Service, trace};
 use spectre_core::{spectred_env::version, task::tick::TickService};
 use spectre_database::{
     prelude::{CachePolicy, DbWriter, DirectDbWriter},
     registry::DatabaseStorePrefixes,
 };
 use spectre_grpc_server::service::GrpcService;
+use spectre_http_server::HttpService;
 use spectre_notify::{address::tracker::Tracker, subscription::context::SubscriptionContext};
 use spectre_rpc_service::service::RpcCoreService;
+use spectre_stratum::{service::DEFAULT_STRATUM_PORT, stats::StratumStats, StratumConfig, StratumService};
 use spectre_txscript::caches::TxScriptCacheCounters;
 use spectre_utils::git;
 use spectre_utils::networking::ContextualNetAddress;
@@ -50,7 +52,13 @@ pub const DESIRED_DAEMON_SOFT_FD_LIMIT: u64 = 8 * 1024;
 /// this value may impact the database performance).
 pub const MINIMUM_DAEMON_SOFT_FD_LIMIT: u64 = 4 * 1024;
 
-use crate::args::Args;
+use crate::{
+    args::{Args, NodeCommand},
+    backup::restore_backup,
+    block_file::{export_blocks, import_blocks},
+    snapshot::{export_snapshot, import_snapshot},
+    verify_db::verify_db,
+};
 
 const DEFAULT_DATA_DIR: &str = "datadir";
 const CONSENSUS_DB: &str = "consensus";
@@ -58,6 +66,7 @@ const UTXOINDEX_DB: &str = "utxoindex";
 const META_DB: &str = "meta";
 const META_DB_FILE_LIMIT: i32 = 5;
 const DEFAULT_LOG_DIR: &str = "logs";
+const DEFAULT_BACKUP_DIR: &str = "backups";
 
 fn get_home_dir() -> PathBuf {
     #[cfg(target_os = "windows")]
@@ -162,6 +171,20 @@ pub fn get_log_dir(args: &Args) -> Option<String> {
     log_dir
 }
 
+/// Get the database backup directory from the supplied [`Args`].
+pub fn get_backup_dir(args: &Args) -> PathBuf {
+    let network = args.network();
+    let app_dir = get_app_dir_from_args(args);
+
+    // Backups directory is usually under the application directory, unless otherwise specified
+    let backup_dir = args.backupdir.clone().unwrap_or_default().replace('~', get_home_dir().as_path().to_str().unwrap());
+    if backup_dir.is_empty() {
+        app_dir.join(network.to_prefixed()).join(DEFAULT_BACKUP_DIR)
+    } else {
+        PathBuf::from(backup_dir)
+    }
+}
+
 impl Runtime {
     pub fn from_args(args: &Args) -> Self {
         let log_dir = get_log_dir(args);
@@ -252,6 +275,9 @@ pub fn create_core_with_runtime(runtime: &Runtime, args: &Args, fd_total_budget:
             info!("Logs to console only");
         }
     }
+    if let Some(assume_valid) = config.assume_valid {
+        info!("Skipping script verification for the selected chain past of assume-valid block {}", assume_valid);
+    }
 
     let consensus_db_dir = db_dir.join(CONSENSUS_DB);
     let utxoindex_db_dir = db_dir.join(UTXOINDEX_DB);
@@ -349,24 +375,17 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
                     let ghostdag_prefix = ghostdag_prefix_vec.as_slice();
 
                     // This section is used to count the records to be deleted. It's not used for the actual delete.
-                    for result in consensus_db.iterator(rocksdb::IteratorMode::From(ghostdag_prefix, rocksdb::Direction::Forward)) {
-                        let (key, _) = result.unwrap();
-                        if !key.starts_with(&[DatabaseStorePrefixes::Ghostdag.into()]) {
-                            break;
-                        }
-
+                    for result in consensus_db.prefix_iterator(&[DatabaseStorePrefixes::Ghostdag.into()], Some(ghostdag_prefix)) {
+                        result.unwrap();
                         gd_record_count += 1;
                     }
 
                     let compact_prefix_vec = DatabaseStorePrefixes::GhostdagCompact.into_iter().chain(start_level_bytes).collect_vec();
                     let compact_prefix = compact_prefix_vec.as_slice();
 
-                    for result in consensus_db.iterator(rocksdb::IteratorMode::From(compact_prefix, rocksdb::Direction::Forward)) {
-                        let (key, _) = result.unwrap();
-                        if !key.starts_with(&[DatabaseStorePrefixes::GhostdagCompact.into()]) {
-                            break;
-                        }
-
+                    for result in consensus_db.prefix_iterator(&[DatabaseStorePrefixes::GhostdagCompact.into()], Some(compact_prefix))
+                    {
+                        result.unwrap();
                         compact_record_count += 1;
                     }
 
@@ -524,7 +543,7 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
         None
     };
 
-    let (address_manager, port_mapping_extender_svc) = AddressManager::new(config.clone(), meta_db, tick_service.clone());
+    let (address_manager, port_mapping_extender_svc) = AddressManager::new(config.clone(), meta_db.clone(), tick_service.clone());
 
     let mining_manager = MiningManagerProxy::new(Arc::new(MiningManager::new_with_extended_config(
         config.target_time_per_block,
@@ -532,6 +551,7 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
         config.max_block_mass,
         config.ram_scale,
         config.block_template_cache_lifetime,
+        Some(meta_db),
         mining_counters.clone(),
     )));
     let mining_monitor =
@@ -557,6 +577,7 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
         p2p_tower_counters.clone(),
     ));
 
+    let stratum_stats = args.stratum_listen.map(|_| Arc::new(StratumStats::default()));
     let rpc_core_service = Arc::new(RpcCoreService::new(
         consensus_manager.clone(),
         notify_service.notifier(),
@@ -574,7 +595,25 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
         p2p_tower_counters.clone(),
         grpc_tower_counters.clone(),
         system_info,
+        get_backup_dir(args),
+        stratum_stats.clone(),
     ));
+    let stratum_service = args.stratum_listen.zip(stratum_stats).map(|(listen_address, stratum_stats)| {
+        Arc::new(StratumService::new(
+            listen_address.normalize(DEFAULT_STRATUM_PORT),
+            StratumConfig::new(config.prefix(), args.stratum_difficulty, args.stratum_shares_per_minute),
+            rpc_core_service.clone(),
+            stratum_stats,
+            tick_service.clone(),
+        ))
+    });
+    let http_service = args.rpclisten_http.map(|listen_address| {
+        Arc::new(HttpService::new(
+            listen_address.normalize(network.default_http_rpc_port()),
+            rpc_core_service.clone(),
+            args.rpc_max_clients,
+        ))
+    });
     let grpc_service_broadcasters: usize = 3; // TODO: add a command line argument or derive from other arg/config/host-related fields
     let grpc_service = if !args.disable_grpc {
         Some(Arc::new(GrpcService::new(
@@ -584,6 +623,7 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
             args.rpc_max_clients,
             grpc_service_broadcasters,
             grpc_tower_counters,
+            args.grpc_reflection,
         )))
     } else {
         None
@@ -603,6 +643,12 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
     if let Some(grpc_service) = grpc_service {
         async_runtime.register(grpc_service)
     }
+    if let Some(stratum_service) = stratum_service {
+        async_runtime.register(stratum_service)
+    }
+    if let Some(http_service) = http_service {
+        async_runtime.register(http_service)
+    }
     async_runtime.register(p2p_service);
     async_runtime.register(consensus_monitor);
     async_runtime.register(mining_monitor);
@@ -637,3 +683,109 @@ do you confirm? (answer y/n or pass --yes to the Spectred command line to confir
 
     (core, rpc_core_service)
 }
+
+/// Runs an offline [`NodeCommand`] over the databases of the network selected by the supplied [`Args`].
+///
+/// The node must not be running while the command runs, since the databases cannot be opened by two processes.
+/// Maps a failure to open a database, typically since its lock is held, to a command error
+fn database_in_use(err: impl std::fmt::Display) -> String {
+    format!("database is in use by a running node or cannot be opened: {err}")
+}
+
+pub fn run_command(args: &Args, command: NodeCommand, fd_total_budget: i32) -> Result<(), Box<dyn std::error::Error>> {
+    let _runtime = Runtime::from_args(args);
+    validate_args(args)?;
+
+    let network = args.network();
+    let config = Arc::new(
+        ConfigBuilder::new(network.into())
+            .adjust_perf_params_to_consensus_params()
+            .apply_args(|config| args.apply_to_config(config))
+            .build(),
+    );
+
+    let db_dir = get_app_dir_from_args(args).join(network.to_prefixed()).join(DEFAULT_DATA_DIR);
+    let consensus_db_dir = db_dir.join(CONSENSUS_DB);
+    let meta_db_dir = db_dir.join(META_DB);
+    info!("Data directory: {}", db_dir.display());
+
+    if let NodeCommand::RestoreBackup(backup_dir) = &command {
+        if !backup_dir.join(META_DB).is_dir() || !backup_dir.join(CONSENSUS_DB).is_dir() {
+            return Err(format!("{} is not a database backup", backup_dir.display()).into());
+        }
+        // Opening the meta database makes sure it is not in use by a running node
+        if meta_db_dir.exists() {
+            drop(
+                spectre_database::prelude::ConnBuilder::default()
+                    .with_db_path(meta_db_dir)
+                    .with_files_limit(META_DB_FILE_LIMIT)
+                    .build()
+                    .map_err(database_in_use)?,
+            );
+        }
+        return Ok(restore_backup(backup_dir, &db_dir)?);
+    }
+
+    fs::create_dir_all(consensus_db_dir.as_path())?;
+    fs::create_dir_all(meta_db_dir.as_path())?;
+
+    // Only the verification reads the utxoindex, which is otherwise resynced by the node on startup
+    let utxoindex_db_dir = db_dir.join(UTXOINDEX_DB);
+    let mut fd_remaining = fd_total_budget - META_DB_FILE_LIMIT;
+    let utxoindex_db = if matches!(command, NodeCommand::VerifyDb { .. }) && args.utxoindex && utxoindex_db_dir.exists() {
+        let utxo_files_limit = fd_remaining * 10 / 100;
+        fd_remaining -= utxo_files_limit;
+        Some(
+            spectre_database::prelude::ConnBuilder::default()
+                .with_db_path(utxoindex_db_dir)
+                .with_files_limit(utxo_files_limit)
+                .build()
+                .map_err(database_in_use)?,
+        )
+    } else {
+        None
+    };
+
+    let meta_db = spectre_database::prelude::ConnBuilder::default()
+        .with_db_path(meta_db_dir)
+        .with_files_limit(META_DB_FILE_LIMIT)
+        .build()
+        .map_err(database_in_use)?;
+    // Consensus notifications have no listeners while running a command, so they are drained until the factory closes the channel
+    let (notification_send, notification_recv) = unbounded();
+    let notification_drain = std::thread::spawn(move || while notification_recv.recv_blocking().is_ok() {});
+    let notification_root = Arc::new(ConsensusNotificationRoot::new(notification_send));
+    let consensus_factory = Arc::new(ConsensusFactory::new(
+        meta_db,
+        &config,
+        consensus_db_dir,
+        num_cpus::get(),
+        notification_root,
+        Default::default(),
+        Default::default(),
+        fd_remaining,
+    ));
+    let consensus_manager = Arc::new(ConsensusManager::new(consensus_factory));
+
+    // Consensus processors run for the duration of the command, so that blocks can be processed and a staging
+    // consensus can be committed the same way it is by a running node
+    let handles = consensus_manager.clone().start(Arc::new(Core::new()));
+    let result: Result<(), Box<dyn std::error::Error>> = match command {
+        NodeCommand::ExportSnapshot(path) => {
+            export_snapshot(&*consensus_manager.consensus().unguarded_session_blocking(), &config, &path)
+                .map(|_| ())
+                .map_err(Into::into)
+        }
+        NodeCommand::ImportSnapshot(path) => import_snapshot(&consensus_manager, &config, &path).map(|_| ()).map_err(Into::into),
+        NodeCommand::ExportBlocks(path) => {
+            export_blocks(&*consensus_manager.consensus().unguarded_session_blocking(), &config, &path).map(|_| ()).map_err(Into::into)
+        }
+        NodeCommand::ImportBlocks(path) => import_blocks(&consensus_manager, &config, &path).map(|_| ()).map_err(Into::into),
+        NodeCommand::VerifyDb { repair } => verify_db(&consensus_manager, utxoindex_db, repair).map(|_| ()).map_err(Into::into),
+        NodeCommand::RestoreBackup(_) => unreachable!(),
+    };
+    consensus_manager.clone().stop();
+    handles.into_iter().for_each(|handle| handle.join().unwrap());
+    notification_drain.join().unwrap();
+    result
+}
@@ -1,2 +1,6 @@
 pub mod args;
+pub mod backup;
+pub mod block_file;
 pub mod daemon;
+pub mod snapshot;
+pub mod verify_db;
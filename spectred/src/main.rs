@@ -9,7 +9,7 @@ use spectre_core::{info, signals::Signals};
 use spectre_utils::fd_budget;
 use spectred_lib::{
     args::parse_args,
-    daemon::{create_core, DESIRED_DAEMON_SOFT_FD_LIMIT, MINIMUM_DAEMON_SOFT_FD_LIMIT},
+    daemon::{create_core, run_command, DESIRED_DAEMON_SOFT_FD_LIMIT, MINIMUM_DAEMON_SOFT_FD_LIMIT},
 };
 
 #[cfg(feature = "heap")]
@@ -39,6 +39,14 @@ pub fn main() {
         }
     }
 
+    if let Some(command) = args.command.clone() {
+        if let Err(err) = run_command(&args, command, fd_budget::limit()) {
+            println!("{err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let fd_total_budget = fd_budget::limit() - args.rpc_max_clients as i32 - args.inbound_limit as i32 - args.outbound_target as i32;
     let (core, _) = create_core(args, fd_total_budget);
 
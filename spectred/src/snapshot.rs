@@ -0,0 +1,482 @@
+//!
+//! Offline snapshots of the pruning point state, used to bootstrap nodes without syncing it from the network.
+//!
+//! A snapshot holds the data a syncee receives from its peer during an IBD with headers proof: the pruning point
+//! proof, the past pruning points, the pruning point anticone with its trusted data, the headers in the future of
+//! the pruning point up to the sink of the exporting node, and the pruning point UTXO set committed to by its MuHash.
+//!
+//! The file opens with [`SNAPSHOT_MAGIC`] and the format version, followed by bincode encoded sections. The headers
+//! and the UTXO set are streamed as chunks, each list terminated by an empty chunk.
+//!
+
+use futures::{executor::block_on, future::try_join_all};
+use itertools::Itertools;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use spectre_consensus_core::{
+    api::ConsensusApi,
+    block::Block,
+    config::Config,
+    errors::{block::RuleError, consensus::ConsensusError, pruning::PruningImportError},
+    header::Header,
+    muhash::MuHashExtensions,
+    network::NetworkId,
+    pruning::{PruningPointProof, PruningPointsList, PruningProofMetadata},
+    trusted::{ExternalGhostdagData, TrustedBlock, TrustedGhostdagData, TrustedHeader},
+    tx::{Transaction, TransactionOutpoint, UtxoEntry},
+    BlueWorkType,
+};
+use spectre_consensusmanager::ConsensusManager;
+use spectre_core::info;
+use spectre_hashes::Hash;
+use spectre_muhash::MuHash;
+use spectre_p2p_lib::{
+    common::ProtocolError,
+    convert::model::trusted::{TrustedDataEntry, TrustedDataPackage},
+};
+use std::{
+    cmp::max,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// Magic bytes opening a snapshot file
+pub const SNAPSHOT_MAGIC: &[u8; 8] = b"SPRSNAP\0";
+
+/// Current version of the snapshot file format
+pub const SNAPSHOT_VERSION: u16 = 1;
+
+const HASHES_CHUNK_SIZE: usize = 1 << 10;
+const UTXOS_CHUNK_SIZE: usize = 1000;
+
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("encoding error: {0}")]
+    Encoding(#[from] bincode::Error),
+
+    #[error("the file is not a {0}")]
+    InvalidMagic(&'static str),
+
+    #[error("unsupported file version {0}, the latest supported version is {1}")]
+    UnsupportedVersion(u16, u16),
+
+    #[error("the file belongs to network {0} while the node runs on network {1}")]
+    NetworkMismatch(NetworkId, NetworkId),
+
+    #[error("the pruning point is the genesis block so there is no state to export")]
+    GenesisPruningPoint,
+
+    #[error("the UTXO set commitment {0} does not match the pruning point UTXO commitment {1}")]
+    UtxoCommitmentMismatch(Hash, Hash),
+
+    #[error("invalid file: {0}")]
+    Invalid(&'static str),
+
+    #[error(transparent)]
+    Consensus(#[from] ConsensusError),
+
+    #[error(transparent)]
+    PruningImport(#[from] PruningImportError),
+
+    #[error(transparent)]
+    Rule(#[from] RuleError),
+
+    #[error(transparent)]
+    Protocol(#[from] ProtocolError),
+}
+
+pub type SnapshotResult<T> = std::result::Result<T, SnapshotError>;
+
+/// Summary of a snapshot, stored at the head of the file
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub network_id: NetworkId,
+    pub pruning_point: Hash,
+    /// Sink of the exporting node, the exported headers or blocks reach up to it
+    pub sink: Hash,
+    pub sink_blue_work: BlueWorkType,
+    /// MuHash commitment of the pruning point UTXO set
+    pub utxo_commitment: Hash,
+}
+
+impl SnapshotInfo {
+    pub(crate) fn new(consensus: &dyn ConsensusApi, config: &Config) -> SnapshotResult<Self> {
+        let pruning_point = consensus.pruning_point();
+        let sink = consensus.get_sink();
+        Ok(Self {
+            network_id: config.net,
+            pruning_point,
+            sink,
+            sink_blue_work: consensus.get_header(sink)?.blue_work,
+            utxo_commitment: consensus.get_header(pruning_point)?.utxo_commitment,
+        })
+    }
+}
+
+pub(crate) fn write<T: Serialize + ?Sized>(writer: &mut impl Write, value: &T) -> SnapshotResult<()> {
+    Ok(bincode::serialize_into(writer, value)?)
+}
+
+pub(crate) fn read<T: DeserializeOwned>(reader: &mut impl Read) -> SnapshotResult<T> {
+    Ok(bincode::deserialize_from(reader)?)
+}
+
+/// Recomputes the hash of a header read from a file rather than trusting the cached one
+pub(crate) fn finalized(mut header: Header) -> Arc<Header> {
+    header.finalize();
+    Arc::new(header)
+}
+
+/// Writes the magic bytes and the format version opening a file, followed by its summary
+pub(crate) fn write_file_info(writer: &mut impl Write, magic: &[u8; 8], version: u16, info: &SnapshotInfo) -> SnapshotResult<()> {
+    writer.write_all(magic)?;
+    writer.write_all(&version.to_le_bytes())?;
+    write(writer, info)
+}
+
+/// Reads the magic bytes and the format version opening a file of type `name`, followed by its summary.
+/// Only the latest version of the format is supported.
+pub(crate) fn read_file_info(
+    reader: &mut impl Read,
+    name: &'static str,
+    magic: &[u8; 8],
+    latest_version: u16,
+) -> SnapshotResult<SnapshotInfo> {
+    let mut file_magic = [0u8; 8];
+    reader.read_exact(&mut file_magic)?;
+    if &file_magic != magic {
+        return Err(SnapshotError::InvalidMagic(name));
+    }
+    let mut version = [0u8; 2];
+    reader.read_exact(&mut version)?;
+    match u16::from_le_bytes(version) {
+        version if version == latest_version => read(reader),
+        version => Err(SnapshotError::UnsupportedVersion(version, latest_version)),
+    }
+}
+
+/// Reads the magic bytes, the format version and the summary opening a snapshot
+pub fn read_snapshot_info(reader: &mut impl Read) -> SnapshotResult<SnapshotInfo> {
+    read_file_info(reader, "snapshot", SNAPSHOT_MAGIC, SNAPSHOT_VERSION)
+}
+
+/// Creates the file at `path` and writes it with `f`, removing the file if writing fails
+pub(crate) fn create_file<T>(path: &Path, f: impl FnOnce(&mut BufWriter<File>) -> SnapshotResult<T>) -> SnapshotResult<T> {
+    let result = File::create(path).map_err(SnapshotError::from).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        let value = f(&mut writer)?;
+        writer.flush()?;
+        Ok(value)
+    });
+    if result.is_err() {
+        let _ = fs::remove_file(path);
+    }
+    result
+}
+
+/// Calls `f` with the chunks of hashes in the future of `low` and in the past of `high`, in topological order.
+/// `high` is included while `low` is not.
+pub(crate) fn for_each_hashes_chunk(
+    consensus: &dyn ConsensusApi,
+    config: &Config,
+    low: Hash,
+    high: Hash,
+    mut f: impl FnMut(Vec<Hash>) -> SnapshotResult<()>,
+) -> SnapshotResult<()> {
+    // Consensus requires `max_blocks > mergeset_size_limit`
+    let max_blocks = max(HASHES_CHUNK_SIZE, config.mergeset_size_limit as usize + 1);
+    let mut low = low;
+    while low != high {
+        let hashes = consensus.get_hashes_between(low, high, max_blocks)?.0;
+        low = *hashes.last().expect("low and high are different chain blocks");
+        f(hashes)?;
+    }
+    Ok(())
+}
+
+/// Writes the pruning point proof, the past pruning points and the pruning point anticone along with the trusted
+/// data required to validate blocks in its future. Returns the size of the anticone.
+pub(crate) fn write_pruning_point_data(consensus: &dyn ConsensusApi, writer: &mut impl Write) -> SnapshotResult<usize> {
+    write(writer, &*consensus.get_pruning_point_proof())?;
+    write(writer, &consensus.pruning_point_headers())?;
+
+    let trusted_data = consensus.get_pruning_point_anticone_and_trusted_data()?;
+    write(writer, &trusted_data.daa_window_blocks.iter().map(|th| (&th.header, &th.ghostdag)).collect_vec())?;
+    write(writer, &trusted_data.ghostdag_blocks.iter().map(|tg| (tg.hash, &tg.ghostdag)).collect_vec())?;
+    write(writer, &(trusted_data.anticone.len() as u64))?;
+    for hash in trusted_data.anticone.iter().copied() {
+        let block = consensus.get_block(hash)?;
+        write(writer, &(&block.header, &block.transactions))?;
+    }
+    Ok(trusted_data.anticone.len())
+}
+
+/// Pruning point data read from a file and validated in the context of the current consensus
+pub(crate) struct PruningPointData {
+    proof: PruningPointProof,
+    pruning_points: PruningPointsList,
+    trusted_set: Vec<TrustedBlock>,
+}
+
+impl PruningPointData {
+    /// Reads the pruning point data written by [`write_pruning_point_data`] and validates it in the context of the
+    /// current `consensus`, the same way the data received during an IBD with headers proof is validated
+    pub(crate) fn read(
+        reader: &mut impl Read,
+        consensus: &dyn ConsensusApi,
+        config: &Config,
+        info: &SnapshotInfo,
+    ) -> SnapshotResult<Self> {
+        let proof: PruningPointProof =
+            read::<Vec<Vec<Header>>>(reader)?.into_iter().map(|level| level.into_iter().map(finalized).collect()).collect();
+        let pruning_points: PruningPointsList = read::<Vec<Header>>(reader)?.into_iter().map(finalized).collect();
+        let daa_window = read::<Vec<(Header, ExternalGhostdagData)>>(reader)?
+            .into_iter()
+            .map(|(header, ghostdag)| TrustedHeader::new(finalized(header), ghostdag))
+            .collect_vec();
+        let ghostdag_window = read::<Vec<(Hash, ExternalGhostdagData)>>(reader)?
+            .into_iter()
+            .map(|(hash, ghostdag)| TrustedGhostdagData::new(hash, ghostdag))
+            .collect_vec();
+        let anticone_len: u64 = read(reader)?;
+        let entries = (0..anticone_len)
+            .map(|_| {
+                let (header, mut transactions): (Header, Vec<Transaction>) = read(reader)?;
+                transactions.iter_mut().for_each(Transaction::finalize);
+                Ok(TrustedDataEntry::new(Block::from_arcs(finalized(header), Arc::new(transactions)), vec![], vec![]))
+            })
+            .collect::<SnapshotResult<Vec<_>>>()?;
+
+        consensus.validate_pruning_proof(&proof, &PruningProofMetadata::new(info.sink_blue_work))?;
+
+        let pruning_point_header = proof[0].last().expect("was just ensured by validation");
+        if pruning_point_header.hash != info.pruning_point {
+            return Err(SnapshotError::Invalid("the proof pruning point is not the file pruning point"));
+        }
+        if pruning_point_header.hash == config.genesis.hash {
+            return Err(SnapshotError::GenesisPruningPoint);
+        }
+        if pruning_point_header.hash == consensus.pruning_point() {
+            return Err(SnapshotError::Invalid("the file pruning point is the same as the current pruning point"));
+        }
+        if pruning_point_header.utxo_commitment != info.utxo_commitment {
+            return Err(SnapshotError::UtxoCommitmentMismatch(info.utxo_commitment, pruning_point_header.utxo_commitment));
+        }
+
+        if pruning_points.last().map(|header| header.hash) != Some(info.pruning_point) {
+            return Err(SnapshotError::Invalid("the file pruning point is not the last pruning point in the list"));
+        }
+        if pruning_points[0].hash != config.genesis.hash {
+            return Err(SnapshotError::Invalid("the first pruning point in the list is expected to be genesis"));
+        }
+        if consensus.are_pruning_points_violating_finality(pruning_points.clone()) {
+            return Err(SnapshotError::Invalid("pruning points are violating finality"));
+        }
+
+        if entries.first().map(|entry| entry.block.hash()) != Some(info.pruning_point) {
+            return Err(SnapshotError::Invalid("the first trusted block is expected to be the pruning point"));
+        }
+        let trusted_set = TrustedDataPackage::new(daa_window, ghostdag_window).build_trusted_subdag(entries)?;
+
+        Ok(Self { proof, pruning_points, trusted_set })
+    }
+
+    /// Applies the pruning point data to a staging consensus
+    pub(crate) fn apply(self, consensus: &dyn ConsensusApi) -> SnapshotResult<()> {
+        consensus.apply_pruning_proof(self.proof, &self.trusted_set)?;
+        consensus.import_pruning_points(self.pruning_points);
+
+        info!("Processing {} trusted blocks", self.trusted_set.len());
+        for tb in self.trusted_set {
+            block_on(consensus.validate_and_insert_trusted_block(tb).virtual_state_task)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the pruning point UTXO set as chunks terminated by an empty chunk, checking it against the UTXO
+/// commitment of the pruning point. Returns the number of UTXOs.
+pub(crate) fn write_utxo_set(consensus: &dyn ConsensusApi, info: &SnapshotInfo, writer: &mut impl Write) -> SnapshotResult<usize> {
+    let mut multiset = MuHash::new();
+    let mut from_outpoint = None;
+    let mut utxo_count = 0;
+    loop {
+        let utxos = consensus.get_pruning_point_utxos(info.pruning_point, from_outpoint, UTXOS_CHUNK_SIZE, from_outpoint.is_some())?;
+        for (outpoint, entry) in utxos.iter() {
+            multiset.add_utxo(outpoint, entry);
+        }
+        utxo_count += utxos.len();
+        if !utxos.is_empty() {
+            write(writer, &utxos)?;
+        }
+        if utxos.len() < UTXOS_CHUNK_SIZE {
+            break;
+        }
+        from_outpoint = Some(utxos.last().expect("not empty by prev condition").0);
+    }
+    write(writer, &Vec::<(TransactionOutpoint, UtxoEntry)>::new())?;
+
+    let utxo_commitment = multiset.finalize();
+    if utxo_commitment != info.utxo_commitment {
+        return Err(SnapshotError::UtxoCommitmentMismatch(utxo_commitment, info.utxo_commitment));
+    }
+    Ok(utxo_count)
+}
+
+/// Imports the pruning point UTXO set written by [`write_utxo_set`] into a staging consensus. Returns the number of UTXOs.
+pub(crate) fn import_utxo_set(consensus: &dyn ConsensusApi, info: &SnapshotInfo, reader: &mut impl Read) -> SnapshotResult<usize> {
+    let mut multiset = MuHash::new();
+    let mut utxo_count = 0;
+    loop {
+        let utxos: Vec<(TransactionOutpoint, UtxoEntry)> = read(reader)?;
+        if utxos.is_empty() {
+            break;
+        }
+        utxo_count += utxos.len();
+        consensus.append_imported_pruning_point_utxos(&utxos, &mut multiset);
+    }
+    let utxo_commitment = multiset.clone().finalize();
+    if utxo_commitment != info.utxo_commitment {
+        return Err(SnapshotError::UtxoCommitmentMismatch(utxo_commitment, info.utxo_commitment));
+    }
+    consensus.import_pruning_point_utxo_set(info.pruning_point, multiset)?;
+    Ok(utxo_count)
+}
+
+/// Runs `f` over a new staging consensus, which replaces the current consensus if `f` succeeds and is discarded otherwise
+pub(crate) fn with_staging_consensus(
+    consensus_manager: &Arc<ConsensusManager>,
+    f: impl FnOnce(&dyn ConsensusApi) -> SnapshotResult<()>,
+) -> SnapshotResult<()> {
+    let staging = consensus_manager.new_staging_consensus();
+    let result = f(&*staging.unguarded_session_blocking());
+    match result {
+        Ok(()) => {
+            staging.commit();
+            info!("Committed staging consensus");
+            Ok(())
+        }
+        Err(err) => {
+            staging.cancel();
+            Err(err)
+        }
+    }
+}
+
+/// Exports the pruning point state of `consensus` to a snapshot file at `path`.
+///
+/// The file is removed if the export fails.
+pub fn export_snapshot(consensus: &dyn ConsensusApi, config: &Config, path: &Path) -> SnapshotResult<SnapshotInfo> {
+    let info = SnapshotInfo::new(consensus, config)?;
+    if info.pruning_point == config.genesis.hash {
+        return Err(SnapshotError::GenesisPruningPoint);
+    }
+    info!("Exporting the snapshot of pruning point {} with headers up to sink {}", info.pruning_point, info.sink);
+    create_file(path, |writer| {
+        write_file_info(writer, SNAPSHOT_MAGIC, SNAPSHOT_VERSION, &info)?;
+
+        let anticone_len = write_pruning_point_data(consensus, writer)?;
+        info!("Exported the pruning point proof and {} pruning point anticone blocks", anticone_len);
+
+        let mut header_count = 0;
+        for_each_hashes_chunk(consensus, config, info.pruning_point, info.sink, |hashes| {
+            let headers = hashes.into_iter().map(|hash| consensus.get_header(hash)).collect::<Result<Vec<_>, _>>()?;
+            header_count += headers.len();
+            write(writer, &headers)
+        })?;
+        write(writer, &Vec::<Header>::new())?;
+        info!("Exported {} headers", header_count);
+
+        let utxo_count = write_utxo_set(consensus, &info, writer)?;
+        info!("Exported {} UTXOs with commitment {}", utxo_count, info.utxo_commitment);
+        Ok(())
+    })?;
+    Ok(info)
+}
+
+/// Imports the snapshot file at `path` into a staging consensus, which replaces the current consensus once the
+/// snapshot was fully validated.
+///
+/// The proof is validated in the context of the current consensus, the same way a proof received during IBD is,
+/// and the UTXO set is validated against the commitment of the pruning point header.
+pub fn import_snapshot(consensus_manager: &Arc<ConsensusManager>, config: &Config, path: &Path) -> SnapshotResult<SnapshotInfo> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let info = read_snapshot_info(&mut reader)?;
+    if info.network_id != config.net {
+        return Err(SnapshotError::NetworkMismatch(info.network_id, config.net));
+    }
+    info!("Importing the snapshot of pruning point {} with headers up to sink {}", info.pruning_point, info.sink);
+
+    let pruning_point_data =
+        PruningPointData::read(&mut reader, &*consensus_manager.consensus().unguarded_session_blocking(), config, &info)?;
+
+    with_staging_consensus(consensus_manager, |consensus| {
+        pruning_point_data.apply(consensus)?;
+
+        let mut header_count = 0;
+        loop {
+            let headers: Vec<Header> = read(&mut reader)?;
+            if headers.is_empty() {
+                break;
+            }
+            header_count += headers.len();
+            let jobs = headers
+                .into_iter()
+                .map(|header| consensus.validate_and_insert_block(Block::from_header_arc(finalized(header))).virtual_state_task)
+                .collect_vec();
+            block_on(try_join_all(jobs))?;
+        }
+        if consensus.get_block_status(info.sink).is_none() {
+            return Err(SnapshotError::Invalid("the snapshot headers do not reach the snapshot sink"));
+        }
+        consensus.validate_pruning_points()?;
+        info!("Processed {} headers", header_count);
+
+        let utxo_count = import_utxo_set(consensus, &info, &mut reader)?;
+        info!("Imported {} UTXOs with commitment {}", utxo_count, info.utxo_commitment);
+        Ok(())
+    })?;
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spectre_consensus_core::network::NetworkType;
+
+    #[test]
+    fn test_snapshot_info_round_trip() {
+        let info = SnapshotInfo {
+            network_id: NetworkId::with_suffix(NetworkType::Testnet, 10),
+            pruning_point: 1.into(),
+            sink: 2.into(),
+            sink_blue_work: 1000.into(),
+            utxo_commitment: 3.into(),
+        };
+        let mut bytes = Vec::new();
+        write_file_info(&mut bytes, SNAPSHOT_MAGIC, SNAPSHOT_VERSION, &info).unwrap();
+
+        let decoded = read_snapshot_info(&mut bytes.as_slice()).unwrap();
+        assert_eq!(decoded.network_id, info.network_id);
+        assert_eq!(decoded.pruning_point, info.pruning_point);
+        assert_eq!(decoded.sink, info.sink);
+        assert_eq!(decoded.sink_blue_work, info.sink_blue_work);
+        assert_eq!(decoded.utxo_commitment, info.utxo_commitment);
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] ^= 1;
+        assert!(matches!(read_snapshot_info(&mut bad_magic.as_slice()), Err(SnapshotError::InvalidMagic("snapshot"))));
+
+        let mut bad_version = bytes.clone();
+        bad_version[SNAPSHOT_MAGIC.len()..SNAPSHOT_MAGIC.len() + 2].copy_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            read_snapshot_info(&mut bad_version.as_slice()),
+            Err(SnapshotError::UnsupportedVersion(version, SNAPSHOT_VERSION)) if version == SNAPSHOT_VERSION + 1
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+//!
+//! Offline consistency verification of the node databases.
+//!
+//! Consensus stores are checked against each other over the future of the pruning point, and the utxoindex is checked
+//! against the virtual UTXO set. Recoverable inconsistencies, such as missing children entries or a utxoindex drift, are
+//! repaired on demand, so that an unclean shutdown does not necessarily call for a database reset.
+//!
+
+use spectre_consensus_core::api::DbInconsistency;
+use spectre_consensusmanager::ConsensusManager;
+use spectre_core::{info, warn};
+use spectre_database::prelude::DB;
+use spectre_utxoindex::{errors::UtxoIndexError, UtxoIndex};
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum VerifyDbError {
+    #[error(transparent)]
+    UtxoIndex(#[from] UtxoIndexError),
+
+    #[error("{0} inconsistencies were found, run the verification with --repair to fix the recoverable ones")]
+    Inconsistent(usize),
+
+    #[error("{0} inconsistencies could not be repaired, the node database should be reset with --reset-db")]
+    Unrepairable(usize),
+}
+
+pub type VerifyDbResult<T> = std::result::Result<T, VerifyDbError>;
+
+/// Verifies the consensus database and, if `utxoindex_db` is provided, the utxoindex database. Returns all the
+/// inconsistencies found, failing if any of them was left unrepaired.
+pub fn verify_db(
+    consensus_manager: &Arc<ConsensusManager>,
+    utxoindex_db: Option<Arc<DB>>,
+    repair: bool,
+) -> VerifyDbResult<Vec<DbInconsistency>> {
+    info!("Verifying the consensus database");
+    let mut inconsistencies = consensus_manager.consensus().unguarded_session_blocking().verify_database(repair);
+    if let Some(utxoindex_db) = utxoindex_db {
+        info!("Verifying the utxoindex database");
+        inconsistencies.extend(UtxoIndex::verify(consensus_manager.clone(), utxoindex_db, repair)?);
+    }
+
+    for inconsistency in inconsistencies.iter() {
+        warn!("{}", inconsistency);
+    }
+    let unrepaired = inconsistencies.iter().filter(|inconsistency| !inconsistency.repaired).count();
+    match (unrepaired, repair) {
+        (0, _) if inconsistencies.is_empty() => {
+            info!("Database verification completed, no inconsistencies were found");
+            Ok(inconsistencies)
+        }
+        (0, _) => {
+            info!("Database verification completed, {} inconsistencies were found and repaired", inconsistencies.len());
+            Ok(inconsistencies)
+        }
+        (unrepaired, false) => Err(VerifyDbError::Inconsistent(unrepaired)),
+        (unrepaired, true) => Err(VerifyDbError::Unrepairable(unrepaired)),
+    }
+}
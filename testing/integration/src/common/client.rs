@@ -56,6 +56,10 @@ impl ListeningClient {
     pub fn virtual_daa_score_changed_listener(&self) -> Option<Listener> {
         self.listener(EventType::VirtualDaaScoreChanged)
     }
+
+    pub fn accepted_transactions_changed_listener(&self) -> Option<Listener> {
+        self.listener(EventType::AcceptedTransactionsChanged)
+    }
 }
 
 impl Deref for ListeningClient {
@@ -5,6 +5,7 @@ use spectre_database::utils::get_spectre_tempdir;
 use spectre_grpc_client::GrpcClient;
 use spectre_grpc_server::service::GrpcService;
 use spectre_notify::subscription::context::SubscriptionContext;
+use spectre_p2p_flows::{flow_context::FlowContext, service::P2pService};
 use spectre_rpc_core::notify::mode::NotificationMode;
 use spectre_rpc_service::service::RpcCoreService;
 use spectre_utils::triggers::Listener;
@@ -91,6 +92,7 @@ pub struct Daemon {
     pub core: Arc<Core>,
     grpc_server_started: Listener,
     shutdown_requested: Listener,
+    flow_context: Arc<FlowContext>,
     workers: Option<Vec<std::thread::JoinHandle<()>>>,
 
     _appdir_tempdir: TempDir,
@@ -143,7 +145,17 @@ impl Daemon {
         let shutdown_requested = rpc_core_service.core_shutdown_request_listener();
         let grpc_server = &Arc::downcast::<GrpcService>(async_service.find(GrpcService::IDENT).unwrap().arc_any()).unwrap();
         let grpc_server_started = grpc_server.started();
-        Daemon { client_manager, core, grpc_server_started, shutdown_requested, workers: None, _appdir_tempdir: appdir_tempdir }
+        let flow_context =
+            Arc::downcast::<P2pService>(async_service.find(P2pService::IDENT).unwrap().arc_any()).unwrap().flow_context();
+        Daemon {
+            client_manager,
+            core,
+            grpc_server_started,
+            shutdown_requested,
+            flow_context,
+            workers: None,
+            _appdir_tempdir: appdir_tempdir,
+        }
     }
 
     pub fn client_manager(&self) -> Arc<ClientManager> {
@@ -158,6 +170,10 @@ impl Daemon {
         self.shutdown_requested.clone()
     }
 
+    pub fn flow_context(&self) -> Arc<FlowContext> {
+        self.flow_context.clone()
+    }
+
     pub fn run(&mut self) {
         self.workers = Some(self.core.start());
     }
@@ -11,10 +11,11 @@ use spectre_consensus::consensus::test_consensus::{TestConsensus, TestConsensusF
 use spectre_consensus::model::stores::block_transactions::{
     BlockTransactionsStore, BlockTransactionsStoreReader, DbBlockTransactionsStore,
 };
+use spectre_consensus::model::stores::children::ChildrenStore;
 use spectre_consensus::model::stores::ghostdag::{GhostdagStoreReader, KType as GhostdagKType};
 use spectre_consensus::model::stores::headers::HeaderStoreReader;
 use spectre_consensus::model::stores::reachability::DbReachabilityStore;
-use spectre_consensus::model::stores::relations::DbRelationsStore;
+use spectre_consensus::model::stores::relations::{DbRelationsStore, RelationsStore, RelationsStoreReader};
 use spectre_consensus::model::stores::selected_chain::SelectedChainStoreReader;
 use spectre_consensus::params::{
     ForkActivation, Params, DEVNET_PARAMS, MAINNET_PARAMS, MAX_DIFFICULTY_TARGET, MAX_DIFFICULTY_TARGET_AS_F64,
@@ -59,7 +60,7 @@ use spectre_core::core::Core;
 use spectre_core::signals::Shutdown;
 use spectre_core::task::runtime::AsyncRuntime;
 use spectre_core::{assert_match, info};
-use spectre_database::create_temp_db;
+use spectre_database::create_memory_db;
 use spectre_database::prelude::{CachePolicy, ConnBuilder};
 use spectre_index_processor::service::IndexService;
 use spectre_math::Uint256;
@@ -126,7 +127,7 @@ fn reachability_stretch_test(use_attack_json: bool) {
     map.get_mut(&blocks[0]).unwrap().parents.push(root);
 
     // Act
-    let (_temp_db_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+    let (_temp_db_lifetime, db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
     let mut store = DbReachabilityStore::new(db.clone(), CachePolicy::Count(50_000), CachePolicy::Count(50_000));
     let mut relations = DbRelationsStore::new(db, 0, CachePolicy::Count(100_000), CachePolicy::Count(100_000)); // TODO: remove level
     let mut builder = DagBuilder::new(&mut store, &mut relations);
@@ -853,6 +854,7 @@ impl SpectredGoParams {
             pruning_proof_m: self.PruningProofM,
             payload_activation: ForkActivation::never(),
             runtime_sig_op_counting: ForkActivation::never(),
+            assume_valid: None,
         }
     }
 }
@@ -966,9 +968,9 @@ async fn json_test(file_path: &str, concurrency: bool) {
     let notify_service = Arc::new(NotifyService::new(tc.notification_root(), notification_recv, subscription_context.clone()));
 
     // External storage for storing block bodies. This allows separating header and body processing phases
-    let (_external_db_lifetime, external_storage) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+    let (_external_db_lifetime, external_storage) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
     let external_block_store = DbBlockTransactionsStore::new(external_storage, CachePolicy::Count(config.perf.block_data_cache_size));
-    let (_utxoindex_db_lifetime, utxoindex_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+    let (_utxoindex_db_lifetime, utxoindex_db) = create_memory_db!(ConnBuilder::default().with_files_limit(10));
     let consensus_manager = Arc::new(ConsensusManager::new(Arc::new(TestConsensusFactory::new(tc.clone()))));
     let utxoindex = UtxoIndex::new(consensus_manager.clone(), utxoindex_db).unwrap();
     let index_service = Arc::new(IndexService::new(
@@ -1801,6 +1803,40 @@ async fn selected_chain_test() {
     consensus.shutdown(wait_handles);
 }
 
+#[tokio::test]
+async fn verify_database_test() {
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS).skip_proof_of_work().build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    consensus.add_utxo_valid_block_with_parents(1.into(), vec![config.genesis.hash], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(2.into(), vec![config.genesis.hash], vec![]).await.unwrap();
+    for i in 3..10 {
+        consensus.add_utxo_valid_block_with_parents(i.into(), vec![(i - 1).into()], vec![]).await.unwrap();
+    }
+    consensus.add_utxo_valid_block_with_parents(10.into(), vec![9.into(), 1.into()], vec![]).await.unwrap();
+    assert!(consensus.verify_database(false).is_empty());
+
+    // Simulate a children entry lost by an unclean shutdown
+    {
+        let mut relations_write = consensus.relations_stores.write();
+        let writer = relations_write[0].default_writer();
+        relations_write[0].delete_child(writer, 4.into(), 5.into()).unwrap();
+    }
+    let inconsistencies = consensus.verify_database(false);
+    assert_eq!(inconsistencies.len(), 1);
+    assert!(!inconsistencies[0].repaired);
+
+    let inconsistencies = consensus.verify_database(true);
+    assert_eq!(inconsistencies.len(), 1);
+    assert!(inconsistencies[0].repaired);
+    assert!(consensus.relations_stores.read()[0].get_children(4.into()).unwrap().read().contains(&5.into()));
+    assert!(consensus.verify_database(false).is_empty());
+
+    consensus.shutdown(wait_handles);
+}
+
 fn assert_selected_chain_store_matches_virtual_chain(consensus: &TestConsensus) {
     let pruning_point = consensus.pruning_point();
     let iter1 = selected_chain_store_iterator(consensus, pruning_point);
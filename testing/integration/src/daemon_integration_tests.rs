@@ -5,6 +5,7 @@ use crate::common::{
     utils::{fetch_spendable_utxos, generate_tx, mine_block, wait_for},
 };
 use rand::thread_rng;
+use serde_json::{json, Value};
 use spectre_addresses::Address;
 use spectre_alloc::init_allocator_with_default_settings;
 use spectre_consensus::params::SIMNET_PARAMS;
@@ -12,11 +13,15 @@ use spectre_consensus_core::header::Header;
 use spectre_consensusmanager::ConsensusManager;
 use spectre_core::{task::runtime::AsyncRuntime, trace};
 use spectre_grpc_client::GrpcClient;
-use spectre_notify::scope::{BlockAddedScope, UtxosChangedScope, VirtualDaaScoreChangedScope};
-use spectre_rpc_core::{api::rpc::RpcApi, Notification, RpcTransactionId};
+use spectre_notify::scope::{AcceptedTransactionsChangedScope, BlockAddedScope, UtxosChangedScope, VirtualDaaScoreChangedScope};
+use spectre_rpc_core::{api::rpc::RpcApi, GetVirtualChainFromBlockRequest, Notification, RpcTransactionId, RpcUtxoEntry};
 use spectre_txscript::pay_to_address_script;
 use spectred_lib::args::Args;
 use std::{sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn daemon_sanity_test() {
@@ -121,6 +126,208 @@ async fn daemon_mining_test() {
     }
 }
 
+/// `cargo test --release --package spectre-testing-integration --lib -- daemon_integration_tests::daemon_parallel_ibd_test`
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn daemon_parallel_ibd_test() {
+    init_allocator_with_default_settings();
+    spectre_core::log::try_init_logger("INFO");
+
+    let args = Args {
+        simnet: true,
+        unsafe_rpc: true,
+        enable_unsynced_mining: true,
+        disable_upnp: true, // UPnP registration might take some time and is not needed for this test
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+
+    let mut spectred1 = Daemon::new_random_with_args(args.clone(), total_fd_limit);
+    let mut spectred2 = Daemon::new_random_with_args(args.clone(), total_fd_limit);
+    let mut spectred3 = Daemon::new_random_with_args(args, total_fd_limit);
+    let rpc_client1 = spectred1.start().await;
+    let rpc_client2 = spectred2.start().await;
+    let rpc_client3 = spectred3.start().await;
+
+    async fn wait_for_block_count(client: GrpcClient, block_count: u64) {
+        let check_client = client.clone();
+        wait_for(
+            100,
+            3000,
+            move || {
+                async fn block_count_reached(client: GrpcClient, block_count: u64) -> bool {
+                    client.get_block_dag_info().await.unwrap().block_count == block_count
+                }
+                Box::pin(block_count_reached(check_client.clone(), block_count))
+            },
+            "the node did not sync the expected number of blocks",
+        )
+        .await;
+    }
+
+    // Mine enough blocks for a body download spanning many batches, and sync daemon #2 so it can serve them as well
+    const BLOCK_COUNT: u64 = 1000;
+    let pay_address = Address::new(spectred1.network.into(), spectre_addresses::Version::PubKey, &[0; 32]);
+    rpc_client2.add_peer(format!("127.0.0.1:{}", spectred1.p2p_port).try_into().unwrap(), true).await.unwrap();
+    rpc_client1.generate_blocks(BLOCK_COUNT as u32, pay_address.clone(), vec![]).await.unwrap();
+    wait_for_block_count(rpc_client2.clone(), BLOCK_COUNT).await;
+
+    // Connecting daemon #3 to both peers triggers its IBD, with daemon #2 eligible to help whichever peer it syncs from
+    rpc_client3.add_peer(format!("127.0.0.1:{}", spectred1.p2p_port).try_into().unwrap(), true).await.unwrap();
+    rpc_client3.add_peer(format!("127.0.0.1:{}", spectred2.p2p_port).try_into().unwrap(), true).await.unwrap();
+    let check_client = rpc_client3.clone();
+    wait_for(
+        50,
+        20,
+        move || {
+            async fn peers_connected(client: GrpcClient) -> bool {
+                client.get_connected_peer_info().await.unwrap().peer_info.len() == 2
+            }
+            Box::pin(peers_connected(check_client.clone()))
+        },
+        "daemon #3 did not connect to both peers",
+    )
+    .await;
+    wait_for_block_count(rpc_client3.clone(), BLOCK_COUNT).await;
+
+    // The peer daemon #3 did not sync from must have served some of the block batches
+    assert!(spectred3.flow_context().ibd_helper_completed_jobs() > 0, "no block batch was downloaded from the helper peer");
+
+    // Expect daemon #3 to be synced and to follow the relay of new blocks
+    let last_block_hash = *rpc_client1.generate_blocks(1, pay_address, vec![]).await.unwrap().last().unwrap();
+    wait_for_block_count(rpc_client3.clone(), BLOCK_COUNT + 1).await;
+
+    let dag_info = rpc_client3.get_block_dag_info().await.unwrap();
+    assert_eq!(dag_info.sink, last_block_hash);
+    assert_eq!(dag_info.header_count, BLOCK_COUNT + 1);
+
+    for (client, daemon) in [(rpc_client1, &mut spectred1), (rpc_client2, &mut spectred2), (rpc_client3, &mut spectred3)] {
+        client.disconnect().await.unwrap();
+        drop(client);
+        daemon.shutdown();
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn daemon_http_rpc_test() {
+    init_allocator_with_default_settings();
+    spectre_core::log::try_init_logger("INFO");
+
+    let http_port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let args = Args {
+        simnet: true,
+        utxoindex: true,
+        unsafe_rpc: true,
+        enable_unsynced_mining: true,
+        disable_upnp: true, // UPnP registration might take some time and is not needed for this test
+        rpclisten_http: Some(format!("127.0.0.1:{http_port}").try_into().unwrap()),
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+
+    let mut spectred = Daemon::new_random_with_args(args, total_fd_limit);
+    let rpc_client = spectred.start().await;
+
+    let pay_address = Address::new(spectred.network.into(), spectre_addresses::Version::PubKey, &[0; 32]);
+    let sink = *rpc_client.generate_blocks(10, pay_address.clone(), vec![]).await.unwrap().last().unwrap();
+
+    /// Sends a bare HTTP/1.1 request and returns the response status code and body
+    async fn http_request(port: u16, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let request = format!(
+            "{method} {path} HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.split(' ').nth(1).unwrap().parse().unwrap(), body.to_owned())
+    }
+
+    async fn http_json(port: u16, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let (status, body) = http_request(port, method, path, body).await;
+        (status, serde_json::from_str(&body).unwrap())
+    }
+
+    // JSON-RPC call with named params
+    let call = json!({ "jsonrpc": "2.0", "id": 1, "method": "getBlock", "params": { "hash": sink, "includeTransactions": false } });
+    let (status, reply) = http_json(http_port, "POST", "/", &call.to_string()).await;
+    assert_eq!(status, 200);
+    assert_eq!(reply["id"], 1);
+    assert_eq!(reply["result"]["block"]["header"]["hash"], json!(sink));
+
+    // Batch mixing a call without params, a call with positional params, a notification and an unknown method
+    let batch = json!([
+        { "jsonrpc": "2.0", "id": "dag", "method": "getBlockDagInfo" },
+        { "jsonrpc": "2.0", "id": "block", "method": "getBlock", "params": [sink, false] },
+        { "jsonrpc": "2.0", "method": "getInfo" },
+        { "jsonrpc": "2.0", "id": null, "method": "getNothing" },
+    ]);
+    let (status, reply) = http_json(http_port, "POST", "/", &batch.to_string()).await;
+    assert_eq!(status, 200);
+    let responses = reply.as_array().unwrap();
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[0]["id"], "dag");
+    assert_eq!(responses[0]["result"]["sink"], json!(sink));
+    assert_eq!(responses[1]["result"]["block"]["header"]["hash"], json!(sink));
+    assert_eq!(responses[2]["id"], Value::Null);
+    assert_eq!(responses[2]["error"]["code"], -32601);
+
+    // Notifications only are not answered
+    let (status, body) = http_request(http_port, "POST", "/", r#"{ "jsonrpc": "2.0", "method": "ping" }"#).await;
+    assert_eq!(status, 204);
+    assert!(body.is_empty());
+
+    // Malformed body and invalid params
+    let (_, reply) = http_json(http_port, "POST", "/", "{").await;
+    assert_eq!(reply["error"]["code"], -32700);
+    let (_, reply) = http_json(http_port, "POST", "/", r#"{ "jsonrpc": "2.0", "id": 2, "method": "getBlock", "params": {} }"#).await;
+    assert_eq!(reply["error"]["code"], -32602);
+
+    // REST routes
+    let (status, info) = http_json(http_port, "GET", "/info", "").await;
+    assert_eq!(status, 200);
+    assert_eq!(info["isUtxoIndexed"], true);
+
+    let (status, block) = http_json(http_port, "GET", &format!("/blocks/{sink}"), "").await;
+    assert_eq!(status, 200);
+    assert_eq!(block["block"]["header"]["hash"], json!(sink));
+    assert!(!block["block"]["transactions"].as_array().unwrap().is_empty());
+    let (_, block) = http_json(http_port, "GET", &format!("/blocks/{sink}?includeTransactions=false"), "").await;
+    assert!(block["block"]["transactions"].as_array().unwrap().is_empty());
+    let (status, _) = http_json(http_port, "GET", "/blocks/nothex", "").await;
+    assert_eq!(status, 400);
+
+    let (status, utxos) = http_json(http_port, "GET", &format!("/addresses/{pay_address}/utxos"), "").await;
+    assert_eq!(status, 200);
+    let expected = rpc_client.get_utxos_by_addresses(vec![pay_address.clone()]).await.unwrap();
+    assert!(!expected.is_empty());
+    assert_eq!(utxos["entries"].as_array().unwrap().len(), expected.len());
+
+    // Walk the same UTXOs in pages of a single entry
+    let mut paged = 0;
+    let mut query = "limit=1".to_string();
+    loop {
+        let (status, page) = http_json(http_port, "GET", &format!("/addresses/{pay_address}/utxos?{query}"), "").await;
+        assert_eq!(status, 200);
+        assert_eq!(page["entries"].as_array().unwrap().len(), 1);
+        paged += 1;
+        let cursor = &page["nextCursor"];
+        if cursor.is_null() {
+            break;
+        }
+        let (transaction_id, index) = (cursor["outpoint"]["transactionId"].as_str().unwrap(), &cursor["outpoint"]["index"]);
+        query = format!("limit=1&cursor={transaction_id}:{index}:{}", cursor["blockDaaScore"]);
+    }
+    assert_eq!(paged, expected.len());
+    let (status, _) = http_json(http_port, "GET", &format!("/addresses/{pay_address}/utxos?limit=1&cursor=nothex"), "").await;
+    assert_eq!(status, 400);
+
+    rpc_client.disconnect().await.unwrap();
+    drop(rpc_client);
+    spectred.shutdown();
+}
+
 /// `cargo test --release --package spectre-testing-integration --lib -- daemon_integration_tests::daemon_utxos_propagation_test`
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn daemon_utxos_propagation_test() {
@@ -247,6 +454,7 @@ async fn daemon_utxos_propagation_test() {
         x.start_notify(BlockAddedScope {}.into()).await.unwrap();
         x.start_notify(UtxosChangedScope::new(vec![miner_address.clone(), user_address.clone()]).into()).await.unwrap();
         x.start_notify(VirtualDaaScoreChangedScope {}.into()).await.unwrap();
+        x.start_notify(AcceptedTransactionsChangedScope::new(vec![user_address.clone()]).into()).await.unwrap();
     }
 
     // Mine some extra blocks so the latest miner reward is added to its balance and some UTXOs reach maturity
@@ -273,6 +481,7 @@ async fn daemon_utxos_propagation_test() {
     // Drain UTXOs and Virtual DAA score changed notification channels
     clients.iter().for_each(|x| x.utxos_changed_listener().unwrap().drain());
     clients.iter().for_each(|x| x.virtual_daa_score_changed_listener().unwrap().drain());
+    clients.iter().for_each(|x| x.accepted_transactions_changed_listener().unwrap().drain());
 
     // Spend some coins - sending funds from miner address to user address
     // The transaction here is later used to verify utxo return address RPC
@@ -342,6 +551,53 @@ async fn daemon_utxos_propagation_test() {
 
     assert_eq!(miner_address, utxo_return_address);
 
+    // Check accepted transactions changed notifications, the transaction being accepted by the last mined chain block
+    for x in clients.iter() {
+        let Notification::AcceptedTransactionsChanged(atc) =
+            tokio::time::timeout(Duration::from_secs(1), x.accepted_transactions_changed_listener().unwrap().receiver.recv())
+                .await
+                .unwrap()
+                .unwrap()
+        else {
+            panic!("wrong notification type")
+        };
+        assert!(!atc.incomplete);
+        assert!(atc.unaccepted.is_empty());
+        assert_eq!(atc.accepted.len(), 1);
+        assert_eq!(atc.accepted[0].accepting_block_hash, x.get_sink().await.unwrap().sink);
+        assert_eq!(atc.accepted[0].accepted_transactions.len(), 1);
+        let accepted_transaction = &atc.accepted[0].accepted_transactions[0];
+        assert_eq!(accepted_transaction.transaction.verbose_data.as_ref().unwrap().transaction_id, transaction.id());
+        assert_eq!(accepted_transaction.input_utxo_entries.len() as u64, NUMBER_INPUTS);
+    }
+
+    // Walk the virtual chain in pages, checking the accepted transaction is returned with the UTXO entries it spends
+    let mut start_hash = spectre_consensus::params::SIMNET_GENESIS.hash;
+    let mut added_chain_blocks = 0;
+    let mut accepted_transaction = None;
+    loop {
+        let request = GetVirtualChainFromBlockRequest::new(start_hash, false).with_limit(4).with_accepted_transactions();
+        let vc = rpc_client1.get_virtual_chain_from_block_call(None, request).await.unwrap();
+        assert!(vc.added_chain_block_hashes.len() <= 4);
+        assert_eq!(vc.accepted_transactions.len(), vc.added_chain_block_hashes.len());
+        added_chain_blocks += vc.added_chain_block_hashes.len();
+        accepted_transaction = accepted_transaction.or(vc
+            .accepted_transactions
+            .into_iter()
+            .flat_map(|x| x.accepted_transactions)
+            .find(|x| x.transaction.verbose_data.as_ref().unwrap().transaction_id == transaction.id()));
+        match vc.next_start_hash {
+            Some(next_start_hash) => start_hash = next_start_hash,
+            None => break,
+        }
+    }
+    assert_eq!(added_chain_blocks as u64, rpc_client1.get_block_dag_info().await.unwrap().block_count);
+    let accepted_transaction = accepted_transaction.expect("the transaction was accepted by a chain block");
+    assert_eq!(
+        accepted_transaction.input_utxo_entries,
+        utxos[0..NUMBER_INPUTS as usize].iter().map(|(_, entry)| RpcUtxoEntry::from(entry.clone())).collect::<Vec<_>>()
+    );
+
     // Terminate multi-listener clients
     for x in clients.iter() {
         x.disconnect().await.unwrap();
@@ -1,21 +1,31 @@
-use std::{str::FromStr, sync::Arc, time::Duration};
+use std::{path::PathBuf, str::FromStr, sync::Arc, time::Duration};
 
 use crate::common::{client_notify::ChannelNotify, daemon::Daemon};
 use futures_util::future::try_join_all;
 use spectre_addresses::{Address, Prefix, Version};
 use spectre_consensus::params::SIMNET_GENESIS;
-use spectre_consensus_core::{constants::MAX_SOMPI, header::Header, subnets::SubnetworkId, tx::Transaction};
+use spectre_consensus_core::{
+    constants::MAX_SOMPI,
+    header::Header,
+    subnets::SubnetworkId,
+    tx::{Transaction, TransactionInput, TransactionOutpoint, TransactionOutput},
+};
 use spectre_core::{assert_match, info};
 use spectre_grpc_core::ops::SpectredPayloadOps;
 use spectre_hashes::Hash;
 use spectre_notify::{
     connection::{ChannelConnection, ChannelType},
     scope::{
-        BlockAddedScope, FinalityConflictScope, NewBlockTemplateScope, PruningPointUtxoSetOverrideScope, Scope,
-        SinkBlueScoreChangedScope, UtxosChangedScope, VirtualChainChangedScope, VirtualDaaScoreChangedScope,
+        AcceptedTransactionsChangedScope, BlockAddedScope, FinalityConflictScope, NewBlockTemplateScope,
+        PruningPointUtxoSetOverrideScope, Scope, SinkBlueScoreChangedScope, UtxosChangedScope, VirtualChainChangedScope,
+        VirtualDaaScoreChangedScope,
     },
 };
-use spectre_rpc_core::{api::rpc::RpcApi, model::*, Notification};
+use spectre_rpc_core::{
+    api::rpc::{RpcApi, MAX_GENERATE_BLOCKS_COUNT, MAX_TEST_MEMPOOL_ACCEPT_TRANSACTIONS},
+    model::*,
+    Notification,
+};
 use spectre_utils::{fd_budget, networking::ContextualNetAddress};
 use spectred_lib::args::Args;
 use tokio::task::JoinHandle;
@@ -90,13 +100,7 @@ async fn sanity_test() {
 
                     // and the virtual chain is the genesis only
                     let response = rpc_client
-                        .get_virtual_chain_from_block_call(
-                            None,
-                            GetVirtualChainFromBlockRequest {
-                                start_hash: SIMNET_GENESIS.hash,
-                                include_accepted_transaction_ids: false,
-                            },
-                        )
+                        .get_virtual_chain_from_block_call(None, GetVirtualChainFromBlockRequest::new(SIMNET_GENESIS.hash, false))
                         .await
                         .unwrap();
                     assert!(response.added_chain_block_hashes.is_empty());
@@ -106,14 +110,13 @@ async fn sanity_test() {
                     let GetBlockTemplateResponse { block, is_synced } = rpc_client
                         .get_block_template_call(
                             None,
-                            GetBlockTemplateRequest {
-                                pay_address: Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32]),
-                                extra_data: Vec::new(),
-                            },
+                            GetBlockTemplateRequest::new(Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32]), Vec::new())
+                                .with_payload_tags(vec!["pool".to_owned(), "rig".to_owned()]),
                         )
                         .await
                         .unwrap();
                     assert!(!is_synced);
+                    assert!(block.transactions[0].payload.ends_with(b"/pool/rig"), "the coinbase payload should end with the tags");
 
                     // Compute the expected block hash for the received block
                     let header: Header = (&block.header).into();
@@ -150,13 +153,7 @@ async fn sanity_test() {
 
                     // and the virtual chain from genesis contains the added block
                     let response = rpc_client
-                        .get_virtual_chain_from_block_call(
-                            None,
-                            GetVirtualChainFromBlockRequest {
-                                start_hash: SIMNET_GENESIS.hash,
-                                include_accepted_transaction_ids: false,
-                            },
-                        )
+                        .get_virtual_chain_from_block_call(None, GetVirtualChainFromBlockRequest::new(SIMNET_GENESIS.hash, false))
                         .await
                         .unwrap();
                     assert!(response.added_chain_block_hashes.contains(&block_hash));
@@ -175,6 +172,21 @@ async fn sanity_test() {
                     // Non-existing blocks should return an error
                     let result = rpc_client.get_current_block_color_call(None, GetCurrentBlockColorRequest { hash: 999.into() }).await;
                     assert!(result.is_err());
+
+                    // Generate blocks in-process, each mined over the previous one
+                    let pay_address = Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32]);
+                    let block_hashes = rpc_client.generate_blocks(2, pay_address.clone(), vec![]).await.unwrap();
+                    assert_eq!(block_hashes.len(), 2);
+                    let response = rpc_client.get_block(block_hashes[1], false).await.unwrap();
+                    assert!(response.header.direct_parents().contains(&block_hashes[0]));
+                    let response = rpc_client.get_sink_call(None, GetSinkRequest {}).await.unwrap();
+                    assert_eq!(response.sink, block_hashes[1]);
+
+                    // The pay address must match the network and the requested transactions must be in the mempool
+                    let mainnet_address = Address::new(Prefix::Mainnet, Version::PubKey, &[0u8; 32]);
+                    assert!(rpc_client.generate_blocks(1, mainnet_address, vec![]).await.is_err());
+                    assert!(rpc_client.generate_blocks(MAX_GENERATE_BLOCKS_COUNT + 1, pay_address.clone(), vec![]).await.is_err());
+                    assert!(rpc_client.generate_blocks(1, pay_address, vec![RpcTransactionId::from_bytes([1; 32])]).await.is_err());
                 })
             }
 
@@ -347,6 +359,89 @@ async fn sanity_test() {
                 })
             }
 
+            SpectredPayloadOps::TestMempoolAccept => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    // Build an erroneous transaction...
+                    let transaction = Transaction::new(0, vec![], vec![], 0, SubnetworkId::default(), 0, vec![]);
+                    let results = rpc_client.test_mempool_accept(vec![(&transaction).into()], false, false).await.unwrap();
+                    // ...that would be rejected by the consensus
+                    assert_eq!(results.len(), 1);
+                    assert_eq!(results[0].transaction_id, transaction.id());
+                    assert!(!results[0].allowed);
+                    assert!(results[0].reject_reason.is_some());
+
+                    // Nothing was inserted in the mempool
+                    assert!(rpc_client.get_mempool_entry(transaction.id(), true, false).await.is_err());
+
+                    // Batches are limited in size
+                    let transactions = vec![(&transaction).into(); MAX_TEST_MEMPOOL_ACCEPT_TRANSACTIONS + 1];
+                    assert!(rpc_client.test_mempool_accept(transactions, false, false).await.is_err());
+                })
+            }
+
+            SpectredPayloadOps::DecodeTransaction => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    // Build a transaction paying to a standard address and spending an unknown outpoint
+                    let address = Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32]);
+                    let script_public_key = spectre_txscript::pay_to_address_script(&address);
+                    let transaction = Transaction::new(
+                        0,
+                        vec![TransactionInput::new(TransactionOutpoint::new(Hash::from_bytes([1; 32]), 0), vec![], 0, 1)],
+                        vec![TransactionOutput::new(1_000, script_public_key.clone())],
+                        0,
+                        SubnetworkId::default(),
+                        0,
+                        vec![],
+                    );
+                    let json = serde_json::to_string(&RpcTransaction::from(&transaction)).unwrap();
+                    let response =
+                        rpc_client.decode_transaction(RpcSerializedTransaction::Json(json.clone()), false, vec![]).await.unwrap();
+                    let verbose_data = response.transaction.verbose_data.unwrap();
+                    assert_eq!(verbose_data.transaction_id, transaction.id());
+                    assert!(verbose_data.compute_mass > 0);
+                    let output_verbose_data = response.transaction.outputs[0].verbose_data.as_ref().unwrap();
+                    assert_eq!(output_verbose_data.script_public_key_address, Some(address));
+                    assert!(response.storage_mass.is_none());
+                    assert!(response.script_verifications.is_empty());
+
+                    // The node cannot resolve the spent entry, hence the script cannot be verified
+                    let response =
+                        rpc_client.decode_transaction(RpcSerializedTransaction::Json(json.clone()), true, vec![]).await.unwrap();
+                    assert_eq!(response.script_verifications.len(), 1);
+                    assert!(!response.script_verifications[0].verified);
+                    assert!(response.script_verifications[0].utxo_entry.is_none());
+
+                    // With a supplied entry, the empty signature script gets executed and rejected
+                    let entry = RpcUtxoEntry::new(2_000, script_public_key, 0, false);
+                    let response = rpc_client
+                        .decode_transaction(RpcSerializedTransaction::Json(json.clone()), true, vec![entry.clone()])
+                        .await
+                        .unwrap();
+                    assert!(response.storage_mass.is_some());
+                    assert_eq!(response.script_verifications[0].utxo_entry, Some(entry.clone()));
+                    assert!(!response.script_verifications[0].verified);
+                    assert!(response.script_verifications[0].error.is_some());
+
+                    // Supplied entries must match the inputs
+                    assert!(rpc_client
+                        .decode_transaction(RpcSerializedTransaction::Json(json), true, vec![entry.clone(), entry])
+                        .await
+                        .is_err());
+
+                    // Malformed serializations are rejected
+                    assert!(rpc_client
+                        .decode_transaction(RpcSerializedTransaction::Hex("zz".to_string()), false, vec![])
+                        .await
+                        .is_err());
+                    assert!(rpc_client
+                        .decode_transaction(RpcSerializedTransaction::Borsh(vec![1, 2, 3]), false, vec![])
+                        .await
+                        .is_err());
+                })
+            }
+
             SpectredPayloadOps::GetSubnetwork => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -389,6 +484,98 @@ async fn sanity_test() {
                 })
             }
 
+            SpectredPayloadOps::InvalidateBlock => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    // Genesis is never in the future of the virtual finality point
+                    let genesis_hash = rpc_client.get_block_dag_info().await.unwrap().pruning_point_hash;
+                    assert!(rpc_client.invalidate_block(genesis_hash).await.is_err());
+
+                    // Unknown blocks are rejected
+                    assert!(rpc_client.invalidate_block(Hash::from_bytes([1; 32])).await.is_err());
+                })
+            }
+
+            SpectredPayloadOps::ReconsiderBlock => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    assert!(rpc_client.reconsider_block(Hash::from_bytes([1; 32])).await.is_err());
+                })
+            }
+
+            SpectredPayloadOps::GenerateBlocks => {
+                tst!(op, "see SubmitBlock")
+            }
+
+            SpectredPayloadOps::CreateBackup => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let path = PathBuf::from(rpc_client.create_backup().await.unwrap());
+                    assert!(path.join("meta").is_dir());
+                    assert!(path.join("consensus").is_dir());
+                    assert!(path.join("utxoindex").is_dir());
+                })
+            }
+
+            SpectredPayloadOps::GetDatabaseStats => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client.get_database_stats().await.unwrap();
+                    for name in ["meta", "utxoindex"] {
+                        assert!(response.databases.iter().any(|db| db.name == name), "missing stats of the {name} database");
+                    }
+                    assert!(response.databases.iter().any(|db| db.name.starts_with("consensus-")));
+                    assert!(response.cache_stats.iter().all(|stats| stats.hits + stats.misses > 0));
+                })
+            }
+
+            SpectredPayloadOps::GetChainBlockByBlueScore => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    // Genesis covers the blue score 0
+                    let chain_block = rpc_client.get_chain_block_by_blue_score(0).await.unwrap();
+                    assert_eq!(chain_block.hash, SIMNET_GENESIS.hash);
+                    assert_eq!(chain_block.blue_score, 0);
+
+                    // No chain block has reached the maximal blue score
+                    assert!(rpc_client.get_chain_block_by_blue_score(u64::MAX).await.is_err());
+                })
+            }
+
+            SpectredPayloadOps::GetChainBlockByDaaScore => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let chain_block = rpc_client.get_chain_block_by_daa_score(SIMNET_GENESIS.daa_score).await.unwrap();
+                    assert_eq!(chain_block.hash, SIMNET_GENESIS.hash);
+                    assert_eq!(chain_block.daa_score, SIMNET_GENESIS.daa_score);
+                    assert!(rpc_client.get_chain_block_by_daa_score(u64::MAX).await.is_err());
+                })
+            }
+
+            SpectredPayloadOps::GetChainBlocksByBlueScoreRange => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let chain_blocks = rpc_client.get_chain_blocks_by_blue_score_range(0, u64::MAX).await.unwrap();
+                    assert_eq!(chain_blocks.first().map(|x| x.hash), Some(SIMNET_GENESIS.hash));
+                    assert!(chain_blocks
+                        .windows(2)
+                        .all(|w| w[0].blue_score < w[1].blue_score && w[0].chain_index + 1 == w[1].chain_index));
+                    assert!(rpc_client.get_chain_blocks_by_blue_score_range(u64::MAX, u64::MAX).await.unwrap().is_empty());
+                })
+            }
+
+            SpectredPayloadOps::GetChainBlocksByDaaScoreRange => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let chain_blocks =
+                        rpc_client.get_chain_blocks_by_daa_score_range(SIMNET_GENESIS.daa_score, u64::MAX).await.unwrap();
+                    assert_eq!(chain_blocks.first().map(|x| x.hash), Some(SIMNET_GENESIS.hash));
+                    assert!(chain_blocks
+                        .windows(2)
+                        .all(|w| w[0].daa_score < w[1].daa_score && w[0].chain_index + 1 == w[1].chain_index));
+                })
+            }
+
             SpectredPayloadOps::GetHeaders => {
                 let rpc_client = client.clone();
                 tst!(op, {
@@ -406,7 +593,7 @@ async fn sanity_test() {
                 tst!(op, {
                     let addresses = vec![Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32])];
                     let response =
-                        rpc_client.get_utxos_by_addresses_call(None, GetUtxosByAddressesRequest { addresses }).await.unwrap();
+                        rpc_client.get_utxos_by_addresses_call(None, GetUtxosByAddressesRequest::new(addresses)).await.unwrap();
                     assert!(response.entries.is_empty());
                 })
             }
@@ -520,12 +707,15 @@ async fn sanity_test() {
                                 process_metrics: true,
                                 storage_metrics: true,
                                 custom_metrics: true,
+                                stratum_metrics: true,
                             },
                         )
                         .await
                         .unwrap();
                     assert!(get_metrics_call_response.process_metrics.is_some());
                     assert!(get_metrics_call_response.consensus_metrics.is_some());
+                    // The Stratum server is not running
+                    assert!(get_metrics_call_response.stratum_metrics.is_none());
 
                     let get_metrics_call_response = rpc_client
                         .get_metrics_call(
@@ -537,6 +727,7 @@ async fn sanity_test() {
                                 process_metrics: true,
                                 storage_metrics: true,
                                 custom_metrics: true,
+                                stratum_metrics: true,
                             },
                         )
                         .await
@@ -554,6 +745,7 @@ async fn sanity_test() {
                                 process_metrics: false,
                                 storage_metrics: false,
                                 custom_metrics: true,
+                                stratum_metrics: true,
                             },
                         )
                         .await
@@ -571,6 +763,7 @@ async fn sanity_test() {
                                 process_metrics: false,
                                 storage_metrics: false,
                                 custom_metrics: true,
+                                stratum_metrics: true,
                             },
                         )
                         .await
@@ -673,6 +866,27 @@ async fn sanity_test() {
                 })
             }
 
+            SpectredPayloadOps::PrioritizeTransaction => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let transaction_id = RpcTransactionId::from_bytes([1; 32]);
+                    rpc_client.prioritize_transaction(transaction_id, 1_000, false).await.unwrap();
+                    let prioritized_transactions = rpc_client.get_prioritized_transactions().await.unwrap();
+                    assert_eq!(prioritized_transactions.len(), 1);
+                    assert_eq!(prioritized_transactions[0].transaction_id, transaction_id);
+                    assert_eq!(prioritized_transactions[0].fee_delta, 1_000);
+                    assert!(!prioritized_transactions[0].excluded);
+
+                    // A neutral delta clears the entry
+                    rpc_client.prioritize_transaction(transaction_id, 0, false).await.unwrap();
+                    assert!(rpc_client.get_prioritized_transactions().await.unwrap().is_empty());
+                })
+            }
+
+            SpectredPayloadOps::GetPrioritizedTransactions => {
+                tst!(op, "see PrioritizeTransaction")
+            }
+
             SpectredPayloadOps::NotifyBlockAdded => {
                 let rpc_client = client.clone();
                 let id = listener_id;
@@ -734,6 +948,13 @@ async fn sanity_test() {
                         .unwrap();
                 })
             }
+            SpectredPayloadOps::NotifyAcceptedTransactionsChanged => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, AcceptedTransactionsChangedScope::new(vec![]).into()).await.unwrap();
+                })
+            }
             SpectredPayloadOps::StopNotifyingUtxosChanged => {
                 let rpc_client = client.clone();
                 let id = listener_id;